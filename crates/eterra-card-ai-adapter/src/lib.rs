@@ -1,23 +1,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod eterra_adapter {
+    use eterra_core_types::play;
+    use eterra_core_types::rules::{
+        energy_cost, final_scores, regenerate_energy, spend_energy,
+    };
     use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
     use scale_info::TypeInfo;
 
-    // Local copies of game types so this adapter has no dependency on pallet-eterra
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
-    pub enum Possession { PlayerOne, PlayerTwo }
-
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
-    pub struct Card {
-        pub top: u8,
-        pub right: u8,
-        pub bottom: u8,
-        pub left: u8,
-        pub possession: Option<Possession>,
-    }
-
-    pub type Board = [[Option<Card>; 4]; 4];
+    // Board and card types are the canonical ones used on-chain, so no mapping is required
+    pub use eterra_core_types::board::Board;
+    pub use eterra_core_types::card::{Card, Possession};
+    pub use eterra_core_types::element::Element;
+    pub use eterra_core_types::rules::Rules;
 
     /// One hand entry (mirrors data needed to place a card)
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
@@ -27,6 +22,7 @@ pub mod eterra_adapter {
         pub south: u8,
         pub west: u8,
         pub used: bool,
+        pub element: Option<Element>,
     }
 
     /// Fixed-size hand (5 entries). If you make HandSize configurable later,
@@ -39,12 +35,14 @@ pub mod eterra_adapter {
     /// Compact, cloneable snapshot of game state used by the AI
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
     pub struct State {
-        pub board: Board,   // 4x4 Option<Card>
+        pub board: Board,   // 3x3 to 5x5 Option<Card>
         pub scores: (u8, u8),     // (p0, p1)
         pub player_turn: u8,      // 0 or 1
         pub round: u8,
         pub max_rounds: u8,
         pub hands: [Hand; 2],
+        pub rules: Rules,         // optional rules active in the game
+        pub energy: [u8; 2],      // energy pools under `Rules::ENERGY`, unused otherwise
     }
 
     impl Default for State {
@@ -56,6 +54,8 @@ pub mod eterra_adapter {
                 round: 0,
                 max_rounds: 0,
                 hands: [Hand::default(), Hand::default()],
+                rules: Rules::default(),
+                energy: [0, 0],
             }
         }
     }
@@ -64,8 +64,8 @@ pub mod eterra_adapter {
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
     pub struct Action {
         pub hand_index: u8, // 0..4
-        pub x: u8,          // 0..3
-        pub y: u8,          // 0..3
+        pub x: u8,          // 0..board dim
+        pub y: u8,          // 0..board dim
     }
 
     /// Adapter gluing your card game rules to the generic Monte-Carlo AI.
@@ -75,7 +75,20 @@ pub mod eterra_adapter {
         fn default() -> Self { Adapter }
     }
 
+    impl HandEntry {
+        fn cost(&self) -> u8 {
+            energy_cost(self.north, self.east, self.south, self.west)
+        }
+    }
+
     impl Adapter {
+        /// Energy left for the player to move after placing `entry`, `None` if unaffordable.
+        fn energy_after(s: &State, entry: &HandEntry) -> Option<u8> {
+            let hand = &s.hands[s.player_turn as usize];
+            let cheapest = hand.entries.iter().filter(|e| !e.used).map(HandEntry::cost).min()?;
+            spend_energy(s.energy[s.player_turn as usize], entry.cost(), cheapest)
+        }
+
         /// Pure helper: list actions without relying on trait resolution.
         pub fn list_actions_pure<const MAX: usize>(
             s: &State,
@@ -85,21 +98,23 @@ pub mod eterra_adapter {
                 return 0;
             }
             let mut k = 0;
-            for x in 0..4u8 {
-                for y in 0..4u8 {
-                    if s.board[x as usize][y as usize].is_some() {
+            for cell in s.board.coords() {
+                let (xi, yi) = cell.index();
+                if s.board[xi][yi].is_some() {
+                    continue;
+                }
+                for (idx, he) in s.hands[s.player_turn as usize].entries.iter().enumerate() {
+                    if he.used {
                         continue;
                     }
-                    for (idx, he) in s.hands[s.player_turn as usize].entries.iter().enumerate() {
-                        if he.used {
-                            continue;
-                        }
-                        if k < MAX {
-                            out[k] = Some(Action { hand_index: idx as u8, x, y });
-                            k += 1;
-                            if k == MAX {
-                                return k;
-                            }
+                    if s.rules.contains(Rules::ENERGY) && Self::energy_after(s, he).is_none() {
+                        continue;
+                    }
+                    if k < MAX {
+                        out[k] = Some(Action { hand_index: idx as u8, x: cell.x(), y: cell.y() });
+                        k += 1;
+                        if k == MAX {
+                            return k;
                         }
                     }
                 }
@@ -113,67 +128,33 @@ pub mod eterra_adapter {
 
             // Build a placed card from hand entry
             let he = g.hands[g.player_turn as usize].entries[a.hand_index as usize].clone();
-            let mut placed = Card {
-                top: he.north,
-                right: he.east,
-                bottom: he.south,
-                left: he.west,
-                possession: None,
-            };
-
-            let placing_player = if g.player_turn == 0 { Possession::PlayerOne } else { Possession::PlayerTwo };
-            placed.possession = Some(placing_player.clone());
-
-            // Place on board
-            g.board[a.x as usize][a.y as usize] = Some(placed.clone());
-
-            // Capture logic (mirrors pallet)
-            let dirs = [
-                (0i8, -1i8, placed.top),
-                (1, 0, placed.right),
-                (0, 1, placed.bottom),
-                (-1, 0, placed.left),
-            ];
-
-            for &(dx, dy, opposing_rank) in &dirs {
-                let nx = a.x as i8 + dx;
-                let ny = a.y as i8 + dy;
-                if nx >= 0 && nx < 4 && ny >= 0 && ny < 4 {
-                    if let Some(mut opp) = g.board[nx as usize][ny as usize].clone() {
-                        let rank = match (dx, dy) {
-                            (0, -1) => opp.bottom,
-                            (1, 0) => opp.left,
-                            (0, 1) => opp.top,
-                            (-1, 0) => opp.right,
-                            _ => 0,
-                        };
-                        if opposing_rank > rank {
-                            if let Some(prev) = opp.possession.clone() {
-                                if prev == Possession::PlayerOne {
-                                    g.scores.0 = g.scores.0.saturating_sub(1);
-                                } else if prev == Possession::PlayerTwo {
-                                    g.scores.1 = g.scores.1.saturating_sub(1);
-                                }
-                            }
-                            if placing_player == Possession::PlayerOne {
-                                g.scores.0 = g.scores.0.saturating_add(1);
-                            } else {
-                                g.scores.1 = g.scores.1.saturating_add(1);
-                            }
-                            opp.possession = Some(placing_player.clone());
-                            g.board[nx as usize][ny as usize] = Some(opp);
-                        }
-                    }
-                }
+            let placing_player = Possession::from_index(g.player_turn);
+            let placed = Card::new(he.north, he.east, he.south, he.west).with_element(he.element);
+
+            // Place on board and resolve captures with the on-chain rules (Same, Plus, Combo);
+            // actions only ever name open cells
+            let _ = play::place_card_with_rules(
+                &mut g.board,
+                &mut g.scores,
+                a.x,
+                a.y,
+                placed,
+                placing_player,
+                g.rules,
+            );
+
+            // Pay for the card before it leaves the hand
+            if g.rules.contains(Rules::ENERGY) {
+                let seat = g.player_turn as usize;
+                g.energy[seat] = Self::energy_after(&g, &he).unwrap_or(0);
             }
 
             // Mark used & advance turn/round (increment round on wrap)
             g.hands[g.player_turn as usize].entries[a.hand_index as usize].used = true;
-            if g.player_turn == 0 {
-                g.player_turn = 1;
-            } else {
-                g.player_turn = 0;
-                g.round = g.round.saturating_add(1);
+            (g.player_turn, g.round) = play::next_turn(g.player_turn, g.round);
+            if g.rules.contains(Rules::ENERGY) {
+                let seat = g.player_turn as usize;
+                g.energy[seat] = regenerate_energy(g.energy[seat]);
             }
             g
         }
@@ -199,7 +180,7 @@ pub mod eterra_adapter {
         }
 
         fn is_terminal(s: &<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::State) -> bool {
-            s.round >= s.max_rounds
+            play::is_finished(&s.board, s.round, s.max_rounds)
         }
 
         fn current_player(s: &<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::State) -> Self::Player {
@@ -210,7 +191,12 @@ pub mod eterra_adapter {
             s: &<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::State,
             for_player: Self::Player,
         ) -> i32 {
-            let (a, b) = s.scores;
+            // End-of-game bonuses only count once the game is over
+            let (a, b) = if Self::is_terminal(s) {
+                final_scores(&s.board, s.scores, s.rules)
+            } else {
+                s.scores
+            };
             if for_player == 0 { (a as i32) - (b as i32) } else { (b as i32) - (a as i32) }
         }
 
@@ -0,0 +1,129 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! SCALE types of `pallet-eterra` for off-chain clients (TypeScript/Unity bridges, bots).
+//!
+//! Everything here is re-exported from the pallet and `eterra-core-types` rather than
+//! redeclared, so a struct change on chain changes these layouts in the same build. The
+//! aliases fix the generic parameters to the solochain runtime's concrete types.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use frame_support::traits::ConstU32;
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+
+pub use eterra_core_types::board::{Board, Move};
+pub use eterra_core_types::card::{Card, Possession};
+pub use eterra_core_types::element::Element;
+pub use eterra_core_types::geometry::{BOARD_DIM, MAX_BOARD_DIM, MIN_BOARD_DIM};
+pub use eterra_core_types::rules::Rules;
+pub use pallet_eterra::{
+    AiProfile, AnnotatedMove, CardUsageStats, EndReason, GameContext, GameMode, GameStart,
+    GameState, Handicap, HandEntry, HandUsage, MoveAnnotation, OpponentHandSummary, ReplayMove,
+    TurnState, CURRENT_GAME_VERSION, GAME_EVENT_VERSION,
+};
+
+/// `AccountId32` of the runtime; encodes as its 32 raw bytes.
+pub type AccountId = [u8; 32];
+/// `H256` game id of the runtime; encodes as its 32 raw bytes.
+pub type GameId = [u8; 32];
+/// Block number type of the runtime.
+pub type BlockNumber = u32;
+/// Seats per game in the runtime (`NumPlayers`).
+pub type NumPlayers = ConstU32<2>;
+
+/// A stored game, as returned by `Eterra.GameStorage`.
+pub type Game = pallet_eterra::Game<AccountId, BlockNumber, NumPlayers>;
+/// Payload of the `Eterra.Game` event.
+pub type GameEvent = pallet_eterra::GameEvent<AccountId>;
+
+/// Fields of an `Eterra.Game` event, in encoding order.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct GameEventRecord {
+    pub version: u8,
+    pub game_id: GameId,
+    pub event: GameEvent,
+}
+
+/// Decode `bytes` as exactly one `T`, rejecting trailing data.
+pub fn decode<T: Decode>(bytes: &[u8]) -> Result<T, parity_scale_codec::Error> {
+    T::decode_all(&mut &bytes[..])
+}
+
+/// SCALE-encode `value`.
+pub fn encode<T: Encode>(value: &T) -> Vec<u8> {
+    value.encode()
+}
+
+/// Decode a `GameStorage` value; games stored before `version` existed decode as version 0.
+pub fn decode_game(bytes: &[u8]) -> Result<Game, parity_scale_codec::Error> {
+    decode(bytes)
+}
+
+/// Decode the fields of an `Eterra.Game` event. Records whose `version` is newer than
+/// `GAME_EVENT_VERSION` are rejected instead of being misread.
+pub fn decode_game_event(bytes: &[u8]) -> Result<GameEventRecord, parity_scale_codec::Error> {
+    let record: GameEventRecord = decode(bytes)?;
+    if record.version > GAME_EVENT_VERSION {
+        return Err("unsupported GameEvent version".into());
+    }
+    Ok(record)
+}
+
+/// Registry describing every exported type, for generating bindings in other languages.
+#[cfg(feature = "std")]
+pub fn type_registry() -> scale_info::PortableRegistry {
+    use scale_info::{meta_type, Registry};
+
+    let mut registry = Registry::new();
+    registry.register_types([
+        meta_type::<Game>(),
+        meta_type::<GameEvent>(),
+        meta_type::<Move>(),
+        meta_type::<HandEntry>(),
+        meta_type::<OpponentHandSummary>(),
+        meta_type::<ReplayMove>(),
+        meta_type::<AnnotatedMove>(),
+        meta_type::<GameStart>(),
+        meta_type::<CardUsageStats>(),
+        meta_type::<AiProfile>(),
+    ]);
+    registry.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_event_record_round_trips() {
+        let record = GameEventRecord {
+            version: GAME_EVENT_VERSION,
+            game_id: [7; 32],
+            event: GameEvent::Moved {
+                seat: 1,
+                x: 2,
+                y: 3,
+                next: Some(0),
+            },
+        };
+        assert_eq!(decode_game_event(&encode(&record)).unwrap(), record);
+    }
+
+    #[test]
+    fn newer_event_versions_are_rejected() {
+        let record = GameEventRecord {
+            version: GAME_EVENT_VERSION + 1,
+            game_id: [0; 32],
+            event: GameEvent::TurnSkipped { seat: 0, next: None },
+        };
+        assert!(decode_game_event(&encode(&record)).is_err());
+    }
+
+    #[test]
+    fn trailing_bytes_are_rejected() {
+        let mut bytes = encode(&Card::new(1, 2, 3, 4));
+        bytes.push(0);
+        assert!(decode::<Card>(&bytes).is_err());
+    }
+}
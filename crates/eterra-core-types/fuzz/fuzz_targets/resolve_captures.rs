@@ -0,0 +1,62 @@
+//! Run with `cargo +nightly fuzz run resolve_captures` from `crates/eterra-core-types`.
+#![no_main]
+
+use eterra_core_types::board::{resolve_captures, Board};
+use eterra_core_types::card::{Card, Possession};
+use eterra_core_types::geometry::{Coord, BOARD_DIM};
+use eterra_core_types::invariants::check_capture;
+use libfuzzer_sys::fuzz_target;
+
+// Input layout: 16 cells of 5 bytes (occupancy/owner, top, right, bottom, left), then the
+// target cell and the placer.
+const CELL_BYTES: usize = 5;
+const INPUT_LEN: usize = 16 * CELL_BYTES + 2;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < INPUT_LEN {
+        return;
+    }
+
+    let mut board: Board = Default::default();
+    let mut scores = (0u8, 0u8);
+    for (i, cell) in Coord::all(BOARD_DIM).enumerate() {
+        let b = &data[i * CELL_BYTES..(i + 1) * CELL_BYTES];
+        // 0 = empty, 1 = unowned, 2 = player one, 3 = player two
+        let mut card = Card::new(b[1] % 11, b[2] % 11, b[3] % 11, b[4] % 11);
+        match b[0] % 4 {
+            0 => continue,
+            1 => {}
+            n => {
+                let owner = Possession::from_index(n - 2);
+                match owner {
+                    Possession::PlayerOne => scores.0 += 1,
+                    Possession::PlayerTwo => scores.1 += 1,
+                }
+                card = card.with_possession(owner);
+            }
+        }
+        let (x, y) = cell.index();
+        board[x][y] = Some(card);
+    }
+
+    let tail = &data[16 * CELL_BYTES..];
+    let Some(at) = Coord::new(tail[0] % 4, (tail[0] / 4) % 4) else {
+        return;
+    };
+    let placer = Possession::from_index(tail[1] % 2);
+
+    let before = board.clone();
+    let scores_before = scores;
+    let captured = resolve_captures(&mut board, &mut scores, at, placer);
+    if let Err(violation) = check_capture(
+        &before,
+        &board,
+        scores_before,
+        scores,
+        at,
+        placer,
+        captured,
+    ) {
+        panic!("{violation:?}: board {before:?}, at {at:?}, placer {placer:?}");
+    }
+});
@@ -0,0 +1,496 @@
+use crate::card::{Card, Possession};
+use crate::element::{modifier, Element, CELL_ELEMENT_ODDS};
+use crate::geometry::{is_valid_dim, Coord, Direction, Edged, BOARD_DIM, MAX_BOARD_DIM};
+use crate::rules::Rules;
+use parity_scale_codec::{Decode, Encode, Error, Input, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+const MAX_DIM: usize = MAX_BOARD_DIM as usize;
+
+/// A square board of `dim` x `dim` cells, indexed `board[x][y]`. Cells are stored at
+/// `MAX_BOARD_DIM` whatever the dimension; those beyond `dim` stay empty.
+#[derive(Encode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+pub struct Board {
+    dim: u8,
+    cells: [[Option<Card>; MAX_DIM]; MAX_DIM],
+    /// Element of each cell under `Rules::ELEMENTAL`, indexed like `cells`.
+    elements: [[Option<Element>; MAX_DIM]; MAX_DIM],
+}
+
+impl Default for Board {
+    /// An empty `BOARD_DIM` board.
+    fn default() -> Self {
+        Self {
+            dim: BOARD_DIM,
+            cells: Default::default(),
+            elements: Default::default(),
+        }
+    }
+}
+
+impl Board {
+    /// An empty `dim` x `dim` board, or `None` if `dim` is not a supported dimension.
+    pub fn new(dim: u8) -> Option<Self> {
+        is_valid_dim(dim).then(|| Self {
+            dim,
+            ..Default::default()
+        })
+    }
+
+    pub fn dim(&self) -> u8 {
+        self.dim
+    }
+
+    /// The cell (`x`, `y`), if it is on this board.
+    pub fn coord(&self, x: u8, y: u8) -> Option<Coord> {
+        Coord::on(self.dim, x, y)
+    }
+
+    /// Every cell of this board, in storage order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        Coord::all(self.dim)
+    }
+
+    /// Contents of every cell of this board, in storage order.
+    pub fn cells(&self) -> impl Iterator<Item = &Option<Card>> + '_ {
+        self.coords().map(move |cell| {
+            let (x, y) = cell.index();
+            &self.cells[x][y]
+        })
+    }
+
+    /// Whether every cell holds a card.
+    pub fn is_full(&self) -> bool {
+        self.cells().all(Option::is_some)
+    }
+
+    /// Element of `cell`, if any.
+    pub fn element(&self, cell: Coord) -> Option<Element> {
+        let (x, y) = cell.index();
+        self.elements[x][y]
+    }
+
+    /// This board with about one cell in `CELL_ELEMENT_ODDS` given an element, each cell
+    /// rolling on the byte of `seed` (e.g. a game id) at its position in storage order.
+    /// Cells past the end of `seed` stay plain.
+    pub fn with_elements(mut self, seed: &[u8]) -> Self {
+        for (cell, byte) in Coord::all(self.dim).zip(seed.iter()) {
+            let (x, y) = cell.index();
+            self.elements[x][y] = Element::roll(*byte, CELL_ELEMENT_ODDS);
+        }
+        self
+    }
+
+    /// An empty board with the dimension and cell elements of this one.
+    pub fn emptied(&self) -> Self {
+        Self {
+            dim: self.dim,
+            cells: Default::default(),
+            elements: self.elements,
+        }
+    }
+
+    /// Rank on `side` of the card at `cell`, after the modifier of the cell's element, or
+    /// `None` if the cell is empty.
+    pub fn rank(&self, cell: Coord, side: Direction) -> Option<i16> {
+        let (x, y) = cell.index();
+        let card = self.cells[x][y].as_ref()?;
+        Some(card.edge(side) as i16 + modifier(card.element, self.elements[x][y]) as i16)
+    }
+}
+
+impl core::ops::Index<usize> for Board {
+    type Output = [Option<Card>; MAX_DIM];
+
+    fn index(&self, x: usize) -> &Self::Output {
+        &self.cells[x]
+    }
+}
+
+impl core::ops::IndexMut<usize> for Board {
+    fn index_mut(&mut self, x: usize) -> &mut Self::Output {
+        &mut self.cells[x]
+    }
+}
+
+impl Decode for Board {
+    /// Boards stored before dimensions existed are `BOARD_DIM` x `BOARD_DIM` bare cells,
+    /// whose first byte is an `Option` tag (0 or 1); every other board starts with its
+    /// dimension, which is at least 3. Both decode, so stored games and puzzles need no
+    /// migration.
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let first = input.read_byte()?;
+        let mut board = Board::default();
+        if first > 1 {
+            if !is_valid_dim(first) {
+                return Err("unsupported board dimension".into());
+            }
+            board.dim = first;
+            board.cells = Decode::decode(input)?;
+            board.elements = Decode::decode(input)?;
+            return Ok(board);
+        }
+        for (i, cell) in Coord::all(BOARD_DIM).enumerate() {
+            let (x, y) = cell.index();
+            let present = if i == 0 {
+                first == 1
+            } else {
+                bool::decode(input)?
+            };
+            if present {
+                board.cells[x][y] = Some(decode_legacy_card(input)?);
+            }
+        }
+        Ok(board)
+    }
+}
+
+/// A card of a legacy board, stored before cards had an element.
+fn decode_legacy_card<I: Input>(input: &mut I) -> Result<Card, Error> {
+    let (top, right, bottom, left, possession): (u8, u8, u8, u8, Option<Possession>) =
+        Decode::decode(input)?;
+    Ok(Card {
+        possession,
+        ..Card::new(top, right, bottom, left)
+    })
+}
+
+#[derive(Encode, Decode, TypeInfo, PartialEq, Clone, Debug)]
+pub struct Move {
+    pub place_index_x: u8,
+    pub place_index_y: u8,
+    pub place_card: Card,
+}
+
+/// Cells on the largest board, and so the most flips a combo can chain.
+pub const MAX_COMBO_FLIPS: usize = MAX_DIM * MAX_DIM;
+
+/// Flip every opponent-owned neighbor of the card at `at` whose facing edge is strictly
+/// beaten (ties do not capture), moving one point per flip from the opponent to `placer`.
+/// Edges are compared by `Board::rank`, so cell elements apply.
+///
+/// Returns the number of captured cards. Does nothing if `at` is empty.
+pub fn resolve_captures(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    at: Coord,
+    placer: Possession,
+) -> u8 {
+    capture_neighbors(board, scores, at, placer, |_| {})
+}
+
+/// `resolve_captures` for `Rules::COMBO`: each flipped card captures around itself as if it
+/// had just been placed, until a pass flips nothing. Flips only ever go to `placer`, so no
+/// card flips twice and the chain ends after at most `MAX_COMBO_FLIPS` flips.
+///
+/// Returns the number of captured cards, chained ones included.
+pub fn resolve_combo_captures(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    at: Coord,
+    placer: Possession,
+) -> u8 {
+    resolve_captures_with_rules(board, scores, at, placer, Rules(Rules::COMBO))
+}
+
+/// Captures of the card just placed at `at` under the capture rules enabled by `rules`.
+/// `Rules::SAME` and `Rules::PLUS` are checked once, around the placed card, before the
+/// usual captures; under `Rules::COMBO` every card flipped by any rule then keeps capturing
+/// as in `resolve_combo_captures`. Without any of them this is `resolve_captures`.
+///
+/// Returns the number of captured cards.
+pub fn resolve_captures_with_rules(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    at: Coord,
+    placer: Possession,
+    rules: Rules,
+) -> u8 {
+    let combo = rules.contains(Rules::COMBO);
+    let mut pending = [at; MAX_COMBO_FLIPS];
+    let (mut next, mut queued) = (0, 1);
+    let mut captured = same_plus_captures(board, scores, at, placer, rules, |cell| {
+        if combo {
+            pending[queued] = cell;
+            queued += 1;
+        }
+    });
+    while next < queued {
+        let from = pending[next];
+        next += 1;
+        let flipped = capture_neighbors(board, scores, from, placer, |cell| {
+            if combo && queued < MAX_COMBO_FLIPS {
+                pending[queued] = cell;
+                queued += 1;
+            }
+        });
+        captured = captured.saturating_add(flipped);
+    }
+    captured
+}
+
+/// Flip the opponent neighbors of the card at `at` matched by `Rules::SAME` or
+/// `Rules::PLUS`, whichever `rules` enables. A match needs at least two neighbors, but own
+/// cards count towards it. Ranks include elemental modifiers, as in `resolve_captures`.
+/// `on_flip` is called with every cell that was flipped.
+fn same_plus_captures(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    at: Coord,
+    placer: Possession,
+    rules: Rules,
+    mut on_flip: impl FnMut(Coord),
+) -> u8 {
+    let (same, plus) = (rules.contains(Rules::SAME), rules.contains(Rules::PLUS));
+    if !same && !plus {
+        return 0;
+    }
+
+    // Each occupied neighbor with the placed card's rank and its own facing rank.
+    let mut touching = [(at, 0i16, 0i16); 4];
+    let mut len = 0;
+    for (dir, cell) in at.neighbors() {
+        if let (Some(mine), Some(theirs)) = (board.rank(at, dir), board.rank(cell, dir.opposite()))
+        {
+            touching[len] = (cell, mine, theirs);
+            len += 1;
+        }
+    }
+    let touching = &touching[..len];
+    let equal_edges = touching
+        .iter()
+        .filter(|(_, mine, theirs)| mine == theirs)
+        .count();
+
+    let mut captured = 0u8;
+    for &(cell, mine, theirs) in touching {
+        let same_match = same && mine == theirs && equal_edges >= 2;
+        let plus_match = plus
+            && touching
+                .iter()
+                .filter(|(_, m, t)| m + t == mine + theirs)
+                .count()
+                >= 2;
+        if (same_match || plus_match) && flip(board, scores, cell, placer) {
+            captured = captured.saturating_add(1);
+            on_flip(cell);
+        }
+    }
+    captured
+}
+
+/// Shared by the capture rules; `on_flip` is called with every cell that was flipped.
+fn capture_neighbors(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    at: Coord,
+    placer: Possession,
+    mut on_flip: impl FnMut(Coord),
+) -> u8 {
+    let mut captured = 0u8;
+    for (dir, cell) in at.neighbors() {
+        let (Some(mine), Some(theirs)) = (board.rank(at, dir), board.rank(cell, dir.opposite()))
+        else {
+            continue;
+        };
+        if mine <= theirs {
+            continue;
+        }
+        if flip(board, scores, cell, placer) {
+            captured = captured.saturating_add(1);
+            on_flip(cell);
+        }
+    }
+    captured
+}
+
+/// Hand the card at `cell` to `placer`, moving one point from its owner. Returns `false`,
+/// changing nothing, if the cell is empty, unowned or already held by `placer`.
+fn flip(board: &mut Board, scores: &mut (u8, u8), cell: Coord, placer: Possession) -> bool {
+    let (x, y) = cell.index();
+    let Some(card) = board[x][y].as_mut() else {
+        return false;
+    };
+    let Some(owner) = card.possession.filter(|owner| *owner != placer) else {
+        return false;
+    };
+    card.possession = Some(placer);
+    match owner {
+        Possession::PlayerOne => scores.0 = scores.0.saturating_sub(1),
+        Possession::PlayerTwo => scores.1 = scores.1.saturating_sub(1),
+    }
+    match placer {
+        Possession::PlayerOne => scores.0 = scores.0.saturating_add(1),
+        Possession::PlayerTwo => scores.1 = scores.1.saturating_add(1),
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boards_decode_with_and_without_a_dimension() {
+        let mut board = Board::new(5)
+            .unwrap()
+            .with_elements(&[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        board[4][3] = Some(
+            Card::new(1, 2, 3, 4)
+                .with_possession(Possession::PlayerTwo)
+                .with_element(Some(Element::Earth)),
+        );
+        assert_eq!(Board::decode(&mut &board.encode()[..]).unwrap(), board);
+        assert!(Board::new(6).is_none() && Board::new(2).is_none());
+
+        // A board stored before dimensions existed: sixteen bare cells of element-less cards.
+        type LegacyCard = (u8, u8, u8, u8, Option<Possession>);
+        let mut legacy: [[Option<LegacyCard>; 4]; 4] = Default::default();
+        legacy[0][0] = Some((9, 9, 9, 9, Some(Possession::PlayerOne)));
+        legacy[3][1] = Some((1, 1, 1, 1, None));
+        let decoded = Board::decode(&mut &legacy.encode()[..]).unwrap();
+        assert_eq!(decoded.dim(), BOARD_DIM);
+        let nines = Card::new(9, 9, 9, 9).with_possession(Possession::PlayerOne);
+        assert_eq!(decoded[0][0], Some(nines));
+        assert_eq!(decoded[3][1], Some(Card::new(1, 1, 1, 1)));
+        assert_eq!(decoded.cells().filter(|c| c.is_some()).count(), 2);
+        assert!(decoded.coords().all(|cell| decoded.element(cell).is_none()));
+        legacy[0][0] = None;
+        let decoded = Board::decode(&mut &legacy.encode()[..]).unwrap();
+        assert_eq!(
+            (decoded[0][0].clone(), decoded[3][1].clone()),
+            (None, Some(Card::new(1, 1, 1, 1)))
+        );
+    }
+
+    #[test]
+    fn captures_only_strictly_beaten_opponent_cards() {
+        let mut board: Board = Default::default();
+        let mut scores = (0u8, 2u8);
+        // Opponent cards to the right (weaker) and below (tied); own card to the left.
+        board[2][1] = Some(Card::new(1, 1, 1, 3).with_possession(Possession::PlayerTwo));
+        board[1][2] = Some(Card::new(5, 1, 1, 1).with_possession(Possession::PlayerTwo));
+        board[0][1] = Some(Card::new(1, 1, 1, 1).with_possession(Possession::PlayerOne));
+        board[1][1] = Some(Card::new(1, 5, 5, 5).with_possession(Possession::PlayerOne));
+
+        let at = Coord::new(1, 1).unwrap();
+        assert_eq!(
+            resolve_captures(&mut board, &mut scores, at, Possession::PlayerOne),
+            1
+        );
+        assert_eq!(scores, (1, 1));
+        assert_eq!(
+            board[2][1].as_ref().unwrap().possession,
+            Some(Possession::PlayerOne)
+        );
+        assert_eq!(
+            board[1][2].as_ref().unwrap().possession,
+            Some(Possession::PlayerTwo)
+        );
+        assert_eq!(
+            board[0][1].as_ref().unwrap().possession,
+            Some(Possession::PlayerOne)
+        );
+    }
+
+    #[test]
+    fn combos_chain_captures_through_flipped_cards() {
+        // Along the top row: the placed card beats (1, 0), which beats (2, 0), which loses
+        // to (3, 0).
+        let mut start: Board = Default::default();
+        start[0][0] = Some(Card::new(1, 9, 1, 1).with_possession(Possession::PlayerOne));
+        start[1][0] = Some(Card::new(1, 5, 1, 1).with_possession(Possession::PlayerTwo));
+        start[2][0] = Some(Card::new(1, 1, 1, 3).with_possession(Possession::PlayerTwo));
+        start[3][0] = Some(Card::new(1, 1, 1, 9).with_possession(Possession::PlayerTwo));
+        let at = Coord::new(0, 0).unwrap();
+        let owner = |board: &Board, x: usize| board[x][0].as_ref().unwrap().possession;
+
+        let (mut board, mut scores) = (start.clone(), (1u8, 3u8));
+        assert_eq!(
+            resolve_captures(&mut board, &mut scores, at, Possession::PlayerOne),
+            1
+        );
+        assert_eq!(
+            (scores, owner(&board, 2)),
+            ((2, 2), Some(Possession::PlayerTwo))
+        );
+
+        let (mut board, mut scores) = (start, (1u8, 3u8));
+        assert_eq!(
+            resolve_combo_captures(&mut board, &mut scores, at, Possession::PlayerOne),
+            2
+        );
+        assert_eq!(scores, (3, 1));
+        assert_eq!(owner(&board, 2), Some(Possession::PlayerOne));
+        assert_eq!(owner(&board, 3), Some(Possession::PlayerTwo));
+    }
+
+    #[test]
+    fn same_and_plus_flip_matched_neighbors_once_two_match() {
+        // Around (1, 1): equal edges above (opponent) and to the right (own), edge sums of
+        // 14 below and to the left (both opponent). No edge is beaten outright.
+        let mut start: Board = Default::default();
+        start[1][1] = Some(Card::new(3, 4, 5, 6).with_possession(Possession::PlayerOne));
+        start[1][0] = Some(Card::new(1, 5, 3, 1).with_possession(Possession::PlayerTwo));
+        start[2][1] = Some(Card::new(1, 1, 1, 4).with_possession(Possession::PlayerOne));
+        start[1][2] = Some(Card::new(9, 1, 1, 1).with_possession(Possession::PlayerTwo));
+        start[0][1] = Some(Card::new(1, 8, 1, 1).with_possession(Possession::PlayerTwo));
+        // Only reachable by chaining from (1, 0).
+        start[2][0] = Some(Card::new(1, 1, 1, 2).with_possession(Possession::PlayerTwo));
+        let at = Coord::new(1, 1).unwrap();
+        let captures = |rules: u8| {
+            let (mut board, mut scores) = (start.clone(), (2u8, 4u8));
+            let n = resolve_captures_with_rules(
+                &mut board,
+                &mut scores,
+                at,
+                Possession::PlayerOne,
+                Rules(rules),
+            );
+            (n, scores)
+        };
+
+        assert_eq!(captures(0), (0, (2, 4)));
+        assert_eq!(captures(Rules::SAME), (1, (3, 3)));
+        assert_eq!(captures(Rules::PLUS), (2, (4, 2)));
+        assert_eq!(captures(Rules::SAME | Rules::PLUS), (3, (5, 1)));
+        assert_eq!(captures(Rules::SAME | Rules::COMBO), (2, (4, 2)));
+        assert_eq!(
+            captures(Rules::SAME | Rules::PLUS | Rules::COMBO),
+            (4, (6, 0))
+        );
+    }
+
+    #[test]
+    fn cell_elements_raise_matching_cards_and_lower_clashing_ones() {
+        // Byte 0 makes (0, 0) a Fire cell; byte 1 leaves (0, 1) plain.
+        let start = Board::default().with_elements(&[0, 1]);
+        let fire = Coord::new(0, 0).unwrap();
+        assert_eq!(start.element(fire), Some(Element::Fire));
+        assert_eq!(start.element(Coord::new(0, 1).unwrap()), None);
+        assert_eq!(start.emptied(), start);
+
+        // A 5 below a Fire cell against a 5 on top of the plain cell under it.
+        let below = Some(Card::new(5, 1, 1, 1).with_possession(Possession::PlayerTwo));
+        let captures = |element: Option<Element>| {
+            let mut board = start.clone();
+            board[0][1] = below.clone();
+            let placed = Card::new(1, 1, 5, 1).with_element(element);
+            board[0][0] = Some(placed.with_possession(Possession::PlayerOne));
+            let mut scores = (1u8, 1u8);
+            (
+                resolve_captures(&mut board, &mut scores, fire, Possession::PlayerOne),
+                board,
+            )
+        };
+
+        assert_eq!(captures(Some(Element::Fire)).0, 1);
+        assert_eq!(captures(None).0, 0);
+        assert_eq!(captures(Some(Element::Earth)).0, 0);
+        let (captured, board) = captures(Some(Element::Water));
+        assert_eq!(
+            (captured, board.rank(fire, Direction::Bottom)),
+            (0, Some(4))
+        );
+    }
+}
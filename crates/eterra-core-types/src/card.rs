@@ -0,0 +1,77 @@
+use crate::element::Element;
+use crate::geometry::{Direction, Edged};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Card {
+    pub top: u8,
+    pub right: u8,
+    pub bottom: u8,
+    pub left: u8,
+    pub possession: Option<Possession>, // None if not yet assigned
+    /// Element of the card, if any; only matters under `Rules::ELEMENTAL`.
+    pub element: Option<Element>,
+}
+
+impl Card {
+    pub fn new(top: u8, right: u8, bottom: u8, left: u8) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+            possession: None,
+            element: None,
+        }
+    }
+
+    pub fn with_element(mut self, element: Option<Element>) -> Self {
+        self.element = element;
+        self
+    }
+
+    pub fn with_possession(mut self, possession: Possession) -> Self {
+        self.possession = Some(possession);
+        self
+    }
+
+    pub fn get_possession(&self) -> Option<&Possession> {
+        self.possession.as_ref()
+    }
+}
+
+impl Edged for Card {
+    fn edge(&self, side: Direction) -> u8 {
+        match side {
+            Direction::Top => self.top,
+            Direction::Right => self.right,
+            Direction::Bottom => self.bottom,
+            Direction::Left => self.left,
+        }
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Possession {
+    PlayerOne,
+    PlayerTwo,
+}
+
+impl Possession {
+    /// Seat `0` is `PlayerOne`, anything else `PlayerTwo`.
+    pub fn from_index(ix: u8) -> Self {
+        if ix == 0 {
+            Possession::PlayerOne
+        } else {
+            Possession::PlayerTwo
+        }
+    }
+
+    pub fn index(self) -> u8 {
+        match self {
+            Possession::PlayerOne => 0,
+            Possession::PlayerTwo => 1,
+        }
+    }
+}
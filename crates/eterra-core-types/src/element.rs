@@ -0,0 +1,73 @@
+//! Elements of cards and board cells under `Rules::ELEMENTAL`. A card on a cell of its own
+//! element ranks one higher on every side; on a cell of the opposite element, one lower.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Board cells out of which one is elemental, on average.
+pub const CELL_ELEMENT_ODDS: u8 = 4;
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Element {
+    Fire,
+    Water,
+    Earth,
+    Wind,
+}
+
+impl Element {
+    pub const ALL: [Element; 4] = [Element::Fire, Element::Water, Element::Earth, Element::Wind];
+
+    /// The element this one clashes with.
+    pub fn opposite(self) -> Element {
+        match self {
+            Element::Fire => Element::Water,
+            Element::Water => Element::Fire,
+            Element::Earth => Element::Wind,
+            Element::Wind => Element::Earth,
+        }
+    }
+
+    /// An element for one in `one_in` values of `byte`, spread evenly over `ALL`, and `None`
+    /// for the others.
+    pub fn roll(byte: u8, one_in: u8) -> Option<Element> {
+        let one_in = one_in.max(1);
+        byte.is_multiple_of(one_in)
+            .then(|| Self::ALL[(byte / one_in) as usize % Self::ALL.len()])
+    }
+}
+
+/// Rank change of a card of element `card` placed on a cell of element `cell`: +1 when
+/// they match, -1 when they clash and 0 otherwise, including on plain cells.
+pub fn modifier(card: Option<Element>, cell: Option<Element>) -> i8 {
+    match (card, cell) {
+        (Some(card), Some(cell)) if card == cell => 1,
+        (Some(card), Some(cell)) if card.opposite() == cell => -1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_elements_boost_and_opposite_ones_weaken() {
+        assert_eq!(modifier(Some(Element::Fire), Some(Element::Fire)), 1);
+        assert_eq!(modifier(Some(Element::Fire), Some(Element::Water)), -1);
+        assert_eq!(modifier(Some(Element::Fire), Some(Element::Earth)), 0);
+        assert_eq!(modifier(None, Some(Element::Wind)), 0);
+        assert_eq!(modifier(Some(Element::Wind), None), 0);
+
+        let rolled: usize = (0..=u8::MAX)
+            .filter(|b| Element::roll(*b, 4).is_some())
+            .count();
+        assert_eq!(rolled, 64);
+        for element in Element::ALL {
+            let n = (0..=u8::MAX)
+                .filter(|b| Element::roll(*b, 4) == Some(element))
+                .count();
+            assert_eq!(n, 16);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Width and height of the (square) board of games that do not pick another size.
+pub const BOARD_DIM: u8 = 4;
+/// Smallest board dimension a game may be played on.
+pub const MIN_BOARD_DIM: u8 = 3;
+/// Largest board dimension a game may be played on.
+pub const MAX_BOARD_DIM: u8 = 5;
+
+/// Whether games may be played on a `dim` x `dim` board.
+pub fn is_valid_dim(dim: u8) -> bool {
+    (MIN_BOARD_DIM..=MAX_BOARD_DIM).contains(&dim)
+}
+
+/// The four orthogonal sides of a cell (and of a card).
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Direction {
+    /// All directions in the order captures are resolved.
+    pub const ALL: [Direction; 4] = [
+        Direction::Top,
+        Direction::Right,
+        Direction::Bottom,
+        Direction::Left,
+    ];
+
+    /// Board delta `(dx, dy)` when stepping in this direction (`y` grows downwards).
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Direction::Top => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Bottom => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    /// The side facing back towards us from the neighbor in this direction.
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Top => Direction::Bottom,
+            Direction::Right => Direction::Left,
+            Direction::Bottom => Direction::Top,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+/// Anything with a rank on each of its four sides (on-chain cards, hand entries, AI cards).
+pub trait Edged {
+    fn edge(&self, side: Direction) -> u8;
+}
+
+/// A cell position guaranteed to be on a board of `dim` x `dim` cells.
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub struct Coord {
+    x: u8,
+    y: u8,
+    dim: u8,
+}
+
+impl Coord {
+    /// Returns `None` when `(x, y)` is off a `BOARD_DIM` board.
+    pub fn new(x: u8, y: u8) -> Option<Self> {
+        Self::on(BOARD_DIM, x, y)
+    }
+
+    /// Returns `None` when `(x, y)` is off a `dim` x `dim` board.
+    pub fn on(dim: u8, x: u8, y: u8) -> Option<Self> {
+        if x < dim && y < dim {
+            Some(Self { x, y, dim })
+        } else {
+            None
+        }
+    }
+
+    pub fn x(self) -> u8 {
+        self.x
+    }
+
+    pub fn y(self) -> u8 {
+        self.y
+    }
+
+    /// Dimension of the board the cell is on.
+    pub fn dim(self) -> u8 {
+        self.dim
+    }
+
+    /// `(x, y)` as array indices, i.e. `board[x][y]`.
+    pub fn index(self) -> (usize, usize) {
+        (self.x as usize, self.y as usize)
+    }
+
+    /// The adjacent cell in `dir`, if it is on the board.
+    pub fn step(self, dir: Direction) -> Option<Coord> {
+        let (dx, dy) = dir.delta();
+        let nx = self.x as i8 + dx;
+        let ny = self.y as i8 + dy;
+        if nx < 0 || ny < 0 {
+            return None;
+        }
+        Coord::on(self.dim, nx as u8, ny as u8)
+    }
+
+    /// On-board neighbors with the direction they lie in, in `Direction::ALL` order.
+    pub fn neighbors(self) -> impl Iterator<Item = (Direction, Coord)> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(d).map(|c| (d, c)))
+    }
+
+    /// Every cell of a `dim` x `dim` board, column-major (`x` outer, `y` inner) as boards
+    /// are stored.
+    pub fn all(dim: u8) -> impl Iterator<Item = Coord> {
+        (0..dim).flat_map(move |x| (0..dim).map(move |y| Coord { x, y, dim }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coord_bounds_and_neighbors() {
+        assert!(Coord::new(BOARD_DIM, 0).is_none());
+        let corner = Coord::new(0, 0).unwrap();
+        let dirs: Vec<Direction> = corner.neighbors().map(|(d, _)| d).collect();
+        assert_eq!(dirs, vec![Direction::Right, Direction::Bottom]);
+        assert_eq!(Coord::new(1, 1).unwrap().neighbors().count(), 4);
+        assert_eq!(Coord::all(BOARD_DIM).count(), (BOARD_DIM * BOARD_DIM) as usize);
+        // Edges of smaller boards have no neighbors beyond them.
+        let edge = Coord::on(MIN_BOARD_DIM, MIN_BOARD_DIM - 1, 1).unwrap();
+        assert_eq!(edge.neighbors().count(), 3);
+        assert!(Coord::on(MAX_BOARD_DIM, BOARD_DIM, BOARD_DIM).is_some());
+        assert!(!is_valid_dim(MAX_BOARD_DIM + 1) && !is_valid_dim(MIN_BOARD_DIM - 1));
+        for d in Direction::ALL {
+            assert_eq!(d.opposite().opposite(), d);
+        }
+    }
+}
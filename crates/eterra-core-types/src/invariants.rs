@@ -0,0 +1,235 @@
+//! Invariants every capture resolution must uphold, shared by the randomized tests below and
+//! the `resolve_captures` fuzz target.
+
+use crate::board::Board;
+use crate::card::Possession;
+use crate::geometry::Coord;
+
+/// Which invariant a capture resolution broke.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Violation {
+    /// A card owned by the placer changed hands.
+    SelfCapture,
+    /// A card that is not adjacent to the placed card changed.
+    NonNeighborChanged,
+    /// A neighbor flipped without being strictly beaten, or was strictly beaten but kept.
+    WrongFlip,
+    /// The sum of both scores changed.
+    ScoreNotConserved,
+    /// The returned capture count, the flipped cards and the score delta disagree.
+    CountMismatch,
+}
+
+/// Check one `resolve_captures(board, scores, at, placer)` call, given the board and scores
+/// before and after it and the capture count it returned.
+///
+/// Assumes scores are far enough from the `u8` bounds that no saturation happened.
+pub fn check_capture(
+    before: &Board,
+    after: &Board,
+    scores_before: (u8, u8),
+    scores_after: (u8, u8),
+    at: Coord,
+    placer: Possession,
+    captured: u8,
+) -> Result<(), Violation> {
+    let (px, py) = at.index();
+    let placed = before[px][py].as_ref();
+    let mut flipped = 0u8;
+
+    for cell in before.coords() {
+        let (x, y) = cell.index();
+        let (old, new) = (&before[x][y], &after[x][y]);
+        let changed = old != new;
+
+        if let Some(old_card) = old {
+            if changed && old_card.possession == Some(placer) {
+                return Err(Violation::SelfCapture);
+            }
+        }
+
+        let facing = at.neighbors().find(|(_, n)| *n == cell).map(|(dir, _)| dir);
+        let Some(dir) = facing else {
+            if changed {
+                return Err(Violation::NonNeighborChanged);
+            }
+            continue;
+        };
+
+        let should_flip = match (placed, old) {
+            (Some(_), Some(n)) => {
+                n.possession.is_some()
+                    && n.possession != Some(placer)
+                    && before.rank(at, dir) > before.rank(cell, dir.opposite())
+            }
+            _ => false,
+        };
+        let did_flip = match (old, new) {
+            (Some(o), Some(n)) => {
+                let mut expected = o.clone();
+                expected.possession = Some(placer);
+                changed && *n == expected
+            }
+            _ => false,
+        };
+        if (changed && !did_flip) || should_flip != did_flip {
+            return Err(Violation::WrongFlip);
+        }
+        if did_flip {
+            flipped += 1;
+        }
+    }
+
+    let total_before = scores_before.0 as u16 + scores_before.1 as u16;
+    let total_after = scores_after.0 as u16 + scores_after.1 as u16;
+    if total_before != total_after {
+        return Err(Violation::ScoreNotConserved);
+    }
+    let gained = match placer {
+        Possession::PlayerOne => scores_after.0 as i16 - scores_before.0 as i16,
+        Possession::PlayerTwo => scores_after.1 as i16 - scores_before.1 as i16,
+    };
+    if flipped != captured || gained != captured as i16 {
+        return Err(Violation::CountMismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::resolve_captures;
+    use crate::card::Card;
+    use crate::geometry::BOARD_DIM;
+
+    /// Small deterministic generator so the suite needs no extra dependencies.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u8 {
+            (self.next() % n) as u8
+        }
+
+        fn card(&mut self, owner: Possession) -> Card {
+            Card::new(
+                1 + self.below(10),
+                1 + self.below(10),
+                1 + self.below(10),
+                1 + self.below(10),
+            )
+            .with_possession(owner)
+        }
+    }
+
+    /// A random position plus an empty cell to play into; scores match card ownership.
+    fn random_position(rng: &mut XorShift) -> (Board, (u8, u8), Coord) {
+        let mut board: Board = Default::default();
+        let mut scores = (5u8, 5u8);
+        let filled = rng.below(16);
+        for _ in 0..filled {
+            let cell = Coord::new(rng.below(4), rng.below(4)).unwrap();
+            let (x, y) = cell.index();
+            let owner = Possession::from_index(rng.below(2));
+            board[x][y] = Some(rng.card(owner));
+        }
+        for cell in Coord::all(BOARD_DIM) {
+            let (x, y) = cell.index();
+            if let Some(owner) = board[x][y].as_ref().and_then(|c| c.possession) {
+                match owner {
+                    Possession::PlayerOne => scores.0 += 1,
+                    Possession::PlayerTwo => scores.1 += 1,
+                }
+            }
+        }
+        // At most 15 cards were placed, so at least one cell is still empty.
+        let empty: Vec<Coord> = Coord::all(BOARD_DIM)
+            .filter(|c| board[c.index().0][c.index().1].is_none())
+            .collect();
+        let at = empty[rng.below(empty.len() as u64) as usize];
+        (board, scores, at)
+    }
+
+    fn mirrored(board: &Board) -> Board {
+        let mut out = board.clone();
+        for cell in Coord::all(BOARD_DIM) {
+            let (x, y) = cell.index();
+            if let Some(card) = out[x][y].as_mut() {
+                card.possession = card
+                    .possession
+                    .map(|p| Possession::from_index(1 - p.index()));
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn random_captures_uphold_invariants() {
+        let mut rng = XorShift(0x5eed_cafe_f00d_beef);
+        for _ in 0..5_000 {
+            let (mut board, scores_before, at) = random_position(&mut rng);
+            let placer = Possession::from_index(rng.below(2));
+            let (x, y) = at.index();
+            board[x][y] = Some(rng.card(placer));
+            let before = board.clone();
+
+            let mut scores = scores_before;
+            match placer {
+                Possession::PlayerOne => scores.0 += 1,
+                Possession::PlayerTwo => scores.1 += 1,
+            }
+            let scores_placed = scores;
+            let captured = resolve_captures(&mut board, &mut scores, at, placer);
+
+            assert_eq!(
+                check_capture(&before, &board, scores_placed, scores, at, placer, captured),
+                Ok(()),
+                "board {:?} at {:?}",
+                before,
+                at
+            );
+        }
+    }
+
+    #[test]
+    fn swapping_players_mirrors_the_outcome() {
+        let mut rng = XorShift(0x0dd_ba11);
+        for _ in 0..2_000 {
+            let (mut board, scores, at) = random_position(&mut rng);
+            let (x, y) = at.index();
+            board[x][y] = Some(rng.card(Possession::PlayerOne));
+
+            let mut a = board.clone();
+            let mut a_scores = scores;
+            let a_captured = resolve_captures(&mut a, &mut a_scores, at, Possession::PlayerOne);
+
+            let mut b = mirrored(&board);
+            let mut b_scores = (scores.1, scores.0);
+            let b_captured = resolve_captures(&mut b, &mut b_scores, at, Possession::PlayerTwo);
+
+            assert_eq!(a_captured, b_captured);
+            assert_eq!(mirrored(&a), b);
+            assert_eq!((a_scores.1, a_scores.0), b_scores);
+        }
+    }
+
+    #[test]
+    fn empty_target_cell_changes_nothing() {
+        let mut rng = XorShift(7);
+        let (mut board, scores, at) = random_position(&mut rng);
+        let before = board.clone();
+        let mut after_scores = scores;
+        assert_eq!(
+            resolve_captures(&mut board, &mut after_scores, at, Possession::PlayerOne),
+            0
+        );
+        assert_eq!(board, before);
+        assert_eq!(after_scores, scores);
+    }
+}
@@ -0,0 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Canonical rules shared by `pallet-eterra`, `eterra-card-ai-adapter`, `pallet-eterra-puzzles`
+//! and off-chain clients, so on-chain play and AI simulation always resolve moves the same way.
+//! Placement, capture, scoring and win detection live here as pure functions (see `play`);
+//! the game pallet only adds storage, origins, events and glue to other pallets.
+
+pub mod board;
+pub mod card;
+pub mod element;
+pub mod geometry;
+pub mod invariants;
+pub mod play;
+pub mod rating;
+pub mod result;
+pub mod rules;
+#[cfg(feature = "test-vectors")]
+pub mod vectors;
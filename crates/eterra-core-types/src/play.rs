@@ -0,0 +1,204 @@
+//! The rules of a turn: where a card may go, what placing it does to the board and scores,
+//! how turns and rounds advance, and when and by whom a game is won. Everything here is a
+//! pure function of its arguments; the game pallet stores the results, while the AI adapter,
+//! the puzzle pallet and clients call the same functions to simulate play.
+
+use crate::board::{resolve_captures_with_rules, Board};
+use crate::card::{Card, Possession};
+use crate::geometry::Coord;
+use crate::rules::{final_scores, Rules};
+
+/// Why a card cannot be placed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlaceError {
+    /// The cell is off the board.
+    OffBoard,
+    /// The cell already holds a card.
+    Occupied,
+}
+
+/// The cell (`x`, `y`) if a card may be placed there.
+pub fn open_cell(board: &Board, x: u8, y: u8) -> Result<Coord, PlaceError> {
+    let cell = board.coord(x, y).ok_or(PlaceError::OffBoard)?;
+    let (xi, yi) = cell.index();
+    if board[xi][yi].is_some() {
+        return Err(PlaceError::Occupied);
+    }
+    Ok(cell)
+}
+
+/// Place `card` for `placer` on (`x`, `y`) and flip the neighbors it captures, moving one
+/// point per flip. Placing a card scores nothing by itself. Returns the number of captures;
+/// on error the board and scores are untouched.
+pub fn place_card(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    x: u8,
+    y: u8,
+    card: Card,
+    placer: Possession,
+) -> Result<u8, PlaceError> {
+    place_card_with_rules(board, scores, x, y, card, placer, Rules::default())
+}
+
+/// `place_card` under the capture rules enabled by `rules`: `Rules::SAME`, `Rules::PLUS`
+/// and `Rules::COMBO` (see `resolve_captures_with_rules`).
+pub fn place_card_with_rules(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    x: u8,
+    y: u8,
+    card: Card,
+    placer: Possession,
+    rules: Rules,
+) -> Result<u8, PlaceError> {
+    let cell = open_cell(board, x, y)?;
+    let (xi, yi) = cell.index();
+    board[xi][yi] = Some(card.with_possession(placer));
+    Ok(resolve_captures_with_rules(board, scores, cell, placer, rules))
+}
+
+/// Seat to move and round after `seat` moved (or was skipped) in `round`. A round ends once
+/// both seats have moved, i.e. when the turn wraps back to seat 0.
+pub fn next_turn(seat: u8, round: u8) -> (u8, u8) {
+    let next = (seat + 1) % 2;
+    let round = if next == 0 { round.saturating_add(1) } else { round };
+    (next, round)
+}
+
+/// Whether a game in `round` has played all of its `max_rounds`.
+pub fn is_over(round: u8, max_rounds: u8) -> bool {
+    round >= max_rounds
+}
+
+/// Whether a game is over: all of its `max_rounds` were played, or a board too small for
+/// them filled up first.
+pub fn is_finished(board: &Board, round: u8, max_rounds: u8) -> bool {
+    is_over(round, max_rounds) || board.is_full()
+}
+
+/// The player ahead on `scores`, or `None` on a tie.
+pub fn leader(scores: (u8, u8)) -> Option<Possession> {
+    match scores.0.cmp(&scores.1) {
+        core::cmp::Ordering::Greater => Some(Possession::PlayerOne),
+        core::cmp::Ordering::Less => Some(Possession::PlayerTwo),
+        core::cmp::Ordering::Equal => None,
+    }
+}
+
+/// `None` while the game goes on; once it is finished, `Some(winner)` with `winner` decided
+/// on the final scores, end-of-game bonuses of `rules` included (`None` for a draw).
+pub fn outcome(
+    board: &Board,
+    scores: (u8, u8),
+    round: u8,
+    max_rounds: u8,
+    rules: Rules,
+) -> Option<Option<Possession>> {
+    is_finished(board, round, max_rounds).then(|| leader(final_scores(board, scores, rules)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{BOARD_DIM, MIN_BOARD_DIM};
+
+    fn card(n: u8, e: u8, s: u8, w: u8) -> Card {
+        Card::new(n, e, s, w)
+    }
+
+    #[test]
+    fn cards_only_go_on_empty_cells_of_the_board() {
+        let mut board: Board = Default::default();
+        assert_eq!(open_cell(&board, BOARD_DIM, 0), Err(PlaceError::OffBoard));
+        assert_eq!(open_cell(&board, 0, BOARD_DIM), Err(PlaceError::OffBoard));
+        assert_eq!(open_cell(&board, 1, 2), Ok(Coord::new(1, 2).unwrap()));
+
+        board[1][2] = Some(card(1, 1, 1, 1).with_possession(Possession::PlayerTwo));
+        assert_eq!(open_cell(&board, 1, 2), Err(PlaceError::Occupied));
+
+        let small = Board::new(MIN_BOARD_DIM).unwrap();
+        assert_eq!(open_cell(&small, MIN_BOARD_DIM, 0), Err(PlaceError::OffBoard));
+        assert_eq!(open_cell(&small, 2, 2).map(Coord::dim), Ok(MIN_BOARD_DIM));
+    }
+
+    #[test]
+    fn placing_sets_possession_and_scores_only_captures() {
+        let mut board: Board = Default::default();
+        let mut scores = (0, 0);
+        assert_eq!(
+            place_card(&mut board, &mut scores, 0, 0, card(1, 1, 1, 1), Possession::PlayerOne),
+            Ok(0)
+        );
+        assert_eq!(scores, (0, 0));
+        assert_eq!(board[0][0].as_ref().unwrap().possession, Some(Possession::PlayerOne));
+
+        // A stronger left edge captures the card at (0, 0).
+        assert_eq!(
+            place_card(&mut board, &mut scores, 1, 0, card(1, 1, 1, 9), Possession::PlayerTwo),
+            Ok(1)
+        );
+        assert_eq!(board[0][0].as_ref().unwrap().possession, Some(Possession::PlayerTwo));
+    }
+
+    #[test]
+    fn rejected_placements_leave_the_game_untouched() {
+        let mut board: Board = Default::default();
+        let mut scores = (3, 4);
+        place_card(&mut board, &mut scores, 2, 2, card(5, 5, 5, 5), Possession::PlayerOne)
+            .unwrap();
+        let before = board.clone();
+
+        for (x, y, err) in [(2, 2, PlaceError::Occupied), (BOARD_DIM, 0, PlaceError::OffBoard)] {
+            assert_eq!(
+                place_card(&mut board, &mut scores, x, y, card(9, 9, 9, 9), Possession::PlayerTwo),
+                Err(err)
+            );
+        }
+        assert_eq!(board, before);
+        assert_eq!(scores, (3, 4));
+    }
+
+    #[test]
+    fn turns_alternate_and_rounds_advance_on_wrap() {
+        assert_eq!(next_turn(0, 0), (1, 0));
+        assert_eq!(next_turn(1, 0), (0, 1));
+        assert_eq!(next_turn(1, u8::MAX), (0, u8::MAX));
+    }
+
+    #[test]
+    fn games_are_decided_on_final_scores_after_the_last_round() {
+        let board: Board = Default::default();
+        let rules = Rules::default();
+        assert!(!is_over(4, 5));
+        assert!(is_over(5, 5));
+        assert_eq!(outcome(&board, (9, 1), 4, 5, rules), None);
+        assert_eq!(outcome(&board, (6, 4), 5, 5, rules), Some(Some(Possession::PlayerOne)));
+        assert_eq!(outcome(&board, (4, 6), 5, 5, rules), Some(Some(Possession::PlayerTwo)));
+        assert_eq!(outcome(&board, (5, 5), 5, 5, rules), Some(None));
+        assert_eq!(leader((0, 0)), None);
+
+        // A full board ends the game even with rounds left.
+        let mut small = Board::new(MIN_BOARD_DIM).unwrap();
+        for cell in small.clone().coords() {
+            let (x, y) = cell.index();
+            small[x][y] = Some(card(1, 1, 1, 1).with_possession(Possession::PlayerOne));
+        }
+        assert_eq!(outcome(&small, (9, 1), 4, 5, rules), Some(Some(Possession::PlayerOne)));
+    }
+
+    #[test]
+    fn end_of_game_bonuses_can_decide_the_winner() {
+        let mut board: Board = Default::default();
+        let last = BOARD_DIM - 1;
+        for (x, y) in [(0, 0), (last, 0), (0, last), (last, last)] {
+            board[x as usize][y as usize] =
+                Some(card(1, 1, 1, 1).with_possession(Possession::PlayerTwo));
+        }
+        let corners = Rules(Rules::CAPTURE_THE_CORNERS);
+        let plain = Rules::default();
+        assert_eq!(outcome(&board, (6, 5), 5, 5, plain), Some(Some(Possession::PlayerOne)));
+        assert_eq!(outcome(&board, (6, 5), 5, 5, corners), Some(Some(Possession::PlayerTwo)));
+        assert_eq!(outcome(&board, (7, 5), 5, 5, corners), Some(None));
+    }
+}
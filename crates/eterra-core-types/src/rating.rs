@@ -0,0 +1,75 @@
+//! Elo ratings of ranked players. Everything is integer arithmetic so every node and client
+//! computes the same numbers: the expected score comes from a table of the logistic curve
+//! every 50 points, linearly interpolated in between.
+
+/// Rating of a player before their first ranked game.
+pub const INITIAL_RATING: u32 = 1200;
+/// Most points a single game can move a rating.
+pub const K_FACTOR: u32 = 32;
+
+/// Expected score, in thousandths, of a player rated `50 * i` points above their opponent.
+const EXPECTED: [u32; 17] = [
+    500, 571, 640, 703, 760, 808, 849, 882, 909, 930, 947, 960, 969, 977, 983, 987, 990,
+];
+
+/// Expected score of a player rated `rating` against one rated `opponent`, in thousandths.
+/// Differences beyond 800 points count as 800.
+pub fn expected_score(rating: u32, opponent: u32) -> u32 {
+    let gap = rating.abs_diff(opponent).min(800);
+    let (step, rest) = ((gap / 50) as usize, gap % 50);
+    let ahead = match EXPECTED.get(step + 1) {
+        Some(next) => EXPECTED[step] + (next - EXPECTED[step]) * rest / 50,
+        None => EXPECTED[step],
+    };
+    if rating >= opponent {
+        ahead
+    } else {
+        1000 - ahead
+    }
+}
+
+/// New ratings of two players after a game; `winner` is `Some(0)` if the first won,
+/// `Some(1)` if the second did and `None` for a draw. Whatever one player gains, the other
+/// loses, except where a rating would go below zero.
+pub fn update(ratings: (u32, u32), winner: Option<u8>) -> (u32, u32) {
+    let score = match winner {
+        Some(0) => 1000,
+        Some(_) => 0,
+        None => 500,
+    };
+    let surprise = score as i64 - expected_score(ratings.0, ratings.1) as i64;
+    // Round half away from zero so a draw between equals moves nothing.
+    let scaled = surprise * K_FACTOR as i64;
+    let delta = (scaled + scaled.signum() * 500) / 1000;
+    let apply = |rating: u32, delta: i64| (rating as i64 + delta).max(0) as u32;
+    (apply(ratings.0, delta), apply(ratings.1, -delta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_scores_follow_the_rating_gap() {
+        assert_eq!(expected_score(1200, 1200), 500);
+        assert_eq!(expected_score(1400, 1200), 760);
+        assert_eq!(expected_score(1200, 1400), 240);
+        assert_eq!(expected_score(1225, 1200), 535);
+        assert_eq!(expected_score(3000, 0), 990);
+        assert_eq!(expected_score(0, 3000), 10);
+    }
+
+    #[test]
+    fn upsets_move_ratings_more_than_expected_wins() {
+        assert_eq!(update((1200, 1200), Some(0)), (1216, 1184));
+        assert_eq!(update((1200, 1200), Some(1)), (1184, 1216));
+        assert_eq!(update((1200, 1200), None), (1200, 1200));
+        // The favourite gains little for winning and loses a lot for losing.
+        assert_eq!(update((1400, 1200), Some(0)), (1408, 1192));
+        assert_eq!(update((1400, 1200), Some(1)), (1376, 1224));
+        assert_eq!(update((1400, 1200), None), (1392, 1208));
+        assert_eq!(update((1200, 1400), Some(0)), (1224, 1376));
+        // Ratings stop at zero.
+        assert_eq!(update((3, 300), Some(1)), (0, 305));
+    }
+}
@@ -0,0 +1,55 @@
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// One seat of a finished game.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+pub struct SeatResult<AccountId> {
+    pub account: AccountId,
+    /// Final score, including end-of-game rule bonuses.
+    pub score: u8,
+    /// Weighted power of the hand played from this seat (0 for generated hands).
+    pub hand_power: u32,
+    /// Difficulty of the AI in this seat; `None` for humans.
+    pub ai_difficulty: Option<u8>,
+}
+
+/// Why a game ended.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum EndReason {
+    /// All rounds were played.
+    Completed,
+    /// A player conceded.
+    Forfeit,
+    /// The final turn was force-finished after the move time limit.
+    Timeout,
+    /// The opponent missed the hand submission deadline.
+    Walkover,
+}
+
+/// Outcome of a finished two-player game, reported to result observers such as bounties.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+pub struct GameResult<AccountId> {
+    pub seats: [SeatResult<AccountId>; 2],
+    /// Winning seat; `None` for a draw.
+    pub winner: Option<u8>,
+    /// How the game ended; only `Completed` games were played out on the board.
+    pub reason: EndReason,
+    /// Whether the queue paired the players, so neither picked the other.
+    pub ranked: bool,
+}
+
+impl<AccountId> GameResult<AccountId> {
+    /// The winning seat, if the game was decided.
+    pub fn winner(&self) -> Option<&SeatResult<AccountId>> {
+        self.winner.and_then(|ix| self.seats.get(ix as usize))
+    }
+
+    /// The losing seat, if the game was decided.
+    pub fn loser(&self) -> Option<&SeatResult<AccountId>> {
+        match self.winner {
+            Some(0) => self.seats.get(1),
+            Some(1) => self.seats.first(),
+            _ => None,
+        }
+    }
+}
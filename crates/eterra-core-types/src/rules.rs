@@ -0,0 +1,134 @@
+use crate::board::Board;
+use crate::card::Possession;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Points awarded under `Rules::CAPTURE_THE_CORNERS`.
+pub const CORNER_BONUS_POINTS: u8 = 2;
+
+/// Energy each player starts a `Rules::ENERGY` game with.
+pub const ENERGY_START: u8 = 4;
+/// Energy a player regains at the start of each of their turns under `Rules::ENERGY`.
+pub const ENERGY_REGEN: u8 = 3;
+/// Most energy a player can bank under `Rules::ENERGY`.
+pub const ENERGY_MAX: u8 = 8;
+
+/// Bitflag set of optional rules active in a game.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Rules(pub u8);
+
+impl Rules {
+    /// Controlling all four corners at game end grants `CORNER_BONUS_POINTS`.
+    pub const CAPTURE_THE_CORNERS: u8 = 1 << 0;
+    /// Players only learn how many cards their opponent has left, not which ones.
+    pub const CLOSED_HAND: u8 = 1 << 1;
+    /// Experimental: placing a card costs `energy_cost` from a per-player pool that
+    /// refills by `ENERGY_REGEN` each turn, so strong cards cannot be played back to back.
+    pub const ENERGY: u8 = 1 << 2;
+    /// Captures chain: every card a capture flips re-checks its own edges against its
+    /// neighbors and captures in turn, see `board::resolve_combo_captures`.
+    pub const COMBO: u8 = 1 << 3;
+    /// A placed card also flips the opponent cards it touches with equal edges, as long as
+    /// at least two of its neighbors (own cards included) match that way.
+    pub const SAME: u8 = 1 << 4;
+    /// A placed card also flips the opponent cards whose facing edge adds up with its own
+    /// to the same total as another neighbor's (own cards included).
+    pub const PLUS: u8 = 1 << 5;
+    /// Some cells get an element when the game is created, raising or lowering the ranks of
+    /// the cards placed on them, see `element::modifier`.
+    pub const ELEMENTAL: u8 = 1 << 6;
+
+    pub fn contains(self, flag: u8) -> bool {
+        self.0 & flag == flag
+    }
+}
+
+/// The player owning all four corner cells, if any.
+pub fn corner_owner(board: &Board) -> Option<Possession> {
+    let last = (board.dim() - 1) as usize;
+    let corners = [(0, 0), (last, 0), (0, last), (last, last)];
+    let first = board[0][0].as_ref()?.possession?;
+    corners
+        .iter()
+        .all(|&(x, y)| board[x][y].as_ref().and_then(|c| c.possession) == Some(first))
+        .then_some(first)
+}
+
+/// Energy needed to place a card under `Rules::ENERGY`: one per six points of its rank
+/// total, from 0 for the weakest cards to 6 for a 9/9/9/9.
+pub fn energy_cost(north: u8, east: u8, south: u8, west: u8) -> u8 {
+    ((north as u16 + east as u16 + south as u16 + west as u16) / 6) as u8
+}
+
+/// Energy left after placing a card costing `cost` from a pool of `energy`, or `None` if it
+/// is unaffordable. A card no dearer than `cheapest`, the cheapest card left in the hand,
+/// may always be placed and empties the pool if needed, so a player is never stuck.
+pub fn spend_energy(energy: u8, cost: u8, cheapest: u8) -> Option<u8> {
+    if cost <= energy {
+        Some(energy - cost)
+    } else if cost <= cheapest {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+/// Pool of a player whose turn starts with `energy` left.
+pub fn regenerate_energy(energy: u8) -> u8 {
+    energy.saturating_add(ENERGY_REGEN).min(ENERGY_MAX)
+}
+
+/// Scores used to decide a finished game, including end-of-game bonuses enabled by `rules`.
+pub fn final_scores(board: &Board, scores: (u8, u8), rules: Rules) -> (u8, u8) {
+    let (mut p0, mut p1) = scores;
+    if rules.contains(Rules::CAPTURE_THE_CORNERS) {
+        match corner_owner(board) {
+            Some(Possession::PlayerOne) => p0 = p0.saturating_add(CORNER_BONUS_POINTS),
+            Some(Possession::PlayerTwo) => p1 = p1.saturating_add(CORNER_BONUS_POINTS),
+            None => {}
+        }
+    }
+    (p0, p1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Card;
+
+    fn owned(p: Possession) -> Option<Card> {
+        Some(Card::new(1, 1, 1, 1).with_possession(p))
+    }
+
+    #[test]
+    fn corner_bonus_only_applies_when_enabled_and_all_corners_match() {
+        let mut board: Board = Default::default();
+        board[0][0] = owned(Possession::PlayerTwo);
+        board[3][0] = owned(Possession::PlayerTwo);
+        board[0][3] = owned(Possession::PlayerTwo);
+        board[3][3] = owned(Possession::PlayerOne);
+
+        let corners = Rules(Rules::CAPTURE_THE_CORNERS);
+        assert_eq!(final_scores(&board, (5, 5), corners), (5, 5));
+
+        board[3][3] = owned(Possession::PlayerTwo);
+        assert_eq!(corner_owner(&board), Some(Possession::PlayerTwo));
+        assert_eq!(final_scores(&board, (5, 5), corners), (5, 7));
+        assert_eq!(final_scores(&board, (5, 5), Rules::default()), (5, 5));
+    }
+
+    #[test]
+    fn energy_costs_scale_with_rank_total_and_never_strand_a_hand() {
+        assert_eq!(energy_cost(1, 1, 1, 1), 0);
+        assert_eq!(energy_cost(5, 5, 5, 5), 3);
+        assert_eq!(energy_cost(9, 9, 9, 9), 6);
+
+        assert_eq!(spend_energy(4, 3, 0), Some(1));
+        assert_eq!(spend_energy(4, 6, 3), None);
+        // Only dear cards left: the cheapest of them drains the pool instead.
+        assert_eq!(spend_energy(1, 5, 5), Some(0));
+
+        assert_eq!(regenerate_energy(1), 4);
+        assert_eq!(regenerate_energy(7), ENERGY_MAX);
+    }
+}
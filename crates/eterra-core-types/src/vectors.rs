@@ -0,0 +1,349 @@
+//! Canonical capture-rule test vectors, generated by running the same `resolve_captures` and
+//! `final_scores` the chain uses. Off-chain rule engines (front-ends, bots) replay each vector
+//! and compare their result with `board_after`, `scores_after`, `captured` and `final_scores`.
+//!
+//! The set is fixed: a handful of hand-written edge cases followed by seeded random positions,
+//! so two builds of the same rules always emit byte-identical JSON and SCALE output.
+
+use crate::board::{resolve_captures, Board};
+use crate::card::{Card, Possession};
+use crate::geometry::{Coord, BOARD_DIM};
+use crate::rules::{final_scores, Rules};
+use parity_scale_codec::{Decode, Encode};
+use std::fmt::Write;
+use std::string::String;
+use std::vec::Vec;
+
+/// Number of seeded random positions appended after the hand-written cases.
+pub const RANDOM_VECTORS: usize = 64;
+
+const SEED: u64 = 0x7e57_7ec7_0125_eed5;
+
+/// One placement and its expected outcome.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct TestVector {
+    pub name: String,
+    pub rules: Rules,
+    /// Board before the placement; the target cell is empty.
+    pub board_before: Board,
+    /// Scores before the placement (already counting every owned card on `board_before`).
+    pub scores_before: (u8, u8),
+    pub x: u8,
+    pub y: u8,
+    /// The card placed, without possession.
+    pub card: Card,
+    pub placer: Possession,
+    pub board_after: Board,
+    /// Scores after the placement: the placed card counts one point for `placer`.
+    pub scores_after: (u8, u8),
+    pub captured: u8,
+    /// `final_scores(board_after, scores_after, rules)`, the scores a finished game is decided on.
+    pub final_scores: (u8, u8),
+}
+
+/// Every vector, in canonical order.
+pub fn rules_test_vectors() -> Vec<TestVector> {
+    let mut out = handwritten();
+    let mut rng = XorShift(SEED);
+    for i in 0..RANDOM_VECTORS {
+        let (board, scores, at) = random_position(&mut rng);
+        let placer = Possession::from_index(rng.below(2));
+        let card = rng.card();
+        let rules = Rules(rng.below(2));
+        out.push(vector(
+            &format!("random_{:03}", i),
+            rules,
+            board,
+            scores,
+            at,
+            card,
+            placer,
+        ));
+    }
+    out
+}
+
+/// The vectors as SCALE-encoded `Vec<TestVector>`.
+pub fn rules_test_vectors_scale() -> Vec<u8> {
+    rules_test_vectors().encode()
+}
+
+/// The vectors as a JSON array. Boards are arrays of columns (`board[x][y]`, `y` grows
+/// downwards); cells are `null` or `{"top","right","bottom","left","owner"}` with `owner`
+/// a seat index or `null`.
+pub fn rules_test_vectors_json() -> String {
+    let vectors = rules_test_vectors();
+    let mut out = String::from("[");
+    for (i, v) in vectors.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(
+            out,
+            "{{\"name\":\"{}\",\"rules\":{},\"board_before\":{},\"scores_before\":[{},{}],\
+             \"x\":{},\"y\":{},\"card\":{},\"placer\":{},\"board_after\":{},\
+             \"scores_after\":[{},{}],\"captured\":{},\"final_scores\":[{},{}]}}",
+            v.name,
+            v.rules.0,
+            board_json(&v.board_before),
+            v.scores_before.0,
+            v.scores_before.1,
+            v.x,
+            v.y,
+            card_json(&v.card),
+            v.placer.index(),
+            board_json(&v.board_after),
+            v.scores_after.0,
+            v.scores_after.1,
+            v.captured,
+            v.final_scores.0,
+            v.final_scores.1,
+        );
+    }
+    out.push(']');
+    out
+}
+
+/// Run the on-chain rules for one placement and record the outcome.
+fn vector(
+    name: &str,
+    rules: Rules,
+    board_before: Board,
+    scores_before: (u8, u8),
+    at: Coord,
+    card: Card,
+    placer: Possession,
+) -> TestVector {
+    let (x, y) = at.index();
+    let mut board = board_before.clone();
+    board[x][y] = Some(card.clone().with_possession(placer));
+    let mut scores = scores_before;
+    match placer {
+        Possession::PlayerOne => scores.0 = scores.0.saturating_add(1),
+        Possession::PlayerTwo => scores.1 = scores.1.saturating_add(1),
+    }
+    let captured = resolve_captures(&mut board, &mut scores, at, placer);
+    TestVector {
+        name: name.into(),
+        rules,
+        board_before,
+        scores_before,
+        x: at.x(),
+        y: at.y(),
+        card,
+        placer,
+        final_scores: final_scores(&board, scores, rules),
+        board_after: board,
+        scores_after: scores,
+        captured,
+    }
+}
+
+fn handwritten() -> Vec<TestVector> {
+    let owned = |top, right, bottom, left, p| {
+        Some(Card::new(top, right, bottom, left).with_possession(p))
+    };
+    let at = |x, y| Coord::new(x, y).expect("hand-written cells are on the board");
+    let mut out = Vec::new();
+
+    let empty: Board = Default::default();
+    out.push(vector(
+        "empty_board",
+        Rules::default(),
+        empty.clone(),
+        (0, 0),
+        at(1, 1),
+        Card::new(9, 9, 9, 9),
+        Possession::PlayerOne,
+    ));
+
+    // Right neighbor is beaten, the one below ties and must not flip.
+    let mut board = empty.clone();
+    board[2][1] = owned(1, 1, 1, 3, Possession::PlayerTwo);
+    board[1][2] = owned(5, 1, 1, 1, Possession::PlayerTwo);
+    out.push(vector(
+        "ties_do_not_capture",
+        Rules::default(),
+        board,
+        (0, 2),
+        at(1, 1),
+        Card::new(1, 5, 5, 5),
+        Possession::PlayerOne,
+    ));
+
+    // The placer's own weaker neighbor is never flipped.
+    let mut board = empty.clone();
+    board[0][1] = owned(1, 1, 1, 1, Possession::PlayerTwo);
+    out.push(vector(
+        "own_cards_are_not_captured",
+        Rules::default(),
+        board,
+        (0, 1),
+        at(1, 1),
+        Card::new(9, 9, 9, 9),
+        Possession::PlayerTwo,
+    ));
+
+    // A corner placement only sees its two on-board neighbors.
+    let mut board = empty.clone();
+    board[1][0] = owned(1, 1, 1, 1, Possession::PlayerTwo);
+    board[0][1] = owned(1, 1, 1, 1, Possession::PlayerTwo);
+    out.push(vector(
+        "corner_captures_both_neighbors",
+        Rules::default(),
+        board,
+        (0, 2),
+        at(0, 0),
+        Card::new(1, 2, 2, 1),
+        Possession::PlayerOne,
+    ));
+
+    // Taking the last corner completes the set and earns the corner bonus.
+    let mut board = empty;
+    board[3][0] = owned(1, 1, 1, 1, Possession::PlayerOne);
+    board[0][3] = owned(1, 1, 1, 1, Possession::PlayerOne);
+    board[3][3] = owned(1, 1, 1, 1, Possession::PlayerOne);
+    out.push(vector(
+        "capture_the_corners_bonus",
+        Rules(Rules::CAPTURE_THE_CORNERS),
+        board,
+        (3, 0),
+        at(0, 0),
+        Card::new(5, 5, 5, 5),
+        Possession::PlayerOne,
+    ));
+    out
+}
+
+fn card_json(card: &Card) -> String {
+    let owner = match card.possession {
+        Some(p) => format!("{}", p.index()),
+        None => String::from("null"),
+    };
+    format!(
+        "{{\"top\":{},\"right\":{},\"bottom\":{},\"left\":{},\"owner\":{}}}",
+        card.top, card.right, card.bottom, card.left, owner
+    )
+}
+
+fn board_json(board: &Board) -> String {
+    let dim = board.dim() as usize;
+    let columns: Vec<String> = (0..dim)
+        .map(|x| {
+            let cells: Vec<String> = board[x][..dim]
+                .iter()
+                .map(|cell| cell.as_ref().map_or_else(|| String::from("null"), card_json))
+                .collect();
+            format!("[{}]", cells.join(","))
+        })
+        .collect();
+    format!("[{}]", columns.join(","))
+}
+
+/// Fixed generator, so vectors never depend on a third-party RNG's output staying stable.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u8 {
+        (self.next() % n) as u8
+    }
+
+    fn card(&mut self) -> Card {
+        Card::new(
+            1 + self.below(10),
+            1 + self.below(10),
+            1 + self.below(10),
+            1 + self.below(10),
+        )
+    }
+}
+
+/// A random position with scores matching card ownership, plus an empty cell to play into.
+fn random_position(rng: &mut XorShift) -> (Board, (u8, u8), Coord) {
+    let mut board: Board = Default::default();
+    let mut scores = (0u8, 0u8);
+    for _ in 0..rng.below(16) {
+        let cell = Coord::new(rng.below(4), rng.below(4)).expect("below 4 is on the board");
+        let (x, y) = cell.index();
+        let owner = Possession::from_index(rng.below(2));
+        board[x][y] = Some(rng.card().with_possession(owner));
+    }
+    for cell in Coord::all(BOARD_DIM) {
+        let (x, y) = cell.index();
+        match board[x][y].as_ref().and_then(|c| c.possession) {
+            Some(Possession::PlayerOne) => scores.0 += 1,
+            Some(Possession::PlayerTwo) => scores.1 += 1,
+            None => {}
+        }
+    }
+    // At most 15 cards were placed, so at least one cell is still empty.
+    let empty: Vec<Coord> = Coord::all(BOARD_DIM)
+        .filter(|c| board[c.index().0][c.index().1].is_none())
+        .collect();
+    let at = empty[rng.below(empty.len() as u64) as usize];
+    (board, scores, at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::invariants::check_capture;
+
+    #[test]
+    fn vectors_are_deterministic() {
+        assert_eq!(rules_test_vectors(), rules_test_vectors());
+        assert_eq!(rules_test_vectors_json(), rules_test_vectors_json());
+        assert_eq!(rules_test_vectors().len(), handwritten().len() + RANDOM_VECTORS);
+    }
+
+    #[test]
+    fn vectors_uphold_capture_invariants() {
+        for v in rules_test_vectors() {
+            let at = Coord::new(v.x, v.y).unwrap();
+            let mut placed = v.board_before.clone();
+            placed[v.x as usize][v.y as usize] = Some(v.card.clone().with_possession(v.placer));
+            let mut scores_placed = v.scores_before;
+            match v.placer {
+                Possession::PlayerOne => scores_placed.0 += 1,
+                Possession::PlayerTwo => scores_placed.1 += 1,
+            }
+            assert_eq!(
+                check_capture(
+                    &placed,
+                    &v.board_after,
+                    scores_placed,
+                    v.scores_after,
+                    at,
+                    v.placer,
+                    v.captured
+                ),
+                Ok(()),
+                "{}",
+                v.name
+            );
+        }
+    }
+
+    #[test]
+    fn handwritten_outcomes() {
+        let vectors = rules_test_vectors();
+        let by_name = |name: &str| vectors.iter().find(|v| v.name == name).unwrap();
+        assert_eq!(by_name("ties_do_not_capture").captured, 1);
+        assert_eq!(by_name("own_cards_are_not_captured").captured, 0);
+        assert_eq!(by_name("corner_captures_both_neighbors").scores_after, (3, 0));
+        assert_eq!(by_name("capture_the_corners_bonus").final_scores, (6, 0));
+    }
+
+    #[test]
+    fn scale_output_round_trips() {
+        let decoded = Vec::<TestVector>::decode(&mut &rules_test_vectors_scale()[..]).unwrap();
+        assert_eq!(decoded, rules_test_vectors());
+    }
+}
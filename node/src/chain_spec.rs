@@ -1,5 +1,7 @@
 use sc_service::{ChainType, Properties};
-use solochain_template_runtime::{AccountId, Signature, WASM_BINARY};
+use solochain_template_runtime::{
+    genesis_config_presets::PLAYTEST_PRESET, AccountId, EterraFaucet, Signature, WASM_BINARY,
+};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{sr25519, Pair, Public};
@@ -40,10 +42,9 @@ fn chain_properties() -> Properties {
     props
 }
 
+/// Dev chain built from the runtime's "playtest" preset: funded accounts with starter decks,
+/// current hands, weighted slot reels and an AI opponent, so it is playable from block one.
 pub fn development_config() -> Result<ChainSpec, String> {
-    // Faucet account derived from dev seed
-    let faucet = get_account_id_from_seed::<sr25519::Public>("Faucet");
-
     Ok(ChainSpec::builder(
         WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
         None,
@@ -52,30 +53,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
     .with_id("dev")
     .with_chain_type(ChainType::Development)
     .with_properties(chain_properties())
-    .with_genesis_config_patch(testnet_genesis(
-        // Initial PoA authorities
-        vec![authority_keys_from_seed("Alice")],
-        // Sudo account
-        get_account_id_from_seed::<sr25519::Public>("Alice"),
-        // Pre-funded accounts
-        vec![
-            get_account_id_from_seed::<sr25519::Public>("Alice"),
-            get_account_id_from_seed::<sr25519::Public>("Bob"),
-            get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-            faucet.clone(),
-        ],
-        true,
-        faucet,
-        1_000_000_000_000_000u128,
-    ))
+    .with_genesis_config_preset_name(PLAYTEST_PRESET)
     .build())
 }
 
 pub fn local_testnet_config() -> Result<ChainSpec, String> {
-    // Faucet account derived from dev seed
-    let faucet = get_account_id_from_seed::<sr25519::Public>("Faucet");
-
     Ok(ChainSpec::builder(
         WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
         None,
@@ -106,10 +88,10 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
             get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
             get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
             get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
-            faucet.clone(),
+            // Faucet payouts come from the pallet's derived account
+            EterraFaucet::account_id(),
         ],
         true,
-        faucet,
         1_000_000_000_000_000u128,
     ))
     .build())
@@ -121,7 +103,6 @@ fn testnet_genesis(
     root_key: AccountId,
     endowed_accounts: Vec<AccountId>,
     _enable_println: bool,
-    faucet_account: AccountId,
     payout_amount: u128,
 ) -> serde_json::Value {
     serde_json::json!({
@@ -137,11 +118,14 @@ fn testnet_genesis(
         },
         "sudo": {
             // Assign network admin rights.
-            "key": Some(root_key),
+            "key": Some(root_key.clone()),
         },
         "eterraFaucet": {
-            "faucetAccount": faucet_account,
             "payoutAmount": payout_amount
+        },
+        "eterra": {
+            // The sudo key doubles as the default PvE AI identity on dev chains.
+            "aiAccounts": vec![root_key.clone()],
         }
     })
 }
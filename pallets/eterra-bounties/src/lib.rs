@@ -0,0 +1,301 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Community bounties: anyone can escrow a reward behind conditions over a finished game
+//! ("beat the level-90 AI", "win with a hand weaker than 20"). The first human winner whose
+//! game satisfies every condition is paid automatically when the game pallet reports the
+//! result through `settle`.
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use eterra_core_types::result::{EndReason, GameResult};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{BalanceStatus, Currency, ReservableCurrency},
+};
+use frame_system::pallet_prelude::*;
+use sp_std::vec::Vec;
+
+type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// A predicate over the winner and loser of a decided game.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum BountyCondition<AccountId> {
+    /// The loser was this account, e.g. a specific AI persona.
+    BeatAccount(AccountId),
+    /// The loser was an AI of at least this difficulty.
+    BeatAiDifficulty(u8),
+    /// The winner played a hand of at most this power. Hands without real cards never
+    /// qualify.
+    MaxHandPower(u32),
+    /// The winner scored at least this many points more than the loser.
+    MinScoreMargin(u8),
+    /// The game was ranked, so the winner did not pick the loser.
+    RankedGame,
+}
+
+impl<AccountId: PartialEq> BountyCondition<AccountId> {
+    /// Whether this condition keeps the winner from choosing whom they beat. Every bounty
+    /// needs one, or it could be claimed by beating a second account of the winner's own.
+    pub fn constrains_loser(&self) -> bool {
+        matches!(
+            self,
+            Self::BeatAccount(_) | Self::BeatAiDifficulty(_) | Self::RankedGame
+        )
+    }
+
+    /// Whether `result` satisfies this condition; draws never do.
+    pub fn holds(&self, result: &GameResult<AccountId>) -> bool {
+        let (Some(winner), Some(loser)) = (result.winner(), result.loser()) else {
+            return false;
+        };
+        match self {
+            Self::BeatAccount(account) => loser.account == *account,
+            Self::BeatAiDifficulty(min) => loser.ai_difficulty.is_some_and(|d| d >= *min),
+            Self::MaxHandPower(max) => winner.hand_power != 0 && winner.hand_power <= *max,
+            Self::MinScoreMargin(margin) => winner.score.saturating_sub(loser.score) >= *margin,
+            Self::RankedGame => result.ranked,
+        }
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Currency the rewards are reserved in until a bounty is claimed or cancelled
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// Smallest reward a bounty may offer
+        #[pallet::constant]
+        type MinReward: Get<BalanceOf<Self>>;
+        /// Conditions a single bounty may combine
+        #[pallet::constant]
+        type MaxConditions: Get<u32>;
+        /// Bounties open at once; each finished game is checked against all of them
+        #[pallet::constant]
+        type MaxOpenBounties: Get<u32>;
+        /// Bounties a single account may have open at once, so one poster cannot fill
+        /// `MaxOpenBounties`
+        #[pallet::constant]
+        type MaxOpenBountiesPerPoster: Get<u32>;
+    }
+
+    /// An escrowed challenge: `reward` is reserved from `poster` until it is paid out.
+    #[derive(
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct Bounty<T: Config> {
+        pub poster: T::AccountId,
+        pub reward: BalanceOf<T>,
+        /// All of these must hold for a game to claim the bounty.
+        pub conditions: BoundedVec<BountyCondition<T::AccountId>, T::MaxConditions>,
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_bounty_id)]
+    pub type NextBountyId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn bounty)]
+    pub type Bounties<T: Config> = StorageMap<_, Blake2_128Concat, u32, Bounty<T>, OptionQuery>;
+
+    /// Ids of unclaimed bounties, oldest first, so the oldest matching bounty pays first.
+    #[pallet::storage]
+    #[pallet::getter(fn open_bounties)]
+    pub type OpenBounties<T: Config> =
+        StorageValue<_, BoundedVec<u32, T::MaxOpenBounties>, ValueQuery>;
+
+    /// Number of unclaimed bounties each account has posted.
+    #[pallet::storage]
+    #[pallet::getter(fn open_bounties_of)]
+    pub type OpenBountiesOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        BountyPosted {
+            bounty_id: u32,
+            poster: T::AccountId,
+            reward: BalanceOf<T>,
+        },
+        BountyClaimed {
+            bounty_id: u32,
+            winner: T::AccountId,
+            reward: BalanceOf<T>,
+        },
+        BountyCancelled {
+            bounty_id: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// A bounty needs at least one condition.
+        NoConditions,
+        /// More than `MaxConditions` conditions were given.
+        TooManyConditions,
+        /// The reward is below `MinReward`.
+        RewardTooLow,
+        /// `MaxOpenBounties` bounties are already open.
+        TooManyBounties,
+        /// The poster already has `MaxOpenBountiesPerPoster` bounties open.
+        TooManyBountiesOfPoster,
+        /// No condition constrains the loser: a bounty needs `BeatAccount`,
+        /// `BeatAiDifficulty` or `RankedGame`.
+        LoserUnconstrained,
+        /// No open bounty has this id.
+        UnknownBounty,
+        /// Only the poster may cancel a bounty.
+        NotPoster,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Escrow `reward` for the first human who wins a game satisfying all `conditions`.
+        /// At least one of them must constrain the loser, see `constrains_loser`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 4))]
+        pub fn post_bounty(
+            origin: OriginFor<T>,
+            conditions: Vec<BountyCondition<T::AccountId>>,
+            reward: BalanceOf<T>,
+        ) -> DispatchResult {
+            let poster = ensure_signed(origin)?;
+            ensure!(!conditions.is_empty(), Error::<T>::NoConditions);
+            let conditions: BoundedVec<_, T::MaxConditions> =
+                conditions.try_into().map_err(|_| Error::<T>::TooManyConditions)?;
+            ensure!(
+                conditions.iter().any(BountyCondition::constrains_loser),
+                Error::<T>::LoserUnconstrained
+            );
+            ensure!(reward >= T::MinReward::get(), Error::<T>::RewardTooLow);
+            ensure!(
+                OpenBountiesOf::<T>::get(&poster) < T::MaxOpenBountiesPerPoster::get(),
+                Error::<T>::TooManyBountiesOfPoster
+            );
+
+            let bounty_id = NextBountyId::<T>::get();
+            OpenBounties::<T>::try_mutate(|open| open.try_push(bounty_id))
+                .map_err(|_| Error::<T>::TooManyBounties)?;
+            T::Currency::reserve(&poster, reward)?;
+            OpenBountiesOf::<T>::mutate(&poster, |n| *n = n.saturating_add(1));
+            NextBountyId::<T>::put(bounty_id.wrapping_add(1));
+            Bounties::<T>::insert(
+                bounty_id,
+                Bounty {
+                    poster: poster.clone(),
+                    reward,
+                    conditions,
+                },
+            );
+
+            Self::deposit_event(Event::BountyPosted {
+                bounty_id,
+                poster,
+                reward,
+            });
+            Ok(())
+        }
+
+        /// Withdraw an unclaimed bounty and release its reward back to the poster.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 3))]
+        pub fn cancel_bounty(origin: OriginFor<T>, bounty_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let bounty = Bounties::<T>::get(bounty_id).ok_or(Error::<T>::UnknownBounty)?;
+            ensure!(bounty.poster == who, Error::<T>::NotPoster);
+
+            T::Currency::unreserve(&who, bounty.reward);
+            Self::close_bounty(bounty_id, &who);
+            OpenBounties::<T>::mutate(|open| open.retain(|id| *id != bounty_id));
+            Self::deposit_event(Event::BountyCancelled { bounty_id });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Pay every open bounty whose conditions `result` satisfies to the game's winner.
+        /// Only games played to the last round count: forfeits, timeouts and walkovers could
+        /// be arranged between two accounts. AI winners and posters winning their own bounty
+        /// are skipped. Called by the game pallet for each finished game; bounded by
+        /// `MaxOpenBounties`, see `settle_weight`.
+        pub fn settle(result: &GameResult<T::AccountId>) {
+            if result.reason != EndReason::Completed {
+                return;
+            }
+            let Some(winner) = result.winner() else {
+                return;
+            };
+            if winner.ai_difficulty.is_some() {
+                return;
+            }
+
+            let mut open = OpenBounties::<T>::get();
+            open.retain(|bounty_id| {
+                let Some(bounty) = Bounties::<T>::get(bounty_id) else {
+                    return false;
+                };
+                if bounty.poster == winner.account
+                    || !bounty.conditions.iter().all(|c| c.holds(result))
+                {
+                    return true;
+                }
+                if T::Currency::repatriate_reserved(
+                    &bounty.poster,
+                    &winner.account,
+                    bounty.reward,
+                    BalanceStatus::Free,
+                )
+                .is_err()
+                {
+                    // Keep the bounty open rather than lose track of the escrow.
+                    return true;
+                }
+                Self::close_bounty(*bounty_id, &bounty.poster);
+                Self::deposit_event(Event::BountyClaimed {
+                    bounty_id: *bounty_id,
+                    winner: winner.account.clone(),
+                    reward: bounty.reward,
+                });
+                false
+            });
+            OpenBounties::<T>::put(open);
+        }
+
+        /// Worst case of `settle`: every open bounty is read and paid out.
+        pub fn settle_weight() -> Weight {
+            let open = T::MaxOpenBounties::get() as u64;
+            T::DbWeight::get().reads_writes(1 + 4 * open, 1 + 5 * open)
+        }
+
+        /// Forget a claimed or cancelled bounty and free its place in the poster's count.
+        /// `OpenBounties` is left to the caller.
+        fn close_bounty(bounty_id: u32, poster: &T::AccountId) {
+            Bounties::<T>::remove(bounty_id);
+            OpenBountiesOf::<T>::mutate_exists(poster, |open| {
+                *open = open.map(|n| n.saturating_sub(1)).filter(|n| *n > 0);
+            });
+        }
+    }
+}
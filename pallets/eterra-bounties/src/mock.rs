@@ -0,0 +1,129 @@
+//! Mock runtime for pallet-eterra-bounties tests.
+#![cfg(test)]
+
+use crate as pallet_eterra_bounties;
+use eterra_core_types::result::{EndReason, GameResult, SeatResult};
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU128, ConstU32, Everything},
+};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::BuildStorage;
+
+pub type Balance = u128;
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CAROL: AccountId = 3;
+pub const AI: AccountId = 90;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        EterraBounties: pallet_eterra_bounties,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = ();
+    type Nonce = u64;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+}
+
+impl pallet_eterra_bounties::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MinReward = ConstU128<10>;
+    type MaxConditions = ConstU32<3>;
+    type MaxOpenBounties = ConstU32<3>;
+    type MaxOpenBountiesPerPoster = ConstU32<2>;
+}
+
+/// A decided ranked game between two humans where `winner` beat `loser` 6-4.
+pub fn human_win(winner: AccountId, loser: AccountId, hand_power: u32) -> GameResult<AccountId> {
+    GameResult {
+        seats: [
+            SeatResult {
+                account: winner,
+                score: 6,
+                hand_power,
+                ai_difficulty: None,
+            },
+            SeatResult {
+                account: loser,
+                score: 4,
+                hand_power: 50,
+                ai_difficulty: None,
+            },
+        ],
+        winner: Some(0),
+        reason: EndReason::Completed,
+        ranked: true,
+    }
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 1_000), (BOB, 1_000), (CAROL, 1_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
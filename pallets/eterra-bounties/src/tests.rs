@@ -0,0 +1,163 @@
+//! Unit tests for pallet-eterra-bounties.
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::{BountyCondition, Error, Event, OpenBounties};
+use eterra_core_types::result::EndReason;
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn first_qualifying_winner_is_paid_from_escrow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EterraBounties::post_bounty(
+            RuntimeOrigin::signed(ALICE),
+            vec![
+                BountyCondition::RankedGame,
+                BountyCondition::MaxHandPower(20)
+            ],
+            100,
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE), 100);
+        assert_eq!(EterraBounties::open_bounties_of(ALICE), 1);
+
+        // Too strong a hand, then the poster's own win: neither claims.
+        EterraBounties::settle(&human_win(BOB, CAROL, 30));
+        EterraBounties::settle(&human_win(ALICE, CAROL, 10));
+        assert!(EterraBounties::bounty(0).is_some());
+
+        EterraBounties::settle(&human_win(BOB, CAROL, 15));
+        assert_eq!(Balances::free_balance(BOB), 1_100);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert!(EterraBounties::bounty(0).is_none());
+        assert!(OpenBounties::<Test>::get().is_empty());
+        assert_eq!(EterraBounties::open_bounties_of(ALICE), 0);
+        System::assert_last_event(
+            Event::BountyClaimed {
+                bounty_id: 0,
+                winner: BOB,
+                reward: 100,
+            }
+            .into(),
+        );
+
+        // Already claimed: a second qualifying win pays nothing.
+        EterraBounties::settle(&human_win(CAROL, BOB, 15));
+        assert_eq!(Balances::free_balance(CAROL), 1_000);
+    });
+}
+
+#[test]
+fn conditions_match_the_loser_and_margin() {
+    new_test_ext().execute_with(|| {
+        let mut result = human_win(BOB, AI, 40);
+        result.seats[1].ai_difficulty = Some(90);
+        result.seats[1].score = 5;
+
+        let beat_ai = BountyCondition::<AccountId>::BeatAiDifficulty(90);
+        assert!(beat_ai.holds(&result));
+        assert!(BountyCondition::BeatAccount(AI).holds(&result));
+        assert!(!BountyCondition::<AccountId>::MinScoreMargin(2).holds(&result));
+        assert!(!BountyCondition::<AccountId>::MaxHandPower(0).holds(&result));
+        assert!(BountyCondition::<AccountId>::RankedGame.holds(&result));
+        result.ranked = false;
+        assert!(!BountyCondition::<AccountId>::RankedGame.holds(&result));
+
+        // The AI winning never satisfies a condition on the human side.
+        result.winner = Some(1);
+        assert!(!beat_ai.holds(&result));
+        result.winner = None;
+        assert!(!BountyCondition::<AccountId>::MaxHandPower(100).holds(&result));
+    });
+}
+
+#[test]
+fn posting_is_validated_and_cancel_refunds() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EterraBounties::post_bounty(RuntimeOrigin::signed(ALICE), vec![], 100),
+            Error::<Test>::NoConditions
+        );
+        // Winner-side conditions alone could be met against a second account of the winner.
+        assert_noop!(
+            EterraBounties::post_bounty(
+                RuntimeOrigin::signed(ALICE),
+                vec![
+                    BountyCondition::MinScoreMargin(1),
+                    BountyCondition::MaxHandPower(20)
+                ],
+                100,
+            ),
+            Error::<Test>::LoserUnconstrained
+        );
+        let margin = || {
+            vec![
+                BountyCondition::RankedGame,
+                BountyCondition::MinScoreMargin(1),
+            ]
+        };
+        assert_noop!(
+            EterraBounties::post_bounty(RuntimeOrigin::signed(ALICE), margin(), 5),
+            Error::<Test>::RewardTooLow
+        );
+
+        for _ in 0..2 {
+            assert_ok!(EterraBounties::post_bounty(
+                RuntimeOrigin::signed(ALICE),
+                margin(),
+                100
+            ));
+        }
+        assert_noop!(
+            EterraBounties::post_bounty(RuntimeOrigin::signed(ALICE), margin(), 100),
+            Error::<Test>::TooManyBountiesOfPoster
+        );
+        assert_ok!(EterraBounties::post_bounty(
+            RuntimeOrigin::signed(BOB),
+            margin(),
+            100
+        ));
+        assert_noop!(
+            EterraBounties::post_bounty(RuntimeOrigin::signed(CAROL), margin(), 100),
+            Error::<Test>::TooManyBounties
+        );
+
+        assert_noop!(
+            EterraBounties::cancel_bounty(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotPoster
+        );
+        assert_ok!(EterraBounties::cancel_bounty(RuntimeOrigin::signed(ALICE), 0));
+        assert_eq!(Balances::reserved_balance(ALICE), 100);
+        assert_eq!(OpenBounties::<Test>::get().into_inner(), vec![1, 2]);
+        assert_eq!(EterraBounties::open_bounties_of(ALICE), 1);
+        assert_noop!(
+            EterraBounties::cancel_bounty(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::UnknownBounty
+        );
+    });
+}
+
+#[test]
+fn only_games_played_out_claim_bounties() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EterraBounties::post_bounty(
+            RuntimeOrigin::signed(ALICE),
+            vec![
+                BountyCondition::RankedGame,
+                BountyCondition::MinScoreMargin(1)
+            ],
+            100,
+        ));
+
+        // A forfeit or walkover can be arranged with a second account.
+        for reason in [EndReason::Forfeit, EndReason::Timeout, EndReason::Walkover] {
+            let mut result = human_win(BOB, CAROL, 15);
+            result.reason = reason;
+            EterraBounties::settle(&result);
+        }
+        assert!(EterraBounties::bounty(0).is_some());
+        assert_eq!(Balances::free_balance(BOB), 1_000);
+
+        EterraBounties::settle(&human_win(BOB, CAROL, 15));
+        assert_eq!(Balances::free_balance(BOB), 1_100);
+    });
+}
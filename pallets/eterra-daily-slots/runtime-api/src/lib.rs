@@ -0,0 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_eterra_daily_slots::{RollRecord, WindowSummary};
+
+sp_api::decl_runtime_apis! {
+    /// Read-only views over daily slots state, so clients don't have to decode whole
+    /// history vectors or recompute window math themselves.
+    pub trait DailySlotsApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Up to `limit` roll results of `who`, most recent first, skipping `offset`.
+        fn roll_history(who: AccountId, offset: u32, limit: u32) -> Vec<RollRecord>;
+        /// Rolls used/remaining and tickets earned by `who` in the current window.
+        fn window_summary(who: AccountId) -> WindowSummary;
+    }
+}
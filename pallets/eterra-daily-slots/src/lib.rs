@@ -7,7 +7,7 @@ use frame_support::{
     traits::{Currency, UnixTime},
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::Hash;
+use sp_runtime::traits::{Hash, Saturating, Zero};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -16,9 +16,6 @@ use log::info;
 type BalanceOf<T> =
     <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-const SECONDS_PER_DAY: u64 = 86_400;
-const EVENING_THRESHOLD: u64 = 18 * 3600;
-
 /// We target ~6 hours per window with 6s block time ⇒ 6h * 3600 / 6 = 3600 blocks.
 const BLOCKS_PER_WINDOW: u64 = 3_600;
 
@@ -27,7 +24,6 @@ pub mod pallet {
     use super::*;
 
     #[pallet::pallet]
-    #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     /// Configuration trait for this pallet.
@@ -37,6 +33,8 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// Time provider
         type TimeProvider: UnixTime;
+        /// Origin allowed to change reel weights and the jackpot (e.g. Root or a council)
+        type EconomyAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// Currency used for COIN payouts
         type Currency: Currency<Self::AccountId>;
@@ -45,6 +43,10 @@ pub mod pallet {
         #[pallet::constant]
         type RewardPerWin: Get<BalanceOf<Self>>;
 
+        /// Amount of COIN added to the progressive jackpot by every roll
+        #[pallet::constant]
+        type JackpotAccrualPerRoll: Get<BalanceOf<Self>>;
+
         /// How many reels (slots)
         #[pallet::constant]
         type MaxSlotLength: Get<u32>;
@@ -60,17 +62,57 @@ pub mod pallet {
         /// Number of entries per reel
         #[pallet::constant]
         type MaxWeightEntries: Get<u32>;
+        /// Number of past reel configurations kept in `ReelConfigHistory`
+        #[pallet::constant]
+        type MaxReelConfigHistory: Get<u32>;
+        /// Blocks between two weekly drawings
+        #[pallet::constant]
+        type DrawingPeriod: Get<BlockNumberFor<Self>>;
     }
 
     // ─── STORAGE ────────────────────────────────────────────────────────────────
 
     #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
     pub struct RollResult<T: Config> {
         pub timestamp: u64,
         pub result: BoundedVec<u32, T::MaxSlotLength>,
     }
 
+    /// One entry of a player's roll history, as returned by the runtime API.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct RollRecord {
+        pub timestamp: u64,
+        pub result: Vec<u32>,
+    }
+
+    /// A player's standing in the current roll window, as returned by the runtime API.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct WindowSummary {
+        pub window_index: u64,
+        pub rolls_used: u32,
+        pub rolls_remaining: u32,
+        pub tickets_this_window: u32,
+        /// First block of the next window, when rolls reset.
+        pub next_window_block: u64,
+    }
+
+    /// Weights of every reel, as (reel index, [(symbol, weight)]); at most one entry per reel.
+    pub type ReelSet<T> = BoundedVec<
+        (u32, BoundedVec<(u32, u32), <T as Config>::MaxWeightEntries>),
+        <T as Config>::MaxSlotLength,
+    >;
+
+    /// A reel configuration that was active starting at `activated_at`.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct ReelConfig<T: Config> {
+        pub activated_at: BlockNumberFor<T>,
+        pub reels: ReelSet<T>,
+    }
+
     /// (window_index, count_in_window)
     #[pallet::storage]
     #[pallet::getter(fn rolls_this_window_for)]
@@ -104,6 +146,12 @@ pub mod pallet {
     pub type TicketsPerUser<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn tickets_this_window_for)]
+    /// Tickets a user earned in their latest roll window, as (window_index, tickets).
+    pub type TicketsThisWindow<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u64, u32), ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn total_tickets)]
     /// Total tickets accumulated across all users.
@@ -114,6 +162,11 @@ pub mod pallet {
     /// Timestamp of the last weekly drawing.
     pub type LastDrawingTime<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn next_drawing_at)]
+    /// Block at which the next weekly drawing runs; advanced by `DrawingPeriod` after each one.
+    pub type NextDrawingAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn roll_history)]
     /// Stores the roll history for each user as a bounded vector.
@@ -136,6 +189,59 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn reel_config_version)]
+    /// Version of the active reel configuration; bumped on every weight change.
+    pub type ReelConfigVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reel_config_history)]
+    /// Snapshot of each reel configuration by version (last `MaxReelConfigHistory` kept).
+    pub type ReelConfigHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, ReelConfig<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_reel_weights)]
+    /// Reel weights waiting to be activated at the keyed block.
+    pub type ScheduledReelWeights<T: Config> =
+        StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, ReelSet<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn jackpot_pool)]
+    /// Progressive jackpot, grown by every roll and paid out in full on a jackpot hit.
+    pub type JackpotPool<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn jackpot_combination)]
+    /// Exact reel result (one symbol per reel) that wins the jackpot. No jackpot while unset;
+    /// its odds follow from the configured reel weights.
+    pub type JackpotCombination<T: Config> =
+        StorageValue<_, BoundedVec<u32, T::MaxSlotLength>, OptionQuery>;
+
+    // ─── GENESIS ───────────────────────────────────────────────────────────────
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Initial reel weights as (reel index, [(symbol, weight)]), activated as version 1.
+        pub reel_weights: Vec<(u32, Vec<(u32, u32)>)>,
+        #[serde(skip)]
+        pub _config: core::marker::PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> frame_support::traits::BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (reel, weights) in self.reel_weights.iter() {
+                Pallet::<T>::update_reel_weights(*reel, weights.clone())
+                    .expect("genesis reel weights must be valid");
+            }
+            if !self.reel_weights.is_empty() {
+                Pallet::<T>::commit_reel_config();
+            }
+        }
+    }
+
     // ─── EVENTS & ERRORS ───────────────────────────────────────────────────────
 
     #[pallet::event]
@@ -153,6 +259,32 @@ pub mod pallet {
             player: T::AccountId,
             amount: BalanceOf<T>,
         },
+        /// Emitted when a roll hits the jackpot combination and empties the pool
+        JackpotWon {
+            player: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Emitted when the economy admin sets (or clears, with `None`) the jackpot combination
+        JackpotCombinationSet {
+            combination: Option<Vec<u32>>,
+        },
+        /// Emitted when a reel weight change is scheduled for a future block
+        ReelWeightsScheduled {
+            activation_block: BlockNumberFor<T>,
+        },
+        /// Emitted when new reel weights take effect, immediately or as scheduled
+        ReelConfigActivated {
+            version: u32,
+        },
+        /// Emitted when the next weekly drawing is (re)scheduled
+        DrawingScheduled {
+            at: BlockNumberFor<T>,
+        },
+        /// Emitted when root ends an account's roll window through `dev_complete_window`
+        #[cfg(feature = "dev-mode")]
+        DevWindowCompleted {
+            who: T::AccountId,
+        },
     }
 
     #[pallet::error]
@@ -161,6 +293,12 @@ pub mod pallet {
         ExceedRollsPerRound,
         InvalidConfiguration,
         NoTicketsAvailable,
+        /// Scheduled changes must activate after the current block.
+        ActivationInPast,
+        /// A change is already scheduled for that block.
+        AlreadyScheduled,
+        /// The reel index is not below `MaxSlotLength`, or a reel is listed twice.
+        InvalidReel,
     }
 
     // ─── DISPATCHABLE CALLS ───────────────────────────────────────────────────
@@ -251,6 +389,30 @@ pub mod pallet {
             if tickets > 0 {
                 TicketsPerUser::<T>::mutate(&who, |t| *t = t.saturating_add(tickets));
                 TotalTickets::<T>::mutate(|t| *t = t.saturating_add(tickets));
+                TicketsThisWindow::<T>::mutate(&who, |(win, count)| {
+                    if *win != window_index {
+                        *win = window_index;
+                        *count = 0;
+                    }
+                    *count = count.saturating_add(tickets);
+                });
+            }
+
+            // ─── JACKPOT ───────────────────────
+            JackpotPool::<T>::mutate(|pool| {
+                *pool = pool.saturating_add(T::JackpotAccrualPerRoll::get())
+            });
+            let hit = JackpotCombination::<T>::get().is_some_and(|combo| combo[..] == result[..]);
+            if hit {
+                let amount = JackpotPool::<T>::take();
+                if !amount.is_zero() {
+                    // Minted like regular wins; the pool is an accounting balance only
+                    T::Currency::deposit_creating(&who, amount);
+                    Self::deposit_event(Event::JackpotWon {
+                        player: who.clone(),
+                        amount,
+                    });
+                }
             }
 
             // ─── PAYOUT ON WIN ─────────────────
@@ -299,26 +461,122 @@ pub mod pallet {
             reel: u32,
             weights: Vec<(u32, u32)>,
         ) -> DispatchResult {
-            ensure_root(origin)?; // or ensure_signed(origin)? with checks
+            T::EconomyAdminOrigin::ensure_origin(origin)?;
 
             Self::update_reel_weights(reel, weights)?;
+            Self::commit_reel_config();
 
             Ok(())
         }
 
-        /// Allows a root origin to update multiple reels' weights in one call.
+        /// Allows the economy admin origin to update multiple reels' weights in one call.
         #[pallet::call_index(2)]
         #[pallet::weight(10_000)]
         pub fn set_all_reel_weights(
             origin: OriginFor<T>,
             all_weights: Vec<(u32, Vec<(u32, u32)>)>,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::EconomyAdminOrigin::ensure_origin(origin)?;
 
             for (reel, weights) in all_weights {
                 Self::update_reel_weights(reel, weights)?;
             }
+            Self::commit_reel_config();
+
+            Ok(())
+        }
+
+        /// Set the jackpot combination (one symbol per reel), or clear it with an empty list.
+        #[pallet::call_index(3)]
+        #[pallet::weight(10_000)]
+        pub fn set_jackpot_combination(
+            origin: OriginFor<T>,
+            combination: Vec<u32>,
+        ) -> DispatchResult {
+            T::EconomyAdminOrigin::ensure_origin(origin)?;
+
+            if combination.is_empty() {
+                JackpotCombination::<T>::kill();
+                Self::deposit_event(Event::JackpotCombinationSet { combination: None });
+                return Ok(());
+            }
+
+            ensure!(
+                combination.len() as u32 == T::MaxSlotLength::get(),
+                Error::<T>::InvalidConfiguration
+            );
+            let bounded: BoundedVec<u32, T::MaxSlotLength> = combination
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidConfiguration)?;
+            JackpotCombination::<T>::put(bounded);
+            Self::deposit_event(Event::JackpotCombinationSet {
+                combination: Some(combination),
+            });
+            Ok(())
+        }
+
+        /// Schedule new weights for several reels to take effect at `activation_block`.
+        /// Front-ends can read `ScheduledReelWeights` to show upcoming odds.
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000)]
+        pub fn schedule_reel_weights(
+            origin: OriginFor<T>,
+            activation_block: BlockNumberFor<T>,
+            all_weights: Vec<(u32, Vec<(u32, u32)>)>,
+        ) -> DispatchResult {
+            T::EconomyAdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                activation_block > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ActivationInPast
+            );
+            ensure!(
+                !ScheduledReelWeights::<T>::contains_key(activation_block),
+                Error::<T>::AlreadyScheduled
+            );
+            ensure!(!all_weights.is_empty(), Error::<T>::InvalidConfiguration);
+
+            let mut reels: ReelSet<T> = BoundedVec::new();
+            for (reel, weights) in all_weights {
+                ensure!(
+                    reel < T::MaxSlotLength::get() && reels.iter().all(|(r, _)| *r != reel),
+                    Error::<T>::InvalidReel
+                );
+                reels
+                    .try_push((reel, Self::bound_weights(weights)?))
+                    .map_err(|_| Error::<T>::InvalidReel)?;
+            }
+            ScheduledReelWeights::<T>::insert(activation_block, reels);
+            Self::deposit_event(Event::ReelWeightsScheduled { activation_block });
+            Ok(())
+        }
+
+        /// Move the next weekly drawing to block `at`; later drawings follow every
+        /// `DrawingPeriod` blocks from there.
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn schedule_drawing(origin: OriginFor<T>, at: BlockNumberFor<T>) -> DispatchResult {
+            T::EconomyAdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ActivationInPast
+            );
+            NextDrawingAt::<T>::put(at);
+            Self::deposit_event(Event::DrawingScheduled { at });
+            Ok(())
+        }
 
+        /// (Root, `dev-mode` builds only) End `who`'s current roll window as if its blocks
+        /// had passed: their rolls and window ticket count start over on the next roll.
+        /// Tickets already earned stay in the drawing.
+        #[cfg(feature = "dev-mode")]
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::DbWeight::get().writes(2))]
+        pub fn dev_complete_window(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            RollsThisWindow::<T>::remove(&who);
+            TicketsThisWindow::<T>::remove(&who);
+            Self::deposit_event(Event::DevWindowCompleted { who });
             Ok(())
         }
     }
@@ -326,18 +584,63 @@ pub mod pallet {
     // ─── INTERNAL ───────────────────────────────────────────────────────────────
 
     impl<T: Config> Pallet<T> {
-        /// Internal helper to update reel weights, converting and inserting into storage.
-        fn update_reel_weights(reel: u32, weights: Vec<(u32, u32)>) -> Result<(), Error<T>> {
+        /// Page through `who`'s roll history, most recent first.
+        pub fn roll_history_page(who: &T::AccountId, offset: u32, limit: u32) -> Vec<RollRecord> {
+            let limit = limit.min(T::MaxRollHistoryLength::get());
+            RollHistory::<T>::get(who)
+                .into_iter()
+                .rev()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .map(|r| RollRecord {
+                    timestamp: r.timestamp,
+                    result: r.result.into_inner(),
+                })
+                .collect()
+        }
+
+        /// Rolls used/remaining and tickets earned by `who` in the current window.
+        pub fn window_summary(who: &T::AccountId) -> WindowSummary {
+            let bn_u64: u64 =
+                TryInto::<u64>::try_into(frame_system::Pallet::<T>::block_number()).unwrap_or(0);
+            let window_index = bn_u64 / BLOCKS_PER_WINDOW;
+            let (stored_win, used) = RollsThisWindow::<T>::get(who);
+            let rolls_used = if stored_win == window_index { used } else { 0 };
+            let (ticket_win, tickets) = TicketsThisWindow::<T>::get(who);
+            WindowSummary {
+                window_index,
+                rolls_used,
+                rolls_remaining: T::MaxRollsPerRound::get().saturating_sub(rolls_used),
+                tickets_this_window: if ticket_win == window_index {
+                    tickets
+                } else {
+                    0
+                },
+                next_window_block: window_index
+                    .saturating_add(1)
+                    .saturating_mul(BLOCKS_PER_WINDOW),
+            }
+        }
+
+        /// Validate a reel's weight list and convert it into its bounded form.
+        fn bound_weights(
+            weights: Vec<(u32, u32)>,
+        ) -> Result<BoundedVec<(u32, u32), T::MaxWeightEntries>, Error<T>> {
             // Reject empty weight lists
             if weights.is_empty() {
                 return Err(Error::<T>::InvalidConfiguration);
             }
+            weights
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidConfiguration)
+        }
 
+        /// Internal helper to update reel weights, converting and inserting into storage.
+        fn update_reel_weights(reel: u32, weights: Vec<(u32, u32)>) -> Result<(), Error<T>> {
+            ensure!(reel < T::MaxSlotLength::get(), Error::<T>::InvalidReel);
             // Clone weights for logging after move into BoundedVec
             let weights_for_log = weights.clone();
-            let bounded: BoundedVec<_, T::MaxWeightEntries> = weights
-                .try_into()
-                .map_err(|_| Error::<T>::InvalidConfiguration)?;
+            let bounded = Self::bound_weights(weights)?;
 
             ReelWeights::<T>::insert(reel, bounded);
             info!(
@@ -347,7 +650,35 @@ pub mod pallet {
             Ok(())
         }
 
-        fn perform_weekly_drawing() -> Result<(), Error<T>> {
+        /// Bump the reel config version and snapshot the now-active weights into history.
+        fn commit_reel_config() {
+            let version = ReelConfigVersion::<T>::mutate(|v| {
+                *v = v.saturating_add(1);
+                *v
+            });
+            // Only reels below `MaxSlotLength` are ever rolled or written.
+            let reels = ReelSet::<T>::truncate_from(
+                ReelWeights::<T>::iter()
+                    .filter(|(reel, _)| *reel < T::MaxSlotLength::get())
+                    .collect(),
+            );
+            ReelConfigHistory::<T>::insert(
+                version,
+                ReelConfig::<T> {
+                    activated_at: frame_system::Pallet::<T>::block_number(),
+                    reels,
+                },
+            );
+            let keep = T::MaxReelConfigHistory::get();
+            if version > keep {
+                ReelConfigHistory::<T>::remove(version - keep);
+            }
+            Self::deposit_event(Event::ReelConfigActivated { version });
+        }
+
+        /// Pick this week's winner and reset the tickets. Returns the number of ticket holders
+        /// cleared.
+        fn perform_weekly_drawing() -> Result<u32, Error<T>> {
             let total = TotalTickets::<T>::get();
             if total == 0 {
                 return Err(Error::<T>::NoTicketsAvailable);
@@ -368,10 +699,10 @@ pub mod pallet {
             }
 
             // reset
-            let _ = TicketsPerUser::<T>::clear(u32::MAX, None);
+            let cleared = TicketsPerUser::<T>::clear(u32::MAX, None).unique;
             TotalTickets::<T>::put(0);
             LastDrawingTime::<T>::put(now);
-            Ok(())
+            Ok(cleared)
         }
 
         /// A simple win condition: all symbols in the spin are identical (e.g., 7-7-7)
@@ -390,9 +721,15 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+        /// Seed the default reels on the first block, activate the reel weights scheduled for
+        /// `n` and run the weekly drawing every `DrawingPeriod` blocks. Only this pallet's own
+        /// events are scheduled here: tournament open/close, league season rollovers and shop
+        /// rotations are not, and still need their own calls.
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let db = T::DbWeight::get();
+            let mut weight = Weight::from_parts(10_000, 0).saturating_add(db.reads_writes(2, 1));
             // Only on the first block
-            if _n == 1u32.into() {
+            if n == 1u32.into() {
                 let default_weights = vec![
                     (0, vec![(0, 5), (1, 3), (2, 2)]),
                     (1, vec![(0, 1), (1, 1), (2, 8)]),
@@ -406,41 +743,57 @@ pub mod pallet {
                         ReelWeights::<T>::insert(reel, bounded);
                     }
                 }
+                weight = weight.saturating_add(db.reads_writes(3, 3));
             }
 
-            // Grab “now” once:
-            let now_secs = T::TimeProvider::now().as_secs();
-
-            // How many seconds have elapsed since UNIX epoch in days:
-            let days_since_epoch = now_secs / SECONDS_PER_DAY;
-            // Adjust so that day_of_week == 0 means Sunday:
-            let day_of_week = (days_since_epoch + 4) % 7;
-
-            // How many seconds into *today* we are:
-            let secs_today = now_secs % SECONDS_PER_DAY;
-
-            // Only run the weekly drawing if *both*:
-            //   1) it's Sunday (day_of_week == 0), and
-            //   2) it's at or after 18:00 (EVENING_THRESHOLD)
-            let is_sunday = day_of_week == 0;
-            let is_after_6pm = secs_today >= EVENING_THRESHOLD;
-            if !(is_sunday && is_after_6pm) {
-                // bail out early, no drawing
-                return Weight::from_parts(10_000, 0);
+            // Activate reel weights scheduled for this block
+            if let Some(reels) = ScheduledReelWeights::<T>::take(n) {
+                let count = reels.len() as u64;
+                for (reel, weights) in reels {
+                    ReelWeights::<T>::insert(reel, weights);
+                }
+                Self::commit_reel_config();
+                // The reel writes, then re-reading every reel into the history entry
+                let reels = T::MaxSlotLength::get() as u64;
+                weight = weight.saturating_add(db.reads_writes(reels + 1, count + 3));
             }
 
-            // If we’ve already done a drawing in the last 24 h, bail again:
-            let last = LastDrawingTime::<T>::get();
-            if now_secs.saturating_sub(last) < 24 * 3600 {
-                return Weight::from_parts(10_000, 0);
+            // Start the weekly drawing cycle one period after genesis (or the upgrade adding it)
+            if NextDrawingAt::<T>::get().is_none() {
+                NextDrawingAt::<T>::put(n.saturating_add(T::DrawingPeriod::get()));
+                weight = weight.saturating_add(db.writes(1));
             }
 
-            // Now we really do a weekly drawing
-            if let Err(e) = Self::perform_weekly_drawing() {
-                log::warn!("(eterra-daily-slots) weekly drawing failed: {:?}", e);
+            // Run the weekly drawing once its block is reached, then schedule the next one
+            if let Some(at) = NextDrawingAt::<T>::get() {
+                if n >= at {
+                    let cleared = match Self::perform_weekly_drawing() {
+                        Ok(cleared) => cleared as u64,
+                        Err(e) => {
+                            log::warn!("(eterra-daily-slots) weekly drawing failed: {:?}", e);
+                            0
+                        }
+                    };
+                    // Walking and clearing every ticket holder, plus the totals and schedule
+                    weight = weight.saturating_add(db.reads_writes(cleared + 2, cleared + 3));
+                    // Keep the cadence, unless blocks were missed past the next slot as well
+                    let mut next = at.saturating_add(T::DrawingPeriod::get());
+                    if next <= n {
+                        next = n.saturating_add(T::DrawingPeriod::get());
+                    }
+                    NextDrawingAt::<T>::put(next);
+                    Self::deposit_event(Event::DrawingScheduled { at: next });
+                }
             }
 
-            Weight::from_parts(10_000, 0)
+            weight
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::DrawingPeriod::get().is_zero(),
+                "`DrawingPeriod` must be at least one block, or every block runs a drawing"
+            );
         }
     }
 }
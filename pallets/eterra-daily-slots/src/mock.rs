@@ -103,7 +103,7 @@ impl system::Config for Test {
     type BaseCallFilter = Everything;
     type BlockWeights = ();
     type BlockLength = ();
-    type DbWeight = ();
+    type DbWeight = frame_support::weights::constants::RocksDbWeight;
     type SystemWeightInfo = ();
     type SS58Prefix = ConstU16<42>;
     type OnSetCode = ();
@@ -145,18 +145,24 @@ parameter_types! {
     pub const MaxRollsPerRound:  u32 = 3;
     pub const MaxRollHistoryLength: u32 = 100;
     pub const MaxWeightEntries: u32 = 10;
+    pub const MaxReelConfigHistory: u32 = 2;
+    pub const DrawingPeriod: u64 = 100_800;
 }
 
 impl pallet_eterra_daily_slots::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type TimeProvider = MockTime;
+    type EconomyAdminOrigin = frame_system::EnsureRoot<u64>;
     type MaxSlotLength = MaxSlotLength;
     type MaxOptionsPerSlot = MaxOptionsPerSlot;
     type MaxRollsPerRound = MaxRollsPerRound;
     type MaxRollHistoryLength = MaxRollHistoryLength;
     type MaxWeightEntries = MaxWeightEntries;
+    type MaxReelConfigHistory = MaxReelConfigHistory;
+    type DrawingPeriod = DrawingPeriod;
     type Currency = Balances;
     type RewardPerWin = ConstU128<1_000>;
+    type JackpotAccrualPerRoll = ConstU128<10>;
 }
 
 // =====================================================
@@ -193,6 +199,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
         let _ = crate::TicketsPerUser::<TestRuntime>::clear(u32::MAX, None);
         let _ = crate::TotalTickets::<TestRuntime>::kill();
         let _ = crate::LastDrawingTime::<TestRuntime>::kill();
+        let _ = crate::NextDrawingAt::<TestRuntime>::kill();
 
         // 🆕 Set default weights for each reel to prevent panics
         for reel in 0..<Test as Config>::MaxSlotLength::get() {
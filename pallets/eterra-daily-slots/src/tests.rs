@@ -7,8 +7,8 @@ use crate::ReelWeights;
 use crate::RollsThisBlock;
 use crate::RollsThisWindow;
 use crate::{
-    Config, Error, Event, LastDrawingTime, LastRollTime, Pallet, RollHistory, TicketsPerUser,
-    TotalTickets,
+    Config, Error, Event, LastDrawingTime, LastRollTime, NextDrawingAt, Pallet, RollHistory,
+    TicketsPerUser, TotalTickets,
 };
 use frame_support::traits::Hooks;
 use frame_support::BoundedVec;
@@ -21,10 +21,6 @@ use std::collections::HashMap; // Optional: use fixed seed for deterministic tes
 
 // ─── Helpers ────────────────────────────────────────────────────────────────
 
-fn set_mock_time_to_sunday_6pm() {
-    MockTimeState::set_now(324_000);
-}
-
 fn roll_n_times<T: crate::pallet::Config>(who: &T::AccountId, n: u32) {
     for _ in 0..n {
         assert_ok!(crate::Pallet::<T>::roll(
@@ -180,15 +176,19 @@ fn test_ticket_awarded_on_special_symbol() {
 // ─── Weekly Drawing Tests ──────────────────────────────────────────────────
 
 #[test]
-fn test_no_weekly_drawing_if_not_sunday_6pm() {
+fn test_no_weekly_drawing_before_scheduled_block() {
     new_test_ext().execute_with(|| {
         TicketsPerUser::<TestRuntime>::insert(1, 5);
         TotalTickets::<TestRuntime>::put(5);
-        LastDrawingTime::<TestRuntime>::put(89_500);
 
         Pallet::<TestRuntime>::on_initialize(1);
 
         assert_eq!(TotalTickets::<TestRuntime>::get(), 5);
+        // The first drawing is scheduled one period after the first block
+        assert_eq!(
+            NextDrawingAt::<TestRuntime>::get(),
+            Some(1 + <Test as Config>::DrawingPeriod::get())
+        );
         let fired = frame_system::Pallet::<TestRuntime>::events()
             .iter()
             .any(|r| {
@@ -204,7 +204,7 @@ fn test_no_weekly_drawing_if_not_sunday_6pm() {
 #[test]
 fn test_no_weekly_drawing_with_no_tickets() {
     new_test_ext().execute_with(|| {
-        set_mock_time_to_sunday_6pm();
+        NextDrawingAt::<TestRuntime>::put(1001);
         TotalTickets::<TestRuntime>::put(0);
         LastDrawingTime::<TestRuntime>::put(0);
         frame_system::Pallet::<TestRuntime>::set_block_number(1001);
@@ -228,7 +228,7 @@ fn test_no_weekly_drawing_with_no_tickets() {
 #[test]
 fn test_weekly_drawing_selects_winner() {
     new_test_ext().execute_with(|| {
-        set_mock_time_to_sunday_6pm();
+        NextDrawingAt::<TestRuntime>::put(1001);
         TicketsPerUser::<TestRuntime>::insert(1, 5);
         TotalTickets::<TestRuntime>::put(5);
         LastDrawingTime::<TestRuntime>::put(0);
@@ -253,7 +253,7 @@ fn test_weekly_drawing_selects_winner() {
 #[test]
 fn test_weekly_drawing_only_once_per_week() {
     new_test_ext().execute_with(|| {
-        set_mock_time_to_sunday_6pm();
+        NextDrawingAt::<TestRuntime>::put(1001);
         TicketsPerUser::<TestRuntime>::insert(1, 5);
         TotalTickets::<TestRuntime>::put(5);
         LastDrawingTime::<TestRuntime>::put(0);
@@ -279,7 +279,7 @@ fn test_weekly_drawing_only_once_per_week() {
 #[test]
 fn test_weekly_winner_event_emitted_correctly() {
     new_test_ext().execute_with(|| {
-        set_mock_time_to_sunday_6pm();
+        NextDrawingAt::<TestRuntime>::put(1001);
         TicketsPerUser::<TestRuntime>::insert(1, 5);
         TotalTickets::<TestRuntime>::put(5);
         LastDrawingTime::<TestRuntime>::put(0);
@@ -610,3 +610,270 @@ fn test_advancing_less_than_window_does_not_reset() {
         assert_noop!(fourth, Error::<TestRuntime>::ExceedRollsPerRound);
     });
 }
+
+// ─── Jackpot Tests ─────────────────────────────────────────────────────────
+
+#[test]
+fn jackpot_accrues_per_roll_and_pays_whole_pool_on_hit() {
+    new_test_ext().execute_with(|| {
+        // Force every reel to land on symbol 1.
+        for reel in 0..<Test as Config>::MaxSlotLength::get() {
+            assert_ok!(Pallet::<Test>::set_reel_weights(
+                RawOrigin::Root.into(),
+                reel,
+                vec![(1, 1)]
+            ));
+        }
+
+        // No combination configured yet: the pool only grows.
+        assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(1).into()));
+        assert_eq!(Pallet::<Test>::jackpot_pool(), 10);
+
+        assert_noop!(
+            Pallet::<Test>::set_jackpot_combination(RawOrigin::Root.into(), vec![1, 1]),
+            Error::<Test>::InvalidConfiguration
+        );
+        assert_ok!(Pallet::<Test>::set_jackpot_combination(
+            RawOrigin::Root.into(),
+            vec![1, 1, 1]
+        ));
+
+        let before = Balances::free_balance(2);
+        assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(2).into()));
+        // 20 from the pool plus the regular three-of-a-kind reward.
+        assert_eq!(Balances::free_balance(2), before + 20 + 1_000);
+        assert_eq!(Pallet::<Test>::jackpot_pool(), 0);
+        System::assert_has_event(RuntimeEvent::EterraDailySlots(Event::JackpotWon {
+            player: 2,
+            amount: 20,
+        }));
+    });
+}
+
+// ─── Query Helper Tests ────────────────────────────────────────────────────
+
+#[test]
+fn roll_history_page_returns_most_recent_first() {
+    new_test_ext().execute_with(|| {
+        for t in 0..3u64 {
+            MockTimeState::set_now(90_000 + t);
+            assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(1).into()));
+        }
+        let page = Pallet::<Test>::roll_history_page(&1, 0, 2);
+        let stamps: Vec<u64> = page.iter().map(|r| r.timestamp).collect();
+        assert_eq!(stamps, vec![90_002, 90_001]);
+        let rest = Pallet::<Test>::roll_history_page(&1, 2, 10);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].timestamp, 90_000);
+        assert!(Pallet::<Test>::roll_history_page(&1, 3, 10).is_empty());
+    });
+}
+
+#[test]
+fn window_summary_tracks_rolls_and_tickets_per_window() {
+    new_test_ext().execute_with(|| {
+        // Every reel lands on the ticket symbol.
+        for reel in 0..<Test as Config>::MaxSlotLength::get() {
+            assert_ok!(Pallet::<Test>::set_reel_weights(
+                RawOrigin::Root.into(),
+                reel,
+                vec![(7, 1)]
+            ));
+        }
+        roll_n_times::<Test>(&1, 2);
+        let summary = Pallet::<Test>::window_summary(&1);
+        assert_eq!(summary.window_index, 0);
+        assert_eq!(summary.rolls_used, 2);
+        assert_eq!(summary.rolls_remaining, 1);
+        assert_eq!(summary.tickets_this_window, 6);
+        assert_eq!(summary.next_window_block, BLOCKS_PER_WINDOW);
+
+        // A new window resets the per-window view.
+        advance_blocks(BLOCKS_PER_WINDOW);
+        let summary = Pallet::<Test>::window_summary(&1);
+        assert_eq!(summary.rolls_used, 0);
+        assert_eq!(summary.rolls_remaining, 3);
+        assert_eq!(summary.tickets_this_window, 0);
+    });
+}
+
+#[test]
+fn scheduled_reel_weights_activate_at_block_and_are_versioned() {
+    new_test_ext().execute_with(|| {
+        let all: Vec<_> = (0..<Test as Config>::MaxSlotLength::get())
+            .map(|i| (i, vec![(7, 10)]))
+            .collect();
+
+        assert_noop!(
+            Pallet::<Test>::schedule_reel_weights(RawOrigin::Root.into(), 1, all.clone()),
+            Error::<Test>::ActivationInPast
+        );
+        assert_ok!(Pallet::<Test>::schedule_reel_weights(
+            RawOrigin::Root.into(),
+            5,
+            all.clone()
+        ));
+        assert_noop!(
+            Pallet::<Test>::schedule_reel_weights(RawOrigin::Root.into(), 5, all.clone()),
+            Error::<Test>::AlreadyScheduled
+        );
+        assert!(Pallet::<Test>::scheduled_reel_weights(5).is_some());
+        assert_eq!(Pallet::<Test>::reel_config_version(), 0);
+
+        // Nothing changes before the activation block
+        <Pallet<Test> as Hooks<BlockNumberFor<Test>>>::on_initialize(4);
+        assert_eq!(
+            ReelWeights::<Test>::get(0).unwrap().into_inner(),
+            vec![(0, 1), (1, 1), (2, 1)]
+        );
+
+        frame_system::Pallet::<Test>::set_block_number(5);
+        <Pallet<Test> as Hooks<BlockNumberFor<Test>>>::on_initialize(5);
+        assert_eq!(
+            ReelWeights::<Test>::get(0).unwrap().into_inner(),
+            vec![(7, 10)]
+        );
+        assert!(Pallet::<Test>::scheduled_reel_weights(5).is_none());
+        assert_eq!(Pallet::<Test>::reel_config_version(), 1);
+        let snapshot = Pallet::<Test>::reel_config_history(1).unwrap();
+        assert_eq!(snapshot.activated_at, 5);
+        assert_eq!(
+            snapshot.reels.len() as u32,
+            <Test as Config>::MaxSlotLength::get()
+        );
+        System::assert_last_event(RuntimeEvent::EterraDailySlots(Event::ReelConfigActivated {
+            version: 1,
+        }));
+    });
+}
+
+#[test]
+fn reel_config_history_keeps_only_recent_versions() {
+    new_test_ext().execute_with(|| {
+        for symbol in 0..3u32 {
+            assert_ok!(Pallet::<Test>::set_reel_weights(
+                RawOrigin::Root.into(),
+                0,
+                vec![(symbol, 1)]
+            ));
+        }
+        // MaxReelConfigHistory = 2 in the mock
+        assert_eq!(Pallet::<Test>::reel_config_version(), 3);
+        assert!(Pallet::<Test>::reel_config_history(1).is_none());
+        assert!(Pallet::<Test>::reel_config_history(2).is_some());
+        assert!(Pallet::<Test>::reel_config_history(3).is_some());
+    });
+}
+
+#[test]
+fn reel_admin_calls_require_economy_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::set_reel_weights(RawOrigin::Signed(1).into(), 0, vec![(7, 10)]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pallet::<Test>::schedule_reel_weights(
+                RawOrigin::Signed(1).into(),
+                5,
+                vec![(0, vec![(7, 10)])]
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pallet::<Test>::set_jackpot_combination(RawOrigin::Signed(1).into(), vec![]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn schedule_drawing_moves_the_next_drawing_and_keeps_the_cadence() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::schedule_drawing(RawOrigin::Signed(1).into(), 50),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pallet::<Test>::schedule_drawing(RawOrigin::Root.into(), 1),
+            Error::<Test>::ActivationInPast
+        );
+        assert_ok!(Pallet::<Test>::schedule_drawing(RawOrigin::Root.into(), 50));
+        assert_eq!(Pallet::<Test>::next_drawing_at(), Some(50));
+
+        TicketsPerUser::<Test>::insert(1, 5);
+        TotalTickets::<Test>::put(5);
+        <Pallet<Test> as Hooks<BlockNumberFor<Test>>>::on_initialize(49);
+        assert_eq!(TotalTickets::<Test>::get(), 5);
+
+        frame_system::Pallet::<Test>::set_block_number(50);
+        <Pallet<Test> as Hooks<BlockNumberFor<Test>>>::on_initialize(50);
+        assert_eq!(TotalTickets::<Test>::get(), 0);
+        let next = 50 + <Test as Config>::DrawingPeriod::get();
+        assert_eq!(Pallet::<Test>::next_drawing_at(), Some(next));
+        System::assert_last_event(RuntimeEvent::EterraDailySlots(Event::DrawingScheduled {
+            at: next,
+        }));
+    });
+}
+
+#[test]
+fn drawing_blocks_report_the_weight_of_clearing_every_ticket_holder() {
+    new_test_ext().execute_with(|| {
+        NextDrawingAt::<Test>::put(50);
+        let quiet = <Pallet<Test> as Hooks<BlockNumberFor<Test>>>::on_initialize(49);
+
+        for who in 1..=3 {
+            TicketsPerUser::<Test>::insert(who, 1);
+        }
+        TotalTickets::<Test>::put(3);
+        frame_system::Pallet::<Test>::set_block_number(50);
+        let drawing = <Pallet<Test> as Hooks<BlockNumberFor<Test>>>::on_initialize(50);
+
+        let db = frame_support::weights::constants::RocksDbWeight::get();
+        assert!(drawing.all_gte(quiet.saturating_add(db.reads_writes(3, 3))));
+        assert_eq!(TicketsPerUser::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn reel_weights_outside_the_slot_length_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let out_of_range = <Test as Config>::MaxSlotLength::get();
+        assert_noop!(
+            Pallet::<Test>::set_reel_weights(RawOrigin::Root.into(), out_of_range, vec![(7, 10)]),
+            Error::<Test>::InvalidReel
+        );
+        assert_noop!(
+            Pallet::<Test>::schedule_reel_weights(
+                RawOrigin::Root.into(),
+                5,
+                vec![(0, vec![(7, 10)]), (0, vec![(8, 10)])]
+            ),
+            Error::<Test>::InvalidReel
+        );
+    });
+}
+
+#[cfg(feature = "dev-mode")]
+#[test]
+fn root_can_complete_a_roll_window_in_dev_mode() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        roll_n_times::<Test>(&1, 3);
+        assert_noop!(
+            Pallet::<Test>::roll(RawOrigin::Signed(1).into()),
+            Error::<Test>::ExceedRollsPerRound
+        );
+
+        assert_noop!(
+            Pallet::<Test>::dev_complete_window(RawOrigin::Signed(1).into(), 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Pallet::<Test>::dev_complete_window(RawOrigin::Root.into(), 1));
+        System::assert_last_event(RuntimeEvent::EterraDailySlots(Event::DevWindowCompleted {
+            who: 1,
+        }));
+        assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(1).into()));
+    });
+}
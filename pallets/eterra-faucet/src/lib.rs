@@ -2,17 +2,36 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 use frame_support::{
     dispatch::DispatchResult,
     pallet_prelude::*,
     traits::{tokens::ExistenceRequirement, BuildGenesisConfig, Currency},
+    PalletId,
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::codec::Encode;
+use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Zero};
 use sp_runtime::transaction_validity::{
     InvalidTransaction, TransactionSource, TransactionValidity, ValidTransaction,
 };
 
+/// ~24h with 6s blocks; the window for activity drips.
+const BLOCKS_PER_DAY: u64 = 14_400;
+
+/// Notified after every successful unsigned faucet claim, e.g. to track onboarding.
+pub trait ClaimObserver<AccountId> {
+    fn on_claimed(who: &AccountId);
+}
+
+impl<AccountId> ClaimObserver<AccountId> for () {
+    fn on_claimed(_who: &AccountId) {}
+}
+
 /// Helper to get the balance type from the configured Currency
 pub type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -28,16 +47,44 @@ pub mod pallet {
 
         /// The currency used for faucet payouts.
         type Currency: Currency<Self::AccountId>;
+
+        /// Id the faucet account is derived from, see `Pallet::account_id`.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Paid automatically for each of an account's first `ActivityDripGames` finished games
+        /// per day (0 disables activity drips).
+        #[pallet::constant]
+        type ActivityDripAmount: Get<BalanceOf<Self>>;
+
+        /// Finished games per account per day that earn an activity drip.
+        #[pallet::constant]
+        type ActivityDripGames: Get<u32>;
+
+        /// Activity drips paid across all accounts per day.
+        #[pallet::constant]
+        type MaxActivityDripsPerDay: Get<u32>;
+
+        /// Rounds a game must have reached to earn an activity drip, so games forfeited or
+        /// abandoned right after creation do not farm the faucet.
+        #[pallet::constant]
+        type MinActivityDripRounds: Get<u32>;
+
+        /// Hook notified after each successful `claim`.
+        type OnClaim: crate::ClaimObserver<Self::AccountId>;
     }
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
-    /// Faucet account id, set via genesis
-    #[pallet::storage]
-    #[pallet::getter(fn faucet_account)]
-    pub type FaucetAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+    /// Faucet account set at genesis before v1, superseded by `Pallet::account_id`.
+    #[frame_support::storage_alias]
+    type FaucetAccount<T: Config> =
+        StorageValue<Pallet<T>, <T as frame_system::Config>::AccountId, OptionQuery>;
 
     /// Fixed payout amount per claim, set via genesis
     #[pallet::storage]
@@ -50,23 +97,42 @@ pub mod pallet {
     pub type LastClaim<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
 
+    /// Activity drips paid per account as (day_index, count_in_day).
+    #[pallet::storage]
+    pub type ActivityDrips<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u64, u32), ValueQuery>;
+
+    /// Activity drips paid across all accounts as (day_index, count_in_day).
+    #[pallet::storage]
+    pub type ActivityDripsToday<T: Config> = StorageValue<_, (u64, u32), ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
-        pub faucet_account: Option<T::AccountId>,
         pub payout_amount: BalanceOf<T>,
     }
 
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            if let Some(ref acc) = self.faucet_account {
-                FaucetAccount::<T>::put(acc);
-            }
             PayoutAmount::<T>::put(&self.payout_amount);
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+            // v0 -> v1: payouts come from the derived account. Whatever is left on the old
+            // genesis account stays with its key holder.
+            FaucetAccount::<T>::kill();
+            STORAGE_VERSION.put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(1, 2)
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -76,6 +142,11 @@ pub mod pallet {
             who: T::AccountId,
             amount: BalanceOf<T>,
         },
+        /// A player was paid for finishing one of their first games of the day.
+        ActivityDripPaid {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -84,15 +155,13 @@ pub mod pallet {
         InsufficientFaucetBalance,
         /// Transfer failed for another reason.
         TransferFailed,
-        /// Faucet was not configured in genesis.
-        NotConfigured,
         /// Destination already claimed this block (rate limit).
         TooFrequent,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Claim faucet funds. Transfers `PayoutAmount` from `FaucetAccount` to `dest`.
+        /// Claim faucet funds. Transfers `PayoutAmount` from the faucet account to `dest`.
         ///
         /// This is an **unsigned** extrinsic, validated via `ValidateUnsigned` so brand-new
         /// accounts (with no balance/nonce) can claim. Rate-limited to once per block per `dest`.
@@ -111,7 +180,7 @@ pub mod pallet {
                 }
             }
 
-            let faucet = FaucetAccount::<T>::get().ok_or(Error::<T>::NotConfigured)?;
+            let faucet = Self::account_id();
             let amount: BalanceOf<T> = PayoutAmount::<T>::get();
 
             // Ensure faucet has enough balance
@@ -124,6 +193,7 @@ pub mod pallet {
 
             // Record the claim block
             LastClaim::<T>::insert(&dest, now);
+            T::OnClaim::on_claimed(&dest);
 
             Self::deposit_event(Event::Claimed { who: dest, amount });
             Ok(())
@@ -152,3 +222,44 @@ pub mod pallet {
         }
     }
 }
+
+impl<T: Config> Pallet<T> {
+    /// The account claims and activity drips are paid from, derived from `PalletId` so no
+    /// one holds its key. Fund it with a plain transfer.
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Called by the game pallet (via the runtime) when `who` finishes a game that reached
+    /// `rounds` rounds. Pays `ActivityDripAmount` for games of at least `MinActivityDripRounds`
+    /// rounds while `who` is within their first `ActivityDripGames` games of the day and the
+    /// global daily cap is not exhausted. Returns whether a drip was paid.
+    pub fn note_game_completed(who: &T::AccountId, rounds: u32) -> bool {
+        let amount = T::ActivityDripAmount::get();
+        if amount.is_zero() || rounds < T::MinActivityDripRounds::get() {
+            return false;
+        }
+        let today =
+            frame_system::Pallet::<T>::block_number().saturated_into::<u64>() / BLOCKS_PER_DAY;
+        let count_today = |(day, count): (u64, u32)| if day == today { count } else { 0 };
+
+        let mine = count_today(ActivityDrips::<T>::get(who));
+        let global = count_today(ActivityDripsToday::<T>::get());
+        if mine >= T::ActivityDripGames::get() || global >= T::MaxActivityDripsPerDay::get() {
+            return false;
+        }
+        let faucet = Self::account_id();
+        if T::Currency::transfer(&faucet, who, amount, ExistenceRequirement::KeepAlive).is_err() {
+            log::debug!(target: "eterra-faucet", "activity drip skipped: faucet transfer failed");
+            return false;
+        }
+
+        ActivityDrips::<T>::insert(who, (today, mine.saturating_add(1)));
+        ActivityDripsToday::<T>::put((today, global.saturating_add(1)));
+        Self::deposit_event(Event::ActivityDripPaid {
+            who: who.clone(),
+            amount,
+        });
+        true
+    }
+}
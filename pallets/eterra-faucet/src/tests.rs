@@ -0,0 +1,31 @@
+//! Unit tests for pallet-eterra-faucet.
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::Event;
+
+#[test]
+fn activity_drips_need_enough_rounds_played() {
+    new_test_ext().execute_with(|| {
+        // Forfeited or abandoned right away: nothing is paid.
+        assert!(!Faucet::note_game_completed(&ALICE, 0));
+        assert!(!Faucet::note_game_completed(&ALICE, 2));
+        assert_eq!(Balances::free_balance(ALICE), 100);
+
+        assert!(Faucet::note_game_completed(&ALICE, 3));
+        assert_eq!(Balances::free_balance(ALICE), 110);
+        System::assert_last_event(
+            Event::ActivityDripPaid {
+                who: ALICE,
+                amount: 10,
+            }
+            .into(),
+        );
+
+        // Short games did not use up the daily allowance of two drips.
+        assert!(Faucet::note_game_completed(&ALICE, 5));
+        assert!(!Faucet::note_game_completed(&ALICE, 5));
+        assert_eq!(Balances::free_balance(ALICE), 120);
+        assert!(Faucet::note_game_completed(&BOB, 5));
+    });
+}
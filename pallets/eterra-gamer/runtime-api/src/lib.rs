@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_eterra_gamer::{CosmeticKind, EquippedCosmetics, ProfileSummary};
+
+sp_api::decl_runtime_apis! {
+    /// Profile page data, so clients don't have to stitch several storage reads together.
+    pub trait ProfileApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Tag, avatar, level, experience and onboarding progress of `who`.
+        fn profile(who: AccountId) -> ProfileSummary;
+        /// Card ids `who` pinned to their showcase, in display order.
+        fn showcase(who: AccountId) -> Vec<u32>;
+    }
+
+    /// Equipped cosmetics of the players of a game, so opponents and spectators render the
+    /// right card backs and board skins.
+    pub trait CosmeticsApi<AccountId, GameId>
+    where
+        AccountId: Codec,
+        GameId: Codec,
+    {
+        /// Cosmetics of each seat of `game_id`, in seat order; empty if the game is unknown.
+        fn game_cosmetics(game_id: GameId) -> Vec<(AccountId, EquippedCosmetics)>;
+    }
+}
@@ -10,10 +10,46 @@ mod tests;
 use frame_support::{
     pallet_prelude::*,
     traits::{Currency, ExistenceRequirement},
+    PalletId,
 };
 use frame_system::pallet_prelude::*;
+use sp_runtime::traits::AccountIdConversion;
 use sp_std::vec::Vec;
 
+/// Onboarding steps, tracked as bits of `OnboardingProgress`.
+pub const ONBOARDING_FAUCET_CLAIMED: u8 = 1 << 0;
+pub const ONBOARDING_STARTER_DECK: u8 = 1 << 1;
+pub const ONBOARDING_FIRST_GAME: u8 = 1 << 2;
+/// All steps required before an account may enter the ranked queue.
+pub const ONBOARDING_COMPLETE: u8 =
+    ONBOARDING_FAUCET_CLAIMED | ONBOARDING_STARTER_DECK | ONBOARDING_FIRST_GAME;
+
+/// Public profile of an account, as returned by the profile runtime API.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug, Default)]
+pub struct ProfileSummary {
+    pub tag: Vec<u8>,
+    pub avatar_cid: Vec<u8>,
+    pub level: u8,
+    pub experience: u128,
+    /// Bitmask of completed onboarding steps (see `ONBOARDING_*`).
+    pub onboarding_progress: u8,
+}
+
+/// Families of vanity items. Ids within a kind are assigned per season off-chain, where
+/// the artwork lives; the chain only tracks who owns and shows what.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CosmeticKind {
+    CardBack,
+    BoardSkin,
+}
+
+/// Cosmetics an account shows to opponents and spectators; `None` is the default look.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, Default)]
+pub struct EquippedCosmetics {
+    pub card_back: Option<u32>,
+    pub board_skin: Option<u32>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -29,9 +65,12 @@ pub mod pallet {
         /// Origin allowed to mint/grant XP (e.g., Root or a custom EnsureOrigin).
         type ExpIssuerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-        /// Account that receives change fees (e.g., faucet/treasury account).
+        /// Origin allowed to grant cosmetics outside of the in-game rewards.
+        type CosmeticIssuerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Id the account receiving change fees is derived from, see `Pallet::account_id`.
         #[pallet::constant]
-        type FaucetAccount: Get<Self::AccountId>;
+        type PalletId: Get<PalletId>;
 
         /// The fee to change gamer tag or avatar after the first set.
         #[pallet::constant]
@@ -71,6 +110,46 @@ pub mod pallet {
     #[pallet::getter(fn level)]
     pub type Level<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u8, ValueQuery>;
 
+    /// Bitmask of completed onboarding steps (see `ONBOARDING_*`), for UI checklists.
+    #[pallet::storage]
+    #[pallet::getter(fn onboarding_progress)]
+    pub type OnboardingProgress<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u8, ValueQuery>;
+
+    /// Cosmetics owned per account, keyed by (kind, id).
+    #[pallet::storage]
+    pub type OwnedCosmetics<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (crate::CosmeticKind, u32),
+        (),
+        OptionQuery,
+    >;
+
+    /// Cosmetics each account has equipped.
+    #[pallet::storage]
+    #[pallet::getter(fn equipped)]
+    pub type Equipped<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, crate::EquippedCosmetics, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Accounts that start with every onboarding step completed (e.g. playtest accounts).
+        pub onboarded: Vec<T::AccountId>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> frame_support::traits::BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for who in self.onboarded.iter() {
+                <OnboardingProgress<T>>::insert(who, crate::ONBOARDING_COMPLETE);
+            }
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -78,6 +157,9 @@ pub mod pallet {
         AvatarSet { who: T::AccountId, cid: Vec<u8>, charged: bool },
         ExperienceGranted { to: T::AccountId, amount: u128 },
         LevelUp { who: T::AccountId, new_level: u8 },
+        OnboardingStepCompleted { who: T::AccountId, step: u8, progress: u8 },
+        CosmeticGranted { who: T::AccountId, kind: crate::CosmeticKind, id: u32 },
+        CosmeticEquipped { who: T::AccountId, kind: crate::CosmeticKind, id: u32 },
     }
 
     #[pallet::error]
@@ -90,12 +172,24 @@ pub mod pallet {
         NotEnoughExperience,
         InsufficientBalanceForChange,
         InvalidLevelRequest,
+        /// The caller does not own this cosmetic.
+        CosmeticNotOwned,
     }
 
+    /// v0 -> v1: accounts that predate the onboarding gate are seeded as onboarded by the
+    /// runtime, which knows who owns cards.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     impl<T: Config> Pallet<T> {
+        /// The account change fees are paid to, derived from `PalletId` so no one holds its key.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
         /// Small ASCII validation for CIDs: non-empty, only visible ASCII (33..=126).
         #[inline]
         fn validate_ascii_cid(cid: &[u8]) -> bool {
@@ -140,6 +234,73 @@ pub mod pallet {
             (lvl, xp, gained)
         }
 
+        /// Credit `amount` experience to `who` on behalf of another pallet (e.g. streak rewards).
+        pub fn award_experience(who: &T::AccountId, amount: u128) {
+            Experience::<T>::mutate(who, |xp| *xp = xp.saturating_add(amount));
+            Self::deposit_event(Event::ExperienceGranted { to: who.clone(), amount });
+        }
+
+        /// Add a cosmetic to `who`'s inventory on behalf of another pallet (e.g. a battle pass
+        /// or an achievement). Granting an owned cosmetic again does nothing.
+        pub fn award_cosmetic(who: &T::AccountId, kind: crate::CosmeticKind, id: u32) {
+            if <OwnedCosmetics<T>>::contains_key(who, (kind, id)) {
+                return;
+            }
+            <OwnedCosmetics<T>>::insert(who, (kind, id), ());
+            Self::deposit_event(Event::CosmeticGranted { who: who.clone(), kind, id });
+        }
+
+        /// Whether `who` owns the cosmetic.
+        pub fn owns_cosmetic(who: &T::AccountId, kind: crate::CosmeticKind, id: u32) -> bool {
+            <OwnedCosmetics<T>>::contains_key(who, (kind, id))
+        }
+
+        /// Delete the gamer tag and avatar of `who`. Experience and level are kept.
+        /// Returns whether anything was removed.
+        pub fn burn_profile(who: &T::AccountId) -> bool {
+            let had_tag = <GamerTag<T>>::take(who).is_some();
+            let had_avatar = <AvatarCid<T>>::take(who).is_some();
+            had_tag || had_avatar
+        }
+
+        /// Record that `who` completed an onboarding `step`. Repeated steps are ignored.
+        pub fn note_onboarding_step(who: &T::AccountId, step: u8) {
+            let progress = <OnboardingProgress<T>>::get(who);
+            if progress & step == step {
+                return;
+            }
+            let progress = progress | step;
+            <OnboardingProgress<T>>::insert(who, progress);
+            Self::deposit_event(Event::OnboardingStepCompleted { who: who.clone(), step, progress });
+        }
+
+        /// Everything a profile page shows about `who`, in one read.
+        pub fn profile_summary(who: &T::AccountId) -> crate::ProfileSummary {
+            crate::ProfileSummary {
+                tag: <GamerTag<T>>::get(who).map(|t| t.into_inner()).unwrap_or_default(),
+                avatar_cid: <AvatarCid<T>>::get(who).map(|c| c.into_inner()).unwrap_or_default(),
+                level: <Level<T>>::get(who),
+                experience: <Experience<T>>::get(who),
+                onboarding_progress: <OnboardingProgress<T>>::get(who),
+            }
+        }
+
+        /// Mark every onboarding step of `who` as done, without events. For accounts that
+        /// were already playing before onboarding existed.
+        pub fn mark_onboarded(who: &T::AccountId) {
+            <OnboardingProgress<T>>::mutate(who, |p| *p |= crate::ONBOARDING_COMPLETE);
+        }
+
+        /// Whether `who` completed every onboarding step. The starter deck step also counts
+        /// as done while `has_starter_deck`, as cards bought or received are never minted.
+        pub fn is_onboarded(who: &T::AccountId, has_starter_deck: bool) -> bool {
+            let mut progress = <OnboardingProgress<T>>::get(who);
+            if has_starter_deck {
+                progress |= crate::ONBOARDING_STARTER_DECK;
+            }
+            progress & crate::ONBOARDING_COMPLETE == crate::ONBOARDING_COMPLETE
+        }
+
         fn charge_change_fee_if_needed(who: &T::AccountId, already_set: bool) -> Result<bool, Error<T>> {
             if !already_set {
                 return Ok(false);
@@ -147,7 +308,7 @@ pub mod pallet {
             let fee = T::ChangeFee::get();
             T::Currency::transfer(
                 who,
-                &T::FaucetAccount::get(),
+                &Self::account_id(),
                 fee,
                 ExistenceRequirement::KeepAlive,
             ).map_err(|_| Error::<T>::InsufficientBalanceForChange)?;
@@ -220,5 +381,33 @@ pub mod pallet {
             Self::deposit_event(Event::LevelUp { who, new_level });
             Ok(())
         }
+
+        /// (Privileged) Add a cosmetic to a player's inventory.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1,1))]
+        pub fn grant_cosmetic(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            kind: crate::CosmeticKind,
+            id: u32,
+        ) -> DispatchResult {
+            T::CosmeticIssuerOrigin::ensure_origin(origin)?;
+            Self::award_cosmetic(&to, kind, id);
+            Ok(())
+        }
+
+        /// Show an owned cosmetic in every game from now on, replacing the one of that kind.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1,1))]
+        pub fn equip_cosmetic(origin: OriginFor<T>, kind: crate::CosmeticKind, id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::owns_cosmetic(&who, kind, id), Error::<T>::CosmeticNotOwned);
+            <Equipped<T>>::mutate(&who, |equipped| match kind {
+                crate::CosmeticKind::CardBack => equipped.card_back = Some(id),
+                crate::CosmeticKind::BoardSkin => equipped.board_skin = Some(id),
+            });
+            Self::deposit_event(Event::CosmeticEquipped { who, kind, id });
+            Ok(())
+        }
     }
 }
@@ -3,7 +3,7 @@
 
 use crate as pallet_eterra_gamer;
 use frame_support::{
-    construct_runtime, parameter_types,
+    construct_runtime, parameter_types, PalletId,
 };
 use frame_system as system;
 use sp_core::H256;
@@ -19,7 +19,6 @@ pub type BlockNumber = u32;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
-pub const FAUCET: AccountId = 99;
 
 parameter_types! {
     pub const BlockHashCount: BlockNumber = 250;
@@ -28,7 +27,7 @@ parameter_types! {
     pub const MaxTagLen: u32 = 32;
     pub const MaxAvatarCidLen: u32 = 96;
     pub const ChangeFee: Balance = 100;
-    pub FaucetAccountParam: AccountId = FAUCET;
+    pub const GamerPalletId: PalletId = PalletId(*b"etr/gamr");
 }
 
 impl system::Config for Test {
@@ -83,7 +82,8 @@ impl pallet_eterra_gamer::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type ExpIssuerOrigin = frame_system::EnsureRoot<AccountId>;
-    type FaucetAccount = FaucetAccountParam;
+    type CosmeticIssuerOrigin = frame_system::EnsureRoot<AccountId>;
+    type PalletId = GamerPalletId;
     type ChangeFee = ChangeFee;
     type MaxTagLen = MaxTagLen;
     type MaxAvatarCidLen = MaxAvatarCidLen;
@@ -108,7 +108,7 @@ construct_runtime!(
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
     pallet_balances::GenesisConfig::<Test> {
-        balances: vec![(ALICE, 1_000_000), (BOB, 1_000), (FAUCET, 1)],
+        balances: vec![(ALICE, 1_000_000), (BOB, 1_000), (EterraGamer::account_id(), 1)],
     }
     .assimilate_storage(&mut t)
     .unwrap();
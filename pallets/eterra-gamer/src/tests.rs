@@ -9,8 +9,9 @@ use crate::mock::*;
 #[test]
 fn first_set_tag_is_free() {
     new_test_ext().execute_with(|| {
-        // Record starting faucet balance (may be set to ED in the mock)
-        let faucet_before = pallet_balances::Pallet::<Test>::free_balance(FAUCET);
+        // Record starting fee account balance (may be set to ED in the mock)
+        let faucet = EterraGamer::account_id();
+        let faucet_before = pallet_balances::Pallet::<Test>::free_balance(faucet);
 
         // First set by ALICE should be free
         let tag = b"AliceTheBrave".to_vec();
@@ -18,7 +19,7 @@ fn first_set_tag_is_free() {
         assert_eq!(GamerTag::<Test>::get(ALICE).unwrap().to_vec(), tag);
 
         // Faucet balance unchanged (no fee on first set)
-        assert_eq!(pallet_balances::Pallet::<Test>::free_balance(FAUCET), faucet_before);
+        assert_eq!(pallet_balances::Pallet::<Test>::free_balance(faucet), faucet_before);
     });
 }
 
@@ -29,13 +30,14 @@ fn second_set_tag_charges_fee() {
         let tag2 = b"Alice2".to_vec();
 
         assert_ok!(EterraGamer::set_gamer_tag(RuntimeOrigin::signed(ALICE), tag1));
-        let before_faucet = pallet_balances::Pallet::<Test>::free_balance(FAUCET);
+        let faucet = EterraGamer::account_id();
+        let before_faucet = pallet_balances::Pallet::<Test>::free_balance(faucet);
         let before_alice = pallet_balances::Pallet::<Test>::free_balance(ALICE);
 
         assert_ok!(EterraGamer::set_gamer_tag(RuntimeOrigin::signed(ALICE), tag2));
         // Fee moved
         let fee = ChangeFee::get();
-        assert_eq!(pallet_balances::Pallet::<Test>::free_balance(FAUCET), before_faucet + fee);
+        assert_eq!(pallet_balances::Pallet::<Test>::free_balance(faucet), before_faucet + fee);
         assert_eq!(pallet_balances::Pallet::<Test>::free_balance(ALICE), before_alice - fee);
     });
 }
@@ -124,3 +126,101 @@ fn already_max_level_fails() {
         );
     });
 }
+
+#[test]
+fn burn_profile_removes_tag_and_avatar_but_keeps_level() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EterraGamer::set_gamer_tag(RuntimeOrigin::signed(ALICE), b"Alice".to_vec()));
+        assert_ok!(EterraGamer::set_avatar(RuntimeOrigin::signed(ALICE), b"bafyok".to_vec()));
+        Level::<Test>::insert(ALICE, 3);
+
+        assert!(EterraGamer::burn_profile(&ALICE));
+        assert!(GamerTag::<Test>::get(ALICE).is_none());
+        assert!(AvatarCid::<Test>::get(ALICE).is_none());
+        assert_eq!(Level::<Test>::get(ALICE), 3);
+
+        // Nothing left to burn
+        assert!(!EterraGamer::burn_profile(&ALICE));
+    });
+}
+
+#[test]
+fn onboarding_completes_once_every_step_is_noted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert!(!EterraGamer::is_onboarded(&ALICE, false));
+
+        EterraGamer::note_onboarding_step(&ALICE, crate::ONBOARDING_FAUCET_CLAIMED);
+        EterraGamer::note_onboarding_step(&ALICE, crate::ONBOARDING_STARTER_DECK);
+        assert!(!EterraGamer::is_onboarded(&ALICE, false));
+        assert_eq!(EterraGamer::onboarding_progress(ALICE), 0b011);
+
+        EterraGamer::note_onboarding_step(&ALICE, crate::ONBOARDING_FIRST_GAME);
+        assert!(EterraGamer::is_onboarded(&ALICE, false));
+        System::assert_last_event(RuntimeEvent::EterraGamer(crate::Event::OnboardingStepCompleted {
+            who: ALICE,
+            step: crate::ONBOARDING_FIRST_GAME,
+            progress: crate::ONBOARDING_COMPLETE,
+        }));
+
+        // Repeating a step is a no-op
+        let events = System::events().len();
+        EterraGamer::note_onboarding_step(&ALICE, crate::ONBOARDING_FIRST_GAME);
+        assert_eq!(System::events().len(), events);
+    });
+}
+
+#[test]
+fn owned_starter_deck_and_seeded_accounts_count_as_onboarded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // Cards bought rather than minted still make up the starter deck
+        EterraGamer::note_onboarding_step(&ALICE, crate::ONBOARDING_FAUCET_CLAIMED);
+        EterraGamer::note_onboarding_step(&ALICE, crate::ONBOARDING_FIRST_GAME);
+        assert!(!EterraGamer::is_onboarded(&ALICE, false));
+        assert!(EterraGamer::is_onboarded(&ALICE, true));
+
+        // Accounts from before the gate are seeded without any step
+        EterraGamer::mark_onboarded(&BOB);
+        assert!(EterraGamer::is_onboarded(&BOB, false));
+        assert_eq!(EterraGamer::onboarding_progress(BOB), crate::ONBOARDING_COMPLETE);
+    });
+}
+
+#[test]
+fn only_owned_cosmetics_can_be_equipped() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let back = crate::CosmeticKind::CardBack;
+        assert_noop!(
+            EterraGamer::equip_cosmetic(RuntimeOrigin::signed(ALICE), back, 7),
+            GamerError::<Test>::CosmeticNotOwned
+        );
+        assert_noop!(
+            EterraGamer::grant_cosmetic(RuntimeOrigin::signed(BOB), ALICE, back, 7),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(EterraGamer::grant_cosmetic(RuntimeOrigin::root(), ALICE, back, 7));
+        EterraGamer::award_cosmetic(&ALICE, crate::CosmeticKind::BoardSkin, 2);
+        assert_ok!(EterraGamer::equip_cosmetic(RuntimeOrigin::signed(ALICE), back, 7));
+        assert_ok!(EterraGamer::equip_cosmetic(
+            RuntimeOrigin::signed(ALICE),
+            crate::CosmeticKind::BoardSkin,
+            2
+        ));
+        assert_eq!(
+            EterraGamer::equipped(ALICE),
+            crate::EquippedCosmetics { card_back: Some(7), board_skin: Some(2) }
+        );
+        // Owning a card back does not unlock the board skin with the same id.
+        assert_noop!(
+            EterraGamer::equip_cosmetic(
+                RuntimeOrigin::signed(ALICE),
+                crate::CosmeticKind::BoardSkin,
+                7
+            ),
+            GamerError::<Test>::CosmeticNotOwned
+        );
+    });
+}
@@ -57,6 +57,16 @@ pub trait GameAdapter {
     fn random_action(state: &Self::State, seed: u64) -> Option<Self::Action>;
 }
 
+/// Aggregate playout statistics for one root action of a search, for offline tuning.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct ActionStats<Action> {
+    pub action: Action,
+    /// Playouts run from this action.
+    pub visits: u32,
+    /// Mean playout score from the searching player's perspective.
+    pub average: i32,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -172,6 +182,36 @@ pub mod pallet {
 
         /// Monte-Carlo rollout suggestor (per-action averaging).
         pub fn suggest<A: GameAdapter>(state: &A::State, difficulty: u8) -> Option<A::Action> {
+            Self::suggest_with_iterations::<A>(state, Self::scaled_iterations::<T>(difficulty))
+        }
+
+        /// Like [`Self::suggest`], but with an explicit iteration budget instead of one derived
+        /// from difficulty. Every legal action still gets at least one playout.
+        pub fn suggest_with_iterations<A: GameAdapter>(
+            state: &A::State,
+            iterations: u32,
+        ) -> Option<A::Action> {
+            Self::search::<A>(state, iterations, |_| {})
+        }
+
+        /// Like [`Self::suggest_with_iterations`], but also returns the playout statistics of
+        /// every root action, in the order the adapter listed them.
+        pub fn suggest_with_stats<A: GameAdapter>(
+            state: &A::State,
+            iterations: u32,
+        ) -> Option<(A::Action, sp_std::vec::Vec<ActionStats<A::Action>>)> {
+            let mut stats = sp_std::vec::Vec::new();
+            let action = Self::search::<A>(state, iterations, |s| stats.push(s))?;
+            Some((action, stats))
+        }
+
+        /// Rollout search shared by the suggestors; `on_stats` sees each root action once its
+        /// playouts are done.
+        fn search<A: GameAdapter>(
+            state: &A::State,
+            iterations: u32,
+            mut on_stats: impl FnMut(ActionStats<A::Action>),
+        ) -> Option<A::Action> {
             if A::is_terminal(state) {
                 return None;
             }
@@ -187,7 +227,7 @@ pub mod pallet {
                 return None;
             }
 
-            let iters = Self::scaled_iterations::<T>(difficulty).max(n as u32);
+            let iters = iterations.max(n as u32);
             let sims_per_action = (iters / n as u32).max(1);
 
             let me = A::current_player(state);
@@ -205,6 +245,11 @@ pub mod pallet {
                     accum += outcome as i64;
                 }
                 let avg = accum / sims_per_action as i64;
+                on_stats(ActionStats {
+                    action: action.clone(),
+                    visits: sims_per_action,
+                    average: avg as i32,
+                });
                 if avg > best_score {
                     best_score = avg;
                     best_idx = i;
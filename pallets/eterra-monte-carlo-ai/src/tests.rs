@@ -96,6 +96,26 @@ fn extrinsic_emits_suggested_event() {
     });
 }
 
+#[test]
+fn stats_cover_every_root_action_and_agree_with_the_suggestion() {
+    let mut ext = crate::mock::new_test_ext();
+    ext.execute_with(|| {
+        use crate::mock::{NimAction, NimState, Test};
+        let s = NimState {
+            pile: 3,
+            to_move: 0,
+        };
+        let (action, stats) =
+            EterraAi::<Test>::suggest_with_stats::<crate::mock::NimAdapter>(&s, 40)
+                .expect("action");
+        assert_eq!(action, NimAction::Take1);
+        assert_eq!(stats.len(), 2);
+        assert!(stats.iter().all(|st| st.visits == 20));
+        let chosen = stats.iter().find(|st| st.action == action).expect("chosen action");
+        assert!(stats.iter().all(|st| st.average <= chosen.average));
+    });
+}
+
 #[test]
 fn nim_terminal_has_no_suggestion() {
     let mut ext = crate::mock::new_test_ext();
@@ -126,6 +146,7 @@ fn eterra_adapter_ai_returns_legal_move_and_applies() {
             south: s,
             west: w,
             used: false,
+            element: None,
         };
 
         // Build simple hands (5 entries each). Tweak values as needed.
@@ -155,6 +176,8 @@ fn eterra_adapter_ai_returns_legal_move_and_applies() {
             round: 0,
             max_rounds: 10,
             hands: [hand0, hand1],
+            rules: Default::default(),
+            energy: [0, 0],
         };
 
         // Ask AI for a suggestion at moderate difficulty
@@ -193,6 +216,7 @@ fn adapter_list_actions_respects_max_bound() {
             south: s,
             west: w,
             used: false,
+            element: None,
         };
         let base = mk(1, 1, 1, 1);
         let hand0 = Hand {
@@ -208,6 +232,8 @@ fn adapter_list_actions_respects_max_bound() {
             round: 0,
             max_rounds: 10,
             hands: [hand0, hand1],
+            rules: Default::default(),
+            energy: [0, 0],
         };
 
         // With an empty 4x4, maximum distinct actions is 16 cells * 5 unused cards = 80.
@@ -244,6 +270,7 @@ fn ai_prefers_capture_when_available_high_difficulty() {
             bottom: 3,
             left: 2,
             possession: Some(eterra_card_ai_adapter::eterra_adapter::Possession::PlayerTwo),
+            element: None,
         };
         board[1][1] = Some(opp_card);
 
@@ -254,6 +281,7 @@ fn ai_prefers_capture_when_available_high_difficulty() {
             south: 1,
             west: 1,
             used: false,
+            element: None,
         };
         // Fill remaining entries with dummies
         let dummy = HandEntry {
@@ -262,6 +290,7 @@ fn ai_prefers_capture_when_available_high_difficulty() {
             south: 1,
             west: 1,
             used: false,
+            element: None,
         };
         let hand0 = Hand {
             entries: [
@@ -283,6 +312,8 @@ fn ai_prefers_capture_when_available_high_difficulty() {
             round: 0,
             max_rounds: 10,
             hands: [hand0, hand1],
+            rules: Default::default(),
+            energy: [0, 0],
         };
 
         // Suggest at high difficulty – should favor the capturing move at x=0,y=1 using hand_index=0
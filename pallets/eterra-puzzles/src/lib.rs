@@ -0,0 +1,345 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Daily puzzles: a game admin publishes a scripted board position and a hand, and players
+//! solve it by submitting the sequence of placements that reaches the target score. The
+//! sequence is replayed with the same capture rules as on-chain games, and every account is
+//! rewarded at most once per puzzle through the `Rewards` hook.
+//!
+//! Solutions are committed while the puzzle is open, as `solution_hash` of the moves salted
+//! with the solver's account, and revealed in the `RevealPeriod` after it expires. A revealed
+//! sequence can then no longer be copied into a commitment of one's own. Once the reveal
+//! period is over anyone may clear the puzzle and its commitments with `clear_puzzle`.
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use eterra_core_types::board::Board;
+use eterra_core_types::card::{Card, Possession};
+use eterra_core_types::play::place_card;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::Hash;
+use sp_runtime::Saturating;
+use sp_std::vec::Vec;
+
+/// Cards a puzzle hand may hold, matching a game hand.
+pub const MAX_PUZZLE_HAND: u32 = 5;
+
+/// Commitments and solver records `clear_puzzle` removes per call.
+pub const MAX_CLEARED_PER_CALL: u32 = 256;
+
+/// Notified when an account solves a puzzle for the first time (drives puzzle rewards).
+pub trait PuzzleRewards<AccountId> {
+    fn on_puzzle_solved(who: &AccountId, puzzle_id: u32);
+}
+
+impl<AccountId> PuzzleRewards<AccountId> for () {
+    fn on_puzzle_solved(_who: &AccountId, _puzzle_id: u32) {}
+}
+
+/// Place the hand card at `hand_index` on cell (`x`, `y`).
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct PuzzleMove {
+    pub hand_index: u8,
+    pub x: u8,
+    pub y: u8,
+}
+
+/// A scripted position. The solver plays `PlayerOne` and places every move in turn, with no
+/// replies from the opponent.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Puzzle<BlockNumber> {
+    pub board: Board,
+    /// Scores of the starting position, (solver, opponent).
+    pub scores: (u8, u8),
+    /// Ranks (north, east, south, west) of the cards the solver may place, each at most once.
+    pub hand: BoundedVec<[u8; 4], ConstU32<MAX_PUZZLE_HAND>>,
+    /// Solver score that must be reached once every move is played.
+    pub target_score: u8,
+    /// Last block at which solutions are accepted.
+    pub expires_at: BlockNumber,
+}
+
+impl<BlockNumber> Puzzle<BlockNumber> {
+    /// Replay `moves` from the starting position and return the final scores, or `None` if
+    /// a move reuses a card, names an unknown card or targets an occupied or invalid cell.
+    pub fn replay(&self, moves: &[PuzzleMove]) -> Option<(u8, u8)> {
+        let mut board = self.board.clone();
+        let mut scores = self.scores;
+        let mut used = [false; MAX_PUZZLE_HAND as usize];
+        for mv in moves {
+            let idx = mv.hand_index as usize;
+            let [north, east, south, west] = *self.hand.get(idx)?;
+            if core::mem::replace(&mut used[idx], true) {
+                return None;
+            }
+            let card = Card::new(north, east, south, west);
+            place_card(&mut board, &mut scores, mv.x, mv.y, card, Possession::PlayerOne).ok()?;
+        }
+        Some(scores)
+    }
+
+    /// Whether the scores count at least the cards each side holds on the board, the
+    /// solver's also the cards of the hand, and the board has an empty cell for each card of
+    /// the hand. Every card on the board must belong to a side.
+    pub fn is_consistent(&self) -> bool {
+        let (mut solver, mut opponent, mut empty) = (0u32, 0u32, 0u32);
+        for cell in self.board.cells() {
+            match cell.as_ref().map(|card| card.possession) {
+                None => empty += 1,
+                Some(Some(Possession::PlayerOne)) => solver += 1,
+                Some(Some(Possession::PlayerTwo)) => opponent += 1,
+                Some(None) => return false,
+            }
+        }
+        let hand = self.hand.len() as u32;
+        solver + hand <= self.scores.0 as u32 && opponent <= self.scores.1 as u32 && hand <= empty
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Origin allowed to publish puzzles (e.g. Root or a council)
+        type PuzzleAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Blocks a published puzzle accepts solution commitments
+        #[pallet::constant]
+        type PuzzleDuration: Get<BlockNumberFor<Self>>;
+        /// Blocks after a puzzle expires in which committed solutions can be revealed
+        #[pallet::constant]
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+        /// Hook notified on each first solve of a puzzle
+        type Rewards: crate::PuzzleRewards<Self::AccountId>;
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_puzzle_id)]
+    pub type NextPuzzleId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn puzzle)]
+    pub type Puzzles<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, Puzzle<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Accounts already rewarded for a puzzle.
+    #[pallet::storage]
+    pub type SolvedBy<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Solution commitment of an account for a puzzle, see `Pallet::solution_hash`.
+    #[pallet::storage]
+    pub type SolutionCommits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        T::Hash,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        PuzzlePublished {
+            puzzle_id: u32,
+            expires_at: BlockNumberFor<T>,
+        },
+        PuzzleSolved {
+            puzzle_id: u32,
+            who: T::AccountId,
+        },
+        SolutionCommitted {
+            puzzle_id: u32,
+            who: T::AccountId,
+        },
+        PuzzleCleared {
+            puzzle_id: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The hand holds more than `MAX_PUZZLE_HAND` cards.
+        HandTooLarge,
+        /// The target is already met by the starting position.
+        TargetAlreadyMet,
+        /// The scores do not count the cards on the board and in the hand, a card on the
+        /// board belongs to no side, or the board has too few empty cells for the hand.
+        InconsistentPosition,
+        /// No puzzle has this id.
+        UnknownPuzzle,
+        /// The puzzle no longer accepts solution commitments.
+        PuzzleExpired,
+        /// Solutions are revealed only once the puzzle has expired.
+        RevealNotOpen,
+        /// The reveal period of the puzzle is over.
+        RevealClosed,
+        /// The caller committed no solution to this puzzle.
+        NoCommitment,
+        /// The revealed moves do not match the caller's commitment.
+        CommitmentMismatch,
+        /// The caller was already rewarded for this puzzle.
+        AlreadySolved,
+        /// A move reuses or names an unknown card, or targets an occupied or invalid cell.
+        InvalidMove,
+        /// The moves are legal but do not reach the target score.
+        NotSolved,
+        /// The reveal period of the puzzle is not over yet.
+        RevealStillOpen,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// (Puzzle admin) Publish a position for the next `PuzzleDuration` blocks.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+        pub fn publish_puzzle(
+            origin: OriginFor<T>,
+            board: Board,
+            scores: (u8, u8),
+            hand: Vec<[u8; 4]>,
+            target_score: u8,
+        ) -> DispatchResult {
+            T::PuzzleAdminOrigin::ensure_origin(origin)?;
+            let hand: BoundedVec<_, _> = hand.try_into().map_err(|_| Error::<T>::HandTooLarge)?;
+            ensure!(target_score > scores.0, Error::<T>::TargetAlreadyMet);
+            let expires_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::PuzzleDuration::get());
+            let puzzle = Puzzle {
+                board,
+                scores,
+                hand,
+                target_score,
+                expires_at,
+            };
+            ensure!(puzzle.is_consistent(), Error::<T>::InconsistentPosition);
+
+            let puzzle_id = NextPuzzleId::<T>::get();
+            NextPuzzleId::<T>::put(puzzle_id.wrapping_add(1));
+            Puzzles::<T>::insert(puzzle_id, puzzle);
+            Self::deposit_event(Event::PuzzlePublished {
+                puzzle_id,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// Reveal the placement sequence committed for a puzzle. Rewarded once per account if
+        /// it matches the commitment and the replay reaches the target score.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 2))]
+        pub fn solve_puzzle(
+            origin: OriginFor<T>,
+            puzzle_id: u32,
+            moves: Vec<PuzzleMove>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let puzzle = Puzzles::<T>::get(puzzle_id).ok_or(Error::<T>::UnknownPuzzle)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now > puzzle.expires_at, Error::<T>::RevealNotOpen);
+            ensure!(
+                now <= puzzle.expires_at.saturating_add(T::RevealPeriod::get()),
+                Error::<T>::RevealClosed
+            );
+            ensure!(
+                !SolvedBy::<T>::contains_key(puzzle_id, &who),
+                Error::<T>::AlreadySolved
+            );
+            let commitment =
+                SolutionCommits::<T>::get(puzzle_id, &who).ok_or(Error::<T>::NoCommitment)?;
+            ensure!(
+                commitment == Self::solution_hash(&who, &moves),
+                Error::<T>::CommitmentMismatch
+            );
+
+            let (score, _) = puzzle.replay(&moves).ok_or(Error::<T>::InvalidMove)?;
+            ensure!(score >= puzzle.target_score, Error::<T>::NotSolved);
+
+            SolutionCommits::<T>::remove(puzzle_id, &who);
+            SolvedBy::<T>::insert(puzzle_id, &who, ());
+            T::Rewards::on_puzzle_solved(&who, puzzle_id);
+            Self::deposit_event(Event::PuzzleSolved { puzzle_id, who });
+            Ok(())
+        }
+
+        /// Commit to a solution while the puzzle is open; `commitment` is
+        /// `solution_hash(who, moves)`. A later commitment replaces the earlier one.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+        pub fn commit_solution(
+            origin: OriginFor<T>,
+            puzzle_id: u32,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let puzzle = Puzzles::<T>::get(puzzle_id).ok_or(Error::<T>::UnknownPuzzle)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= puzzle.expires_at,
+                Error::<T>::PuzzleExpired
+            );
+            ensure!(
+                !SolvedBy::<T>::contains_key(puzzle_id, &who),
+                Error::<T>::AlreadySolved
+            );
+
+            SolutionCommits::<T>::insert(puzzle_id, &who, commitment);
+            Self::deposit_event(Event::SolutionCommitted { puzzle_id, who });
+            Ok(())
+        }
+
+        /// Remove a puzzle whose reveal period is over, with its commitments and solver
+        /// records. Callable by anyone; clears at most `MAX_CLEARED_PER_CALL` entries, and
+        /// the puzzle itself goes with the last of them, so a large puzzle takes several
+        /// calls.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(
+            1 + MAX_CLEARED_PER_CALL as u64,
+            1 + MAX_CLEARED_PER_CALL as u64,
+        ))]
+        pub fn clear_puzzle(origin: OriginFor<T>, puzzle_id: u32) -> DispatchResult {
+            ensure_signed(origin)?;
+            let puzzle = Puzzles::<T>::get(puzzle_id).ok_or(Error::<T>::UnknownPuzzle)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number()
+                    > puzzle.expires_at.saturating_add(T::RevealPeriod::get()),
+                Error::<T>::RevealStillOpen
+            );
+
+            let commits = SolutionCommits::<T>::clear_prefix(puzzle_id, MAX_CLEARED_PER_CALL, None);
+            if commits.maybe_cursor.is_some() {
+                return Ok(());
+            }
+            let solvers = SolvedBy::<T>::clear_prefix(
+                puzzle_id,
+                MAX_CLEARED_PER_CALL.saturating_sub(commits.unique),
+                None,
+            );
+            if solvers.maybe_cursor.is_some() {
+                return Ok(());
+            }
+            Puzzles::<T>::remove(puzzle_id);
+            Self::deposit_event(Event::PuzzleCleared { puzzle_id });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Commitment to `moves` for `who`. The account salts the hash, so a copied
+        /// commitment cannot be revealed by anyone else.
+        pub fn solution_hash(who: &T::AccountId, moves: &[PuzzleMove]) -> T::Hash {
+            T::Hashing::hash_of(&(who, moves))
+        }
+    }
+}
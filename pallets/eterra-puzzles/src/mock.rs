@@ -0,0 +1,90 @@
+//! Mock runtime for pallet-eterra-puzzles tests.
+#![cfg(test)]
+
+use crate as pallet_eterra_puzzles;
+use frame_support::{
+    construct_runtime,
+    traits::{ConstU32, ConstU64, Everything},
+};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::BuildStorage;
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        EterraPuzzles: pallet_eterra_puzzles,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = ();
+    type Nonce = u64;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+thread_local! {
+    static REWARDS: std::cell::RefCell<Vec<(AccountId, u32)>> = Default::default();
+}
+
+/// Test-only reward hook recording every first solve.
+pub struct MockRewards;
+impl pallet_eterra_puzzles::PuzzleRewards<AccountId> for MockRewards {
+    fn on_puzzle_solved(who: &AccountId, puzzle_id: u32) {
+        REWARDS.with(|r| r.borrow_mut().push((*who, puzzle_id)));
+    }
+}
+
+pub fn rewards() -> Vec<(AccountId, u32)> {
+    REWARDS.with(|r| r.borrow().clone())
+}
+
+impl pallet_eterra_puzzles::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type PuzzleAdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type PuzzleDuration = ConstU64<10>;
+    type RevealPeriod = ConstU64<5>;
+    type Rewards = MockRewards;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
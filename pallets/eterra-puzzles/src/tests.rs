@@ -0,0 +1,229 @@
+//! Unit tests for pallet-eterra-puzzles.
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::{Error, Event, PuzzleMove};
+use eterra_core_types::board::Board;
+use eterra_core_types::card::{Card, Possession};
+use frame_support::{assert_noop, assert_ok};
+
+/// One weak opponent card in the middle; the solver must capture it to reach 6.
+fn publish_capture_puzzle() {
+    let mut board: Board = Default::default();
+    board[1][1] = Some(Card::new(3, 3, 3, 3).with_possession(Possession::PlayerTwo));
+    assert_ok!(EterraPuzzles::publish_puzzle(
+        RuntimeOrigin::root(),
+        board,
+        (5, 5),
+        vec![[5, 5, 5, 5], [1, 1, 1, 1]],
+        6,
+    ));
+}
+
+fn mv(hand_index: u8, x: u8, y: u8) -> PuzzleMove {
+    PuzzleMove { hand_index, x, y }
+}
+
+fn commit(who: AccountId, moves: &[PuzzleMove]) {
+    let commitment = EterraPuzzles::solution_hash(&who, moves);
+    assert_ok!(EterraPuzzles::commit_solution(
+        RuntimeOrigin::signed(who),
+        0,
+        commitment
+    ));
+}
+
+/// First block of the reveal period of a puzzle published at block 1.
+const REVEAL_BLOCK: u64 = 12;
+
+#[test]
+fn correct_solution_is_rewarded_once_per_account() {
+    new_test_ext().execute_with(|| {
+        publish_capture_puzzle();
+        commit(ALICE, &[mv(0, 1, 0)]);
+        commit(BOB, &[mv(1, 0, 0), mv(0, 1, 2)]);
+        System::set_block_number(REVEAL_BLOCK);
+
+        assert_ok!(EterraPuzzles::solve_puzzle(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            vec![mv(0, 1, 0)]
+        ));
+        System::assert_last_event(
+            Event::PuzzleSolved {
+                puzzle_id: 0,
+                who: ALICE,
+            }
+            .into(),
+        );
+        assert_noop!(
+            EterraPuzzles::solve_puzzle(RuntimeOrigin::signed(ALICE), 0, vec![mv(0, 1, 0)]),
+            Error::<Test>::AlreadySolved
+        );
+        assert_ok!(EterraPuzzles::solve_puzzle(
+            RuntimeOrigin::signed(BOB),
+            0,
+            vec![mv(1, 0, 0), mv(0, 1, 2)]
+        ));
+        assert_eq!(rewards(), vec![(ALICE, 0), (BOB, 0)]);
+    });
+}
+
+#[test]
+fn wrong_or_illegal_sequences_are_rejected() {
+    new_test_ext().execute_with(|| {
+        publish_capture_puzzle();
+
+        // The weak card cannot capture; reusing a card, playing onto the opponent's card or
+        // off the board is illegal.
+        let attempts = [
+            (vec![mv(1, 1, 0)], Error::<Test>::NotSolved),
+            (vec![mv(1, 0, 0), mv(1, 1, 0)], Error::<Test>::InvalidMove),
+            (vec![mv(0, 1, 1)], Error::<Test>::InvalidMove),
+            (vec![mv(0, 4, 0)], Error::<Test>::InvalidMove),
+            (vec![mv(2, 1, 0)], Error::<Test>::InvalidMove),
+        ];
+        for (moves, error) in attempts {
+            System::set_block_number(1);
+            commit(ALICE, &moves);
+            System::set_block_number(REVEAL_BLOCK);
+            assert_noop!(
+                EterraPuzzles::solve_puzzle(RuntimeOrigin::signed(ALICE), 0, moves),
+                error
+            );
+        }
+        assert!(rewards().is_empty());
+    });
+}
+
+#[test]
+fn solutions_are_revealed_against_the_callers_own_commitment() {
+    new_test_ext().execute_with(|| {
+        publish_capture_puzzle();
+        let solution = vec![mv(0, 1, 0)];
+        commit(ALICE, &solution);
+
+        // Reveals wait for the puzzle to expire, so nobody sees a solution while it is open.
+        assert_noop!(
+            EterraPuzzles::solve_puzzle(RuntimeOrigin::signed(ALICE), 0, solution.clone()),
+            Error::<Test>::RevealNotOpen
+        );
+
+        // Copying Alice's commitment does not help Bob: it is salted with her account.
+        let copied = crate::SolutionCommits::<Test>::get(0, ALICE).unwrap();
+        assert_ok!(EterraPuzzles::commit_solution(
+            RuntimeOrigin::signed(BOB),
+            0,
+            copied
+        ));
+        System::set_block_number(REVEAL_BLOCK);
+        assert_noop!(
+            EterraPuzzles::solve_puzzle(RuntimeOrigin::signed(BOB), 0, solution.clone()),
+            Error::<Test>::CommitmentMismatch
+        );
+        assert_noop!(
+            EterraPuzzles::solve_puzzle(RuntimeOrigin::signed(3), 0, solution.clone()),
+            Error::<Test>::NoCommitment
+        );
+        assert_ok!(EterraPuzzles::solve_puzzle(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            solution
+        ));
+        assert_eq!(rewards(), vec![(ALICE, 0)]);
+    });
+}
+
+#[test]
+fn puzzles_expire_and_are_admin_only() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EterraPuzzles::publish_puzzle(
+                RuntimeOrigin::signed(ALICE),
+                Default::default(),
+                (5, 5),
+                vec![],
+                6,
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        publish_capture_puzzle();
+        commit(ALICE, &[mv(0, 1, 0)]);
+
+        System::set_block_number(REVEAL_BLOCK);
+        let commitment = EterraPuzzles::solution_hash(&BOB, &[mv(0, 1, 0)]);
+        assert_noop!(
+            EterraPuzzles::commit_solution(RuntimeOrigin::signed(BOB), 0, commitment),
+            Error::<Test>::PuzzleExpired
+        );
+
+        // Reveals close `RevealPeriod` blocks after the puzzle expired.
+        System::set_block_number(REVEAL_BLOCK + 5);
+        assert_noop!(
+            EterraPuzzles::solve_puzzle(RuntimeOrigin::signed(ALICE), 0, vec![mv(0, 1, 0)]),
+            Error::<Test>::RevealClosed
+        );
+    });
+}
+
+#[test]
+fn positions_the_scores_do_not_account_for_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let opponent = Card::new(3, 3, 3, 3).with_possession(Possession::PlayerTwo);
+        let mut one_card: Board = Default::default();
+        one_card[1][1] = Some(opponent.clone());
+        let mut unowned: Board = Default::default();
+        unowned[1][1] = Some(Card::new(3, 3, 3, 3));
+        let mut full: Board = Default::default();
+        for x in 0..3 {
+            for y in 0..3 {
+                full[x][y] = Some(opponent.clone());
+            }
+        }
+
+        // The opponent's card is not counted, the hand outnumbers the solver's score, a card
+        // belongs to nobody, or the hand has nowhere to go.
+        let attempts = [
+            (one_card.clone(), (5, 0), vec![[5, 5, 5, 5]]),
+            (one_card, (1, 5), vec![[5, 5, 5, 5], [1, 1, 1, 1]]),
+            (unowned, (5, 5), vec![[5, 5, 5, 5]]),
+            (full, (5, 9), vec![[5, 5, 5, 5]]),
+        ];
+        for (board, scores, hand) in attempts {
+            assert_noop!(
+                EterraPuzzles::publish_puzzle(RuntimeOrigin::root(), board, scores, hand, 6),
+                Error::<Test>::InconsistentPosition
+            );
+        }
+    });
+}
+
+#[test]
+fn finished_puzzles_are_cleared_with_their_commitments() {
+    new_test_ext().execute_with(|| {
+        publish_capture_puzzle();
+        commit(ALICE, &[mv(0, 1, 0)]);
+        commit(BOB, &[mv(1, 1, 0)]);
+        System::set_block_number(REVEAL_BLOCK);
+        assert_ok!(EterraPuzzles::solve_puzzle(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            vec![mv(0, 1, 0)]
+        ));
+        assert_noop!(
+            EterraPuzzles::clear_puzzle(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::RevealStillOpen
+        );
+
+        System::set_block_number(REVEAL_BLOCK + 5);
+        assert_ok!(EterraPuzzles::clear_puzzle(RuntimeOrigin::signed(BOB), 0));
+        System::assert_last_event(Event::PuzzleCleared { puzzle_id: 0 }.into());
+        assert!(EterraPuzzles::puzzle(0).is_none());
+        assert!(!crate::SolvedBy::<Test>::contains_key(0, ALICE));
+        assert!(!crate::SolutionCommits::<Test>::contains_key(0, BOB));
+        assert_noop!(
+            EterraPuzzles::clear_puzzle(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::UnknownPuzzle
+        );
+    });
+}
@@ -0,0 +1,167 @@
+//! Round-robin leagues: a roster registers during a sign-up window, then every pair of
+//! players meets once. Fixtures are grouped into matchdays, each playable during its own
+//! block window, and results award league points. Unlike series, which are formed by the
+//! queue, the roster is fixed when the league starts.
+
+use frame_support::pallet_prelude::*;
+use sp_std::prelude::*;
+
+/// Players a league roster may hold.
+pub const MAX_LEAGUE_PLAYERS: u32 = 16;
+/// League points for a win.
+pub const WIN_POINTS: u32 = 3;
+/// League points for a draw.
+pub const DRAW_POINTS: u32 = 1;
+
+pub type LeagueId = u32;
+/// Index of a fixture within its league, in matchday order.
+pub type FixtureIndex = u32;
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum LeagueStatus<BlockNumber> {
+    /// Players may join until the league's `registration_ends` block.
+    Registration,
+    /// Fixtures were generated; matchday `d` is playable from `started_at + d *
+    /// matchday_length` until the next matchday begins.
+    Running {
+        started_at: BlockNumber,
+        matchdays: u32,
+        /// Fixtures not yet played or missed.
+        open_fixtures: u32,
+    },
+    Finished,
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct League<AccountId, BlockNumber> {
+    pub players: BoundedVec<AccountId, ConstU32<MAX_LEAGUE_PLAYERS>>,
+    /// Last block at which players may join.
+    pub registration_ends: BlockNumber,
+    /// Blocks each matchday stays playable.
+    pub matchday_length: BlockNumber,
+    pub status: LeagueStatus<BlockNumber>,
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum FixtureState<AccountId, GameId> {
+    /// Waiting for either player to start the game.
+    Scheduled,
+    Live(GameId),
+    Played {
+        winner: Option<AccountId>,
+    },
+    /// Nobody started the game before the matchday ended; neither player scores.
+    Missed,
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Fixture<AccountId, GameId> {
+    pub matchday: u32,
+    pub players: [AccountId; 2],
+    pub state: FixtureState<AccountId, GameId>,
+}
+
+/// A player's league record.
+#[derive(
+    Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug,
+)]
+pub struct Standing {
+    pub played: u32,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub points: u32,
+}
+
+impl Standing {
+    /// Count a played fixture, `Some(true)` for a win and `None` for a draw.
+    pub fn record(&mut self, won: Option<bool>) {
+        self.played = self.played.saturating_add(1);
+        match won {
+            Some(true) => {
+                self.won = self.won.saturating_add(1);
+                self.points = self.points.saturating_add(WIN_POINTS);
+            }
+            Some(false) => self.lost = self.lost.saturating_add(1),
+            None => {
+                self.drawn = self.drawn.saturating_add(1);
+                self.points = self.points.saturating_add(DRAW_POINTS);
+            }
+        }
+    }
+}
+
+/// Reward of every rank below `up_to_rank` that no earlier tier covers. Ranks start at 0.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RewardTier<Balance> {
+    pub up_to_rank: u32,
+    pub amount: Balance,
+    /// (north, east, south, west) ranks of a card granted on top of `amount`.
+    pub card: Option<[u8; 4]>,
+}
+
+/// The tier paying `rank`, if any. `tiers` are ordered by `up_to_rank`.
+pub fn tier_for<Balance>(tiers: &[RewardTier<Balance>], rank: u32) -> Option<&RewardTier<Balance>> {
+    tiers.iter().find(|tier| rank < tier.up_to_rank)
+}
+
+/// Final ranking of a league, frozen when its season rewards are distributed, and the rank
+/// to pay next.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SeasonPayout<AccountId> {
+    pub ranking: BoundedVec<AccountId, ConstU32<MAX_LEAGUE_PLAYERS>>,
+    pub next_rank: u32,
+}
+
+impl<AccountId> SeasonPayout<AccountId> {
+    pub fn is_complete(&self) -> bool {
+        self.next_rank as usize >= self.ranking.len()
+    }
+}
+
+/// Round-robin schedule of `players` roster seats using the circle method: one list of
+/// seat pairs per matchday, every pair meeting exactly once. Odd rosters get a bye seat,
+/// so one player rests on each matchday.
+pub fn round_robin(players: u32) -> Vec<Vec<(u32, u32)>> {
+    if players < 2 {
+        return Vec::new();
+    }
+    let seats = players + players % 2;
+    // Seat 0 stays put while the others rotate around it.
+    let mut ring: Vec<u32> = (1..seats).collect();
+    let mut matchdays = Vec::new();
+    for _ in 1..seats {
+        let lineup: Vec<u32> = core::iter::once(0).chain(ring.iter().copied()).collect();
+        let pairs = (0..seats as usize / 2)
+            .map(|i| (lineup[i], lineup[seats as usize - 1 - i]))
+            .filter(|(a, b)| *a < players && *b < players)
+            .collect();
+        matchdays.push(pairs);
+        ring.rotate_right(1);
+    }
+    matchdays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_std::collections::btree_set::BTreeSet;
+
+    #[test]
+    fn every_pair_meets_once_and_nobody_plays_twice_a_matchday() {
+        for players in 2..=MAX_LEAGUE_PLAYERS {
+            let schedule = round_robin(players);
+            assert_eq!(schedule.len() as u32, players + players % 2 - 1);
+            let mut pairs = BTreeSet::new();
+            for matchday in schedule.iter() {
+                let mut seen = BTreeSet::new();
+                for (a, b) in matchday.iter().copied() {
+                    assert!(seen.insert(a) && seen.insert(b));
+                    assert!(pairs.insert((a.min(b), a.max(b))));
+                }
+            }
+            assert_eq!(pairs.len() as u32, players * (players - 1) / 2);
+        }
+        assert!(round_robin(1).is_empty());
+    }
+}
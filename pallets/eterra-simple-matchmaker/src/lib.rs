@@ -2,28 +2,156 @@
 
 pub use pallet::*;
 
-use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::Get};
+use frame_support::{
+    dispatch::DispatchResult,
+    pallet_prelude::*,
+    traits::{Currency, ExistenceRequirement, Get, ReservableCurrency},
+    PalletId,
+};
 use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    traits::{AccountIdConversion, Hash, Zero},
+    Saturating,
+};
 use sp_std::prelude::*;
 
 /// A lightweight bridge to verify that an account has configured a Current Hand
 /// in the cards/game pallet. The runtime implements this by delegating to the
-/// other pallet's storage (e.g. `eterra::CurrentHandOf`).
+/// other pallet's storage (e.g. the active `eterra::Decks` entry).
 pub trait CurrentHandProvider<AccountId> {
     /// Returns true iff the account has a non-None current hand configured.
     fn has_current_hand(who: &AccountId) -> bool;
+    /// Card count and power score of the account's current hand, if any.
+    fn hand_summary(who: &AccountId) -> Option<HandSummary>;
+    /// Pin the account's current hand to its new queue entry, so the game it is matched
+    /// into uses that hand even if the player edits their current hand while waiting.
+    fn bind_queued_hand(_who: &AccountId) {}
+    /// Release the hand pinned by `bind_queued_hand` once the entry is matched or left.
+    fn release_queued_hand(_who: &AccountId) {}
+}
+
+/// Aggregate stats of a player's current hand, used to gate the ranked queue.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct HandSummary {
+    pub cards: u32,
+    /// Power score of the whole hand, as computed by the cards/game pallet.
+    pub total_power: u32,
+}
+
+/// Skill rating of queued players, used to pair players of similar strength. The runtime
+/// implements this with the game pallet's Elo ratings.
+pub trait RatingProvider<AccountId> {
+    /// The account's rating, or `None` if it has none; unrated players pair with anyone.
+    fn rating(who: &AccountId) -> Option<u32>;
+}
+
+impl<AccountId> RatingProvider<AccountId> for () {
+    fn rating(_who: &AccountId) -> Option<u32> {
+        None
+    }
+}
+
+/// Whether an account finished onboarding and may enter the ranked queue. The runtime
+/// implements this by delegating to the gamer/profile pallet.
+pub trait OnboardingStatus<AccountId> {
+    fn is_onboarded(who: &AccountId) -> bool;
+}
+
+impl<AccountId> OnboardingStatus<AccountId> for () {
+    fn is_onboarded(_who: &AccountId) -> bool {
+        true
+    }
+}
+
+/// Coarse location hint a player may attach to their queue entry. The matchmaker prefers
+/// pairing players of the same region, which keeps real-time clients on nearby servers.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Region {
+    NorthAmerica,
+    SouthAmerica,
+    Europe,
+    Africa,
+    MiddleEast,
+    Asia,
+    Oceania,
+}
+
+/// A join accepted during the current block. It gets its ticket in `on_finalize`, when the
+/// block's joins are ordered by a seed the block author cannot choose.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PendingJoin<AccountId> {
+    pub who: AccountId,
+    pub best_of: Option<u8>,
+    pub region: Option<Region>,
 }
 
 /// A callback interface the runtime/game pallet implements so the matchmaker
 /// can create a game the moment two players are matched.
 pub trait GameCreator<AccountId> {
     /// The concrete GameId type of the game pallet.
-    type GameId;
+    type GameId: Parameter + MaxEncodedLen;
     /// Create a new game for the given players. Implemented in the game pallet.
     fn create_from_matchmaking(
         p1: &AccountId,
         p2: &AccountId,
     ) -> Result<Self::GameId, sp_runtime::DispatchError>;
+    /// Whether `who` is free to enter the ranked queue (e.g. not already in a ranked game).
+    /// Games in other contexts (casual, PvE, tournament) must not block queueing.
+    fn can_queue(_who: &AccountId) -> bool {
+        true
+    }
+    /// Worst-case weight of one `create_from_matchmaking`, reserved for the pairing at the
+    /// end of every block.
+    fn creation_weight() -> Weight {
+        Weight::zero()
+    }
+    /// Create the game of a league fixture. Defaults to a matchmaking game; the game pallet
+    /// may create it in a context that does not count towards ranked play.
+    fn create_for_league(
+        p1: &AccountId,
+        p2: &AccountId,
+    ) -> Result<Self::GameId, sp_runtime::DispatchError> {
+        Self::create_from_matchmaking(p1, p2)
+    }
+    /// Create the game of a tournament bracket match. Defaults to a league game, which is
+    /// kept out of ranked play as well.
+    fn create_for_tournament(
+        p1: &AccountId,
+        p2: &AccountId,
+    ) -> Result<Self::GameId, sp_runtime::DispatchError> {
+        Self::create_for_league(p1, p2)
+    }
+}
+
+/// Notified by the game pallet whenever a game ends, so that series bound to the game
+/// can record the result and start their next game.
+pub trait GameOutcomeObserver<AccountId, GameId> {
+    fn on_game_finished(game_id: &GameId, winner: Option<&AccountId>);
+    /// Both seats of the game have played their first turn.
+    fn on_first_round_played(_game_id: &GameId) {}
+    /// `who` forfeited, timed out or deregistered before the first round was played.
+    fn on_abandoned_before_first_round(_game_id: &GameId, _who: &AccountId) {}
+    /// A moderator cancelled the game without a result.
+    fn on_game_voided(_game_id: &GameId) {}
+    /// An admin handed `old`'s seat in the live game to `new`.
+    fn on_seat_reassigned(_game_id: &GameId, _old: &AccountId, _new: &AccountId) {}
+}
+
+impl<AccountId, GameId> GameOutcomeObserver<AccountId, GameId> for () {
+    fn on_game_finished(_game_id: &GameId, _winner: Option<&AccountId>) {}
+}
+
+/// Grants the card part of season rewards. The runtime implements this with the cards
+/// pallet.
+pub trait SeasonCardRewards<AccountId> {
+    /// Give `who` a new card with `edges`, returning its id, or `None` if none was created.
+    fn grant_card(who: &AccountId, edges: [u8; 4]) -> Option<u32>;
+}
+
+impl<AccountId> SeasonCardRewards<AccountId> for () {
+    fn grant_card(_who: &AccountId, _edges: [u8; 4]) -> Option<u32> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -32,9 +160,19 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod simulation;
+
+pub mod league;
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use crate::league::{
+        round_robin, tier_for, Fixture, FixtureIndex, FixtureState, League, LeagueId,
+        LeagueStatus, RewardTier, SeasonPayout, Standing,
+    };
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -42,7 +180,7 @@ pub mod pallet {
         #[pallet::constant]
         type PlayersPerMatch: Get<u8>;
 
-        /// Capacity of the matchmaking queue.
+        /// Maximum number of accounts waiting in the matchmaking queue.
         #[pallet::constant]
         type QueueCapacity: Get<u32>;
         /// A runtime hook used to check whether a player has a preset hand.
@@ -50,52 +188,386 @@ pub mod pallet {
         type HandProvider: super::CurrentHandProvider<Self::AccountId>;
         /// Hook to the game pallet that actually creates a game once two players are matched.
         type GameCreator: super::GameCreator<Self::AccountId>;
+        /// Longest series (best-of-N, N odd) players may request through `join_series_queue`.
+        #[pallet::constant]
+        type MaxSeriesLength: Get<u8>;
+        /// Minimum `HandSummary::total_power` needed to enter the queue (0 disables the check).
+        /// The queue only feeds ranked games; casual games are created directly and are exempt.
+        #[pallet::constant]
+        type MinRankedHandPower: Get<u32>;
+        /// When non-zero, the oldest queued player is paired with the queued player whose
+        /// hand power is closest to theirs, provided the difference is at most this value;
+        /// players without a close enough opponent keep waiting. 0 keeps strict FIFO pairing.
+        #[pallet::constant]
+        type MaxHandPowerGap: Get<u32>;
+        /// Ratings of queued players.
+        type Ratings: super::RatingProvider<Self::AccountId>;
+        /// When non-zero, only players whose ratings differ by at most this value are paired,
+        /// and the partner with the closest rating is preferred over the closest hand power.
+        /// 0 ignores ratings.
+        #[pallet::constant]
+        type MaxRatingGap: Get<u32>;
+        /// Oldest queued players the pairing scan compares when a power, rating or region
+        /// constraint applies; younger tickets wait until they move into the window.
+        #[pallet::constant]
+        type PairingWindow: Get<u32>;
+        /// Onboarding gate checked before joining the queue.
+        type Onboarding: crate::OnboardingStatus<Self::AccountId>;
+        /// Blocks a player who joined with a region waits for a same-region opponent before
+        /// being paired across regions. Players without a region pair with anyone.
+        #[pallet::constant]
+        type MaxRegionWaitBlocks: Get<BlockNumberFor<Self>>;
+        /// Currency the queue deposit is reserved in.
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// Reserved on joining the queue and released once the matched game's first round is
+        /// played, or on leaving the queue. Slashed if the player's hand is gone when they are
+        /// matched, or if they abandon the game before round 1. 0 disables the deposit.
+        #[pallet::constant]
+        type QueueDeposit: Get<BalanceOf<Self>>;
+        /// Most pairs turned into games per block. Pairs beyond it stay queued and are paired
+        /// at the end of the next block, so a burst of joins cannot flood a single block.
+        #[pallet::constant]
+        type MaxGamesCreatedPerBlock: Get<u32>;
+        /// Origin allowed to open leagues (e.g. Root or a council).
+        type LeagueAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Blocks a queued player may go without a `heartbeat` before being dropped from the
+        /// queue at the start of a block. Being ticketed counts as a heartbeat. 0 disables it.
+        #[pallet::constant]
+        type QueueHeartbeatBlocks: Get<BlockNumberFor<Self>>;
+        /// Id of the account season rewards are paid from, see `Pallet::account_id`.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+        /// Season reward tiers, ordered by `up_to_rank`.
+        type SeasonRewardTiers: Get<Vec<RewardTier<BalanceOf<Self>>>>;
+        /// Ranks paid per block while season rewards are distributed.
+        #[pallet::constant]
+        type SeasonRewardBatch: Get<u32>;
+        /// Grants the card part of season rewards.
+        type SeasonCards: crate::SeasonCardRewards<Self::AccountId>;
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
     }
 
-    pub type QIndex = u32;
+    /// Queue ticket. Tickets are handed out in increasing order and never reused.
+    pub type QIndex = u64;
+    pub type SeriesId = u32;
+    pub type GameIdOf<T> = <<T as Config>::GameCreator as super::GameCreator<
+        <T as frame_system::Config>::AccountId,
+    >>::GameId;
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    /// A best-of-N series between a matched pair. Draws do not count towards either side, so
+    /// a series that has not been decided after `2 * best_of` games ends undecided.
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct Series<AccountId, GameId> {
+        pub players: [AccountId; 2],
+        pub best_of: u8,
+        pub wins: (u8, u8),
+        pub current_game: GameId,
+        /// Finished games of the series, draws included.
+        pub games: u8,
+    }
+
+    /// v0 -> v1: the wrapping `Head`/`Tail`/`Ring` buffer became a ticket-indexed counted map.
+    /// v1 -> v2: `Series` counts its finished games.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Oldest ticket that may still be queued; every ticket below it was popped or left.
     #[pallet::storage]
     #[pallet::getter(fn head)]
-    pub type Head<T: Config> = StorageValue<_, QIndex, ValueQuery>;
+    pub type OldestTicket<T: Config> = StorageValue<_, QIndex, ValueQuery>;
 
+    /// Ticket handed to the next joiner.
     #[pallet::storage]
     #[pallet::getter(fn tail)]
-    pub type Tail<T: Config> = StorageValue<_, QIndex, ValueQuery>;
+    pub type NextTicket<T: Config> = StorageValue<_, QIndex, ValueQuery>;
+
+    /// Queued accounts by ticket. Leaving removes the entry, so no stale slots are left behind.
+    #[pallet::storage]
+    pub type Queue<T: Config> =
+        CountedStorageMap<_, Twox64Concat, QIndex, T::AccountId, OptionQuery>;
+
+    /// Ticket held by each queued account.
+    #[pallet::storage]
+    #[pallet::getter(fn ticket_of)]
+    pub type TicketOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, QIndex, OptionQuery>;
+
+    /// Series length requested by a queued player; absent means a single game.
+    #[pallet::storage]
+    #[pallet::getter(fn series_request)]
+    pub type SeriesRequest<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u8, OptionQuery>;
+
+    /// Joins of the current block, in extrinsic order until `on_finalize` reorders them.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_joins)]
+    pub type PendingJoins<T: Config> = StorageValue<
+        _,
+        BoundedVec<crate::PendingJoin<T::AccountId>, T::QueueCapacity>,
+        ValueQuery,
+    >;
+
+    /// Games created by pairing in the given block, checked against `MaxGamesCreatedPerBlock`.
+    #[pallet::storage]
+    #[pallet::getter(fn games_created)]
+    pub type GamesCreated<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+    /// Region hint of a queued player and the block they joined in. Counted, so pairing
+    /// only pays for region checks while someone queued with a region.
+    #[pallet::storage]
+    #[pallet::getter(fn region_of)]
+    pub type RegionOf<T: Config> = CountedStorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (crate::Region, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    /// Season rewards of each finished league, frozen on `distribute_season_rewards`. An
+    /// entry is never removed, so a season is paid at most once.
+    #[pallet::storage]
+    #[pallet::getter(fn season_payout)]
+    pub type SeasonPayouts<T: Config> =
+        StorageMap<_, Blake2_128Concat, LeagueId, SeasonPayout<T::AccountId>, OptionQuery>;
+
+    /// League whose season rewards are being paid, a batch per block.
+    #[pallet::storage]
+    #[pallet::getter(fn active_season_payout)]
+    pub type ActiveSeasonPayout<T: Config> = StorageValue<_, LeagueId, OptionQuery>;
 
+    /// Block of each queued player's latest heartbeat, see `QueueHeartbeatBlocks`.
     #[pallet::storage]
-    #[pallet::getter(fn ring)]
-    pub type Ring<T: Config> = StorageMap<_, Blake2_128Concat, QIndex, T::AccountId, OptionQuery>;
+    #[pallet::getter(fn last_heartbeat)]
+    pub type LastHeartbeat<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
 
+    /// Deposit reserved for a live queue entry. The amount is kept so a later change of
+    /// `QueueDeposit` never releases more or less than was reserved.
     #[pallet::storage]
-    #[pallet::getter(fn in_queue)]
-    pub type InQueue<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+    #[pallet::getter(fn queue_deposit_of)]
+    pub type QueueDepositOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
 
+    /// Queue deposits of matched players, held until the game's first round is played.
     #[pallet::storage]
-    #[pallet::getter(fn live_size)]
-    pub type LiveSize<T: Config> = StorageValue<_, u32, ValueQuery>;
+    #[pallet::getter(fn game_deposit)]
+    pub type GameDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameIdOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
 
+    #[pallet::storage]
+    pub type NextSeriesId<T: Config> = StorageValue<_, SeriesId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn series)]
+    pub type SeriesById<T: Config> =
+        StorageMap<_, Blake2_128Concat, SeriesId, Series<T::AccountId, GameIdOf<T>>, OptionQuery>;
+
+    /// Series the given game belongs to (only for the series' current game).
+    #[pallet::storage]
+    #[pallet::getter(fn series_of_game)]
+    pub type SeriesOfGame<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameIdOf<T>, SeriesId, OptionQuery>;
+
+    /// Series a player is currently bound into; such players cannot queue.
+    #[pallet::storage]
+    #[pallet::getter(fn active_series_of)]
+    pub type ActiveSeriesOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SeriesId, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextLeagueId<T: Config> = StorageValue<_, LeagueId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn league)]
+    pub type Leagues<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        LeagueId,
+        League<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Fixtures of a running league, indexed in matchday order.
+    #[pallet::storage]
+    #[pallet::getter(fn fixture)]
+    pub type Fixtures<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        LeagueId,
+        Twox64Concat,
+        FixtureIndex,
+        Fixture<T::AccountId, GameIdOf<T>>,
+        OptionQuery,
+    >;
+
+    /// League fixture a live game was created for.
+    #[pallet::storage]
+    #[pallet::getter(fn fixture_of_game)]
+    pub type FixtureOfGame<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameIdOf<T>, (LeagueId, FixtureIndex), OptionQuery>;
+
+    /// League record of each rostered player, see `Pallet::standings`.
+    #[pallet::storage]
+    #[pallet::getter(fn standing)]
+    pub type Standings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        LeagueId,
+        Blake2_128Concat,
+        T::AccountId,
+        Standing,
+        ValueQuery,
+    >;
+
+    /// Every variant pins its index, so builds with and without `legacy-events` encode the
+    /// same event alike. New variants take the next free index.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
+        /// A join was accepted; it gets a ticket at the end of the block.
+        #[codec(index = 0)]
         Joined { who: T::AccountId },
+        #[codec(index = 1)]
         Left { who: T::AccountId },
+        /// `who` sent no heartbeat for `QueueHeartbeatBlocks` and was taken out of the queue.
+        #[codec(index = 12)]
+        DroppedInactive { who: T::AccountId },
+        /// `who` received `ticket`. A block's joins are enqueued in their shuffled order.
+        #[codec(index = 13)]
+        Enqueued { who: T::AccountId, ticket: QIndex },
+        #[codec(index = 2)]
         Matched { players: [T::AccountId; 2] },
         /// Emitted right after a join increases live size to at least the players-per-match threshold.
+        #[codec(index = 3)]
         TwoReadyToMatch { live_size: u32 },
-        /// Emitted when `process_queue`/`join_queue` kicks off processing.
+        /// Emitted once whenever the queue is processed.
+        #[codec(index = 4)]
         ProcessingStarted { live_size: u32, head: QIndex, tail: QIndex },
-        /// Emitted when we have popped two candidates to pair.
+        /// Deprecated: every pair is reported by `Matched`.
+        #[cfg(feature = "legacy-events")]
+        #[codec(index = 5)]
         PairFound { a: T::AccountId, b: T::AccountId },
-        /// Emitted immediately before calling into the game pallet to create a game.
+        /// Deprecated: every pair is reported by `Matched`.
+        #[cfg(feature = "legacy-events")]
+        #[codec(index = 6)]
         GameCreateAttempt { a: T::AccountId, b: T::AccountId },
         /// Emitted when the second pop was unavailable and the first player was requeued.
+        #[codec(index = 7)]
         Requeued { who: T::AccountId },
         /// Emitted after processing finishes for this call.
+        #[codec(index = 8)]
         ProcessingCompleted { remaining_live: u32, head: QIndex, tail: QIndex },
+        /// `MaxGamesCreatedPerBlock` was reached; the rest of the queue is paired next block.
+        #[codec(index = 14)]
+        PairingDeferred { remaining_live: u32 },
+        /// A matched pair was bound into a best-of-N series.
+        #[codec(index = 9)]
+        SeriesStarted {
+            series_id: SeriesId,
+            players: [T::AccountId; 2],
+            best_of: u8,
+        },
+        /// A game of the series was created (the first one included).
+        #[codec(index = 10)]
+        SeriesGameStarted {
+            series_id: SeriesId,
+            game_id: GameIdOf<T>,
+        },
+        /// The series ended. `winner` is `None` if it was aborted because a game could not be
+        /// created or voided, or if it was still undecided after `2 * best_of` games.
+        #[codec(index = 11)]
+        SeriesFinished {
+            series_id: SeriesId,
+            winner: Option<T::AccountId>,
+            wins: (u8, u8),
+        },
+        /// The queue deposit of `who` was slashed for a missing hand or an early abandon.
+        #[codec(index = 15)]
+        QueueDepositSlashed {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        #[codec(index = 16)]
+        LeagueCreated {
+            league_id: LeagueId,
+            registration_ends: BlockNumberFor<T>,
+            matchday_length: BlockNumberFor<T>,
+        },
+        #[codec(index = 17)]
+        LeagueJoined {
+            league_id: LeagueId,
+            who: T::AccountId,
+        },
+        /// Fixtures were generated; matchday 0 is playable from this block on.
+        #[codec(index = 18)]
+        LeagueStarted {
+            league_id: LeagueId,
+            matchdays: u32,
+            fixtures: u32,
+        },
+        /// Fewer than two players registered, so no fixtures were generated.
+        #[codec(index = 19)]
+        LeagueCancelled { league_id: LeagueId },
+        #[codec(index = 20)]
+        FixtureStarted {
+            league_id: LeagueId,
+            fixture: FixtureIndex,
+            game_id: GameIdOf<T>,
+        },
+        /// A fixture's game ended; `winner` is `None` for a draw.
+        #[codec(index = 21)]
+        FixturePlayed {
+            league_id: LeagueId,
+            fixture: FixtureIndex,
+            winner: Option<T::AccountId>,
+        },
+        #[codec(index = 22)]
+        FixtureMissed {
+            league_id: LeagueId,
+            fixture: FixtureIndex,
+        },
+        /// Every fixture was played or missed; `Standings` are final.
+        #[codec(index = 23)]
+        LeagueFinished { league_id: LeagueId },
+        /// The final ranking of a league was frozen for its season rewards.
+        #[codec(index = 24)]
+        SeasonRewardsStarted {
+            league_id: LeagueId,
+            recipients: u32,
+        },
+        /// `rank` (0 for the champion) of the league's season was paid.
+        #[codec(index = 25)]
+        SeasonRewardPaid {
+            league_id: LeagueId,
+            rank: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            card_id: Option<u32>,
+        },
+        #[codec(index = 26)]
+        SeasonRewardsCompleted { league_id: LeagueId },
+        /// The reward of `rank` could not be transferred and was skipped.
+        #[codec(index = 27)]
+        SeasonRewardFailed {
+            league_id: LeagueId,
+            rank: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -106,175 +578,1119 @@ pub mod pallet {
         BadCapacity,
         /// Player attempted to queue without having a preset hand configured.
         NoPresetHand,
+        /// Player is already in an active ranked game.
+        AlreadyInGame,
+        /// Series length must be odd, at least 3 and at most `MaxSeriesLength`.
+        InvalidSeriesLength,
+        /// Player is bound into a series that has not finished yet.
+        AlreadyInSeries,
+        /// The current hand's total power is below `MinRankedHandPower`.
+        HandTooWeak,
+        /// The account has not completed the onboarding quest chain yet.
+        OnboardingIncomplete,
+        /// The free balance does not cover `QueueDeposit`.
+        InsufficientDeposit,
+        /// Registration must end in the future and matchdays must last at least one block.
+        InvalidLeagueSchedule,
+        /// No league has this id.
+        UnknownLeague,
+        /// The league no longer accepts players.
+        RegistrationClosed,
+        /// The caller is already on the league roster.
+        AlreadyInLeague,
+        /// The roster holds `MAX_LEAGUE_PLAYERS` players.
+        LeagueFull,
+        /// The league is still registering players, or has already started.
+        LeagueNotReady,
+        /// No fixture of the league has this index.
+        UnknownFixture,
+        /// The caller does not play in this fixture.
+        NotInFixture,
+        /// The fixture was already started, played or missed.
+        FixtureNotScheduled,
+        /// The fixture's matchday is not playable at this block.
+        OutsideMatchday,
+        /// The fixture's matchday has not ended yet.
+        MatchdayNotOver,
+        /// Season rewards are only paid for leagues that finished.
+        LeagueNotFinished,
+        /// The season rewards of this league were already distributed.
+        SeasonAlreadyDistributed,
+        /// Another league's season rewards are still being paid.
+        SeasonPayoutInProgress,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Pay the next batch of an active season payout, then drop queued players whose
+        /// latest heartbeat is older than `QueueHeartbeatBlocks`, before this block's pairing
+        /// can match them. The worst case of that pairing in `on_finalize` is reserved here.
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let payouts = Self::pay_season_batch().saturating_add(Self::pairing_weight());
+            let timeout = T::QueueHeartbeatBlocks::get();
+            if timeout.is_zero() {
+                return payouts;
+            }
+            let mut queued = 0u64;
+            let stale: Vec<T::AccountId> = LastHeartbeat::<T>::iter()
+                .inspect(|_| queued += 1)
+                .filter(|(_, at)| n.saturating_sub(*at) > timeout)
+                .map(|(who, _)| who)
+                .collect();
+            for who in stale.iter() {
+                Self::remove_from_queue(who);
+                Self::deposit_event(Event::DroppedInactive { who: who.clone() });
+            }
+            T::DbWeight::get()
+                .reads_writes(
+                    queued.saturating_add(stale.len() as u64 * 4),
+                    stale.len() as u64 * 6,
+                )
+                .saturating_add(payouts)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = StorageVersion::get::<Pallet<T>>();
+            if on_chain >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            if on_chain < 1 {
+                weight = weight.saturating_add(crate::migrations::v1::migrate::<T>());
+            }
+            weight = weight.saturating_add(crate::migrations::v2::migrate::<T>());
+            STORAGE_VERSION.put::<Pallet<T>>();
+            weight
+        }
+
+        /// Ticket this block's joins in seed-shuffled order and pair the queue, so the block
+        /// author cannot pick opponents by ordering the join extrinsics. Pairs deferred by
+        /// `MaxGamesCreatedPerBlock` in an earlier block are paired here too.
+        fn on_finalize(n: BlockNumberFor<T>) {
+            let mut joins = PendingJoins::<T>::take();
+            let (last, created) = GamesCreated::<T>::get();
+            let carried = last < n && created >= T::MaxGamesCreatedPerBlock::get();
+            if joins.is_empty() && !carried {
+                return;
+            }
+            Self::shuffle_joins(&mut joins);
+            let cap = T::QueueCapacity::get();
+            for join in joins {
+                // Capacity was checked against the pending joins when they were accepted.
+                if Self::enqueue(&join.who, join.best_of, cap).is_err() {
+                    Self::refund_join(&join.who);
+                    Self::deposit_event(Event::Left { who: join.who });
+                    continue;
+                }
+                if let Some(region) = join.region {
+                    let now = frame_system::Pallet::<T>::block_number();
+                    RegionOf::<T>::insert(&join.who, (region, now));
+                }
+            }
+            let _ = Self::do_process();
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Join the ranked queue. The join is ticketed and paired at the end of the block.
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(Pallet::<T>::join_weight())]
         pub fn join_queue(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::do_join(who, None, None)
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(10_000)]
+        pub fn leave_queue(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::remove_from_queue(&who), Error::<T>::NotQueued);
+            Self::deposit_event(Event::Left { who });
+            Ok(())
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(10_000)]
+        pub fn process_queue(origin: OriginFor<T>) -> DispatchResult {
+            let _ = ensure_signed(origin).ok();
             let cap = T::QueueCapacity::get();
             ensure!(cap > 1, Error::<T>::BadCapacity);
+            Self::do_process()
+        }
+
+        /// Queue for a best-of-`best_of` series. When paired with a player who asked for a
+        /// shorter series (or a single game), the shorter format is used.
+        #[pallet::call_index(3)]
+        #[pallet::weight(Pallet::<T>::join_weight())]
+        pub fn join_series_queue(origin: OriginFor<T>, best_of: u8) -> DispatchResult {
+            let who = ensure_signed(origin)?;
             ensure!(
-                InQueue::<T>::contains_key(&who) == false,
-                Error::<T>::AlreadyQueued
+                best_of >= 3 && best_of % 2 == 1 && best_of <= T::MaxSeriesLength::get(),
+                Error::<T>::InvalidSeriesLength
             );
-            // Require that the player has configured a Current Hand in the game/cards pallet.
+            Self::do_join(who, Some(best_of), None)
+        }
+
+        /// (Root, `dev-mode` builds only) Enqueue `joins` in order without the hand,
+        /// onboarding and ranked-game checks of `join_queue`, then process the queue once.
+        /// Lets tests and local networks drive many queue cycles in a single call.
+        #[cfg(feature = "dev-mode")]
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000)]
+        pub fn simulate_queue(origin: OriginFor<T>, joins: Vec<T::AccountId>) -> DispatchResult {
+            ensure_root(origin)?;
+            let cap = T::QueueCapacity::get();
+            ensure!(cap > 1, Error::<T>::BadCapacity);
+            for who in joins.iter() {
+                ensure!(!Self::is_queued(who), Error::<T>::AlreadyQueued);
+                Self::enqueue(who, None, cap)?;
+                Self::deposit_event(Event::Joined { who: who.clone() });
+            }
+            Self::do_process()
+        }
+
+        /// Like `join_queue`, but prefer opponents from `region`. After `MaxRegionWaitBlocks`
+        /// without one, the player is paired across regions.
+        #[pallet::call_index(5)]
+        #[pallet::weight(Pallet::<T>::join_weight())]
+        pub fn join_regional_queue(origin: OriginFor<T>, region: crate::Region) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_join(who, None, Some(region))
+        }
+
+        /// (League admin) Pay the season rewards of a finished league to its final ranking,
+        /// following `SeasonRewardTiers`. The ranking is frozen now; `SeasonRewardBatch` ranks
+        /// are paid right away and the rest in the following blocks. Each league is paid once.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(
+            4u64.saturating_add(3u64.saturating_mul(T::SeasonRewardBatch::get() as u64)),
+            3u64.saturating_add(3u64.saturating_mul(T::SeasonRewardBatch::get() as u64)),
+        ))]
+        pub fn distribute_season_rewards(
+            origin: OriginFor<T>,
+            season_id: LeagueId,
+        ) -> DispatchResult {
+            T::LeagueAdminOrigin::ensure_origin(origin)?;
+            let league = Leagues::<T>::get(season_id).ok_or(Error::<T>::UnknownLeague)?;
             ensure!(
-                T::HandProvider::has_current_hand(&who),
-                Error::<T>::NoPresetHand
+                league.status == LeagueStatus::Finished,
+                Error::<T>::LeagueNotFinished
+            );
+            ensure!(
+                !SeasonPayouts::<T>::contains_key(season_id),
+                Error::<T>::SeasonAlreadyDistributed
+            );
+            ensure!(
+                ActiveSeasonPayout::<T>::get().is_none(),
+                Error::<T>::SeasonPayoutInProgress
             );
 
-            Head::<T>::mutate(|head| {
-                Tail::<T>::mutate(|tail| -> DispatchResult {
-                    let size = Self::ring_size(*head, *tail, cap);
-                    ensure!(size < cap, Error::<T>::QueueFull);
-
-                    let idx = *tail % cap;
-                    Ring::<T>::insert(idx, &who);
-                    *tail = tail.wrapping_add(1);
+            let ranking: Vec<T::AccountId> =
+                Self::standings(season_id).into_iter().map(|(who, _)| who).collect();
+            let payout = SeasonPayout {
+                ranking: ranking.try_into().map_err(|_| Error::<T>::LeagueFull)?,
+                next_rank: 0,
+            };
+            let recipients = payout.ranking.len() as u32;
+            SeasonPayouts::<T>::insert(season_id, payout);
+            ActiveSeasonPayout::<T>::put(season_id);
+            Self::deposit_event(Event::SeasonRewardsStarted {
+                league_id: season_id,
+                recipients,
+            });
+            Self::pay_season_batch();
+            Ok(())
+        }
 
-                    InQueue::<T>::insert(&who, ());
-                    LiveSize::<T>::mutate(|n| *n = n.saturating_add(1));
+        /// Show that the client of a queued player is still around, see
+        /// `QueueHeartbeatBlocks`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(TicketOf::<T>::contains_key(&who), Error::<T>::NotQueued);
+            LastHeartbeat::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
+            Ok(())
+        }
 
-                    // If we now have enough players to match, emit a signal.
-                    let threshold = T::PlayersPerMatch::get() as u32;
-                    let current = LiveSize::<T>::get();
-                    if current >= threshold {
-                        Self::deposit_event(Event::TwoReadyToMatch { live_size: current });
-                    }
+        /// (League admin) Open a league for registration until `registration_ends`. Once it
+        /// starts, each matchday is playable for `matchday_length` blocks.
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)]
+        pub fn create_league(
+            origin: OriginFor<T>,
+            registration_ends: BlockNumberFor<T>,
+            matchday_length: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::LeagueAdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                registration_ends > frame_system::Pallet::<T>::block_number()
+                    && !matchday_length.is_zero(),
+                Error::<T>::InvalidLeagueSchedule
+            );
+            let league_id = NextLeagueId::<T>::mutate(|id| {
+                let current = *id;
+                *id = id.wrapping_add(1);
+                current
+            });
+            Leagues::<T>::insert(
+                league_id,
+                League {
+                    players: Default::default(),
+                    registration_ends,
+                    matchday_length,
+                    status: LeagueStatus::Registration,
+                },
+            );
+            Self::deposit_event(Event::LeagueCreated {
+                league_id,
+                registration_ends,
+                matchday_length,
+            });
+            Ok(())
+        }
 
-                    Self::deposit_event(Event::Joined { who: who.clone() });
-                    Self::do_process(cap)?;
-                    Ok(())
-                })
+        /// Join a league's roster while it is registering. Requires a current hand, like
+        /// the queue.
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn join_league(origin: OriginFor<T>, league_id: LeagueId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::HandProvider::has_current_hand(&who),
+                Error::<T>::NoPresetHand
+            );
+            Leagues::<T>::try_mutate(league_id, |league| {
+                let league = league.as_mut().ok_or(Error::<T>::UnknownLeague)?;
+                ensure!(
+                    league.status == LeagueStatus::Registration
+                        && frame_system::Pallet::<T>::block_number() <= league.registration_ends,
+                    Error::<T>::RegistrationClosed
+                );
+                ensure!(
+                    !league.players.contains(&who),
+                    Error::<T>::AlreadyInLeague
+                );
+                league
+                    .players
+                    .try_push(who.clone())
+                    .map_err(|_| Error::<T>::LeagueFull)?;
+                Ok::<_, sp_runtime::DispatchError>(())
             })?;
+            Self::deposit_event(Event::LeagueJoined { league_id, who });
+            Ok(())
+        }
+
+        /// Close registration and generate the round-robin fixtures. Callable by anyone
+        /// once registration ended; matchday 0 opens right away. A league with fewer than
+        /// two players is cancelled instead.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(
+            1,
+            1 + (crate::league::MAX_LEAGUE_PLAYERS as u64).pow(2),
+        ))]
+        pub fn start_league(origin: OriginFor<T>, league_id: LeagueId) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let mut league = Leagues::<T>::get(league_id).ok_or(Error::<T>::UnknownLeague)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                league.status == LeagueStatus::Registration && now > league.registration_ends,
+                Error::<T>::LeagueNotReady
+            );
+
+            let schedule = round_robin(league.players.len() as u32);
+            if schedule.is_empty() {
+                league.status = LeagueStatus::Finished;
+                Leagues::<T>::insert(league_id, league);
+                Self::deposit_event(Event::LeagueCancelled { league_id });
+                return Ok(());
+            }
 
+            let mut fixtures: FixtureIndex = 0;
+            for (matchday, pairs) in schedule.iter().enumerate() {
+                for (a, b) in pairs.iter() {
+                    let players = [
+                        league.players[*a as usize].clone(),
+                        league.players[*b as usize].clone(),
+                    ];
+                    Fixtures::<T>::insert(
+                        league_id,
+                        fixtures,
+                        Fixture {
+                            matchday: matchday as u32,
+                            players,
+                            state: FixtureState::Scheduled,
+                        },
+                    );
+                    fixtures = fixtures.saturating_add(1);
+                }
+            }
+            // Every rostered player shows up in the standings, even before playing.
+            for who in league.players.iter() {
+                Standings::<T>::insert(league_id, who, Standing::default());
+            }
+            let matchdays = schedule.len() as u32;
+            league.status = LeagueStatus::Running {
+                started_at: now,
+                matchdays,
+                open_fixtures: fixtures,
+            };
+            Leagues::<T>::insert(league_id, league);
+            Self::deposit_event(Event::LeagueStarted {
+                league_id,
+                matchdays,
+                fixtures,
+            });
             Ok(())
         }
 
-        #[pallet::call_index(1)]
+        /// Create the game of one of your fixtures during its matchday.
+        #[pallet::call_index(9)]
         #[pallet::weight(10_000)]
-        pub fn leave_queue(origin: OriginFor<T>) -> DispatchResult {
+        pub fn play_fixture(
+            origin: OriginFor<T>,
+            league_id: LeagueId,
+            fixture: FixtureIndex,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(InQueue::<T>::contains_key(&who), Error::<T>::NotQueued);
+            let mut entry =
+                Fixtures::<T>::get(league_id, fixture).ok_or(Error::<T>::UnknownFixture)?;
+            ensure!(entry.players.contains(&who), Error::<T>::NotInFixture);
+            ensure!(
+                entry.state == FixtureState::Scheduled,
+                Error::<T>::FixtureNotScheduled
+            );
+            let (opens, closes) = Self::matchday_window(league_id, entry.matchday)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(opens <= now && now < closes, Error::<T>::OutsideMatchday);
 
-            InQueue::<T>::remove(&who);
-            LiveSize::<T>::mutate(|n| *n = n.saturating_sub(1));
-            Self::deposit_event(Event::Left { who });
+            let [a, b] = &entry.players;
+            let game_id = T::GameCreator::create_for_league(a, b)?;
+            FixtureOfGame::<T>::insert(&game_id, (league_id, fixture));
+            entry.state = FixtureState::Live(game_id.clone());
+            Fixtures::<T>::insert(league_id, fixture, entry);
+            Self::deposit_event(Event::FixtureStarted {
+                league_id,
+                fixture,
+                game_id,
+            });
             Ok(())
         }
 
-        #[pallet::call_index(2)]
+        /// Record a fixture nobody started before its matchday ended as missed. Callable by
+        /// anyone.
+        #[pallet::call_index(10)]
         #[pallet::weight(10_000)]
-        pub fn process_queue(origin: OriginFor<T>) -> DispatchResult {
-            let _ = ensure_signed(origin).ok();
+        pub fn expire_fixture(
+            origin: OriginFor<T>,
+            league_id: LeagueId,
+            fixture: FixtureIndex,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let mut entry =
+                Fixtures::<T>::get(league_id, fixture).ok_or(Error::<T>::UnknownFixture)?;
+            ensure!(
+                entry.state == FixtureState::Scheduled,
+                Error::<T>::FixtureNotScheduled
+            );
+            let (_, closes) = Self::matchday_window(league_id, entry.matchday)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= closes,
+                Error::<T>::MatchdayNotOver
+            );
+            entry.state = FixtureState::Missed;
+            Fixtures::<T>::insert(league_id, fixture, entry);
+            Self::deposit_event(Event::FixtureMissed { league_id, fixture });
+            Self::close_fixture(league_id);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn do_join(
+            who: T::AccountId,
+            best_of: Option<u8>,
+            region: Option<crate::Region>,
+        ) -> DispatchResult {
             let cap = T::QueueCapacity::get();
             ensure!(cap > 1, Error::<T>::BadCapacity);
-            Self::deposit_event(Event::ProcessingStarted {
-                live_size: LiveSize::<T>::get(),
-                head: Head::<T>::get(),
-                tail: Tail::<T>::get(),
+            ensure!(!Self::is_queued(&who), Error::<T>::AlreadyQueued);
+            // Require that the player has configured a Current Hand in the game/cards pallet.
+            ensure!(
+                T::HandProvider::has_current_hand(&who),
+                Error::<T>::NoPresetHand
+            );
+            // Empty accounts must finish onboarding before polluting the ranked pool.
+            ensure!(
+                T::Onboarding::is_onboarded(&who),
+                Error::<T>::OnboardingIncomplete
+            );
+            // Throwaway hands would let players tank opponents' time or farm losses.
+            let min_power = T::MinRankedHandPower::get();
+            if min_power > 0 {
+                let power = T::HandProvider::hand_summary(&who)
+                    .map(|summary| summary.total_power)
+                    .unwrap_or(0);
+                ensure!(power >= min_power, Error::<T>::HandTooWeak);
+            }
+            // Ranked games are exclusive; other game contexts do not block the queue.
+            ensure!(
+                <T::GameCreator as super::GameCreator<T::AccountId>>::can_queue(&who),
+                Error::<T>::AlreadyInGame
+            );
+            ensure!(
+                !ActiveSeriesOf::<T>::contains_key(&who),
+                Error::<T>::AlreadyInSeries
+            );
+
+            let mut pending = PendingJoins::<T>::get();
+            ensure!(
+                Self::live_size().saturating_add(pending.len() as u32) < cap,
+                Error::<T>::QueueFull
+            );
+            pending
+                .try_push(crate::PendingJoin {
+                    who: who.clone(),
+                    best_of,
+                    region,
+                })
+                .map_err(|_| Error::<T>::QueueFull)?;
+
+            let deposit = T::QueueDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::reserve(&who, deposit)
+                    .map_err(|_| Error::<T>::InsufficientDeposit)?;
+                QueueDepositOf::<T>::insert(&who, deposit);
+            }
+            PendingJoins::<T>::put(pending);
+            Self::deposit_event(Event::Joined { who });
+            Ok(())
+        }
+
+        /// Order `joins` by a hash of each account and the parent block hash. The order does
+        /// not depend on extrinsic placement, and the parent hash is fixed before the block
+        /// author picks the extrinsics.
+        fn shuffle_joins(joins: &mut [crate::PendingJoin<T::AccountId>]) {
+            let parent = frame_system::Pallet::<T>::parent_hash();
+            let seed = T::Hashing::hash_of(&(parent, b"matchmaker/joins"));
+            joins.sort_by_cached_key(|join| T::Hashing::hash_of(&(seed, &join.who)));
+        }
+
+        /// Give `who` the next ticket.
+        fn enqueue(who: &T::AccountId, best_of: Option<u8>, cap: u32) -> DispatchResult {
+            ensure!(Self::live_size() < cap, Error::<T>::QueueFull);
+
+            let ticket = NextTicket::<T>::mutate(|next| {
+                let ticket = *next;
+                *next = next.saturating_add(1);
+                ticket
             });
-            Self::do_process(cap)
+            Queue::<T>::insert(ticket, who);
+            TicketOf::<T>::insert(who, ticket);
+            LastHeartbeat::<T>::insert(who, frame_system::Pallet::<T>::block_number());
+            T::HandProvider::bind_queued_hand(who);
+            if let Some(n) = best_of {
+                SeriesRequest::<T>::insert(who, n);
+            }
+
+            // If we now have enough players to match, emit a signal.
+            let threshold = T::PlayersPerMatch::get() as u32;
+            let current = Self::live_size();
+            if current >= threshold {
+                Self::deposit_event(Event::TwoReadyToMatch { live_size: current });
+            }
+
+            Self::deposit_event(Event::Enqueued {
+                who: who.clone(),
+                ticket,
+            });
+            Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        fn ring_size(head: QIndex, tail: QIndex, _cap: QIndex) -> QIndex {
-            tail.wrapping_sub(head)
-        }
-
-        fn pop_live(cap: QIndex) -> Option<T::AccountId> {
-            Head::<T>::mutate(|head| {
-                // We’ll search up to `cap` slots (one full cycle) to find a live account.
-                // This makes the ring robust even if `head` previously advanced past older entries.
-                let mut h = *head;
-                let tail = Tail::<T>::get();
-
-                for _ in 0..cap {
-                    let idx = h % cap;
-                    h = h.wrapping_add(1);
-
-                    if let Some(acc) = Ring::<T>::take(idx) {
-                        if InQueue::<T>::contains_key(&acc) {
-                            *head = h;
-                            InQueue::<T>::remove(&acc);
-                            LiveSize::<T>::mutate(|n| *n = n.saturating_sub(1));
-                            return Some(acc);
-                        }
+        /// Bind `a` and `b` into a series and create its first game. If the game cannot be
+        /// created no series is recorded, mirroring single-game matching.
+        fn start_series(a: &T::AccountId, b: &T::AccountId, best_of: u8) -> Option<GameIdOf<T>> {
+            let game_id = T::GameCreator::create_from_matchmaking(a, b).ok()?;
+            let series_id = NextSeriesId::<T>::mutate(|id| {
+                let current = *id;
+                *id = id.wrapping_add(1);
+                current
+            });
+            SeriesById::<T>::insert(
+                series_id,
+                Series {
+                    players: [a.clone(), b.clone()],
+                    best_of,
+                    wins: (0, 0),
+                    current_game: game_id.clone(),
+                    games: 0,
+                },
+            );
+            SeriesOfGame::<T>::insert(&game_id, series_id);
+            ActiveSeriesOf::<T>::insert(a, series_id);
+            ActiveSeriesOf::<T>::insert(b, series_id);
+            Self::deposit_event(Event::SeriesStarted {
+                series_id,
+                players: [a.clone(), b.clone()],
+                best_of,
+            });
+            Self::deposit_event(Event::SeriesGameStarted {
+                series_id,
+                game_id: game_id.clone(),
+            });
+            Some(game_id)
+        }
+
+        /// Move the queue deposits of a matched pair onto their game. Without a game, the
+        /// deposit of a player whose hand is gone is slashed and the other one released.
+        fn hold_queue_deposits(players: [&T::AccountId; 2], game_id: Option<&GameIdOf<T>>) {
+            for who in players {
+                let Some(amount) = QueueDepositOf::<T>::take(who) else {
+                    continue;
+                };
+                match game_id {
+                    Some(game_id) => GameDeposits::<T>::insert(game_id, who, amount),
+                    None if !T::HandProvider::has_current_hand(who) => {
+                        Self::slash_deposit(who, amount)
+                    }
+                    None => {
+                        T::Currency::unreserve(who, amount);
+                    }
+                }
+            }
+        }
+
+        fn slash_deposit(who: &T::AccountId, amount: BalanceOf<T>) {
+            let (_, missing) = T::Currency::slash_reserved(who, amount);
+            Self::deposit_event(Event::QueueDepositSlashed {
+                who: who.clone(),
+                amount: amount.saturating_sub(missing),
+            });
+        }
+
+        /// Release every deposit still held for `game_id`.
+        fn release_game_deposits(game_id: &GameIdOf<T>) {
+            for (who, amount) in GameDeposits::<T>::drain_prefix(game_id) {
+                T::Currency::unreserve(&who, amount);
+            }
+        }
+
+        /// Award league points for a finished fixture game.
+        fn record_fixture(
+            league_id: LeagueId,
+            fixture: FixtureIndex,
+            winner: Option<&T::AccountId>,
+        ) {
+            let Some(mut entry) = Fixtures::<T>::get(league_id, fixture) else {
+                return;
+            };
+            for p in entry.players.iter() {
+                Standings::<T>::mutate(league_id, p, |s| s.record(winner.map(|w| w == p)));
+            }
+            entry.state = FixtureState::Played {
+                winner: winner.cloned(),
+            };
+            Fixtures::<T>::insert(league_id, fixture, entry);
+            Self::deposit_event(Event::FixturePlayed {
+                league_id,
+                fixture,
+                winner: winner.cloned(),
+            });
+            Self::close_fixture(league_id);
+        }
+
+        fn finish_series(
+            series_id: SeriesId,
+            series: Series<T::AccountId, GameIdOf<T>>,
+            winner: Option<T::AccountId>,
+        ) {
+            SeriesById::<T>::remove(series_id);
+            for p in series.players.iter() {
+                ActiveSeriesOf::<T>::remove(p);
+            }
+            Self::deposit_event(Event::SeriesFinished {
+                series_id,
+                winner,
+                wins: series.wins,
+            });
+        }
+
+        /// First block of `matchday` and the block the next matchday opens at.
+        fn matchday_window(
+            league_id: LeagueId,
+            matchday: u32,
+        ) -> Result<(BlockNumberFor<T>, BlockNumberFor<T>), sp_runtime::DispatchError> {
+            let league = Leagues::<T>::get(league_id).ok_or(Error::<T>::UnknownLeague)?;
+            let LeagueStatus::Running { started_at, .. } = league.status else {
+                return Err(Error::<T>::LeagueNotReady.into());
+            };
+            let opens = started_at
+                .saturating_add(league.matchday_length.saturating_mul(matchday.into()));
+            Ok((opens, opens.saturating_add(league.matchday_length)))
+        }
+
+        /// Count one more fixture of `league_id` as decided, finishing the league with the
+        /// last one.
+        fn close_fixture(league_id: LeagueId) {
+            let Some(mut league) = Leagues::<T>::get(league_id) else {
+                return;
+            };
+            let LeagueStatus::Running { open_fixtures, .. } = &mut league.status else {
+                return;
+            };
+            *open_fixtures = open_fixtures.saturating_sub(1);
+            if *open_fixtures == 0 {
+                league.status = LeagueStatus::Finished;
+                Self::deposit_event(Event::LeagueFinished { league_id });
+            }
+            Leagues::<T>::insert(league_id, league);
+        }
+
+        /// Account season rewards are paid from.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Pay the next `SeasonRewardBatch` ranks of the active season payout. A rank whose
+        /// transfer fails is skipped with `SeasonRewardFailed`, so one recipient cannot stall
+        /// the payout or the next league's.
+        fn pay_season_batch() -> Weight {
+            let Some(league_id) = ActiveSeasonPayout::<T>::get() else {
+                return T::DbWeight::get().reads(1);
+            };
+            let Some(mut payout) = SeasonPayouts::<T>::get(league_id) else {
+                ActiveSeasonPayout::<T>::kill();
+                return T::DbWeight::get().reads_writes(2, 1);
+            };
+            let tiers = T::SeasonRewardTiers::get();
+            let pot = Self::account_id();
+            let mut paid = 0u64;
+            while !payout.is_complete() && paid < T::SeasonRewardBatch::get() as u64 {
+                let rank = payout.next_rank;
+                let who = payout.ranking[rank as usize].clone();
+                if let Some(tier) = tier_for(&tiers, rank) {
+                    let keep_alive = ExistenceRequirement::KeepAlive;
+                    if T::Currency::transfer(&pot, &who, tier.amount, keep_alive).is_ok() {
+                        let card_id = tier
+                            .card
+                            .and_then(|edges| T::SeasonCards::grant_card(&who, edges));
+                        Self::deposit_event(Event::SeasonRewardPaid {
+                            league_id,
+                            rank,
+                            who,
+                            amount: tier.amount,
+                            card_id,
+                        });
+                    } else {
+                        Self::deposit_event(Event::SeasonRewardFailed {
+                            league_id,
+                            rank,
+                            who,
+                            amount: tier.amount,
+                        });
                     }
+                }
+                payout.next_rank = rank.saturating_add(1);
+                paid += 1;
+            }
+            if payout.is_complete() {
+                ActiveSeasonPayout::<T>::kill();
+                Self::deposit_event(Event::SeasonRewardsCompleted { league_id });
+            }
+            SeasonPayouts::<T>::insert(league_id, payout);
+            let accesses = 2u64.saturating_add(paid * 3);
+            T::DbWeight::get().reads_writes(accesses, accesses)
+        }
 
-                    // If we made a full pass to the current tail without success and there are no
-                    // gaps to consider, continue scanning; the `for` cap-guard prevents infinite loops.
-                    if h == tail {
-                        // continue scanning post-tail region in case of wrap-around entries
+        /// Standings of a league, by points, then wins, then fewest losses.
+        pub fn standings(league_id: LeagueId) -> Vec<(T::AccountId, Standing)> {
+            let mut table: Vec<_> = Standings::<T>::iter_prefix(league_id).collect();
+            table.sort_by(|(_, a), (_, b)| {
+                b.points
+                    .cmp(&a.points)
+                    .then(b.won.cmp(&a.won))
+                    .then(a.lost.cmp(&b.lost))
+            });
+            table
+        }
+
+        /// Number of accounts currently queued.
+        pub fn live_size() -> u32 {
+            Queue::<T>::count()
+        }
+
+        /// Whether `who` is waiting in the queue, or joined during this block.
+        pub fn is_queued(who: &T::AccountId) -> bool {
+            TicketOf::<T>::contains_key(who)
+                || PendingJoins::<T>::get().iter().any(|join| join.who == *who)
+        }
+
+        /// Take `who` out of the queue. Returns false if they were not queued.
+        pub fn remove_from_queue(who: &T::AccountId) -> bool {
+            let mut pending = PendingJoins::<T>::get();
+            if let Some(pos) = pending.iter().position(|join| join.who == *who) {
+                pending.remove(pos);
+                PendingJoins::<T>::put(pending);
+                Self::refund_join(who);
+                return true;
+            }
+            let Some(ticket) = TicketOf::<T>::take(who) else {
+                return false;
+            };
+            Queue::<T>::remove(ticket);
+            SeriesRequest::<T>::remove(who);
+            RegionOf::<T>::remove(who);
+            LastHeartbeat::<T>::remove(who);
+            Self::refund_join(who);
+            T::HandProvider::release_queued_hand(who);
+            // Nobody left waiting: skip the gaps instead of walking them on the next pop.
+            if Queue::<T>::count() == 0 {
+                OldestTicket::<T>::put(NextTicket::<T>::get());
+            }
+            true
+        }
+
+        /// Release the queue deposit `who` reserved on joining.
+        fn refund_join(who: &T::AccountId) {
+            if let Some(amount) = QueueDepositOf::<T>::take(who) {
+                T::Currency::unreserve(who, amount);
+            }
+        }
+
+        /// Pop the account holding the oldest ticket. Tickets freed by `remove_from_queue`
+        /// are stepped over once and never revisited.
+        fn pop_live() -> Option<(QIndex, T::AccountId)> {
+            let next = NextTicket::<T>::get();
+            let mut head = OldestTicket::<T>::get();
+            let mut found = None;
+            while head < next {
+                let ticket = head;
+                head += 1;
+                if let Some(acc) = Queue::<T>::take(ticket) {
+                    TicketOf::<T>::remove(&acc);
+                    RegionOf::<T>::remove(&acc);
+                    found = Some((ticket, acc));
+                    break;
+                }
+            }
+            OldestTicket::<T>::put(head);
+            found
+        }
+
+        /// Remove and return the first pair, by ticket order of its older member, whose hand
+        /// powers differ by at most `max_gap` and whose ratings differ by at most
+        /// `max_rating_gap` (0 disables either check), and whose regions are compatible. The
+        /// partner is the same-region candidate with the closest rating, then the smallest
+        /// power gap; ties go to the older candidate. Powers come from the hand provider,
+        /// which reports the hand bound at join time where it keeps one.
+        ///
+        /// Players without a region match anyone. Two different regions match only once one
+        /// of the players has waited `MaxRegionWaitBlocks`.
+        ///
+        /// Only the oldest `PairingWindow` tickets are compared. Tickets freed by leavers count
+        /// against a walk of at most four windows; leading ones are stepped over for good.
+        fn take_closest_pair(
+            max_gap: u32,
+            max_rating_gap: u32,
+        ) -> Option<(T::AccountId, T::AccountId)> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let max_wait = T::MaxRegionWaitBlocks::get();
+            let window = T::PairingWindow::get().max(2) as usize;
+            let next = NextTicket::<T>::get();
+            let head = OldestTicket::<T>::get();
+            let mut waiting: Vec<(QIndex, T::AccountId, u32, Option<u32>, _)> = Vec::new();
+            let mut ticket = head;
+            while ticket < next && waiting.len() < window && ticket - head < 4 * window as u64 {
+                if let Some(who) = Queue::<T>::get(ticket) {
+                    let power = T::HandProvider::hand_summary(&who)
+                        .map(|summary| summary.total_power)
+                        .unwrap_or(0);
+                    let rating = (max_rating_gap > 0)
+                        .then(|| T::Ratings::rating(&who))
+                        .flatten();
+                    let region = RegionOf::<T>::get(&who).map(|(region, joined)| {
+                        (region, now.saturating_sub(joined) >= max_wait)
+                    });
+                    waiting.push((ticket, who, power, rating, region));
+                }
+                ticket += 1;
+            }
+            OldestTicket::<T>::put(waiting.first().map_or(ticket, |(first, ..)| *first));
+
+            // `Some(true)` for a cross-region pair one side has waited long enough for.
+            let cross_region = |mine: &Option<(crate::Region, bool)>,
+                                theirs: &Option<(crate::Region, bool)>| {
+                match (mine, theirs) {
+                    (Some((a, a_waited)), Some((b, b_waited))) if a != b => {
+                        (*a_waited || *b_waited).then_some(true)
                     }
+                    _ => Some(false),
                 }
-                // Nothing live found in a full cycle.
-                None
-            })
+            };
+            let (a, b) = waiting.iter().enumerate().find_map(|(i, (_, _, power, rating, region))| {
+                waiting[i + 1..]
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(j, (_, _, other, other_rating, other_region))| {
+                        let cross = cross_region(region, other_region)?;
+                        let gap = power.abs_diff(*other);
+                        let rating_gap = match (rating, other_rating) {
+                            (Some(mine), Some(theirs)) => mine.abs_diff(*theirs),
+                            _ => 0,
+                        };
+                        let fits = (max_gap == 0 || gap <= max_gap)
+                            && (max_rating_gap == 0 || rating_gap <= max_rating_gap);
+                        fits.then_some((cross, rating_gap, gap, i + 1 + j))
+                    })
+                    .min()
+                    .map(|(.., j)| (i, j))
+            })?;
+            let a = waiting[a].1.clone();
+            let b = waiting[b].1.clone();
+            Self::take_ticket(&a);
+            Self::take_ticket(&b);
+            Some((a, b))
+        }
+
+        /// Drop `who`'s queue entry while keeping their series request for the pairing.
+        fn take_ticket(who: &T::AccountId) {
+            if let Some(ticket) = TicketOf::<T>::take(who) {
+                Queue::<T>::remove(ticket);
+            }
+            RegionOf::<T>::remove(who);
+            if Queue::<T>::count() == 0 {
+                OldestTicket::<T>::put(NextTicket::<T>::get());
+            }
+        }
+
+        fn games_created_this_block() -> u32 {
+            let (at, created) = GamesCreated::<T>::get();
+            if at == frame_system::Pallet::<T>::block_number() {
+                created
+            } else {
+                0
+            }
+        }
+
+        /// Weight of a join call, including the ticketing of the join in `on_finalize`.
+        pub fn join_weight() -> Weight {
+            Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 5))
         }
 
-        fn do_process(cap: QIndex) -> DispatchResult {
-            // Mirror the start event for calls coming from join_queue path.
+        /// Worst-case weight of the pairing in `on_finalize`: `MaxGamesCreatedPerBlock`
+        /// pairs, each scanning up to `4 * PairingWindow` tickets and reading hand power,
+        /// rating and region of the `PairingWindow` live ones, then creating the game.
+        pub fn pairing_weight() -> Weight {
+            let pairs = T::MaxGamesCreatedPerBlock::get() as u64;
+            let window = T::PairingWindow::get().max(2) as u64;
+            let per_pair = T::DbWeight::get()
+                .reads_writes(7 * window + 14, 16)
+                .saturating_add(T::GameCreator::creation_weight());
+            T::DbWeight::get()
+                .reads_writes(3, 2)
+                .saturating_add(per_pair.saturating_mul(pairs))
+        }
+
+        fn note_game_created() {
+            let created = Self::games_created_this_block().saturating_add(1);
+            GamesCreated::<T>::put((frame_system::Pallet::<T>::block_number(), created));
+        }
+
+        fn do_process() -> DispatchResult {
             Self::deposit_event(Event::ProcessingStarted {
-                live_size: LiveSize::<T>::get(),
-                head: Head::<T>::get(),
-                tail: Tail::<T>::get(),
+                live_size: Self::live_size(),
+                head: OldestTicket::<T>::get(),
+                tail: NextTicket::<T>::get(),
             });
+            let max_gap = T::MaxHandPowerGap::get();
+            let max_rating_gap = T::MaxRatingGap::get();
             loop {
-                if LiveSize::<T>::get() < 2 {
+                if Self::live_size() < 2 {
                     break;
                 }
-                let a = match Self::pop_live(cap) {
-                    Some(x) => x,
-                    None => break,
-                };
-                let b = match Self::pop_live(cap) {
-                    Some(x) => x,
-                    None => {
-                        Tail::<T>::mutate(|tail| {
-                            let idx = *tail % cap;
-                            Ring::<T>::insert(idx, &a);
-                            *tail = tail.wrapping_add(1);
-                        });
-                        InQueue::<T>::insert(&a, ());
-                        LiveSize::<T>::mutate(|n| *n = n.saturating_add(1));
-                        Self::deposit_event(Event::Requeued { who: a.clone() });
-                        break;
-                    }
-                };
-                Self::deposit_event(Event::PairFound { a: a.clone(), b: b.clone() });
-
-                if a == b {
-                    // Extremely defensive: never match the same account with itself.
-                    // Requeue `a` and stop this processing round.
-                    Tail::<T>::mutate(|tail| {
-                        let idx = *tail % cap;
-                        Ring::<T>::insert(idx, &a);
-                        *tail = tail.wrapping_add(1);
+                if Self::games_created_this_block() >= T::MaxGamesCreatedPerBlock::get() {
+                    Self::deposit_event(Event::PairingDeferred {
+                        remaining_live: Self::live_size(),
                     });
-                    InQueue::<T>::insert(&a, ());
-                    LiveSize::<T>::mutate(|n| *n = n.saturating_add(1));
-                    Self::deposit_event(Event::Requeued { who: a.clone() });
                     break;
                 }
-
-                Self::deposit_event(Event::GameCreateAttempt { a: a.clone(), b: b.clone() });
+                // Regional entries need the pairing scan too; plain FIFO ignores them.
+                let scan = max_gap > 0 || max_rating_gap > 0 || RegionOf::<T>::count() > 0;
+                let (a, b) = if scan {
+                    match Self::take_closest_pair(max_gap, max_rating_gap) {
+                        Some(pair) => pair,
+                        None => break,
+                    }
+                } else {
+                    let (a_ticket, a) = match Self::pop_live() {
+                        Some(x) => x,
+                        None => break,
+                    };
+                    match Self::pop_live() {
+                        Some((_, b)) => (a, b),
+                        None => {
+                            // Put `a` back in front with its original ticket.
+                            Queue::<T>::insert(a_ticket, &a);
+                            TicketOf::<T>::insert(&a, a_ticket);
+                            OldestTicket::<T>::put(a_ticket);
+                            Self::deposit_event(Event::Requeued { who: a.clone() });
+                            break;
+                        }
+                    }
+                };
+                #[cfg(feature = "legacy-events")]
+                {
+                    Self::deposit_event(Event::PairFound { a: a.clone(), b: b.clone() });
+                    Self::deposit_event(Event::GameCreateAttempt { a: a.clone(), b: b.clone() });
+                }
+                // The pair plays the shorter of the two requested formats.
+                let best_of = SeriesRequest::<T>::take(&a)
+                    .unwrap_or(1)
+                    .min(SeriesRequest::<T>::take(&b).unwrap_or(1));
                 // Ask the game pallet to create a game for this pair. If it fails we still emit Matched.
-                let _ = T::GameCreator::create_from_matchmaking(&a, &b);
+                let game_id = if best_of > 1 {
+                    Self::start_series(&a, &b, best_of)
+                } else {
+                    T::GameCreator::create_from_matchmaking(&a, &b).ok()
+                };
+                // Only games actually created count against `MaxGamesCreatedPerBlock`.
+                if game_id.is_some() {
+                    Self::note_game_created();
+                }
+                Self::hold_queue_deposits([&a, &b], game_id.as_ref());
+                T::HandProvider::release_queued_hand(&a);
+                T::HandProvider::release_queued_hand(&b);
+                LastHeartbeat::<T>::remove(&a);
+                LastHeartbeat::<T>::remove(&b);
                 Self::deposit_event(Event::Matched {
                     players: [a.clone(), b.clone()],
                 });
             }
             Self::deposit_event(Event::ProcessingCompleted {
-                remaining_live: LiveSize::<T>::get(),
-                head: Head::<T>::get(),
-                tail: Tail::<T>::get(),
+                remaining_live: Self::live_size(),
+                head: OldestTicket::<T>::get(),
+                tail: NextTicket::<T>::get(),
             });
             Ok(())
         }
     }
+
+    impl<T: Config> super::GameOutcomeObserver<T::AccountId, GameIdOf<T>> for Pallet<T> {
+        fn on_game_finished(game_id: &GameIdOf<T>, winner: Option<&T::AccountId>) {
+            Self::release_game_deposits(game_id);
+            if let Some((league_id, fixture)) = FixtureOfGame::<T>::take(game_id) {
+                Self::record_fixture(league_id, fixture, winner);
+                return;
+            }
+            let Some(series_id) = SeriesOfGame::<T>::take(game_id) else {
+                return;
+            };
+            let Some(mut series) = SeriesById::<T>::get(series_id) else {
+                return;
+            };
+
+            match winner {
+                Some(w) if *w == series.players[0] => {
+                    series.wins.0 = series.wins.0.saturating_add(1)
+                }
+                Some(w) if *w == series.players[1] => {
+                    series.wins.1 = series.wins.1.saturating_add(1)
+                }
+                _ => {}
+            }
+            series.games = series.games.saturating_add(1);
+
+            let needed = series.best_of / 2 + 1;
+            if series.wins.0 >= needed || series.wins.1 >= needed {
+                let champion = if series.wins.0 >= needed {
+                    series.players[0].clone()
+                } else {
+                    series.players[1].clone()
+                };
+                Self::finish_series(series_id, series, Some(champion));
+                return;
+            }
+            // Draws do not count, so a run of them must not keep the pair bound forever.
+            if series.games >= series.best_of.saturating_mul(2) {
+                Self::finish_series(series_id, series, None);
+                return;
+            }
+
+            match T::GameCreator::create_from_matchmaking(&series.players[0], &series.players[1]) {
+                Ok(next) => {
+                    series.current_game = next.clone();
+                    SeriesOfGame::<T>::insert(&next, series_id);
+                    SeriesById::<T>::insert(series_id, series);
+                    Self::deposit_event(Event::SeriesGameStarted {
+                        series_id,
+                        game_id: next,
+                    });
+                }
+                Err(_) => Self::finish_series(series_id, series, None),
+            }
+        }
+
+        fn on_first_round_played(game_id: &GameIdOf<T>) {
+            Self::release_game_deposits(game_id);
+        }
+
+        fn on_abandoned_before_first_round(game_id: &GameIdOf<T>, who: &T::AccountId) {
+            if let Some(amount) = GameDeposits::<T>::take(game_id, who) {
+                Self::slash_deposit(who, amount);
+            }
+        }
+
+        /// Refund the deposits held on the game and abort its series, if any: a voided game
+        /// has no result to count.
+        fn on_game_voided(game_id: &GameIdOf<T>) {
+            Self::release_game_deposits(game_id);
+            // A voided fixture game may be started again while its matchday lasts.
+            if let Some((league_id, fixture)) = FixtureOfGame::<T>::take(game_id) {
+                Fixtures::<T>::mutate(league_id, fixture, |entry| {
+                    if let Some(entry) = entry {
+                        entry.state = FixtureState::Scheduled;
+                    }
+                });
+                return;
+            }
+            let Some(series_id) = SeriesOfGame::<T>::take(game_id) else {
+                return;
+            };
+            if let Some(series) = SeriesById::<T>::get(series_id) {
+                Self::finish_series(series_id, series, None);
+            }
+        }
+
+        /// Hand `old`'s place in the game's series or league fixture to `new`. `old`'s deposit
+        /// was reserved from their own balance, so it is released rather than moved.
+        fn on_seat_reassigned(game_id: &GameIdOf<T>, old: &T::AccountId, new: &T::AccountId) {
+            if let Some(amount) = GameDeposits::<T>::take(game_id, old) {
+                T::Currency::unreserve(old, amount);
+            }
+            if let Some((league_id, fixture)) = FixtureOfGame::<T>::get(game_id) {
+                Fixtures::<T>::mutate(league_id, fixture, |entry| {
+                    if let Some(entry) = entry {
+                        for p in entry.players.iter_mut().filter(|p| *p == old) {
+                            *p = new.clone();
+                        }
+                    }
+                });
+            }
+            let Some(series_id) = SeriesOfGame::<T>::get(game_id) else {
+                return;
+            };
+            SeriesById::<T>::mutate(series_id, |series| {
+                if let Some(series) = series {
+                    for p in series.players.iter_mut().filter(|p| *p == old) {
+                        *p = new.clone();
+                    }
+                }
+            });
+            if ActiveSeriesOf::<T>::get(old) == Some(series_id) {
+                ActiveSeriesOf::<T>::remove(old);
+            }
+            ActiveSeriesOf::<T>::insert(new, series_id);
+        }
+    }
 }
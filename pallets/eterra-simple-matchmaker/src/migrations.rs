@@ -0,0 +1,139 @@
+//! Storage migrations of the matchmaker pallet.
+
+pub mod v1 {
+    //! Moves the queue from the wrapping `Head`/`Tail`/`Ring` buffer (indexed `tail % cap`)
+    //! onto `Queue`/`TicketOf`, keeping the waiting order. Stale ring slots of accounts that
+    //! left, and later duplicates of accounts that rejoined, are dropped.
+
+    use crate::pallet::{Config, NextTicket, OldestTicket, Pallet, Queue, TicketOf};
+    use frame_support::{pallet_prelude::*, storage_alias, traits::Get};
+    use sp_std::vec::Vec;
+
+    #[storage_alias]
+    type Head<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
+
+    #[storage_alias]
+    type Tail<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
+
+    #[storage_alias]
+    type Ring<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        u32,
+        <T as frame_system::Config>::AccountId,
+        OptionQuery,
+    >;
+
+    #[storage_alias]
+    type InQueue<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    #[storage_alias]
+    type LiveSize<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
+
+    pub fn migrate<T: Config>() -> Weight {
+        let cap = T::QueueCapacity::get().max(1);
+        let head = Head::<T>::take();
+        let size = Tail::<T>::take().wrapping_sub(head).min(cap);
+
+        let mut waiting: Vec<T::AccountId> = Vec::new();
+        for i in 0..size {
+            if let Some(acc) = Ring::<T>::get(head.wrapping_add(i) % cap) {
+                if InQueue::<T>::contains_key(&acc) && !waiting.contains(&acc) {
+                    waiting.push(acc);
+                }
+            }
+        }
+
+        let ring = Ring::<T>::clear(u32::MAX, None);
+        let queued = InQueue::<T>::clear(u32::MAX, None);
+        LiveSize::<T>::kill();
+
+        let first = NextTicket::<T>::get();
+        for (i, acc) in waiting.iter().enumerate() {
+            let ticket = first + i as u64;
+            Queue::<T>::insert(ticket, acc);
+            TicketOf::<T>::insert(acc, ticket);
+        }
+        OldestTicket::<T>::put(first);
+        NextTicket::<T>::put(first + waiting.len() as u64);
+
+        let moved = waiting.len() as u64;
+        T::DbWeight::get().reads_writes(
+            3 + 2 * size as u64,
+            5 + ring.unique as u64 + queued.unique as u64 + 2 * moved,
+        )
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mock::{new_test_ext, Matchmaker, QueueCapacityConst, Test};
+
+        #[test]
+        fn migrates_the_ring_in_waiting_order() {
+            new_test_ext().execute_with(|| {
+                let cap = QueueCapacityConst::get();
+                // A window wrapping around u32: 7 left, 8 rejoined further back.
+                let head = u32::MAX - 1;
+                for (i, acc) in [5u64, 7, 8, 6, 8].into_iter().enumerate() {
+                    Ring::<Test>::insert(head.wrapping_add(i as u32) % cap, acc);
+                }
+                for acc in [5u64, 6, 8] {
+                    InQueue::<Test>::insert(acc, ());
+                }
+                Head::<Test>::put(head);
+                Tail::<Test>::put(head.wrapping_add(5));
+                LiveSize::<Test>::put(3);
+
+                migrate::<Test>();
+
+                let order: Vec<u64> = (Matchmaker::head()..Matchmaker::tail())
+                    .map(|t| Queue::<Test>::get(t).unwrap())
+                    .collect();
+                assert_eq!(order, vec![5, 8, 6]);
+                assert_eq!(Matchmaker::live_size(), 3);
+                assert_eq!(TicketOf::<Test>::get(6), Some(Matchmaker::head() + 2));
+                assert_eq!(Ring::<Test>::iter().count(), 0);
+                assert_eq!(InQueue::<Test>::iter().count(), 0);
+                assert!(!Head::<Test>::exists() && !Tail::<Test>::exists());
+            });
+        }
+    }
+}
+
+pub mod v2 {
+    //! Adds the finished-game count to `Series`. Draws were not recorded, so running series
+    //! start from the decided games only.
+
+    use crate::pallet::{Config, GameIdOf, Series, SeriesById};
+    use frame_support::{pallet_prelude::*, traits::Get};
+
+    #[derive(Decode)]
+    struct OldSeries<AccountId, GameId> {
+        players: [AccountId; 2],
+        best_of: u8,
+        wins: (u8, u8),
+        current_game: GameId,
+    }
+
+    pub fn migrate<T: Config>() -> Weight {
+        let mut translated = 0u64;
+        SeriesById::<T>::translate::<OldSeries<T::AccountId, GameIdOf<T>>, _>(|_, old| {
+            translated += 1;
+            Some(Series {
+                players: old.players,
+                best_of: old.best_of,
+                wins: old.wins,
+                current_game: old.current_game,
+                games: old.wins.0.saturating_add(old.wins.1),
+            })
+        });
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+}
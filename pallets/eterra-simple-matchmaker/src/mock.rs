@@ -3,9 +3,11 @@
 
 use crate as pallet_matchmaker;
 
+use crate::league::RewardTier;
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{Everything, OnFinalize, OnInitialize},
+    traits::{ConstU32, Everything, OnFinalize, OnInitialize},
+    PalletId,
 };
 use frame_system as system;
 use sp_core::H256;
@@ -20,9 +22,26 @@ pub type BlockNumber = u64;
 
 parameter_types! {
     pub const BlockHashCount: u64 = 240;
-    pub const ExistentialDeposit: u64 = 0;
+    pub const ExistentialDeposit: u64 = 1;
     pub const PlayersPerMatchConst: u8 = 2;      // For 1v1 matching
     pub const QueueCapacityConst: u32 = 64;      // Circular buffer capacity for tests
+    pub const MaxSeriesLengthConst: u8 = 5;      // Up to best-of-5 series
+    pub storage MinRankedHandPower: u32 = 0;     // Disabled unless a test sets it
+    pub storage MaxHandPowerGap: u32 = 0;        // FIFO pairing unless a test sets it
+    pub storage MaxRatingGap: u32 = 0;           // Ratings ignored unless a test sets it
+    pub storage PairingWindow: u32 = 64;         // The whole queue unless a test sets it
+    pub const MaxRegionWaitBlocksConst: u64 = 10; // Cross-region pairing after 10 blocks
+    pub storage QueueDeposit: u64 = 0;           // No deposit unless a test sets it
+    pub storage MaxGamesCreatedPerBlock: u32 = 64; // No cap in practice unless set
+    pub storage FailGameCreation: bool = false;  // Games are created unless a test sets it
+    pub storage QueueHeartbeatBlocks: u64 = 0;   // No heartbeats required unless set
+    pub const MatchmakerPalletId: PalletId = PalletId(*b"etr/mtch");
+    pub storage SeasonRewardBatch: u32 = 1;      // One rank per block to exercise batching
+    // Champion: 100 and a card; runner-up: 50.
+    pub SeasonRewardTiers: Vec<RewardTier<u64>> = vec![
+        RewardTier { up_to_rank: 1, amount: 100, card: Some([9, 9, 9, 9]) },
+        RewardTier { up_to_rank: 2, amount: 50, card: None },
+    ];
 }
 
 impl system::Config for Test {
@@ -41,7 +60,7 @@ impl system::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type OnNewAccount = ();
     type OnKilledAccount = ();
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type Version = ();
     type PalletInfo = PalletInfo;
     type SS58Prefix = ();
@@ -58,13 +77,32 @@ impl system::Config for Test {
     type PostTransactions = (); // no post-transactions hooks in mock
 }
 
+impl pallet_balances::Config for Test {
+    type Balance = u64;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+}
+
 // --- Controllable HandProvider for tests ---
 // We keep a per-test thread-local set of accounts that "have" a hand.
 use std::cell::RefCell;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 thread_local! {
     static TL_HAND_SET: RefCell<BTreeSet<AccountId>> = RefCell::new(BTreeSet::new());
+    static TL_HAND_POWER: RefCell<BTreeMap<AccountId, u32>> = RefCell::new(BTreeMap::new());
+    static TL_NOT_ONBOARDED: RefCell<BTreeSet<AccountId>> = RefCell::new(BTreeSet::new());
+    static TL_RATING: RefCell<BTreeMap<AccountId, u32>> = RefCell::new(BTreeMap::new());
 }
 
 thread_local! {
@@ -83,6 +121,55 @@ impl pallet_matchmaker::CurrentHandProvider<AccountId> for MockHandProvider {
     fn has_current_hand(who: &AccountId) -> bool {
         TL_HAND_SET.with(|s| s.borrow().contains(who))
     }
+    fn hand_summary(who: &AccountId) -> Option<pallet_matchmaker::HandSummary> {
+        if !Self::has_current_hand(who) {
+            return None;
+        }
+        // Hands default to a strong 5 x (9,9,9,9) unless a test overrides the power.
+        let total_power = TL_HAND_POWER.with(|m| m.borrow().get(who).copied().unwrap_or(180));
+        Some(pallet_matchmaker::HandSummary {
+            cards: 5,
+            total_power,
+        })
+    }
+}
+
+/// Helper: override the total power reported for `who`'s hand in this test thread.
+pub fn set_hand_power(who: AccountId, power: u32) {
+    TL_HAND_POWER.with(|m| m.borrow_mut().insert(who, power));
+}
+
+/// Test-only ratings: accounts are unrated unless a test sets a rating.
+pub struct MockRatings;
+impl pallet_matchmaker::RatingProvider<AccountId> for MockRatings {
+    fn rating(who: &AccountId) -> Option<u32> {
+        TL_RATING.with(|m| m.borrow().get(who).copied())
+    }
+}
+
+/// Helper: set the rating reported for `who` in this test thread.
+pub fn set_rating(who: AccountId, rating: u32) {
+    TL_RATING.with(|m| m.borrow_mut().insert(who, rating));
+}
+
+/// Test-only onboarding gate: every account is onboarded unless a test says otherwise.
+pub struct MockOnboarding;
+impl pallet_matchmaker::OnboardingStatus<AccountId> for MockOnboarding {
+    fn is_onboarded(who: &AccountId) -> bool {
+        !TL_NOT_ONBOARDED.with(|s| s.borrow().contains(who))
+    }
+}
+
+/// Helper: mark `who` as (not) onboarded in this test thread.
+pub fn set_onboarded(who: AccountId, onboarded: bool) {
+    TL_NOT_ONBOARDED.with(|s| {
+        let mut s = s.borrow_mut();
+        if onboarded {
+            s.remove(&who);
+        } else {
+            s.insert(who);
+        }
+    });
 }
 
 /// Helper: mark/unmark an account as having a hand in this test thread.
@@ -100,6 +187,9 @@ pub fn set_has_hand(who: AccountId, has: bool) {
 /// Helper: clear all "has hand" flags (called by new_test_ext).
 pub fn clear_all_hands() {
     TL_HAND_SET.with(|s| s.borrow_mut().clear());
+    TL_HAND_POWER.with(|m| m.borrow_mut().clear());
+    TL_NOT_ONBOARDED.with(|s| s.borrow_mut().clear());
+    TL_RATING.with(|m| m.borrow_mut().clear());
 }
 
 // --- Test-only GameCreator implementation for () ---
@@ -110,6 +200,9 @@ impl pallet_matchmaker::GameCreator<AccountId> for () {
         a: &AccountId,
         b: &AccountId,
     ) -> Result<Self::GameId, DispatchError> {
+        if FailGameCreation::get() {
+            return Err(DispatchError::Other("game creation failed"));
+        }
         // Record the created game pair for assertions.
         CREATED_GAMES.with(|v| v.borrow_mut().push((*a, *b)));
         // Bump a simple counter for the returned GameId.
@@ -120,6 +213,10 @@ impl pallet_matchmaker::GameCreator<AccountId> for () {
         });
         Ok(id as u32)
     }
+
+    fn creation_weight() -> frame_support::weights::Weight {
+        frame_support::weights::Weight::from_parts(1_000, 0)
+    }
 }
 
 impl pallet_matchmaker::Config for Test {
@@ -128,6 +225,39 @@ impl pallet_matchmaker::Config for Test {
     type QueueCapacity = QueueCapacityConst;
     type HandProvider = MockHandProvider;
     type GameCreator = ();
+    type MaxSeriesLength = MaxSeriesLengthConst;
+    type MinRankedHandPower = MinRankedHandPower;
+    type MaxHandPowerGap = MaxHandPowerGap;
+    type Ratings = MockRatings;
+    type MaxRatingGap = MaxRatingGap;
+    type PairingWindow = PairingWindow;
+    type Onboarding = MockOnboarding;
+    type MaxRegionWaitBlocks = MaxRegionWaitBlocksConst;
+    type Currency = Balances;
+    type QueueDeposit = QueueDeposit;
+    type MaxGamesCreatedPerBlock = MaxGamesCreatedPerBlock;
+    type LeagueAdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type QueueHeartbeatBlocks = QueueHeartbeatBlocks;
+    type PalletId = MatchmakerPalletId;
+    type SeasonRewardTiers = SeasonRewardTiers;
+    type SeasonRewardBatch = SeasonRewardBatch;
+    type SeasonCards = MockSeasonCards;
+}
+
+thread_local! {
+    pub static GRANTED_CARDS: RefCell<Vec<(AccountId, [u8; 4])>> = RefCell::new(Vec::new());
+}
+
+/// Test-only card grants: records each grant and returns its position as the card id.
+pub struct MockSeasonCards;
+impl pallet_matchmaker::SeasonCardRewards<AccountId> for MockSeasonCards {
+    fn grant_card(who: &AccountId, edges: [u8; 4]) -> Option<u32> {
+        GRANTED_CARDS.with(|v| {
+            let mut v = v.borrow_mut();
+            v.push((*who, edges));
+            Some(v.len() as u32 - 1)
+        })
+    }
 }
 
 construct_runtime!(
@@ -137,6 +267,7 @@ construct_runtime!(
         UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>,
     {
         System: frame_system,
+        Balances: pallet_balances,
         Matchmaker: pallet_matchmaker,
     }
 );
@@ -150,6 +281,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     ext.execute_with(|| {
         clear_all_hands();
         CREATED_GAMES.with(|v| v.borrow_mut().clear());
+        GRANTED_CARDS.with(|v| v.borrow_mut().clear());
         NEXT_GAME_ID.with(|c| c.set(1));
         System::set_block_number(1);
     });
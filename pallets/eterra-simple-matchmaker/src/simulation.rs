@@ -0,0 +1,198 @@
+// pallets/eterra-simple-matchmaker/src/simulation.rs
+//! Deterministic queue simulation: drives hundreds of join/leave/process cycles through the
+//! real extrinsics and checks the queue's conservation invariants after every step.
+
+use super::*;
+
+use frame_support::{assert_noop, assert_ok, traits::OnFinalize};
+use std::collections::BTreeSet;
+
+use crate::mock::{
+    new_test_ext, set_has_hand, AccountId, Matchmaker, QueueCapacityConst, RuntimeEvent,
+    RuntimeOrigin, System, Test,
+};
+
+/// Small deterministic generator so runs are reproducible from a seed.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// Ticket and pair the joins of the current block.
+fn end_block() {
+    <Matchmaker as OnFinalize<u64>>::on_finalize(System::block_number());
+}
+
+/// Mirrors who should be queued from the emitted events and compares it with storage.
+struct Simulation {
+    rng: XorShift,
+    accounts: u64,
+    queued: BTreeSet<AccountId>,
+    matches: u32,
+    last_ticket: QIndex,
+}
+
+impl Simulation {
+    fn new(seed: u64, accounts: u64) -> Self {
+        for who in 1..=accounts {
+            set_has_hand(who, true);
+        }
+        Self {
+            rng: XorShift(seed),
+            accounts,
+            queued: BTreeSet::new(),
+            matches: 0,
+            last_ticket: 0,
+        }
+    }
+
+    fn pick(&mut self) -> AccountId {
+        1 + self.rng.below(self.accounts)
+    }
+
+    fn run(&mut self, steps: u32) {
+        for _ in 0..steps {
+            self.step();
+            end_block();
+            self.absorb_events();
+            self.check_invariants();
+        }
+    }
+
+    fn step(&mut self) {
+        match self.rng.below(10) {
+            0..=4 => {
+                let who = self.pick();
+                if self.queued.contains(&who) {
+                    assert_noop!(
+                        Matchmaker::join_queue(RuntimeOrigin::signed(who)),
+                        Error::<Test>::AlreadyQueued
+                    );
+                } else {
+                    assert_ok!(Matchmaker::join_queue(RuntimeOrigin::signed(who)));
+                }
+            }
+            5..=7 => {
+                let who = self.pick();
+                if self.queued.contains(&who) {
+                    assert_ok!(Matchmaker::leave_queue(RuntimeOrigin::signed(who)));
+                } else {
+                    assert_noop!(
+                        Matchmaker::leave_queue(RuntimeOrigin::signed(who)),
+                        Error::<Test>::NotQueued
+                    );
+                }
+            }
+            #[cfg(feature = "dev-mode")]
+            9 => {
+                let mut joins = Vec::new();
+                for _ in 0..self.rng.below(9) {
+                    let who = self.pick();
+                    if !self.queued.contains(&who) && !joins.contains(&who) {
+                        joins.push(who);
+                    }
+                }
+                assert_ok!(Matchmaker::simulate_queue(RuntimeOrigin::root(), joins));
+            }
+            _ => assert_ok!(Matchmaker::process_queue(RuntimeOrigin::signed(1))),
+        }
+    }
+
+    /// Replay this step's events onto the model; every match must consume two distinct
+    /// accounts that were queued, so nobody is matched twice for one join.
+    fn absorb_events(&mut self) {
+        for record in System::events() {
+            let RuntimeEvent::Matchmaker(event) = record.event else {
+                continue;
+            };
+            match event {
+                Event::Joined { who } => assert!(self.queued.insert(who), "{} joined twice", who),
+                Event::Left { who } => assert!(self.queued.remove(&who), "{} left unqueued", who),
+                Event::Matched { players: [a, b] } => {
+                    assert_ne!(a, b, "account matched with itself");
+                    assert!(self.queued.remove(&a), "{} matched while not queued", a);
+                    assert!(self.queued.remove(&b), "{} matched while not queued", b);
+                    self.matches += 1;
+                }
+                _ => {}
+            }
+        }
+        System::reset_events();
+    }
+
+    fn check_invariants(&mut self) {
+        let head = OldestTicket::<Test>::get();
+        let next = NextTicket::<Test>::get();
+        assert!(head <= next, "head {} passed next ticket {}", head, next);
+        assert!(next >= self.last_ticket, "tickets went backwards");
+        self.last_ticket = next;
+
+        let ticketed: BTreeSet<AccountId> = TicketOf::<Test>::iter_keys().collect();
+        assert_eq!(ticketed, self.queued);
+        assert_eq!(Matchmaker::live_size() as usize, self.queued.len());
+        assert_eq!(Queue::<Test>::iter().count(), self.queued.len());
+        assert!(Matchmaker::live_size() <= QueueCapacityConst::get());
+
+        // Queue and TicketOf agree, and every live ticket lies in [head, next).
+        for (ticket, who) in Queue::<Test>::iter() {
+            assert_eq!(TicketOf::<Test>::get(who), Some(ticket));
+            assert!(head <= ticket && ticket < next, "ticket {} outside window", ticket);
+        }
+
+        // Processing leaves at most one player waiting.
+        assert!(self.queued.len() < 2, "{} players left unmatched", self.queued.len());
+    }
+}
+
+#[test]
+fn random_cycles_conserve_queue_state() {
+    for seed in [1u64, 0xdead_beef, 0x5eed_0f_4a11] {
+        new_test_ext().execute_with(|| {
+            let mut sim = Simulation::new(seed, 24);
+            sim.run(600);
+            assert!(sim.matches > 0, "seed {} formed no matches", seed);
+        });
+    }
+}
+
+#[test]
+fn cycles_cross_the_old_u32_ticket_range() {
+    new_test_ext().execute_with(|| {
+        let start = u32::MAX as QIndex - 40;
+        OldestTicket::<Test>::put(start);
+        NextTicket::<Test>::put(start);
+        let mut sim = Simulation::new(0xc0ff_ee, 16);
+        sim.last_ticket = start;
+        sim.run(600);
+        assert!(Matchmaker::tail() > u32::MAX as QIndex, "tickets never passed u32::MAX");
+    });
+}
+
+#[test]
+fn join_leave_churn_never_fills_the_queue() {
+    new_test_ext().execute_with(|| {
+        let mut sim = Simulation::new(7, 3);
+        // A lone player churning must not exhaust capacity or leave gaps to walk.
+        for _ in 0..3 * QueueCapacityConst::get() {
+            assert_ok!(Matchmaker::join_queue(RuntimeOrigin::signed(1)));
+            assert_ok!(Matchmaker::leave_queue(RuntimeOrigin::signed(1)));
+        }
+        assert_eq!(Matchmaker::head(), Matchmaker::tail());
+        assert_ok!(Matchmaker::join_queue(RuntimeOrigin::signed(2)));
+        assert_ok!(Matchmaker::join_queue(RuntimeOrigin::signed(3)));
+        end_block();
+        sim.absorb_events();
+        sim.check_invariants();
+        assert_eq!(sim.matches, 1);
+    });
+}
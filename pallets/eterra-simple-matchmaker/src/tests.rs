@@ -3,13 +3,15 @@
 
 use super::*;
 
-use frame_support::{assert_noop, assert_ok, traits::OnFinalize};
+use frame_support::{assert_noop, assert_ok, traits::{OnFinalize, OnInitialize}};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::DispatchError;
 
 use crate::mock::{
-    clear_all_hands, created_games, new_test_ext, set_has_hand, Matchmaker, RuntimeEvent,
-    RuntimeOrigin as SystemOrigin, Test,
+    clear_all_hands, created_games, new_test_ext, set_has_hand, set_hand_power, set_rating,
+    Balances, FailGameCreation, Matchmaker, MaxGamesCreatedPerBlock, MaxHandPowerGap,
+    MaxRatingGap, MinRankedHandPower, PairingWindow, QueueDeposit, QueueHeartbeatBlocks,
+    RuntimeEvent, RuntimeOrigin as SystemOrigin, Test, GRANTED_CARDS,
 };
 
 fn last_event() -> RuntimeEvent {
@@ -19,6 +21,12 @@ fn last_event() -> RuntimeEvent {
         .event
 }
 
+/// Close the current block so its joins are ticketed and paired.
+fn end_block() {
+    let now = frame_system::Pallet::<Test>::block_number();
+    <Matchmaker as OnFinalize<BlockNumberFor<Test>>>::on_finalize(now);
+}
+
 fn take_events() -> Vec<RuntimeEvent> {
     frame_system::Pallet::<Test>::events()
         .into_iter()
@@ -51,8 +59,11 @@ fn join_queue_emits_event_and_persists() {
         });
         assert!(joined_seen, "expected Joined event for who=1, got: {:?}", evs);
 
-        // Also assert the state persisted: live size should be 1.
-        assert_eq!(LiveSize::<Test>::get(), 1);
+        // The join is ticketed when the block ends; live size should then be 1.
+        assert!(Matchmaker::is_queued(&1));
+        assert_eq!(Matchmaker::live_size(), 0);
+        end_block();
+        assert_eq!(Matchmaker::live_size(), 1);
     });
 }
 
@@ -68,21 +79,35 @@ fn join_queue_rejects_duplicates() {
     });
 }
 
+#[cfg(feature = "dev-mode")]
 #[test]
 fn queue_capacity_enforced() {
     new_test_ext().execute_with(|| {
-        // QueueCapacityConst is defined in mock.rs; fill it completely.
-        for who in 1..=mock::QueueCapacityConst::get() as u64 {
-            set_has_hand(who, true);
-            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
-        }
-        // One more should fail (ensure the overflow player also has a preset hand so we hit QueueFull, not NoPresetHand)
-        let overflow = mock::QueueCapacityConst::get() as u64 + 1;
-        set_has_hand(overflow, true);
+        // Joins through `join_queue` are matched immediately, so fill the queue in one
+        // `simulate_queue` batch. One more than QueueCapacityConst must not fit.
+        let cap = mock::QueueCapacityConst::get() as u64;
         assert_noop!(
-            Matchmaker::join_queue(SystemOrigin::signed(overflow)),
+            Matchmaker::simulate_queue(SystemOrigin::root(), (1..=cap + 1).collect()),
             Error::<Test>::QueueFull
         );
+        // Exactly QueueCapacityConst fits, and processing pairs everyone off.
+        assert_ok!(Matchmaker::simulate_queue(
+            SystemOrigin::root(),
+            (1..=cap).collect()
+        ));
+        assert_eq!(Matchmaker::live_size(), 0);
+        assert_eq!(created_games().len() as u64, cap / 2);
+    });
+}
+
+#[cfg(feature = "dev-mode")]
+#[test]
+fn simulate_queue_is_root_only() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Matchmaker::simulate_queue(SystemOrigin::signed(1), vec![1, 2]),
+            DispatchError::BadOrigin
+        );
     });
 }
 
@@ -136,6 +161,45 @@ fn join_queue_requires_current_hand() {
     });
 }
 
+#[test]
+fn join_queue_rejects_hands_below_min_power() {
+    new_test_ext().execute_with(|| {
+        MinRankedHandPower::set(&40);
+        set_has_hand(1, true);
+        set_has_hand(2, true);
+
+        // A throwaway 5 x (1,1,1,1) hand
+        set_hand_power(1, 20);
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(1)),
+            Error::<Test>::HandTooWeak
+        );
+        assert_noop!(
+            Matchmaker::join_series_queue(SystemOrigin::signed(1), 3),
+            Error::<Test>::HandTooWeak
+        );
+
+        set_hand_power(2, 40);
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(2)));
+    });
+}
+
+#[test]
+fn join_queue_requires_completed_onboarding() {
+    new_test_ext().execute_with(|| {
+        set_has_hand(1, true);
+        set_onboarded(1, false);
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(1)),
+            Error::<Test>::OnboardingIncomplete
+        );
+
+        set_onboarded(1, true);
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+    });
+}
+
+#[cfg(any(feature = "dev_tests_with_try_match"))]
 #[cfg(any(feature = "dev_tests_with_try_match"))]
 #[test]
 fn try_match_noop_with_fewer_than_two() {
@@ -295,3 +359,597 @@ fn finalize_blocks_does_not_break_queue() {
         <Matchmaker as frame_support::traits::Hooks<BlockNumberFor<Test>>>::on_finalize(2);
     });
 }
+
+#[test]
+fn series_runs_until_one_side_clinches() {
+    new_test_ext().execute_with(|| {
+        set_has_hand(1, true);
+        set_has_hand(2, true);
+        assert_noop!(
+            Matchmaker::join_series_queue(SystemOrigin::signed(1), 4),
+            Error::<Test>::InvalidSeriesLength
+        );
+        assert_ok!(Matchmaker::join_series_queue(SystemOrigin::signed(1), 3));
+        end_block();
+        assert_ok!(Matchmaker::join_series_queue(SystemOrigin::signed(2), 3));
+        end_block();
+
+        // First game created and bound into series 0
+        assert_eq!(created_games(), vec![(1, 2)]);
+        let series = Matchmaker::series(0).expect("series stored");
+        assert_eq!(series.best_of, 3);
+        assert_eq!(series.current_game, 1);
+        assert_eq!(Matchmaker::active_series_of(1), Some(0));
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(1)),
+            Error::<Test>::AlreadyInSeries
+        );
+
+        // Game 1: player 1 wins -> game 2 starts
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&1, Some(&1));
+        assert_eq!(created_games().len(), 2);
+        assert_eq!(Matchmaker::series(0).unwrap().current_game, 2);
+
+        // Results for games that are not the series' current game are ignored
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&1, Some(&1));
+        assert_eq!(Matchmaker::series(0).unwrap().wins, (1, 0));
+
+        // Game 2: player 1 wins again and clinches the best-of-3
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&2, Some(&1));
+        assert_eq!(created_games().len(), 2);
+        assert!(Matchmaker::series(0).is_none());
+        assert_eq!(Matchmaker::active_series_of(1), None);
+        assert_eq!(Matchmaker::active_series_of(2), None);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Matchmaker(Event::<Test>::SeriesFinished {
+                series_id: 0,
+                winner: Some(1),
+                wins: (2, 0),
+            })
+        );
+    });
+}
+
+#[test]
+fn series_of_draws_ends_undecided_after_twice_its_length() {
+    new_test_ext().execute_with(|| {
+        set_has_hand(1, true);
+        set_has_hand(2, true);
+        assert_ok!(Matchmaker::join_series_queue(SystemOrigin::signed(1), 3));
+        end_block();
+        assert_ok!(Matchmaker::join_series_queue(SystemOrigin::signed(2), 3));
+        end_block();
+
+        // One win and four draws: still bound, the sixth game is started
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&1, Some(&2));
+        for game in 2..6u32 {
+            <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&game, None);
+        }
+        assert_eq!(created_games().len(), 6);
+        assert_eq!(Matchmaker::series(0).unwrap().games, 5);
+
+        // Another draw reaches 2 * best_of games and ends the series without a winner
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&6, None);
+        assert_eq!(created_games().len(), 6);
+        assert!(Matchmaker::series(0).is_none());
+        assert_eq!(Matchmaker::active_series_of(1), None);
+        assert_eq!(Matchmaker::active_series_of(2), None);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Matchmaker(Event::<Test>::SeriesFinished {
+                series_id: 0,
+                winner: None,
+                wins: (0, 1),
+            })
+        );
+    });
+}
+
+#[test]
+fn reassigned_seat_moves_the_series_and_refunds_the_old_deposit() {
+    use frame_support::traits::{Currency, ReservableCurrency};
+
+    new_test_ext().execute_with(|| {
+        QueueDeposit::set(&10);
+        for who in 1..=2 {
+            set_has_hand(who, true);
+            let _ = Balances::deposit_creating(&who, 100);
+        }
+        assert_ok!(Matchmaker::join_series_queue(SystemOrigin::signed(1), 3));
+        end_block();
+        assert_ok!(Matchmaker::join_series_queue(SystemOrigin::signed(2), 3));
+        end_block();
+        assert_eq!(Matchmaker::game_deposit(1, 1), Some(10));
+
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_seat_reassigned(&1, &1, &3);
+        assert_eq!(Matchmaker::series(0).unwrap().players, [3, 2]);
+        assert_eq!(Matchmaker::active_series_of(1), None);
+        assert_eq!(Matchmaker::active_series_of(3), Some(0));
+        assert_eq!(Matchmaker::game_deposit(1, 1), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Matchmaker::game_deposit(1, 2), Some(10));
+
+        // The next game of the series is created for the new seat holder
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&1, Some(&3));
+        assert_eq!(Matchmaker::series(0).unwrap().wins, (1, 0));
+        assert_eq!(created_games().last(), Some(&(3, 2)));
+    });
+}
+
+#[test]
+fn series_uses_the_shorter_requested_format() {
+    new_test_ext().execute_with(|| {
+        set_has_hand(1, true);
+        set_has_hand(2, true);
+        assert_ok!(Matchmaker::join_series_queue(SystemOrigin::signed(1), 5));
+        end_block();
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(2)));
+        end_block();
+
+        // A single game was created; no series and no leftover request
+        assert_eq!(created_games(), vec![(1, 2)]);
+        assert!(Matchmaker::series(0).is_none());
+        assert_eq!(Matchmaker::series_request(1), None);
+        assert_eq!(Matchmaker::active_series_of(1), None);
+    });
+}
+
+#[test]
+fn process_queue_emits_processing_started_once() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::process_queue(SystemOrigin::signed(1)));
+        let started = filter_matchmaker(&take_events())
+            .into_iter()
+            .filter(|ev| {
+                matches!(
+                    ev,
+                    RuntimeEvent::Matchmaker(Event::<Test>::ProcessingStarted { .. })
+                )
+            })
+            .count();
+        assert_eq!(started, 1);
+    });
+}
+
+#[test]
+fn power_gap_pairs_players_with_comparable_hands() {
+    new_test_ext().execute_with(|| {
+        MaxHandPowerGap::set(&20);
+        for (who, power) in [(1, 100), (2, 180), (3, 110), (4, 250)] {
+            set_has_hand(who, true);
+            set_hand_power(who, power);
+            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            end_block();
+        }
+
+        // 1 skips the stronger 2 for 3; 2 and 4 are too far apart and keep waiting.
+        assert_eq!(created_games(), vec![(1, 3)]);
+        assert_eq!(Matchmaker::live_size(), 2);
+        assert!(Matchmaker::is_queued(&2) && Matchmaker::is_queued(&4));
+
+        set_has_hand(5, true);
+        set_hand_power(5, 240);
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(5)));
+        end_block();
+        assert_eq!(created_games(), vec![(1, 3), (4, 5)]);
+        assert_eq!(Matchmaker::live_size(), 1);
+        assert_eq!(Matchmaker::ticket_of(2), Some(1));
+    });
+}
+
+#[test]
+fn pairing_scan_only_compares_the_oldest_tickets() {
+    new_test_ext().execute_with(|| {
+        MaxHandPowerGap::set(&20);
+        PairingWindow::set(&2);
+        for (who, power) in [(1, 100), (2, 300), (3, 110)] {
+            set_has_hand(who, true);
+            set_hand_power(who, power);
+            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            end_block();
+        }
+
+        // 3 would fit 1 but is outside the window of two
+        assert!(created_games().is_empty());
+        assert_eq!(Matchmaker::live_size(), 3);
+
+        // Once 2 leaves, 3 moves into the window
+        assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(2)));
+        assert_ok!(Matchmaker::process_queue(SystemOrigin::signed(1)));
+        assert_eq!(created_games(), vec![(1, 3)]);
+    });
+}
+
+#[test]
+fn rating_band_pairs_players_of_similar_strength() {
+    new_test_ext().execute_with(|| {
+        MaxRatingGap::set(&100);
+        for (who, rating) in [(1, 1200), (2, 1500), (3, 1290), (4, 1230)] {
+            set_has_hand(who, true);
+            set_rating(who, rating);
+            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            end_block();
+        }
+
+        // 1 skips the much stronger 2 for 3; 2 and 4 are too far apart and keep waiting.
+        assert_eq!(created_games(), vec![(1, 3)]);
+        assert!(Matchmaker::is_queued(&2) && Matchmaker::is_queued(&4));
+
+        // An unrated player is paired with the longest waiting player.
+        set_has_hand(5, true);
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(5)));
+        end_block();
+        assert_eq!(created_games(), vec![(1, 3), (2, 5)]);
+        assert_eq!(Matchmaker::live_size(), 1);
+    });
+}
+
+#[test]
+fn regional_players_wait_for_a_nearby_opponent() {
+    new_test_ext().execute_with(|| {
+        for (who, region) in [(1, Region::Europe), (2, Region::Asia), (3, Region::Europe)] {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_regional_queue(SystemOrigin::signed(who), region));
+            end_block();
+        }
+
+        // 1 skips 2 from another region for 3; 2 keeps waiting alone.
+        assert_eq!(created_games(), vec![(1, 3)]);
+        assert_eq!(Matchmaker::region_of(2), Some((Region::Asia, 1)));
+        assert_eq!(Matchmaker::region_of(1), None);
+
+        set_has_hand(4, true);
+        assert_ok!(Matchmaker::join_regional_queue(
+            SystemOrigin::signed(4),
+            Region::NorthAmerica
+        ));
+        end_block();
+        assert_eq!(Matchmaker::live_size(), 2);
+
+        // Once 2 has waited `MaxRegionWaitBlocks`, the regions no longer hold them apart.
+        frame_system::Pallet::<Test>::set_block_number(11);
+        assert_ok!(Matchmaker::process_queue(SystemOrigin::signed(99)));
+        assert_eq!(created_games(), vec![(1, 3), (2, 4)]);
+        assert_eq!(RegionOf::<Test>::count(), 0);
+
+        // Players without a region pair with anyone right away.
+        set_has_hand(5, true);
+        set_has_hand(6, true);
+        assert_ok!(Matchmaker::join_regional_queue(SystemOrigin::signed(5), Region::Oceania));
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(6)));
+        end_block();
+        assert_eq!(created_games(), vec![(1, 3), (2, 4), (5, 6)]);
+    });
+}
+
+#[test]
+fn queue_deposit_is_held_until_the_first_round_and_slashed_on_early_abandon() {
+    use frame_support::traits::{Currency, ReservableCurrency};
+
+    new_test_ext().execute_with(|| {
+        QueueDeposit::set(&10);
+        for who in 1..=5 {
+            set_has_hand(who, true);
+            let _ = Balances::deposit_creating(&who, 100);
+        }
+        set_has_hand(6, true);
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(6)),
+            Error::<Test>::InsufficientDeposit
+        );
+
+        // Leaving the queue refunds the deposit.
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(5)));
+        assert_eq!(Balances::reserved_balance(5), 10);
+        assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(5)));
+        assert_eq!(Balances::reserved_balance(5), 0);
+
+        // Matched deposits follow the game until its first round is played.
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        end_block();
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(2)));
+        end_block();
+        assert_eq!(created_games(), vec![(1, 2)]);
+        assert_eq!(Matchmaker::queue_deposit_of(1), None);
+        assert_eq!(Matchmaker::game_deposit(1, 2), Some(10));
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_first_round_played(&1);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // Abandoning before round 1 costs the deposit; the opponent gets theirs back.
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(3)));
+        end_block();
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(4)));
+        end_block();
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_abandoned_before_first_round(&2, &3);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Matchmaker(Event::QueueDepositSlashed { who: 3, amount: 10 })
+        );
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&2, Some(&4));
+        assert_eq!(Balances::free_balance(3), 90);
+        assert_eq!(Balances::free_balance(4), 100);
+        assert_eq!(Balances::reserved_balance(4), 0);
+    });
+}
+
+#[test]
+fn joins_within_a_block_are_paired_in_a_seeded_order() {
+    // Ticket order of four joins landing in one block, given their extrinsic order.
+    let enqueue_order = |joins: [u64; 4]| {
+        new_test_ext().execute_with(|| {
+            for who in joins {
+                set_has_hand(who, true);
+                assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            }
+            assert!(created_games().is_empty());
+            end_block();
+
+            let events = filter_matchmaker(&take_events());
+            let enqueued: Vec<(u64, QIndex)> = events
+                .iter()
+                .filter_map(|ev| match ev {
+                    RuntimeEvent::Matchmaker(Event::<Test>::Enqueued { who, ticket }) => {
+                        Some((*who, *ticket))
+                    }
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                enqueued.iter().map(|(_, t)| *t).collect::<Vec<_>>(),
+                vec![0, 1, 2, 3]
+            );
+            let order: Vec<u64> = enqueued.into_iter().map(|(who, _)| who).collect();
+            // Pairs follow the shuffled ticket order.
+            assert_eq!(created_games(), vec![(order[0], order[1]), (order[2], order[3])]);
+            assert!(Matchmaker::pending_joins().is_empty());
+            order
+        })
+    };
+
+    let order = enqueue_order([1, 2, 3, 4]);
+    assert_eq!(enqueue_order([4, 3, 2, 1]), order);
+    assert_eq!(enqueue_order([2, 4, 1, 3]), order);
+}
+
+#[test]
+fn block_start_reserves_the_worst_case_pairing() {
+    new_test_ext().execute_with(|| {
+        // The mock weighs no storage access, leaving the game creations.
+        MaxGamesCreatedPerBlock::set(&4);
+        assert_eq!(Matchmaker::pairing_weight(), Weight::from_parts(4_000, 0));
+        let reserved = <Matchmaker as OnInitialize<BlockNumberFor<Test>>>::on_initialize(1);
+        assert!(reserved.all_gte(Matchmaker::pairing_weight()));
+
+        MaxGamesCreatedPerBlock::set(&8);
+        assert_eq!(Matchmaker::pairing_weight(), Weight::from_parts(8_000, 0));
+    });
+}
+
+#[test]
+fn pairs_beyond_the_per_block_cap_are_created_next_block() {
+    new_test_ext().execute_with(|| {
+        MaxGamesCreatedPerBlock::set(&1);
+        for who in 1..=4 {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+        }
+        end_block();
+        assert_eq!(created_games().len(), 1);
+        assert_eq!(Matchmaker::live_size(), 2);
+        assert!(take_events().contains(&RuntimeEvent::Matchmaker(Event::PairingDeferred {
+            remaining_live: 2
+        })));
+
+        // No new joins, but the deferred pair is created at the end of the next block.
+        frame_system::Pallet::<Test>::set_block_number(2);
+        end_block();
+        assert_eq!(created_games().len(), 2);
+        assert_eq!(Matchmaker::live_size(), 0);
+    });
+}
+
+#[test]
+fn failed_game_creations_do_not_count_against_the_per_block_cap() {
+    new_test_ext().execute_with(|| {
+        MaxGamesCreatedPerBlock::set(&1);
+        FailGameCreation::set(&true);
+        for who in 1..=4 {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+        }
+        end_block();
+        assert!(created_games().is_empty());
+        // Both pairs were tried in this block; nothing was deferred.
+        assert_eq!(Matchmaker::live_size(), 0);
+        assert!(!take_events()
+            .iter()
+            .any(|e| matches!(e, RuntimeEvent::Matchmaker(Event::PairingDeferred { .. }))));
+    });
+}
+
+#[test]
+fn league_fixtures_follow_matchdays_and_award_points() {
+    use crate::league::{FixtureState, LeagueStatus, Standing};
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::create_league(SystemOrigin::root(), 5, 10));
+        for who in 1..=3 {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_league(SystemOrigin::signed(who), 0));
+        }
+        assert_noop!(
+            Matchmaker::join_league(SystemOrigin::signed(4), 0),
+            Error::<Test>::NoPresetHand
+        );
+        assert_noop!(
+            Matchmaker::start_league(SystemOrigin::signed(1), 0),
+            Error::<Test>::LeagueNotReady
+        );
+
+        frame_system::Pallet::<Test>::set_block_number(6);
+        assert_ok!(Matchmaker::start_league(SystemOrigin::signed(4), 0));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Matchmaker(Event::LeagueStarted {
+                league_id: 0,
+                matchdays: 3,
+                fixtures: 3
+            })
+        );
+        // Three players: one rests each matchday while the other two meet.
+        let pairs: Vec<_> = (0..3).map(|f| Matchmaker::fixture(0, f).unwrap().players).collect();
+        assert_eq!(pairs, vec![[2, 3], [1, 3], [1, 2]]);
+
+        // Matchday 0: player 2 beats player 3.
+        assert_noop!(
+            Matchmaker::play_fixture(SystemOrigin::signed(1), 0, 0),
+            Error::<Test>::NotInFixture
+        );
+        assert_ok!(Matchmaker::play_fixture(SystemOrigin::signed(3), 0, 0));
+        assert_eq!(created_games(), vec![(2, 3)]);
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&1, Some(&2));
+
+        // Matchday 1 (blocks 16..26) is not open yet and nobody plays it.
+        assert_noop!(
+            Matchmaker::play_fixture(SystemOrigin::signed(1), 0, 1),
+            Error::<Test>::OutsideMatchday
+        );
+        frame_system::Pallet::<Test>::set_block_number(20);
+        assert_noop!(
+            Matchmaker::expire_fixture(SystemOrigin::signed(4), 0, 1),
+            Error::<Test>::MatchdayNotOver
+        );
+        frame_system::Pallet::<Test>::set_block_number(26);
+        assert_ok!(Matchmaker::expire_fixture(SystemOrigin::signed(4), 0, 1));
+        assert_eq!(Matchmaker::fixture(0, 1).unwrap().state, FixtureState::Missed);
+
+        // Matchday 2: players 1 and 2 draw, which decides the last fixture.
+        assert_ok!(Matchmaker::play_fixture(SystemOrigin::signed(1), 0, 2));
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&2, None);
+        assert_eq!(last_event(), RuntimeEvent::Matchmaker(Event::LeagueFinished { league_id: 0 }));
+        assert_eq!(Matchmaker::league(0).unwrap().status, LeagueStatus::Finished);
+
+        let table = Matchmaker::standings(0);
+        let order: Vec<u64> = table.iter().map(|(who, _)| *who).collect();
+        assert_eq!(order, vec![2, 1, 3]);
+        assert_eq!(
+            table[0].1,
+            Standing {
+                played: 2,
+                won: 1,
+                drawn: 1,
+                lost: 0,
+                points: 4
+            }
+        );
+        assert_eq!((table[2].1.played, table[2].1.lost, table[2].1.points), (1, 1, 0));
+    });
+}
+
+#[test]
+fn silent_queued_players_are_dropped() {
+    new_test_ext().execute_with(|| {
+        QueueHeartbeatBlocks::set(&5);
+        set_has_hand(1, true);
+        assert_noop!(
+            Matchmaker::heartbeat(SystemOrigin::signed(1)),
+            Error::<Test>::NotQueued
+        );
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        end_block();
+        assert_eq!(Matchmaker::last_heartbeat(1), Some(1));
+
+        frame_system::Pallet::<Test>::set_block_number(5);
+        assert_ok!(Matchmaker::heartbeat(SystemOrigin::signed(1)));
+        <Matchmaker as OnInitialize<BlockNumberFor<Test>>>::on_initialize(10);
+        assert_eq!(Matchmaker::live_size(), 1);
+
+        <Matchmaker as OnInitialize<BlockNumberFor<Test>>>::on_initialize(11);
+        assert_eq!(Matchmaker::live_size(), 0);
+        assert!(!Matchmaker::is_queued(&1));
+        assert_eq!(Matchmaker::last_heartbeat(1), None);
+        assert_eq!(last_event(), RuntimeEvent::Matchmaker(Event::DroppedInactive { who: 1 }));
+    });
+}
+
+#[test]
+fn season_rewards_are_paid_in_batches_and_only_once() {
+    use frame_support::traits::Currency;
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::create_league(SystemOrigin::root(), 5, 10));
+        for who in [1, 2] {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_league(SystemOrigin::signed(who), 0));
+        }
+        frame_system::Pallet::<Test>::set_block_number(6);
+        assert_ok!(Matchmaker::start_league(SystemOrigin::signed(1), 0));
+        assert_noop!(
+            Matchmaker::distribute_season_rewards(SystemOrigin::root(), 0),
+            Error::<Test>::LeagueNotFinished
+        );
+        assert_ok!(Matchmaker::play_fixture(SystemOrigin::signed(1), 0, 0));
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&1, Some(&2));
+
+        let pot = Matchmaker::account_id();
+        let _ = Balances::deposit_creating(&pot, 1_000);
+        assert_ok!(Matchmaker::distribute_season_rewards(SystemOrigin::root(), 0));
+        // The champion is paid with the call, the runner-up in the next block.
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(GRANTED_CARDS.with(|v| v.borrow().clone()), vec![(2, [9, 9, 9, 9])]);
+        assert_eq!(Balances::free_balance(1), 0);
+        assert_eq!(Matchmaker::active_season_payout(), Some(0));
+
+        <Matchmaker as OnInitialize<BlockNumberFor<Test>>>::on_initialize(7);
+        assert_eq!(Balances::free_balance(1), 50);
+        assert_eq!(Matchmaker::active_season_payout(), None);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Matchmaker(Event::SeasonRewardsCompleted { league_id: 0 })
+        );
+
+        assert_noop!(
+            Matchmaker::distribute_season_rewards(SystemOrigin::root(), 0),
+            Error::<Test>::SeasonAlreadyDistributed
+        );
+        <Matchmaker as OnInitialize<BlockNumberFor<Test>>>::on_initialize(8);
+        assert_eq!(Balances::free_balance(pot), 850);
+    });
+}
+
+#[test]
+fn failed_season_rewards_are_skipped_without_stalling_the_payout() {
+    use frame_support::traits::Currency;
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::create_league(SystemOrigin::root(), 5, 10));
+        for who in [1, 2] {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_league(SystemOrigin::signed(who), 0));
+        }
+        frame_system::Pallet::<Test>::set_block_number(6);
+        assert_ok!(Matchmaker::start_league(SystemOrigin::signed(1), 0));
+        assert_ok!(Matchmaker::play_fixture(SystemOrigin::signed(1), 0, 0));
+        <Matchmaker as GameOutcomeObserver<u64, u32>>::on_game_finished(&1, Some(&2));
+
+        // Enough for the champion only: the runner-up's transfer fails.
+        let pot = Matchmaker::account_id();
+        let _ = Balances::deposit_creating(&pot, 120);
+        assert_ok!(Matchmaker::distribute_season_rewards(SystemOrigin::root(), 0));
+        assert_eq!(Balances::free_balance(2), 100);
+
+        <Matchmaker as OnInitialize<BlockNumberFor<Test>>>::on_initialize(7);
+        assert_eq!(Balances::free_balance(1), 0);
+        assert_eq!(Matchmaker::active_season_payout(), None);
+        let events = take_events();
+        assert!(events.contains(&RuntimeEvent::Matchmaker(Event::SeasonRewardFailed {
+            league_id: 0,
+            rank: 1,
+            who: 1,
+            amount: 50,
+        })));
+        assert_eq!(
+            events.last(),
+            Some(&RuntimeEvent::Matchmaker(Event::SeasonRewardsCompleted { league_id: 0 }))
+        );
+    });
+}
@@ -0,0 +1,45 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Read APIs of the card pallet. Clients should read through these rather than storage or
+//! the pallet's `#[pallet::getter]` helpers, which follow the storage layout and may change
+//! with it. Signatures here only change behind a bumped `#[api_version]`.
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_eterra_simple_tcg::{CardEdition, CardView, Element, Provenance, RarityType};
+
+sp_api::decl_runtime_apis! {
+    /// Cards and collections, in shapes that stay put when storage is reorganized.
+    pub trait CardsApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// A card, or `None` if it was never minted.
+        fn card(card_id: u32) -> Option<CardView<AccountId, Balance>>;
+        /// Ids of the cards `who` owns.
+        fn owned_cards(who: AccountId) -> Vec<u32>;
+    }
+
+    /// Marketplace pricing guidance, so the UI can show floors without an off-chain indexer.
+    pub trait CardMarketApi<Balance>
+    where
+        Balance: Codec,
+    {
+        /// Lowest recent sale price for cards of this rarity and edition.
+        fn floor_price(rarity: RarityType, edition: CardEdition) -> Option<Balance>;
+        /// Recent sale prices for cards of this rarity and edition, oldest first.
+        fn recent_sales(rarity: RarityType, edition: CardEdition) -> Vec<Balance>;
+        /// Open listings as (card id, price), filtered by price range, rarity and edition
+        /// (`None` matches any). Pages with `offset`; at most 100 entries per call.
+        fn listings_filtered(
+            min_price: Option<Balance>,
+            max_price: Option<Balance>,
+            rarity: Option<RarityType>,
+            edition: Option<CardEdition>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(u32, Balance)>;
+    }
+}
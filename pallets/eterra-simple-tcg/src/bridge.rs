@@ -0,0 +1,36 @@
+//! Card portability between this chain and a future parachain deployment.
+//!
+//! Exporting locks a card in place and publishes a `CardAttestation`; importing verifies the
+//! attestation came from the configured bridge origin and unlocks the card again. Once XCM is
+//! wired in, the attestation becomes the payload of the transfer message and `BridgeOrigin`
+//! an XCM origin for the sibling chain.
+
+use crate::pallet::{CardEdition, CardId, RarityType};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Remote chain identifier (a para id once bridged over XCM).
+pub type ChainId = u32;
+
+/// Everything a remote chain needs to recreate an exported card.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct CardAttestation<AccountId> {
+    pub card_id: CardId,
+    pub owner: AccountId,
+    pub destination: ChainId,
+    /// Unique per export, so a stale attestation cannot unlock a card exported again later.
+    pub nonce: u64,
+    /// North, east, south, west.
+    pub stats: [u8; 4],
+    pub edition: CardEdition,
+    pub rarity: RarityType,
+    pub name: BoundedVec<u8, ConstU32<64>>,
+}
+
+/// Lock held on a card while it lives on another chain.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct ExportLock {
+    pub destination: ChainId,
+    pub nonce: u64,
+}
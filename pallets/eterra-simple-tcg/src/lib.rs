@@ -6,26 +6,84 @@ extern crate alloc;
 
 pub use self::pallet::*;
 
+pub mod bridge;
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
 use frame_support::traits::{Currency, ExistenceRequirement};
-use frame_support::{pallet_prelude::*, traits::Get, BoundedVec};
+use frame_support::{pallet_prelude::*, traits::Get, BoundedVec, PalletId};
 // ===== New: utilities for in-pallet game logic =====
 
 const GRID_DIM: usize = 4;
 const BOARD_SIZE: usize = GRID_DIM * GRID_DIM; // 16
 
 use core::array;
+pub use eterra_core_types::element::Element;
+pub use eterra_core_types::geometry::Direction;
 use frame_support::pallet_prelude::ConstU32;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{Hash, SaturatedConversion};
+use sp_runtime::traits::{AccountIdConversion, Hash, SaturatedConversion, Saturating, Zero};
 use sp_std::prelude::*;
 
+/// Notified after a card is minted, with the owner's card count afterwards,
+/// e.g. to track onboarding once a starter deck is complete.
+pub trait MintObserver<AccountId> {
+    fn on_card_minted(owner: &AccountId, owned_cards: u32);
+}
+
+impl<AccountId> MintObserver<AccountId> for () {
+    fn on_card_minted(_owner: &AccountId, _owned_cards: u32) {}
+}
+
+/// Notified after the ranks of an existing card change, e.g. through `level_up_card`, so
+/// scores derived from them can be refreshed.
+pub trait CardObserver<AccountId> {
+    fn on_card_changed(owner: &AccountId, card_id: u32);
+}
+
+impl<AccountId> CardObserver<AccountId> for () {
+    fn on_card_changed(_owner: &AccountId, _card_id: u32) {}
+}
+
+/// Shortest name an owner may give a card.
+pub const MIN_CARD_NAME_LEN: usize = 3;
+/// Longest card name, matching `CardInfo::name`.
+pub const MAX_CARD_NAME_LEN: u32 = 64;
+/// Terms `BannedNameTerms` may hold.
+pub const MAX_BANNED_TERMS: u32 = 64;
+/// Longest banned term, in bytes.
+pub const MAX_BANNED_TERM_LEN: u32 = 16;
+
+/// Whether `name` is a well-formed card name: ASCII letters, digits, spaces and `-'.`, with
+/// no leading, trailing or doubled spaces, and at least `MIN_CARD_NAME_LEN` bytes long.
+pub fn is_valid_card_name(name: &[u8]) -> bool {
+    let allowed = |b: &u8| b.is_ascii_alphanumeric() || matches!(b, b' ' | b'-' | b'\'' | b'.');
+    name.len() >= MIN_CARD_NAME_LEN
+        && name.iter().all(allowed)
+        && name.first() != Some(&b' ')
+        && name.last() != Some(&b' ')
+        && !name.windows(2).any(|pair| pair == b"  ")
+}
+
+/// Whether `name` contains any of the banned `terms`. Case and everything but letters and
+/// digits are ignored, so spacing or punctuating a term out does not get it past the check.
+pub fn contains_banned_term<Term: AsRef<[u8]>>(name: &[u8], terms: &[Term]) -> bool {
+    let folded: Vec<u8> = name
+        .iter()
+        .filter(|b| b.is_ascii_alphanumeric())
+        .map(u8::to_ascii_lowercase)
+        .collect();
+    terms.iter().map(AsRef::as_ref).any(|term| {
+        !term.is_empty() && folded.windows(term.len()).any(|w| w.eq_ignore_ascii_case(term))
+    })
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -42,7 +100,11 @@ pub mod pallet {
     // Max number of cards we track per owner (bounded index)
     pub type OwnedLimit = ConstU32<600>;
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    /// v1 -> v2: `CardPrices` became a counted map.
+    /// v2 -> v3: `CardInfo` gained `provenance`.
+    /// v3 -> v4: listings are indexed by rarity in `ListingsByRarity`.
+    /// v4 -> v5: `CardInfo` gained `last_transfer_block`.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
     /// Which edition a card belongs to (extensible for future sets).
     #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
@@ -72,6 +134,61 @@ pub mod pallet {
             RarityType::Common
         }
     }
+    impl RarityType {
+        /// Position of this rarity from `Common` (0) to `Legendary` (4).
+        pub fn index(&self) -> usize {
+            match self {
+                RarityType::Common => 0,
+                RarityType::Uncommon => 1,
+                RarityType::Rare => 2,
+                RarityType::Epic => 3,
+                RarityType::Legendary => 4,
+            }
+        }
+    }
+
+    /// Percent of the curve price charged per rarity until pricing is set on-chain.
+    pub const DEFAULT_RARITY_FEE_PERCENT: [u32; 5] = [100, 200, 400, 800, 1600];
+
+    /// Highest rank `level_up_card` raises a side to, per rarity from `Common` to `Legendary`.
+    /// Sides minted above the cap keep their rank but cannot be levelled further.
+    pub const MAX_LEVELED_RANK: [u8; 5] = [5, 6, 7, 8, 9];
+
+    /// Most listings `listings_filtered` returns per call.
+    pub const MAX_LISTINGS_PAGE: u32 = 100;
+
+    /// How a card entered the game. Cards handed out for free are soulbound: they stay
+    /// with their first owner and can never be transferred, listed, sold or exported.
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default)]
+    pub enum Provenance {
+        /// Paid for through a mint.
+        #[default]
+        Minted,
+        /// Part of a genesis starter deck, or a `mint_card` toward the caller's first
+        /// `StarterDeckSize` cards. Those mints are what faucet drips pay for during
+        /// onboarding, so sybil accounts cannot turn them into marketplace stock.
+        Starter,
+        /// Granted by another pallet as a reward.
+        Reward,
+    }
+    impl Provenance {
+        pub fn is_soulbound(self) -> bool {
+            !matches!(self, Provenance::Minted)
+        }
+    }
+
+    /// On-chain mint pricing. A card of rarity `r` costs
+    /// `(base + step * (supply / step_cards)) * rarity_percent[r.index()] / 100`,
+    /// where `supply` is the number of cards ever created.
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct MintPricing<Balance> {
+        pub base: Balance,
+        /// Added for every `step_cards` cards in existence.
+        pub step: Balance,
+        /// Supply per price step; 0 keeps the price flat.
+        pub step_cards: u32,
+        pub rarity_percent: [u32; 5],
+    }
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -93,13 +210,72 @@ pub mod pallet {
         /// Currency used to charge the mint fee.
         type Currency: Currency<Self::AccountId>;
 
-        /// Fixed fee to mint a new card (e.g., 100 tokens).
+        /// Fee to mint a Common card while no `MintPricing` is set on-chain (e.g., 100 tokens).
         #[pallet::constant]
         type MintFee: Get<<Self::Currency as Currency<Self::AccountId>>::Balance>;
 
-        /// Faucet account that receives the mint fee.
+        /// Origin allowed to change the mint pricing curve.
+        type PricingAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Id the account collecting mint and rename fees is derived from, see
+        /// `Pallet::account_id`.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Origin allowed to import cards returning from another chain (the bridge/XCM origin).
+        type BridgeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of recent sale prices kept per (rarity, edition) for floor tracking.
+        #[pallet::constant]
+        type PriceHistoryLen: Get<u32>;
+
+        /// Hook notified after each `mint_card`.
+        type OnMint: crate::MintObserver<Self::AccountId>;
+
+        /// Hook notified after a card's ranks change.
+        type OnCardChanged: crate::CardObserver<Self::AccountId>;
+
+        /// Maximum number of cards an account can pin to its profile showcase.
+        #[pallet::constant]
+        type MaxShowcase: Get<u32>;
+
+        /// Blocks between requesting `unvault_card` and the card becoming usable again.
+        #[pallet::constant]
+        type UnvaultDelay: Get<BlockNumberFor<Self>>;
+
+        /// Lowest price a card may be listed at, so dust listings cannot fill owners' bounded
+        /// listing lists or the sale history with meaningless trades.
+        #[pallet::constant]
+        type MinListingPrice: Get<BalanceOf<Self>>;
+
+        /// Listing prices must be a multiple of this step (0 or 1 accepts any price).
+        #[pallet::constant]
+        type PriceTick: Get<BalanceOf<Self>>;
+
+        /// Fee to rename a card that was already renamed; the first custom name is free.
         #[pallet::constant]
-        type FaucetAccount: Get<Self::AccountId>;
+        type RenameFee: Get<BalanceOf<Self>>;
+
+        /// Past names kept per card; the oldest is dropped once this is reached.
+        #[pallet::constant]
+        type MaxRenameHistory: Get<u32>;
+
+        /// Origin allowed to maintain the banned name terms.
+        type NameModeratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks after changing owner during which a card may not enter ranked hands, so
+        /// one strong hand cannot be passed around between accounts' ranked games.
+        #[pallet::constant]
+        type TradeCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Card XP spent by each `level_up_card`.
+        #[pallet::constant]
+        type XpPerLevel: Get<u32>;
+
+        /// Cards an account owns once its starter deck is complete; `mint_card` hands out
+        /// soulbound `Provenance::Starter` cards until then.
+        #[pallet::constant]
+        type StarterDeckSize: Get<u32>;
     }
 
     // ------------------
@@ -118,7 +294,7 @@ pub mod pallet {
         pub slot_values: Option<[u8; 4]>,
 
         /// Display name for the card (bounded).
-        pub name: BoundedVec<u8, ConstU32<64>>,
+        pub name: BoundedVec<u8, ConstU32<MAX_CARD_NAME_LEN>>,
         /// Directional values used by the front end.
         pub north: u8,
         pub east: u8,
@@ -135,6 +311,51 @@ pub mod pallet {
         pub edition: CardEdition,
         /// New: rarity classification.
         pub rarity: RarityType,
+        /// How the card entered the game; soulbound cards never change owner.
+        pub provenance: Provenance,
+        /// Block the card last changed owner at, `None` if it never did.
+        pub last_transfer_block: Option<BlockNumberFor<T>>,
+        /// Element of the card, boosting it on matching board cells in elemental games.
+        pub element: Option<Element>,
+    }
+
+    /// A card as returned by `Pallet::card_view`. Its layout is part of the runtime API and
+    /// only grows by appending fields, unlike `CardInfo`, which follows the storage layout.
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Debug)]
+    pub struct CardView<AccountId, Balance> {
+        pub owner: AccountId,
+        pub name: Vec<u8>,
+        pub north: u8,
+        pub east: u8,
+        pub south: u8,
+        pub west: u8,
+        pub edition: CardEdition,
+        pub rarity: RarityType,
+        pub provenance: Provenance,
+        /// Asking price while the card is listed on the marketplace.
+        pub listing_price: Option<Balance>,
+        /// Whether the card is escrowed, exported or vaulted and so cannot move right now.
+        pub locked: bool,
+        pub element: Option<Element>,
+        /// Unspent experience, see `CardXp`.
+        pub xp: u32,
+    }
+
+    /// Lock on a vaulted card.
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum VaultLock<BlockNumber> {
+        /// Held until the owner asks to unvault.
+        Locked,
+        /// Unvault requested; the owner can complete it from `ready_at`.
+        Unlocking { ready_at: BlockNumber },
+    }
+
+    /// A name a card carried before it was renamed.
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct RenameRecord<AccountId, BlockNumber> {
+        pub previous: BoundedVec<u8, ConstU32<MAX_CARD_NAME_LEN>>,
+        pub renamed_by: AccountId,
+        pub at: BlockNumber,
     }
 
     impl<T: Config> CardInfo<T> {
@@ -163,11 +384,24 @@ pub mod pallet {
     pub type OwnedCards<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<u32, OwnedLimit>, ValueQuery>;
 
-    /// A map of cards that are up for sale: card_id => price.
+    /// A map of cards that are up for sale: card_id => price. Counted, so the number of
+    /// open listings is available without iterating.
     #[pallet::storage]
     #[pallet::getter(fn card_prices)]
     pub type CardPrices<T: Config> =
-        StorageMap<_, Blake2_128Concat, CardId, BalanceOf<T>, OptionQuery>;
+        CountedStorageMap<_, Blake2_128Concat, CardId, BalanceOf<T>, OptionQuery>;
+
+    /// Cards staked on a game. They stay owned by the staker but cannot be moved, sold,
+    /// exported or vaulted until the game pallet settles or releases them.
+    #[pallet::storage]
+    #[pallet::getter(fn escrowed)]
+    pub type EscrowedCards<T: Config> = StorageMap<_, Blake2_128Concat, CardId, (), OptionQuery>;
+
+    /// Listed cards by rarity, so marketplace queries only walk the rarities they ask for.
+    /// Kept in step with `CardPrices`.
+    #[pallet::storage]
+    pub type ListingsByRarity<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, RarityType, Twox64Concat, CardId, (), OptionQuery>;
 
     /// Optional: index of cards a given owner has listed (bounded by OwnedLimit for simplicity).
     #[pallet::storage]
@@ -175,6 +409,107 @@ pub mod pallet {
     pub type ListedByOwner<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<CardId, OwnedLimit>, ValueQuery>;
 
+    /// Cards currently exported to another chain; they cannot be moved or sold here.
+    #[pallet::storage]
+    #[pallet::getter(fn exported)]
+    pub type ExportedCards<T: Config> =
+        StorageMap<_, Blake2_128Concat, CardId, crate::bridge::ExportLock, OptionQuery>;
+
+    /// Cards in cold storage: they cannot be moved, sold, exported or used in hands until a
+    /// time-delayed unvault completes.
+    #[pallet::storage]
+    #[pallet::getter(fn vaulted)]
+    pub type VaultedCards<T: Config> =
+        StorageMap<_, Blake2_128Concat, CardId, VaultLock<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Mint pricing curve; when unset, `MintFee` is the flat Common price and the other
+    /// rarities are scaled by `DEFAULT_RARITY_FEE_PERCENT`.
+    #[pallet::storage]
+    #[pallet::getter(fn mint_pricing)]
+    pub type MintPricingOf<T: Config> = StorageValue<_, MintPricing<BalanceOf<T>>, OptionQuery>;
+
+    /// Nonce for the next export.
+    #[pallet::storage]
+    pub type NextExportNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Cards an account displays on its profile, in the chosen order.
+    /// A card leaves the showcase when it changes owner.
+    #[pallet::storage]
+    #[pallet::getter(fn showcase)]
+    pub type Showcase<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<CardId, T::MaxShowcase>,
+        ValueQuery,
+    >;
+
+    /// Most recent sale prices per (rarity, edition), oldest first; the oldest entry is dropped
+    /// once `PriceHistoryLen` is reached.
+    #[pallet::storage]
+    #[pallet::getter(fn recent_sales)]
+    pub type RecentSales<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RarityType,
+        Blake2_128Concat,
+        CardEdition,
+        BoundedVec<BalanceOf<T>, T::PriceHistoryLen>,
+        ValueQuery,
+    >;
+
+    /// Names each card carried before it was renamed, oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn rename_history)]
+    pub type RenameHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        CardId,
+        BoundedVec<RenameRecord<T::AccountId, BlockNumberFor<T>>, T::MaxRenameHistory>,
+        ValueQuery,
+    >;
+
+    /// Terms no card name may contain, lowercase; see `contains_banned_term`.
+    #[pallet::storage]
+    #[pallet::getter(fn banned_name_terms)]
+    pub type BannedNameTerms<T: Config> = StorageValue<
+        _,
+        BoundedVec<BoundedVec<u8, ConstU32<MAX_BANNED_TERM_LEN>>, ConstU32<MAX_BANNED_TERMS>>,
+        ValueQuery,
+    >;
+
+    /// Experience each card earned in won games and has not spent on `level_up_card` yet.
+    /// Stays with the card when it changes owner.
+    #[pallet::storage]
+    #[pallet::getter(fn card_xp)]
+    pub type CardXp<T: Config> = StorageMap<_, Blake2_128Concat, CardId, u32, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Cards minted fee-free at genesis as (owner, [north, east, south, west] per card).
+        /// Ids are assigned in order, starting from 0.
+        pub starter_cards: Vec<(T::AccountId, Vec<[u8; 4]>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> frame_support::traits::BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (owner, cards) in self.starter_cards.iter() {
+                for edges in cards.iter() {
+                    Pallet::<T>::insert_card(
+                        owner,
+                        *edges,
+                        RarityType::Common,
+                        Provenance::Starter,
+                        None,
+                    )
+                    .expect("genesis starter cards must fit OwnedLimit");
+                }
+            }
+        }
+    }
+
     // ------------------
     // Events
     // ------------------
@@ -184,6 +519,15 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// A card was minted for `player` with ID `card_id`.
         CardMinted { player: T::AccountId, card_id: u32 },
+        /// A card of a chosen rarity was minted for `player`, who paid `fee`.
+        RareCardMinted {
+            player: T::AccountId,
+            card_id: u32,
+            rarity: RarityType,
+            fee: BalanceOf<T>,
+        },
+        /// The mint pricing curve was replaced.
+        MintPricingSet { pricing: MintPricing<BalanceOf<T>> },
         /// A card was transferred from `from` to `to`.
         CardTransferred {
             from: T::AccountId,
@@ -205,6 +549,43 @@ pub mod pallet {
             card_id: u32,
             price: BalanceOf<T>,
         },
+        /// A card was locked for export; relayers forward `attestation` to `destination`.
+        CardExported {
+            card_id: u32,
+            attestation: crate::bridge::CardAttestation<T::AccountId>,
+            attestation_hash: T::Hash,
+        },
+        /// A card came back from another chain and was unlocked for `owner`.
+        CardImported { card_id: u32, owner: T::AccountId },
+        /// The profile showcase of `who` was replaced.
+        ShowcaseSet { who: T::AccountId, card_ids: Vec<CardId> },
+        /// A card was put in cold storage (or a pending unvault was cancelled).
+        CardVaulted { owner: T::AccountId, card_id: CardId },
+        /// The owner asked to unvault a card; it can be completed from `ready_at`.
+        UnvaultRequested {
+            card_id: CardId,
+            ready_at: BlockNumberFor<T>,
+        },
+        /// A card left cold storage and is usable again.
+        CardUnvaulted { owner: T::AccountId, card_id: CardId },
+        /// The owner gave a card a new name, paying `RenameFee` if `charged`.
+        CardRenamed {
+            owner: T::AccountId,
+            card_id: CardId,
+            name: Vec<u8>,
+            charged: bool,
+        },
+        /// The banned name terms were replaced.
+        BannedNameTermsSet { count: u32 },
+        /// A card played in a won game earned `xp` experience.
+        CardXpGained { card_id: CardId, xp: u32 },
+        /// The owner spent `XpPerLevel` experience to raise one side of a card to `rank`.
+        CardLeveledUp {
+            owner: T::AccountId,
+            card_id: CardId,
+            side: Direction,
+            rank: u8,
+        },
     }
 
     // ------------------
@@ -224,6 +605,76 @@ pub mod pallet {
         NotForSale,
         /// Only the current owner may list/unlist.
         NotOwner,
+        /// The card is locked on another chain.
+        CardIsExported,
+        /// The card is not exported, or the attestation does not match its export lock.
+        InvalidAttestation,
+        /// More cards than `MaxShowcase` were given.
+        ShowcaseTooLong,
+        /// The same card appears twice in the showcase.
+        DuplicateShowcaseCard,
+        /// The card is in cold storage.
+        CardIsVaulted,
+        /// The card is already vaulted and not being unvaulted.
+        AlreadyVaulted,
+        /// The card is not in cold storage.
+        NotVaulted,
+        /// The unvault delay has not passed yet.
+        UnvaultPending,
+        /// Soulbound cards cannot be transferred, listed, sold or exported.
+        CardIsSoulbound,
+        /// The card is staked on a game and held in escrow.
+        CardIsEscrowed,
+        /// The price is below `MinListingPrice`.
+        PriceTooLow,
+        /// The price is not a multiple of `PriceTick`.
+        PriceOffTick,
+        /// The name is too short or too long, or uses characters or spacing that are not allowed.
+        InvalidCardName,
+        /// The name contains a banned term.
+        CardNameNotAllowed,
+        /// The caller cannot pay `RenameFee`.
+        InsufficientBalanceForRename,
+        /// More than `MAX_BANNED_TERMS` terms, or a term longer than `MAX_BANNED_TERM_LEN`.
+        InvalidBannedTerms,
+        /// The card has less than `XpPerLevel` experience.
+        NotEnoughXp,
+        /// The side already reached `MAX_LEVELED_RANK` for the card's rarity.
+        RankAtRarityCap,
+        /// The mint fee rose above the caller's `max_fee`.
+        MintFeeAboveMax,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = StorageVersion::get::<Pallet<T>>();
+            if on_chain >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            if on_chain < 2 {
+                // Listings made before the map was counted are not in its counter yet.
+                let listings = CardPrices::<T>::initialize_counter();
+                weight = weight
+                    .saturating_add(T::DbWeight::get().reads_writes(listings as u64, 1));
+            }
+            if on_chain < 3 {
+                weight = weight.saturating_add(crate::migrations::v3::migrate::<T>());
+            }
+            if on_chain < 4 {
+                weight = weight.saturating_add(crate::migrations::v4::migrate::<T>());
+            }
+            // The v3 and v5 migrations already write the current `CardInfo` layout.
+            if (3..5).contains(&on_chain) {
+                weight = weight.saturating_add(crate::migrations::v5::migrate::<T>());
+            }
+            if on_chain == 5 {
+                weight = weight.saturating_add(crate::migrations::v6::migrate::<T>());
+            }
+            STORAGE_VERSION.put::<Pallet<T>>();
+            weight
+        }
     }
 
     // ------------------
@@ -232,12 +683,21 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Mint a single card for the caller.
+        /// Mint a single card for the caller. Cards minted toward the starter deck are
+        /// soulbound, see `Provenance::Starter`.
         #[pallet::call_index(0)]
         #[pallet::weight(10_000)]
         pub fn mint_card(origin: OriginFor<T>) -> DispatchResult {
             let player = ensure_signed(origin)?;
-            let card_id = Self::create_new_card(&player)?;
+            let owned = OwnedCards::<T>::decode_len(&player).unwrap_or(0) as u32;
+            let provenance = if owned < T::StarterDeckSize::get() {
+                Provenance::Starter
+            } else {
+                Provenance::Minted
+            };
+            let card_id = Self::create_new_card(&player, RarityType::Common, provenance)?;
+            let owned = OwnedCards::<T>::decode_len(&player).unwrap_or(0) as u32;
+            T::OnMint::on_card_minted(&player, owned);
             Self::deposit_event(Event::CardMinted { player, card_id });
             Ok(())
         }
@@ -283,12 +743,25 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             // Verify ownership
-            let is_owner = Cards::<T>::get(card_id)
-                .map(|c| c.owner == who)
-                .ok_or(Error::<T>::NoSuchCard)?;
-            ensure!(is_owner, Error::<T>::NotOwner);
+            let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(card.owner == who, Error::<T>::NotOwner);
+            ensure!(!card.provenance.is_soulbound(), Error::<T>::CardIsSoulbound);
+            ensure!(
+                !ExportedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsExported
+            );
+            ensure!(
+                !VaultedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsVaulted
+            );
+            ensure!(
+                !EscrowedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsEscrowed
+            );
+            Self::ensure_valid_price(price)?;
 
             CardPrices::<T>::insert(card_id, price);
+            ListingsByRarity::<T>::insert(&card.rarity, card_id, ());
             ListedByOwner::<T>::try_mutate(&who, |v| -> DispatchResult {
                 if !v.iter().any(|&id| id == card_id) {
                     if v.len() as u32 >= <OwnedLimit as frame_support::traits::Get<u32>>::get() {
@@ -336,9 +809,9 @@ pub mod pallet {
 
             // Get price and current owner
             let price = CardPrices::<T>::get(card_id).ok_or(Error::<T>::NotForSale)?;
-            let seller = Cards::<T>::get(card_id)
-                .map(|c| c.owner)
-                .ok_or(Error::<T>::NoSuchCard)?;
+            let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(!card.provenance.is_soulbound(), Error::<T>::CardIsSoulbound);
+            let seller = card.owner;
 
             // Prevent self-buy (optional)
             ensure!(seller != buyer, Error::<T>::NotOwner);
@@ -356,6 +829,7 @@ pub mod pallet {
 
             // Transfer ownership seller -> buyer
             Self::do_transfer(&seller, &buyer, card_id)?;
+            Self::record_sale(card.rarity, card.edition, price);
 
             Self::deposit_event(Event::CardBought {
                 buyer,
@@ -365,21 +839,378 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Lock a card and publish an attestation describing it for `destination`.
+        /// The card stays owned by the caller but cannot be moved, sold or played here
+        /// until it is imported back.
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn export_card(
+            origin: OriginFor<T>,
+            card_id: CardId,
+            destination: crate::bridge::ChainId,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(card.owner == owner, Error::<T>::NotOwner);
+            ensure!(!card.provenance.is_soulbound(), Error::<T>::CardIsSoulbound);
+            ensure!(
+                !ExportedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsExported
+            );
+            ensure!(
+                !VaultedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsVaulted
+            );
+            ensure!(
+                !EscrowedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsEscrowed
+            );
+
+            if CardPrices::<T>::contains_key(card_id) {
+                Self::unlist(card_id, &owner);
+            }
+
+            let nonce = NextExportNonce::<T>::mutate(|n| {
+                let current = *n;
+                *n = n.wrapping_add(1);
+                current
+            });
+            ExportedCards::<T>::insert(card_id, crate::bridge::ExportLock { destination, nonce });
+
+            let attestation = crate::bridge::CardAttestation {
+                card_id,
+                owner,
+                destination,
+                nonce,
+                stats: [card.north, card.east, card.south, card.west],
+                edition: card.edition,
+                rarity: card.rarity,
+                name: card.name,
+            };
+            let attestation_hash = T::Hashing::hash_of(&attestation);
+            Self::deposit_event(Event::CardExported {
+                card_id,
+                attestation,
+                attestation_hash,
+            });
+            Ok(())
+        }
+
+        /// (Bridge origin) Unlock a card returning from another chain. The attestation must
+        /// match the card's export lock; its `owner` (who may have changed remotely) receives it.
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)]
+        pub fn import_card(
+            origin: OriginFor<T>,
+            attestation: crate::bridge::CardAttestation<T::AccountId>,
+        ) -> DispatchResult {
+            T::BridgeOrigin::ensure_origin(origin)?;
+            let card_id = attestation.card_id;
+            let lock = ExportedCards::<T>::get(card_id).ok_or(Error::<T>::InvalidAttestation)?;
+            ensure!(
+                lock.nonce == attestation.nonce && lock.destination == attestation.destination,
+                Error::<T>::InvalidAttestation
+            );
+            let current = Cards::<T>::get(card_id)
+                .map(|c| c.owner)
+                .ok_or(Error::<T>::NoSuchCard)?;
+
+            ExportedCards::<T>::remove(card_id);
+            if current != attestation.owner {
+                Self::do_transfer(&current, &attestation.owner, card_id)?;
+            }
+            Self::deposit_event(Event::CardImported {
+                card_id,
+                owner: attestation.owner,
+            });
+            Ok(())
+        }
+
+        /// Replace the caller's profile showcase with `card_ids` (display order kept).
+        /// Every card must be owned by the caller; an empty list clears the showcase.
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn set_showcase(origin: OriginFor<T>, card_ids: Vec<CardId>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let bounded: BoundedVec<CardId, T::MaxShowcase> = card_ids
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::ShowcaseTooLong)?;
+            for (i, card_id) in bounded.iter().enumerate() {
+                ensure!(
+                    !bounded[..i].contains(card_id),
+                    Error::<T>::DuplicateShowcaseCard
+                );
+                let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+                ensure!(card.owner == who, Error::<T>::NotCardOwner);
+            }
+
+            if bounded.is_empty() {
+                Showcase::<T>::remove(&who);
+            } else {
+                Showcase::<T>::insert(&who, bounded);
+            }
+            Self::deposit_event(Event::ShowcaseSet { who, card_ids });
+            Ok(())
+        }
+
+        /// Put a card in cold storage: it is unlisted and cannot be transferred, sold,
+        /// exported or put in a hand until `unvault_card` completes. Vaulting a card that is
+        /// being unvaulted cancels the pending unvault, e.g. if a hot key was compromised.
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)]
+        pub fn vault_card(origin: OriginFor<T>, card_id: CardId) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(card.owner == owner, Error::<T>::NotCardOwner);
+            ensure!(
+                !ExportedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsExported
+            );
+            ensure!(
+                VaultedCards::<T>::get(card_id) != Some(VaultLock::Locked),
+                Error::<T>::AlreadyVaulted
+            );
+            ensure!(
+                !EscrowedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsEscrowed
+            );
+
+            if CardPrices::<T>::contains_key(card_id) {
+                Self::unlist(card_id, &owner);
+            }
+            VaultedCards::<T>::insert(card_id, VaultLock::Locked);
+            Self::deposit_event(Event::CardVaulted { owner, card_id });
+            Ok(())
+        }
+
+        /// Take a card out of cold storage in two steps: the first call starts the
+        /// `UnvaultDelay` timer, a call once it has passed releases the card.
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)]
+        pub fn unvault_card(origin: OriginFor<T>, card_id: CardId) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(card.owner == owner, Error::<T>::NotCardOwner);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            match VaultedCards::<T>::get(card_id).ok_or(Error::<T>::NotVaulted)? {
+                VaultLock::Locked => {
+                    let ready_at = now.saturating_add(T::UnvaultDelay::get());
+                    VaultedCards::<T>::insert(card_id, VaultLock::Unlocking { ready_at });
+                    Self::deposit_event(Event::UnvaultRequested { card_id, ready_at });
+                }
+                VaultLock::Unlocking { ready_at } => {
+                    ensure!(now >= ready_at, Error::<T>::UnvaultPending);
+                    VaultedCards::<T>::remove(card_id);
+                    Self::deposit_event(Event::CardUnvaulted { owner, card_id });
+                }
+            }
+            Ok(())
+        }
+
+        /// Mint a single card of the given rarity for the caller, paying `mint_fee(rarity)`.
+        /// Fails if the fee, which moves with supply and pricing updates, exceeds `max_fee`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn mint_card_of_rarity(
+            origin: OriginFor<T>,
+            rarity: RarityType,
+            max_fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            let fee = Self::mint_fee(&rarity);
+            ensure!(fee <= max_fee, Error::<T>::MintFeeAboveMax);
+            let card_id = Self::create_new_card(&player, rarity.clone(), Provenance::Minted)?;
+            let owned = OwnedCards::<T>::decode_len(&player).unwrap_or(0) as u32;
+            T::OnMint::on_card_minted(&player, owned);
+            Self::deposit_event(Event::RareCardMinted {
+                player,
+                card_id,
+                rarity,
+                fee,
+            });
+            Ok(())
+        }
+
+        /// (Pricing admin) Replace the mint pricing curve.
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn set_mint_pricing(
+            origin: OriginFor<T>,
+            pricing: MintPricing<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::PricingAdminOrigin::ensure_origin(origin)?;
+            MintPricingOf::<T>::put(pricing.clone());
+            Self::deposit_event(Event::MintPricingSet { pricing });
+            Ok(())
+        }
+
+        /// Replace a card's name. The first custom name is free; renaming again costs
+        /// `RenameFee`. The old name is kept in `RenameHistory`.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 4))]
+        pub fn rename_card(origin: OriginFor<T>, card_id: CardId, name: Vec<u8>) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let mut card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(card.owner == owner, Error::<T>::NotCardOwner);
+            ensure!(
+                !ExportedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsExported
+            );
+            ensure!(is_valid_card_name(&name), Error::<T>::InvalidCardName);
+            let bounded: BoundedVec<u8, ConstU32<MAX_CARD_NAME_LEN>> =
+                name.clone().try_into().map_err(|_| Error::<T>::InvalidCardName)?;
+            ensure!(
+                !contains_banned_term(&name, &BannedNameTerms::<T>::get()),
+                Error::<T>::CardNameNotAllowed
+            );
+
+            let renamed_before = RenameHistory::<T>::decode_len(card_id).unwrap_or(0) > 0;
+            if renamed_before {
+                T::Currency::transfer(
+                    &owner,
+                    &Self::account_id(),
+                    T::RenameFee::get(),
+                    ExistenceRequirement::KeepAlive,
+                )
+                .map_err(|_| Error::<T>::InsufficientBalanceForRename)?;
+            }
+
+            let record = RenameRecord {
+                previous: core::mem::replace(&mut card.name, bounded),
+                renamed_by: owner.clone(),
+                at: <frame_system::Pallet<T>>::block_number(),
+            };
+            RenameHistory::<T>::mutate(card_id, |history| {
+                if history.is_full() && !history.is_empty() {
+                    history.remove(0);
+                }
+                let _ = history.try_push(record);
+            });
+            Cards::<T>::insert(card_id, card);
+            Self::deposit_event(Event::CardRenamed {
+                owner,
+                card_id,
+                name,
+                charged: renamed_before,
+            });
+            Ok(())
+        }
+
+        /// (Name moderator) Replace the terms no card name may contain. Existing names are
+        /// not touched.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_banned_name_terms(origin: OriginFor<T>, terms: Vec<Vec<u8>>) -> DispatchResult {
+            T::NameModeratorOrigin::ensure_origin(origin)?;
+            let terms = terms
+                .into_iter()
+                .map(|term| {
+                    BoundedVec::<u8, ConstU32<MAX_BANNED_TERM_LEN>>::try_from(
+                        term.to_ascii_lowercase(),
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| Error::<T>::InvalidBannedTerms)?;
+            let terms: BoundedVec<_, ConstU32<MAX_BANNED_TERMS>> =
+                terms.try_into().map_err(|_| Error::<T>::InvalidBannedTerms)?;
+            let count = terms.len() as u32;
+            BannedNameTerms::<T>::put(terms);
+            Self::deposit_event(Event::BannedNameTermsSet { count });
+            Ok(())
+        }
+
+        /// Spend `XpPerLevel` of a card's experience to raise its rank on `side` by one, up to
+        /// the `MAX_LEVELED_RANK` of its rarity. Cards staked on a game cannot be levelled.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(11, 3))]
+        pub fn level_up_card(
+            origin: OriginFor<T>,
+            card_id: CardId,
+            side: Direction,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let mut card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(card.owner == owner, Error::<T>::NotCardOwner);
+            ensure!(
+                !ExportedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsExported
+            );
+            ensure!(
+                !EscrowedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsEscrowed
+            );
+            let xp = CardXp::<T>::get(card_id);
+            let cost = T::XpPerLevel::get();
+            ensure!(xp >= cost, Error::<T>::NotEnoughXp);
+
+            let cap = MAX_LEVELED_RANK[card.rarity.index()];
+            let (rank, slot) = match side {
+                Direction::Top => (&mut card.north, 0),
+                Direction::Right => (&mut card.east, 1),
+                Direction::Bottom => (&mut card.south, 2),
+                Direction::Left => (&mut card.west, 3),
+            };
+            ensure!(*rank < cap, Error::<T>::RankAtRarityCap);
+            *rank += 1;
+            let rank = *rank;
+            if let Some(values) = card.slot_values.as_mut() {
+                values[slot] = rank;
+            }
+
+            CardXp::<T>::insert(card_id, xp - cost);
+            Cards::<T>::insert(card_id, card);
+            T::OnCardChanged::on_card_changed(&owner, card_id);
+            Self::deposit_event(Event::CardLeveledUp {
+                owner,
+                card_id,
+                side,
+                rank,
+            });
+            Ok(())
+        }
     }
 
     // ------------------
     // Pallet Internals (helpers; not dispatchables)
     // ------------------
     impl<T: Config> Pallet<T> {
-        /// Create a brand-new card with `owner`.
-        fn create_new_card(owner: &T::AccountId) -> Result<u32, DispatchError> {
-            // Charge the mint fee to the caller and send it to the faucet account.
+        /// The account mint and rename fees are paid to, derived from `PalletId` so no one
+        /// holds its key.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Current price of minting a card of `rarity`.
+        pub fn mint_fee(rarity: &RarityType) -> BalanceOf<T> {
+            let pricing = MintPricingOf::<T>::get().unwrap_or_else(|| MintPricing {
+                base: T::MintFee::get(),
+                step: Zero::zero(),
+                step_cards: 0,
+                rarity_percent: DEFAULT_RARITY_FEE_PERCENT,
+            });
+            let steps = NextCardId::<T>::get().checked_div(pricing.step_cards).unwrap_or(0);
+            let curve = pricing
+                .base
+                .saturating_add(pricing.step.saturating_mul(steps.into()));
+            let percent = pricing.rarity_percent[rarity.index()];
+            curve.saturating_mul(percent.into()) / BalanceOf::<T>::from(100u32)
+        }
+
+        /// Create a brand-new card of `rarity` and `provenance` with `owner`.
+        fn create_new_card(
+            owner: &T::AccountId,
+            rarity: RarityType,
+            provenance: Provenance,
+        ) -> Result<u32, DispatchError> {
+            // Charge the mint fee to the caller and send it to the pallet account.
             // This will fail with an error if the caller has insufficient funds.
-            let fee: <<T as Config>::Currency as Currency<T::AccountId>>::Balance =
-                T::MintFee::get();
+            let fee = Self::mint_fee(&rarity);
             T::Currency::transfer(
                 owner,
-                &T::FaucetAccount::get(),
+                &Self::account_id(),
                 fee,
                 ExistenceRequirement::KeepAlive,
             )?;
@@ -391,7 +1222,8 @@ pub mod pallet {
             let seed = T::RandomnessSeed::get();
             let hash = T::Hashing::hash_of(&(current_block, owner, seed, card_id));
 
-            // Use the first 4 bytes for the four directions (1..=9)
+            // Use the first 4 bytes for the four directions (1..=9) and the fifth to roll an
+            // element, which half of all minted cards get
             let bytes = hash.as_ref();
             let mut to_stat = |b: u8| -> u8 { (b % 9) + 1 };
 
@@ -399,10 +1231,46 @@ pub mod pallet {
             let e = to_stat(bytes.get(1).copied().unwrap_or(0));
             let s = to_stat(bytes.get(2).copied().unwrap_or(0));
             let w = to_stat(bytes.get(3).copied().unwrap_or(0));
+            let element = bytes.get(4).and_then(|b| Element::roll(*b, 2));
+
+            Self::insert_card(owner, [n, e, s, w], rarity, provenance, element)
+        }
+
+        /// Add `xp` experience to each of `card_ids` still owned by `owner`, e.g. the cards
+        /// they played in a game they won.
+        pub fn award_xp(owner: &T::AccountId, card_ids: &[CardId], xp: u32) {
+            if xp == 0 {
+                return;
+            }
+            for &card_id in card_ids {
+                if Cards::<T>::get(card_id).map_or(true, |card| card.owner != *owner) {
+                    continue;
+                }
+                CardXp::<T>::mutate(card_id, |total| *total = total.saturating_add(xp));
+                Self::deposit_event(Event::CardXpGained { card_id, xp });
+            }
+        }
+
+        /// Grant a fee-free, soulbound card with the given edges to `owner`, e.g. as a reward
+        /// paid out by another pallet.
+        pub fn grant_reward_card(owner: &T::AccountId, edges: [u8; 4]) -> Result<u32, DispatchError> {
+            Self::insert_card(owner, edges, RarityType::Common, Provenance::Reward, None)
+        }
+
+        /// Store a new Base card with the given edges, rarity, provenance and element under
+        /// `owner`.
+        fn insert_card(
+            owner: &T::AccountId,
+            [n, e, s, w]: [u8; 4],
+            rarity: RarityType,
+            provenance: Provenance,
+            element: Option<Element>,
+        ) -> Result<u32, DispatchError> {
+            let card_id = NextCardId::<T>::get();
 
             // Name: "Card-<id>"
             let name_string = alloc::format!("Card-{}", card_id);
-            let name_bv: BoundedVec<u8, ConstU32<64>> =
+            let name_bv: BoundedVec<u8, ConstU32<MAX_CARD_NAME_LEN>> =
                 BoundedVec::try_from(name_string.into_bytes())
                     .map_err(|_| DispatchError::Other("NameTooLong"))?;
 
@@ -421,7 +1289,10 @@ pub mod pallet {
                 minted_at: <frame_system::Pallet<T>>::block_number(),
                 price: 0u128,
                 edition: CardEdition::Base,
-                rarity: RarityType::Common,
+                rarity,
+                provenance,
+                last_transfer_block: None,
+                element,
             };
 
             Cards::<T>::insert(card_id, new_card_info);
@@ -445,6 +1316,7 @@ pub mod pallet {
         fn unlist(card_id: CardId, owner: &T::AccountId) {
             // Remove price entry if any
             CardPrices::<T>::remove(card_id);
+            Self::unindex_listing(card_id);
             // Remove from owner's listed index, if present
             ListedByOwner::<T>::mutate(owner, |v| {
                 if let Some(pos) = v.iter().position(|&id| id == card_id) {
@@ -458,17 +1330,210 @@ pub mod pallet {
             });
         }
 
+        /// Drop `card_id` from `ListingsByRarity`.
+        fn unindex_listing(card_id: CardId) {
+            if let Some(card) = Cards::<T>::get(card_id) {
+                ListingsByRarity::<T>::remove(card.rarity, card_id);
+            }
+        }
+
+        /// Listings priced within [`min_price`, `max_price`] of the given rarity and edition
+        /// (`None` matches any), skipping the first `offset` matches and returning at most
+        /// `limit`, capped at `MAX_LISTINGS_PAGE`. Rarities are walked from `Common` up;
+        /// within a rarity the order is stable but unspecified.
+        pub fn listings_filtered(
+            min_price: Option<BalanceOf<T>>,
+            max_price: Option<BalanceOf<T>>,
+            rarity: Option<RarityType>,
+            edition: Option<CardEdition>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(CardId, BalanceOf<T>)> {
+            let rarities = match rarity {
+                Some(rarity) => sp_std::vec![rarity],
+                None => sp_std::vec![
+                    RarityType::Common,
+                    RarityType::Uncommon,
+                    RarityType::Rare,
+                    RarityType::Epic,
+                    RarityType::Legendary,
+                ],
+            };
+            rarities
+                .into_iter()
+                .flat_map(ListingsByRarity::<T>::iter_key_prefix)
+                .filter_map(|card_id| Some((card_id, CardPrices::<T>::get(card_id)?)))
+                .filter(|(_, price)| min_price.map_or(true, |min| *price >= min))
+                .filter(|(_, price)| max_price.map_or(true, |max| *price <= max))
+                .filter(|(card_id, _)| match &edition {
+                    Some(edition) => {
+                        Cards::<T>::get(card_id).is_some_and(|card| card.edition == *edition)
+                    }
+                    None => true,
+                })
+                .skip(offset as usize)
+                .take(limit.min(MAX_LISTINGS_PAGE) as usize)
+                .collect()
+        }
+
+        /// Append a sale price to the (rarity, edition) history, evicting the oldest entry when full.
+        fn record_sale(rarity: RarityType, edition: CardEdition, price: BalanceOf<T>) {
+            RecentSales::<T>::mutate(rarity, edition, |sales| {
+                if sales.is_full() && !sales.is_empty() {
+                    sales.remove(0);
+                }
+                let _ = sales.try_push(price);
+            });
+        }
+
+        /// A card in the stable `CardView` shape, or `None` if it does not exist.
+        pub fn card_view(card_id: CardId) -> Option<CardView<T::AccountId, BalanceOf<T>>> {
+            let card = Cards::<T>::get(card_id)?;
+            Some(CardView {
+                owner: card.owner,
+                name: card.name.into_inner(),
+                north: card.north,
+                east: card.east,
+                south: card.south,
+                west: card.west,
+                edition: card.edition,
+                rarity: card.rarity,
+                provenance: card.provenance,
+                listing_price: CardPrices::<T>::get(card_id),
+                locked: EscrowedCards::<T>::contains_key(card_id)
+                    || ExportedCards::<T>::contains_key(card_id)
+                    || VaultedCards::<T>::contains_key(card_id),
+                element: card.element,
+                xp: CardXp::<T>::get(card_id),
+            })
+        }
+
+        /// Lowest price among the recent sales of cards with this rarity and edition,
+        /// or `None` if none have sold yet.
+        pub fn floor_price(rarity: RarityType, edition: CardEdition) -> Option<BalanceOf<T>> {
+            RecentSales::<T>::get(rarity, edition).into_iter().min()
+        }
+
+        /// Whether the card is currently locked on another chain.
+        /// Check `price` against `MinListingPrice` and `PriceTick`.
+        pub fn ensure_valid_price(price: BalanceOf<T>) -> Result<(), Error<T>> {
+            ensure!(price >= T::MinListingPrice::get(), Error::<T>::PriceTooLow);
+            let tick = T::PriceTick::get();
+            ensure!(tick.is_zero() || (price % tick).is_zero(), Error::<T>::PriceOffTick);
+            Ok(())
+        }
+
+        pub fn is_exported(card_id: CardId) -> bool {
+            ExportedCards::<T>::contains_key(card_id)
+        }
+
+        /// Whether the card is in cold storage, including while an unvault is pending.
+        pub fn is_vaulted(card_id: CardId) -> bool {
+            VaultedCards::<T>::contains_key(card_id)
+        }
+
+        /// Whether the card changed owner less than `TradeCooldown` blocks ago.
+        pub fn is_cooling_down(card_id: CardId) -> bool {
+            let Some(moved_at) = Cards::<T>::get(card_id).and_then(|c| c.last_transfer_block)
+            else {
+                return false;
+            };
+            <frame_system::Pallet<T>>::block_number()
+                < moved_at.saturating_add(T::TradeCooldown::get())
+        }
+
+        /// Whether the card is staked on a game.
+        pub fn is_escrowed(card_id: CardId) -> bool {
+            EscrowedCards::<T>::contains_key(card_id)
+        }
+
+        /// Lock `owner`'s card as a game stake, taking it off the marketplace. Fails for
+        /// cards that could not be handed to another player.
+        pub fn escrow_card(owner: &T::AccountId, card_id: CardId) -> DispatchResult {
+            let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            ensure!(card.owner == *owner, Error::<T>::NotCardOwner);
+            ensure!(!card.provenance.is_soulbound(), Error::<T>::CardIsSoulbound);
+            ensure!(
+                !ExportedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsExported
+            );
+            ensure!(
+                !VaultedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsVaulted
+            );
+            ensure!(
+                !EscrowedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsEscrowed
+            );
+            if CardPrices::<T>::contains_key(card_id) {
+                Self::unlist(card_id, owner);
+            }
+            EscrowedCards::<T>::insert(card_id, ());
+            Ok(())
+        }
+
+        /// Unlock a staked card for its owner.
+        pub fn release_escrow(card_id: CardId) {
+            EscrowedCards::<T>::remove(card_id);
+        }
+
+        /// Unlock a staked card and hand it to `to`, e.g. the winner of the game it was
+        /// staked on. The card is unlocked even if the transfer fails, and stays with its
+        /// owner then.
+        pub fn settle_escrow(card_id: CardId, to: &T::AccountId) -> DispatchResult {
+            EscrowedCards::<T>::remove(card_id);
+            let from = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?.owner;
+            if from == *to {
+                return Ok(());
+            }
+            frame_support::storage::with_storage_layer(|| Self::do_transfer(&from, to, card_id))?;
+            Self::deposit_event(Event::CardTransferred {
+                from,
+                to: to.clone(),
+                card_id,
+            });
+            Ok(())
+        }
+
+        /// Remove every marketplace listing of `owner` without emitting per-card events.
+        /// Returns the number of cards unlisted. Used by account cleanup in the game pallet.
+        pub fn unlist_all(owner: &T::AccountId) -> u32 {
+            let listed = ListedByOwner::<T>::take(owner);
+            for card_id in listed.iter() {
+                CardPrices::<T>::remove(card_id);
+                Self::unindex_listing(*card_id);
+            }
+            listed.len() as u32
+        }
+
         /// Internal: transfer ownership from `from` to `to` and ensure indices are updated.
         fn do_transfer(
             from: &T::AccountId,
             to: &T::AccountId,
             card_id: CardId,
         ) -> Result<(), DispatchError> {
+            ensure!(
+                !ExportedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsExported
+            );
+            ensure!(
+                !VaultedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsVaulted
+            );
+            ensure!(
+                !EscrowedCards::<T>::contains_key(card_id),
+                Error::<T>::CardIsEscrowed
+            );
             // Update the card owner in main storage (ensures existence and ownership)
             Cards::<T>::try_mutate(card_id, |maybe_card| -> DispatchResult {
                 let card_info = maybe_card.as_mut().ok_or(Error::<T>::NoSuchCard)?;
                 ensure!(card_info.owner == *from, Error::<T>::NotCardOwner);
+                ensure!(
+                    !card_info.provenance.is_soulbound(),
+                    Error::<T>::CardIsSoulbound
+                );
                 card_info.owner = to.clone();
+                card_info.last_transfer_block = Some(<frame_system::Pallet<T>>::block_number());
                 Ok(())
             })?;
 
@@ -478,6 +1543,15 @@ pub mod pallet {
                     list.swap_remove(pos);
                 }
             });
+            // The previous owner can no longer display it
+            Showcase::<T>::mutate_exists(from, |maybe| {
+                if let Some(list) = maybe {
+                    list.retain(|&id| id != card_id);
+                    if list.is_empty() {
+                        *maybe = None;
+                    }
+                }
+            });
 
             // Add card_id to `to`'s OwnedCards list (bounded)
             OwnedCards::<T>::try_mutate(to, |list| -> DispatchResult {
@@ -0,0 +1,275 @@
+//! Storage migrations of the simple TCG pallet.
+
+pub mod v3 {
+    //! Adds `provenance` to every stored `CardInfo`. Cards created at genesis (block 0) were
+    //! handed out as starter decks and become soulbound; everything else was minted.
+
+    use crate::pallet::{
+        Balance, CardEdition, CardId, CardInfo, Cards, Config, Provenance, RarityType,
+    };
+    use frame_support::{pallet_prelude::*, traits::Get};
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_runtime::traits::Zero;
+
+    /// `CardInfo` as stored before v3.
+    #[derive(Encode, Decode)]
+    pub struct OldCardInfo<T: Config> {
+        pub owner: T::AccountId,
+        pub finalized: bool,
+        pub slot_values: Option<[u8; 4]>,
+        pub name: BoundedVec<u8, ConstU32<64>>,
+        pub north: u8,
+        pub east: u8,
+        pub south: u8,
+        pub west: u8,
+        pub card_id: CardId,
+        pub minted_at: BlockNumberFor<T>,
+        pub price: Balance,
+        pub edition: CardEdition,
+        pub rarity: RarityType,
+    }
+
+    pub fn migrate<T: Config>() -> Weight {
+        let mut cards = 0u64;
+        Cards::<T>::translate::<OldCardInfo<T>, _>(|_, old| {
+            cards += 1;
+            let provenance = if old.minted_at.is_zero() {
+                Provenance::Starter
+            } else {
+                Provenance::Minted
+            };
+            Some(CardInfo {
+                owner: old.owner,
+                finalized: old.finalized,
+                slot_values: old.slot_values,
+                name: old.name,
+                north: old.north,
+                east: old.east,
+                south: old.south,
+                west: old.west,
+                card_id: old.card_id,
+                minted_at: old.minted_at,
+                price: old.price,
+                edition: old.edition,
+                rarity: old.rarity,
+                provenance,
+                last_transfer_block: None,
+                element: None,
+            })
+        });
+        T::DbWeight::get().reads_writes(cards, cards)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mock::{new_test_ext, Test, ALICE, BOB};
+
+        fn old_card(owner: u64, card_id: CardId, minted_at: u64) -> OldCardInfo<Test> {
+            OldCardInfo {
+                owner,
+                finalized: true,
+                slot_values: Some([1, 2, 3, 4]),
+                name: Default::default(),
+                north: 1,
+                east: 2,
+                south: 3,
+                west: 4,
+                card_id,
+                minted_at,
+                price: 0,
+                edition: CardEdition::Base,
+                rarity: RarityType::Common,
+            }
+        }
+
+        #[test]
+        fn genesis_cards_become_soulbound_starters() {
+            new_test_ext().execute_with(|| {
+                for (card_id, owner, minted_at) in [(0, ALICE, 0), (1, BOB, 7)] {
+                    frame_support::storage::unhashed::put(
+                        &Cards::<Test>::hashed_key_for(card_id),
+                        &old_card(owner, card_id, minted_at),
+                    );
+                }
+
+                migrate::<Test>();
+
+                let starter = Cards::<Test>::get(0).expect("decodes after migration");
+                assert_eq!(starter.provenance, Provenance::Starter);
+                assert_eq!(starter.owner, ALICE);
+                let minted = Cards::<Test>::get(1).expect("decodes after migration");
+                assert_eq!(minted.provenance, Provenance::Minted);
+                assert_eq!((minted.north, minted.west, minted.minted_at), (1, 4, 7));
+            });
+        }
+    }
+}
+
+pub mod v4 {
+    //! Indexes every open listing by rarity in `ListingsByRarity`.
+
+    use crate::pallet::{CardPrices, Cards, Config, ListingsByRarity};
+    use frame_support::{pallet_prelude::*, traits::Get};
+
+    pub fn migrate<T: Config>() -> Weight {
+        let mut listings = 0u64;
+        for card_id in CardPrices::<T>::iter_keys() {
+            listings += 1;
+            if let Some(card) = Cards::<T>::get(card_id) {
+                ListingsByRarity::<T>::insert(card.rarity, card_id, ());
+            }
+        }
+        T::DbWeight::get().reads_writes(listings.saturating_mul(2), listings)
+    }
+}
+
+pub mod v5 {
+    //! Adds `last_transfer_block` to every stored `CardInfo`. Past transfers were not
+    //! recorded, so no card starts in its trade cooldown.
+
+    use crate::pallet::{
+        Balance, CardEdition, CardId, CardInfo, Cards, Config, Provenance, RarityType,
+    };
+    use frame_support::{pallet_prelude::*, traits::Get};
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// `CardInfo` as stored before v5.
+    #[derive(Encode, Decode)]
+    pub struct OldCardInfo<T: Config> {
+        pub owner: T::AccountId,
+        pub finalized: bool,
+        pub slot_values: Option<[u8; 4]>,
+        pub name: BoundedVec<u8, ConstU32<64>>,
+        pub north: u8,
+        pub east: u8,
+        pub south: u8,
+        pub west: u8,
+        pub card_id: CardId,
+        pub minted_at: BlockNumberFor<T>,
+        pub price: Balance,
+        pub edition: CardEdition,
+        pub rarity: RarityType,
+        pub provenance: Provenance,
+    }
+
+    pub fn migrate<T: Config>() -> Weight {
+        let mut cards = 0u64;
+        Cards::<T>::translate::<OldCardInfo<T>, _>(|_, old| {
+            cards += 1;
+            Some(CardInfo {
+                owner: old.owner,
+                finalized: old.finalized,
+                slot_values: old.slot_values,
+                name: old.name,
+                north: old.north,
+                east: old.east,
+                south: old.south,
+                west: old.west,
+                card_id: old.card_id,
+                minted_at: old.minted_at,
+                price: old.price,
+                edition: old.edition,
+                rarity: old.rarity,
+                provenance: old.provenance,
+                last_transfer_block: None,
+                element: None,
+            })
+        });
+        T::DbWeight::get().reads_writes(cards, cards)
+    }
+}
+
+pub mod v6 {
+    //! Adds `element` to every stored `CardInfo`. Cards minted before elements existed stay
+    //! element-less.
+
+    use crate::pallet::{
+        Balance, CardEdition, CardId, CardInfo, Cards, Config, Provenance, RarityType,
+    };
+    use frame_support::{pallet_prelude::*, traits::Get};
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// `CardInfo` as stored before v6.
+    #[derive(Encode, Decode)]
+    pub struct OldCardInfo<T: Config> {
+        pub owner: T::AccountId,
+        pub finalized: bool,
+        pub slot_values: Option<[u8; 4]>,
+        pub name: BoundedVec<u8, ConstU32<64>>,
+        pub north: u8,
+        pub east: u8,
+        pub south: u8,
+        pub west: u8,
+        pub card_id: CardId,
+        pub minted_at: BlockNumberFor<T>,
+        pub price: Balance,
+        pub edition: CardEdition,
+        pub rarity: RarityType,
+        pub provenance: Provenance,
+        pub last_transfer_block: Option<BlockNumberFor<T>>,
+    }
+
+    pub fn migrate<T: Config>() -> Weight {
+        let mut cards = 0u64;
+        Cards::<T>::translate::<OldCardInfo<T>, _>(|_, old| {
+            cards += 1;
+            Some(CardInfo {
+                owner: old.owner,
+                finalized: old.finalized,
+                slot_values: old.slot_values,
+                name: old.name,
+                north: old.north,
+                east: old.east,
+                south: old.south,
+                west: old.west,
+                card_id: old.card_id,
+                minted_at: old.minted_at,
+                price: old.price,
+                edition: old.edition,
+                rarity: old.rarity,
+                provenance: old.provenance,
+                last_transfer_block: old.last_transfer_block,
+                element: None,
+            })
+        });
+        T::DbWeight::get().reads_writes(cards, cards)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mock::{new_test_ext, Test, ALICE};
+
+        #[test]
+        fn stored_cards_gain_no_element() {
+            new_test_ext().execute_with(|| {
+                let old = OldCardInfo::<Test> {
+                    owner: ALICE,
+                    finalized: true,
+                    slot_values: Some([1, 2, 3, 4]),
+                    name: Default::default(),
+                    north: 1,
+                    east: 2,
+                    south: 3,
+                    west: 4,
+                    card_id: 9,
+                    minted_at: 3,
+                    price: 0,
+                    edition: CardEdition::Base,
+                    rarity: RarityType::Rare,
+                    provenance: Provenance::Minted,
+                    last_transfer_block: Some(5),
+                };
+                frame_support::storage::unhashed::put(&Cards::<Test>::hashed_key_for(9), &old);
+
+                migrate::<Test>();
+
+                let card = Cards::<Test>::get(9).expect("decodes after migration");
+                assert_eq!(card.element, None);
+                assert_eq!((card.owner, card.west, card.rarity), (ALICE, 4, RarityType::Rare));
+                assert_eq!(card.last_transfer_block, Some(5));
+            });
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate as pallet_eterra_simple_tcg;
 use frame_support::{
     construct_runtime, parameter_types,
     traits::{ConstU128, ConstU32, ConstU64, Everything, GenesisBuild},
+    PalletId,
 };
 use frame_system as system;
 use sp_core::H256;
@@ -28,7 +29,10 @@ parameter_types! {
     pub const RandomnessSeed: u64 = 42;
     pub const ExistentialDeposit: u128 = 0; // keep accounts alive at 0 for tests
     pub const MintFeeConst: u128 = 100;     // 100 whole tokens in tests
-    pub FaucetAccountParam: u64 = ALICE;    // faucet is Alice for tests
+    pub const TcgPalletId: PalletId = PalletId(*b"etr/tcg_");
+    pub storage MinListingPrice: u128 = 10;
+    pub storage PriceTick: u128 = 1;        // any price unless a test sets it
+    pub storage StarterDeckSize: u32 = 0;   // every mint is tradable unless a test sets it
 }
 
 impl system::Config for Test {
@@ -88,7 +92,22 @@ impl pallet_eterra_simple_tcg::Config for Test {
     // Currency integration for mint fee & marketplace
     type Currency = Balances;
     type MintFee = ConstU128<100>;
-    type FaucetAccount = FaucetAccountParam;
+    type PalletId = TcgPalletId;
+    type BridgeOrigin = frame_system::EnsureRoot<u64>;
+    type PriceHistoryLen = ConstU32<3>;
+    type OnMint = ();
+    type OnCardChanged = ();
+    type MaxShowcase = ConstU32<3>;
+    type UnvaultDelay = ConstU64<10>;
+    type MinListingPrice = MinListingPrice;
+    type PriceTick = PriceTick;
+    type PricingAdminOrigin = frame_system::EnsureRoot<u64>;
+    type RenameFee = ConstU128<25>;
+    type MaxRenameHistory = ConstU32<2>;
+    type NameModeratorOrigin = frame_system::EnsureRoot<u64>;
+    type TradeCooldown = ConstU64<50>;
+    type XpPerLevel = ConstU32<30>;
+    type StarterDeckSize = StarterDeckSize;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
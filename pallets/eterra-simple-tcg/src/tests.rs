@@ -12,10 +12,11 @@ fn mint_card_charges_fee_and_mints() {
         // Block 1 for deterministic hashing
         System::set_block_number(1);
 
-        let faucet_before = Balances::free_balance(ALICE);
+        let faucet = EterraSimpleTCGConfig::account_id();
+        let faucet_before = Balances::free_balance(faucet);
         let bob_before = Balances::free_balance(BOB);
 
-        // Bob mints a card (fee 100 should go to Alice faucet)
+        // Bob mints a card (fee 100 should go to the pallet account)
         assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
 
         // Ownership & indices
@@ -26,7 +27,7 @@ fn mint_card_charges_fee_and_mints() {
         assert_eq!(card.owner, BOB);
 
         // Fee accounting (Balances is u128 in mock)
-        let faucet_after = Balances::free_balance(ALICE);
+        let faucet_after = Balances::free_balance(faucet);
         let bob_after = Balances::free_balance(BOB);
         assert_eq!(faucet_after, faucet_before + 100);
         assert_eq!(bob_after, bob_before - 100);
@@ -39,6 +40,56 @@ fn mint_card_charges_fee_and_mints() {
     });
 }
 
+#[test]
+fn mint_fee_scales_with_rarity_and_supply() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Without on-chain pricing, MintFee is the Common price and rarities scale from it.
+        assert_noop!(
+            EterraSimpleTCGConfig::mint_card_of_rarity(
+                RuntimeOrigin::signed(BOB),
+                RarityType::Rare,
+                399
+            ),
+            Error::<Test>::MintFeeAboveMax
+        );
+        assert_ok!(EterraSimpleTCGConfig::mint_card_of_rarity(
+            RuntimeOrigin::signed(BOB),
+            RarityType::Rare,
+            400
+        ));
+        let card_id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        assert_eq!(
+            EterraSimpleTCGConfig::cards(card_id).unwrap().rarity,
+            RarityType::Rare
+        );
+        assert_eq!(Balances::free_balance(BOB), 1_000_000 - 400);
+
+        let pricing = MintPricing {
+            base: 50,
+            step: 10,
+            step_cards: 2,
+            rarity_percent: [100, 150, 300, 600, 1000],
+        };
+        assert_noop!(
+            EterraSimpleTCGConfig::set_mint_pricing(RuntimeOrigin::signed(BOB), pricing.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(EterraSimpleTCGConfig::set_mint_pricing(
+            RuntimeOrigin::root(),
+            pricing
+        ));
+        // One card exists: still on the first step.
+        assert_eq!(EterraSimpleTCGConfig::mint_fee(&RarityType::Common), 50);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        // Two cards: the price moves up one step, and rarities scale the stepped price.
+        assert_eq!(EterraSimpleTCGConfig::mint_fee(&RarityType::Common), 60);
+        assert_eq!(EterraSimpleTCGConfig::mint_fee(&RarityType::Uncommon), 90);
+        assert_eq!(EterraSimpleTCGConfig::mint_fee(&RarityType::Legendary), 600);
+    });
+}
+
 #[test]
 fn set_and_remove_price_updates_storage_and_events() {
     new_test_ext().execute_with(|| {
@@ -54,6 +105,7 @@ fn set_and_remove_price_updates_storage_and_events() {
         ));
         assert_eq!(EterraSimpleTCGConfig::card_prices(id), Some(500));
         assert!(EterraSimpleTCGConfig::listed_by_owner(BOB).contains(&id));
+        assert_eq!(crate::CardPrices::<Test>::count(), 1);
         System::assert_has_event(RuntimeEvent::EterraSimpleTCGConfig(TcgEvent::CardListed {
             owner: BOB,
             card_id: id,
@@ -67,6 +119,7 @@ fn set_and_remove_price_updates_storage_and_events() {
         ));
         assert_eq!(EterraSimpleTCGConfig::card_prices(id), None);
         assert!(!EterraSimpleTCGConfig::listed_by_owner(BOB).contains(&id));
+        assert_eq!(crate::CardPrices::<Test>::count(), 0);
         System::assert_has_event(RuntimeEvent::EterraSimpleTCGConfig(
             TcgEvent::CardUnlisted {
                 owner: BOB,
@@ -107,6 +160,29 @@ fn transfer_card_auto_unlists() {
     });
 }
 
+#[test]
+fn transfers_start_the_trade_cooldown() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        assert!(!EterraSimpleTCGConfig::is_cooling_down(id));
+
+        System::set_block_number(5);
+        assert_ok!(EterraSimpleTCGConfig::transfer_card(
+            RuntimeOrigin::signed(BOB),
+            id,
+            ALICE
+        ));
+        assert_eq!(EterraSimpleTCGConfig::cards(id).unwrap().last_transfer_block, Some(5));
+        assert!(EterraSimpleTCGConfig::is_cooling_down(id));
+        System::set_block_number(54);
+        assert!(EterraSimpleTCGConfig::is_cooling_down(id));
+        System::set_block_number(55);
+        assert!(!EterraSimpleTCGConfig::is_cooling_down(id));
+    });
+}
+
 #[test]
 fn buy_card_transfers_funds_and_ownership_then_unlists() {
     new_test_ext().execute_with(|| {
@@ -172,3 +248,456 @@ fn buy_card_fails_if_not_listed() {
         );
     });
 }
+
+#[test]
+fn buy_card_records_recent_sales_and_floor_price() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(ALICE)));
+        let id = EterraSimpleTCGConfig::owned_cards(ALICE)[0];
+        let card = EterraSimpleTCGConfig::cards(id).unwrap();
+        assert_eq!(
+            EterraSimpleTCGConfig::floor_price(card.rarity.clone(), card.edition.clone()),
+            None
+        );
+
+        // Trade the card back and forth; the history keeps only the last 3 sales (mock).
+        let mut seller = ALICE;
+        for price in [200u128, 300, 100, 400] {
+            let buyer = if seller == ALICE { BOB } else { ALICE };
+            assert_ok!(EterraSimpleTCGConfig::set_price(
+                RuntimeOrigin::signed(seller),
+                id,
+                price
+            ));
+            assert_ok!(EterraSimpleTCGConfig::buy_card(RuntimeOrigin::signed(buyer), id));
+            seller = buyer;
+        }
+
+        assert_eq!(
+            EterraSimpleTCGConfig::recent_sales(card.rarity.clone(), card.edition.clone())
+                .into_inner(),
+            vec![300, 100, 400]
+        );
+        assert_eq!(
+            EterraSimpleTCGConfig::floor_price(card.rarity, card.edition),
+            Some(100)
+        );
+    });
+}
+
+#[test]
+fn showcase_requires_ownership_and_drops_transferred_cards() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for _ in 0..3 {
+            assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        }
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(ALICE)));
+        let owned = EterraSimpleTCGConfig::owned_cards(BOB).into_inner();
+        let alice_card = EterraSimpleTCGConfig::owned_cards(ALICE)[0];
+
+        assert_noop!(
+            EterraSimpleTCGConfig::set_showcase(RuntimeOrigin::signed(BOB), vec![alice_card]),
+            Error::<Test>::NotCardOwner
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::set_showcase(
+                RuntimeOrigin::signed(BOB),
+                vec![owned[0], owned[0]]
+            ),
+            Error::<Test>::DuplicateShowcaseCard
+        );
+        // MaxShowcase = 3 in the mock
+        assert_noop!(
+            EterraSimpleTCGConfig::set_showcase(
+                RuntimeOrigin::signed(BOB),
+                vec![owned[0], owned[1], owned[2], owned[0]]
+            ),
+            Error::<Test>::ShowcaseTooLong
+        );
+
+        let order = vec![owned[2], owned[0], owned[1]];
+        assert_ok!(EterraSimpleTCGConfig::set_showcase(
+            RuntimeOrigin::signed(BOB),
+            order.clone()
+        ));
+        assert_eq!(EterraSimpleTCGConfig::showcase(BOB).into_inner(), order);
+
+        // Giving a card away removes it while keeping the order of the rest
+        assert_ok!(EterraSimpleTCGConfig::transfer_card(
+            RuntimeOrigin::signed(BOB),
+            owned[0],
+            ALICE
+        ));
+        assert_eq!(
+            EterraSimpleTCGConfig::showcase(BOB).into_inner(),
+            vec![owned[2], owned[1]]
+        );
+    });
+}
+
+#[test]
+fn exported_card_is_locked_until_imported_back() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let card_id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        assert_ok!(EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), card_id, 50));
+
+        assert_ok!(EterraSimpleTCGConfig::export_card(RuntimeOrigin::signed(BOB), card_id, 2000));
+        let attestation = match last_event() {
+            RuntimeEvent::EterraSimpleTCGConfig(TcgEvent::CardExported { attestation, .. }) => {
+                attestation
+            }
+            other => panic!("unexpected event {:?}", other),
+        };
+        assert_eq!(attestation.owner, BOB);
+        assert_eq!(attestation.destination, 2000);
+        // Exporting unlists the card, and it can no longer move locally.
+        assert!(EterraSimpleTCGConfig::card_prices(card_id).is_none());
+        assert_noop!(
+            EterraSimpleTCGConfig::transfer_card(RuntimeOrigin::signed(BOB), card_id, ALICE),
+            Error::<Test>::CardIsExported
+        );
+
+        // Only the bridge origin may import, and only with the matching attestation.
+        assert_noop!(
+            EterraSimpleTCGConfig::import_card(RuntimeOrigin::signed(BOB), attestation.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        let mut stale = attestation.clone();
+        stale.nonce += 1;
+        assert_noop!(
+            EterraSimpleTCGConfig::import_card(RuntimeOrigin::root(), stale),
+            Error::<Test>::InvalidAttestation
+        );
+
+        // The card was sold remotely, so it comes back to Alice.
+        let mut returning = attestation;
+        returning.owner = ALICE;
+        assert_ok!(EterraSimpleTCGConfig::import_card(RuntimeOrigin::root(), returning));
+        assert!(EterraSimpleTCGConfig::exported(card_id).is_none());
+        assert_eq!(EterraSimpleTCGConfig::cards(card_id).unwrap().owner, ALICE);
+        assert!(EterraSimpleTCGConfig::owned_cards(ALICE).contains(&card_id));
+    });
+}
+
+#[test]
+fn vaulted_card_is_frozen_until_the_delayed_unvault_completes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let card_id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        assert_ok!(EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), card_id, 50));
+
+        assert_noop!(
+            EterraSimpleTCGConfig::vault_card(RuntimeOrigin::signed(ALICE), card_id),
+            Error::<Test>::NotCardOwner
+        );
+        assert_ok!(EterraSimpleTCGConfig::vault_card(RuntimeOrigin::signed(BOB), card_id));
+        assert!(EterraSimpleTCGConfig::card_prices(card_id).is_none());
+        assert_noop!(
+            EterraSimpleTCGConfig::transfer_card(RuntimeOrigin::signed(BOB), card_id, ALICE),
+            Error::<Test>::CardIsVaulted
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), card_id, 50),
+            Error::<Test>::CardIsVaulted
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::export_card(RuntimeOrigin::signed(BOB), card_id, 2000),
+            Error::<Test>::CardIsVaulted
+        );
+
+        // A pending unvault keeps the card frozen and can be cancelled by vaulting again.
+        assert_ok!(EterraSimpleTCGConfig::unvault_card(RuntimeOrigin::signed(BOB), card_id));
+        assert_eq!(
+            EterraSimpleTCGConfig::vaulted(card_id),
+            Some(VaultLock::Unlocking { ready_at: 11 })
+        );
+        assert_ok!(EterraSimpleTCGConfig::vault_card(RuntimeOrigin::signed(BOB), card_id));
+        assert_eq!(EterraSimpleTCGConfig::vaulted(card_id), Some(VaultLock::Locked));
+
+        assert_ok!(EterraSimpleTCGConfig::unvault_card(RuntimeOrigin::signed(BOB), card_id));
+        System::set_block_number(10);
+        assert_noop!(
+            EterraSimpleTCGConfig::unvault_card(RuntimeOrigin::signed(BOB), card_id),
+            Error::<Test>::UnvaultPending
+        );
+        System::set_block_number(11);
+        assert_ok!(EterraSimpleTCGConfig::unvault_card(RuntimeOrigin::signed(BOB), card_id));
+        assert!(!EterraSimpleTCGConfig::is_vaulted(card_id));
+        assert_ok!(EterraSimpleTCGConfig::transfer_card(RuntimeOrigin::signed(BOB), card_id, ALICE));
+    });
+}
+
+#[test]
+fn genesis_starter_cards_are_minted_fee_free() {
+    use sp_runtime::BuildStorage;
+
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test> {
+        starter_cards: vec![(BOB, vec![[5, 5, 5, 5], [7, 3, 6, 4]]), (ALICE, vec![[1, 2, 3, 4]])],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::from(t).execute_with(|| {
+        assert_eq!(OwnedCards::<Test>::get(BOB).into_inner(), vec![0, 1]);
+        assert_eq!(OwnedCards::<Test>::get(ALICE).into_inner(), vec![2]);
+        let card = Cards::<Test>::get(1).unwrap();
+        assert_eq!(card.owner, BOB);
+        assert_eq!((card.north, card.east, card.south, card.west), (7, 3, 6, 4));
+        assert_eq!(card.provenance, Provenance::Starter);
+        assert_eq!(NextCardId::<Test>::get(), 3);
+        assert_eq!(Balances::free_balance(BOB), 0);
+    });
+}
+
+#[test]
+fn soulbound_cards_never_leave_their_owner() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let card_id = EterraSimpleTCGConfig::grant_reward_card(&BOB, [5, 5, 5, 5]).unwrap();
+        assert_eq!(
+            EterraSimpleTCGConfig::cards(card_id).unwrap().provenance,
+            Provenance::Reward
+        );
+        // Granted for free: nothing was charged.
+        assert_eq!(Balances::free_balance(BOB), 1_000_000);
+
+        assert_noop!(
+            EterraSimpleTCGConfig::transfer_card(RuntimeOrigin::signed(BOB), card_id, ALICE),
+            Error::<Test>::CardIsSoulbound
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), card_id, 10),
+            Error::<Test>::CardIsSoulbound
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::export_card(RuntimeOrigin::signed(BOB), card_id, 1),
+            Error::<Test>::CardIsSoulbound
+        );
+
+        // Minted cards stay tradable.
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let minted = *EterraSimpleTCGConfig::owned_cards(BOB).last().unwrap();
+        assert_ok!(EterraSimpleTCGConfig::transfer_card(
+            RuntimeOrigin::signed(BOB),
+            minted,
+            ALICE
+        ));
+    });
+}
+
+#[test]
+fn cards_minted_toward_the_starter_deck_are_soulbound() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        StarterDeckSize::set(&2);
+        for _ in 0..3 {
+            assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        }
+        let owned = EterraSimpleTCGConfig::owned_cards(BOB);
+        let provenance = |id| EterraSimpleTCGConfig::cards(id).unwrap().provenance;
+        assert_eq!(provenance(owned[0]), Provenance::Starter);
+        assert_eq!(provenance(owned[1]), Provenance::Starter);
+        assert_eq!(provenance(owned[2]), Provenance::Minted);
+        assert_noop!(
+            EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), owned[0], 10),
+            Error::<Test>::CardIsSoulbound
+        );
+        assert_ok!(EterraSimpleTCGConfig::set_price(
+            RuntimeOrigin::signed(BOB),
+            owned[2],
+            10
+        ));
+    });
+}
+
+#[test]
+fn listings_filter_by_price_rarity_and_edition() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        assert_ok!(EterraSimpleTCGConfig::mint_card_of_rarity(
+            RuntimeOrigin::signed(BOB),
+            RarityType::Rare,
+            u128::MAX
+        ));
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let owned = EterraSimpleTCGConfig::owned_cards(BOB);
+        let (cheap, rare, pricey) = (owned[0], owned[1], owned[2]);
+        for (id, price) in [(cheap, 100), (rare, 300), (pricey, 500)] {
+            assert_ok!(EterraSimpleTCGConfig::set_price(
+                RuntimeOrigin::signed(BOB),
+                id,
+                price
+            ));
+        }
+        let sorted = |mut listings: Vec<(CardId, u128)>| {
+            listings.sort();
+            listings
+        };
+
+        let common = Some(RarityType::Common);
+        let commons = EterraSimpleTCGConfig::listings_filtered(None, None, common, None, 0, 10);
+        assert_eq!(sorted(commons), vec![(cheap, 100), (pricey, 500)]);
+        let mid = EterraSimpleTCGConfig::listings_filtered(Some(200), Some(400), None, None, 0, 10);
+        assert_eq!(mid, vec![(rare, 300)]);
+        let promos = EterraSimpleTCGConfig::listings_filtered(
+            None,
+            None,
+            None,
+            Some(CardEdition::Promo),
+            0,
+            10,
+        );
+        assert!(promos.is_empty());
+
+        // Pages walk Common before Rare.
+        let first = EterraSimpleTCGConfig::listings_filtered(None, None, None, None, 0, 2);
+        assert_eq!(sorted(first), vec![(cheap, 100), (pricey, 500)]);
+        let second = EterraSimpleTCGConfig::listings_filtered(None, None, None, None, 2, 2);
+        assert_eq!(second, vec![(rare, 300)]);
+
+        // Sales and unlisting keep the index in step.
+        assert_ok!(EterraSimpleTCGConfig::buy_card(RuntimeOrigin::signed(ALICE), rare));
+        assert_ok!(EterraSimpleTCGConfig::remove_price(RuntimeOrigin::signed(BOB), cheap));
+        let all = EterraSimpleTCGConfig::listings_filtered(None, None, None, None, 0, 10);
+        assert_eq!(all, vec![(pricey, 500)]);
+        assert_eq!(ListingsByRarity::<Test>::iter().count(), 1);
+    });
+}
+
+#[test]
+fn listings_must_meet_the_minimum_price_and_tick() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        PriceTick::set(&5);
+
+        assert_noop!(
+            EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), id, 1),
+            Error::<Test>::PriceTooLow
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), id, 12),
+            Error::<Test>::PriceOffTick
+        );
+        assert_ok!(EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), id, 15));
+        assert_eq!(EterraSimpleTCGConfig::card_prices(id), Some(15));
+    });
+}
+
+#[test]
+fn owners_rename_cards_with_valid_names_and_pay_for_later_renames() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        let rename = |who, name: &[u8]| {
+            EterraSimpleTCGConfig::rename_card(RuntimeOrigin::signed(who), id, name.to_vec())
+        };
+
+        assert_noop!(rename(ALICE, b"Ember Fox"), Error::<Test>::NotCardOwner);
+        for bad in [&b"Ab"[..], b" Ember", b"Ember  Fox", b"Ember_Fox", &[b'a'; 65]] {
+            assert_noop!(rename(BOB, bad), Error::<Test>::InvalidCardName);
+        }
+        assert_ok!(EterraSimpleTCGConfig::set_banned_name_terms(
+            RuntimeOrigin::root(),
+            vec![b"Crud".to_vec()]
+        ));
+        assert_noop!(rename(BOB, b"Mr. C-R-U-D"), Error::<Test>::CardNameNotAllowed);
+
+        // The first custom name is free, the next one costs `RenameFee`.
+        let balance = Balances::free_balance(BOB);
+        assert_ok!(rename(BOB, b"Ember Fox"));
+        assert_eq!(Balances::free_balance(BOB), balance);
+        assert_ok!(rename(BOB, b"Ember Fox II"));
+        assert_eq!(Balances::free_balance(BOB), balance - 25);
+        System::assert_last_event(RuntimeEvent::EterraSimpleTCGConfig(TcgEvent::CardRenamed {
+            owner: BOB,
+            card_id: id,
+            name: b"Ember Fox II".to_vec(),
+            charged: true,
+        }));
+
+        let card = EterraSimpleTCGConfig::cards(id).unwrap();
+        assert_eq!(card.name.to_vec(), b"Ember Fox II".to_vec());
+        let history = EterraSimpleTCGConfig::rename_history(id);
+        let previous: Vec<Vec<u8>> = history.iter().map(|r| r.previous.to_vec()).collect();
+        assert_eq!(previous, vec![format!("Card-{id}").into_bytes(), b"Ember Fox".to_vec()]);
+        assert!(history.iter().all(|r| r.renamed_by == BOB && r.at == 1));
+    });
+}
+
+#[test]
+fn card_views_report_listing_and_lock_state() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(ALICE)));
+        let id = EterraSimpleTCGConfig::owned_cards(ALICE)[0];
+        let card = EterraSimpleTCGConfig::cards(id).unwrap();
+
+        let view = EterraSimpleTCGConfig::card_view(id).unwrap();
+        assert_eq!((view.owner, view.rarity, view.edition), (ALICE, card.rarity, card.edition));
+        let ranks = [view.north, view.east, view.south, view.west];
+        assert_eq!(ranks, [card.north, card.east, card.south, card.west]);
+        assert_eq!((view.listing_price, view.locked), (None, false));
+
+        assert_ok!(EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(ALICE), id, 200));
+        assert_eq!(EterraSimpleTCGConfig::card_view(id).unwrap().listing_price, Some(200));
+        assert_eq!(EterraSimpleTCGConfig::card_view(id + 1), None);
+    });
+}
+
+#[test]
+fn won_game_xp_levels_up_one_side_within_the_rarity_cap() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let id = EterraSimpleTCGConfig::grant_reward_card(&ALICE, [1, 5, 9, 2]).unwrap();
+        let level_up =
+            |side| EterraSimpleTCGConfig::level_up_card(RuntimeOrigin::signed(ALICE), id, side);
+
+        // Only the owner's cards earn XP.
+        EterraSimpleTCGConfig::award_xp(&BOB, &[id], 50);
+        assert_eq!(EterraSimpleTCGConfig::card_xp(id), 0);
+        assert_noop!(level_up(Direction::Top), Error::<Test>::NotEnoughXp);
+        EterraSimpleTCGConfig::award_xp(&ALICE, &[id, id + 1], 50);
+        assert_eq!(EterraSimpleTCGConfig::card_xp(id), 50);
+        System::assert_last_event(RuntimeEvent::EterraSimpleTCGConfig(TcgEvent::CardXpGained {
+            card_id: id,
+            xp: 50,
+        }));
+
+        assert_noop!(
+            EterraSimpleTCGConfig::level_up_card(RuntimeOrigin::signed(BOB), id, Direction::Top),
+            Error::<Test>::NotCardOwner
+        );
+        assert_ok!(level_up(Direction::Top));
+        System::assert_last_event(RuntimeEvent::EterraSimpleTCGConfig(TcgEvent::CardLeveledUp {
+            owner: ALICE,
+            card_id: id,
+            side: Direction::Top,
+            rank: 2,
+        }));
+        assert_eq!(EterraSimpleTCGConfig::card_xp(id), 20);
+        assert_noop!(level_up(Direction::Left), Error::<Test>::NotEnoughXp);
+
+        // Commons stop at rank 5, and a side minted above the cap stays where it is.
+        EterraSimpleTCGConfig::award_xp(&ALICE, &[id], 100);
+        assert_noop!(level_up(Direction::Right), Error::<Test>::RankAtRarityCap);
+        assert_noop!(level_up(Direction::Bottom), Error::<Test>::RankAtRarityCap);
+        assert_ok!(level_up(Direction::Left));
+
+        let card = EterraSimpleTCGConfig::cards(id).unwrap();
+        assert_eq!([card.north, card.east, card.south, card.west], [2, 5, 9, 3]);
+        assert_eq!(card.slot_values, Some([2, 5, 9, 3]));
+        assert_eq!(EterraSimpleTCGConfig::card_view(id).unwrap().xp, 90);
+    });
+}
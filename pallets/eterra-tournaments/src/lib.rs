@@ -0,0 +1,548 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Single-elimination tournaments: an organizer opens a bracket of 8, 16 or 32 seats,
+//! players with a current hand register, and the bracket plays itself out. Each match gets
+//! a PvP game through the game pallet's `GameCreator` as soon as both of its players are
+//! known, and results reported back through `GameOutcomeObserver` advance the winner.
+//! Unfilled seats are byes, and drawn or voided games are replayed.
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use pallet_eterra_simple_matchmaker::{CurrentHandProvider, GameCreator, GameOutcomeObserver};
+use sp_std::vec::Vec;
+
+/// Largest bracket an organizer may open.
+pub const MAX_BRACKET_SIZE: u32 = 32;
+
+pub type TournamentId = u32;
+/// Index of a match in its bracket: the first round's matches come first, the final last.
+pub type MatchIndex = u32;
+
+/// Whether `size` is a bracket an organizer may open.
+pub fn is_valid_size(size: u32) -> bool {
+    matches!(size, 8 | 16 | 32)
+}
+
+/// Rounds of a bracket of `size` seats, the final included.
+pub fn rounds(size: u32) -> u32 {
+    size.trailing_zeros()
+}
+
+/// Index of the first match of `round` (0 for the first round).
+pub fn round_start(size: u32, round: u32) -> MatchIndex {
+    size - (size >> round)
+}
+
+/// The match the winner of `index` (in `round`) moves on to, and the slot it takes there.
+/// `None` for the final.
+pub fn next_match(size: u32, round: u32, index: MatchIndex) -> Option<(MatchIndex, usize)> {
+    if round + 1 >= rounds(size) {
+        return None;
+    }
+    let position = index - round_start(size, round);
+    Some((
+        round_start(size, round + 1) + position / 2,
+        (position % 2) as usize,
+    ))
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum TournamentStatus<AccountId> {
+    /// Players may register until the bracket is full or `registration_ends` passed.
+    Registration,
+    Running,
+    Finished {
+        winner: AccountId,
+    },
+    /// Started with fewer than two players.
+    Cancelled,
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Tournament<AccountId, BlockNumber> {
+    pub organizer: AccountId,
+    /// Seats of the bracket: 8, 16 or 32.
+    pub size: u32,
+    /// Last block at which players may register.
+    pub registration_ends: BlockNumber,
+    /// Registered players, seeded in registration order.
+    pub players: BoundedVec<AccountId, ConstU32<MAX_BRACKET_SIZE>>,
+    pub status: TournamentStatus<AccountId>,
+}
+
+/// One side of a bracket match.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Slot<AccountId> {
+    /// Waiting for the winner of an earlier match.
+    Open,
+    Player(AccountId),
+    /// Nobody will fill it; the other side advances without playing.
+    Bye,
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum MatchState<AccountId, GameId> {
+    /// Waiting for a slot to be filled, or for its game to be created (see `start_match`).
+    Pending,
+    Live(GameId),
+    /// `None` when both sides were byes.
+    Decided(Option<AccountId>),
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BracketMatch<AccountId, GameId> {
+    pub round: u32,
+    pub slots: [Slot<AccountId>; 2],
+    pub state: MatchState<AccountId, GameId>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    pub type GameIdOf<T> =
+        <<T as Config>::GameCreator as GameCreator<<T as frame_system::Config>::AccountId>>::GameId;
+    pub type BracketMatchOf<T> = BracketMatch<<T as frame_system::Config>::AccountId, GameIdOf<T>>;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Creates the PvP game of each bracket match
+        type GameCreator: GameCreator<Self::AccountId>;
+        /// Checks that registering players have a current hand
+        type HandProvider: CurrentHandProvider<Self::AccountId>;
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_tournament_id)]
+    pub type NextTournamentId<T: Config> = StorageValue<_, TournamentId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn tournament)]
+    pub type Tournaments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        TournamentId,
+        Tournament<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Bracket matches of a started tournament, see `MatchIndex`.
+    #[pallet::storage]
+    #[pallet::getter(fn bracket_match)]
+    pub type Matches<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TournamentId,
+        Twox64Concat,
+        MatchIndex,
+        BracketMatchOf<T>,
+        OptionQuery,
+    >;
+
+    /// Bracket match each live tournament game decides.
+    #[pallet::storage]
+    pub type MatchOfGame<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameIdOf<T>, (TournamentId, MatchIndex), OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        TournamentCreated {
+            tournament_id: TournamentId,
+            organizer: T::AccountId,
+            size: u32,
+            registration_ends: BlockNumberFor<T>,
+        },
+        Registered {
+            tournament_id: TournamentId,
+            who: T::AccountId,
+        },
+        /// The bracket was seeded with `players` players; first-round games were created.
+        TournamentStarted {
+            tournament_id: TournamentId,
+            players: u32,
+        },
+        TournamentCancelled {
+            tournament_id: TournamentId,
+        },
+        MatchStarted {
+            tournament_id: TournamentId,
+            index: MatchIndex,
+            game_id: GameIdOf<T>,
+        },
+        /// The match's game could not be created; anyone may retry with `start_match`.
+        MatchDelayed {
+            tournament_id: TournamentId,
+            index: MatchIndex,
+        },
+        /// `winner` advances; `None` if both sides were byes.
+        MatchDecided {
+            tournament_id: TournamentId,
+            index: MatchIndex,
+            winner: Option<T::AccountId>,
+        },
+        TournamentWinner {
+            tournament_id: TournamentId,
+            winner: T::AccountId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Brackets have 8, 16 or 32 seats.
+        InvalidBracketSize,
+        /// Registration must end in the future.
+        InvalidSchedule,
+        UnknownTournament,
+        /// The tournament no longer accepts registrations.
+        RegistrationClosed,
+        AlreadyRegistered,
+        /// Every seat of the bracket is taken.
+        TournamentFull,
+        /// Registering requires a current hand.
+        NoPresetHand,
+        /// Only the organizer may start a tournament before registration ends.
+        TournamentNotReady,
+        UnknownMatch,
+        /// The match is not waiting for its game.
+        MatchNotReady,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Open a bracket of `size` seats for registration until `registration_ends`. The
+        /// caller becomes its organizer.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+        pub fn create_tournament(
+            origin: OriginFor<T>,
+            size: u32,
+            registration_ends: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let organizer = ensure_signed(origin)?;
+            ensure!(is_valid_size(size), Error::<T>::InvalidBracketSize);
+            ensure!(
+                registration_ends > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidSchedule
+            );
+            let tournament_id = NextTournamentId::<T>::mutate(|id| {
+                let current = *id;
+                *id = id.wrapping_add(1);
+                current
+            });
+            Tournaments::<T>::insert(
+                tournament_id,
+                Tournament {
+                    organizer: organizer.clone(),
+                    size,
+                    registration_ends,
+                    players: Default::default(),
+                    status: TournamentStatus::Registration,
+                },
+            );
+            Self::deposit_event(Event::TournamentCreated {
+                tournament_id,
+                organizer,
+                size,
+                registration_ends,
+            });
+            Ok(())
+        }
+
+        /// Take a seat in a registering tournament. Requires a current hand. The player
+        /// taking the last seat starts the tournament.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(
+            2,
+            2 + 2 * MAX_BRACKET_SIZE as u64,
+        ))]
+        pub fn register(origin: OriginFor<T>, tournament_id: TournamentId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::HandProvider::has_current_hand(&who),
+                Error::<T>::NoPresetHand
+            );
+            let mut tournament =
+                Tournaments::<T>::get(tournament_id).ok_or(Error::<T>::UnknownTournament)?;
+            ensure!(
+                tournament.status == TournamentStatus::Registration
+                    && frame_system::Pallet::<T>::block_number() <= tournament.registration_ends,
+                Error::<T>::RegistrationClosed
+            );
+            ensure!(
+                !tournament.players.contains(&who),
+                Error::<T>::AlreadyRegistered
+            );
+            ensure!(
+                (tournament.players.len() as u32) < tournament.size,
+                Error::<T>::TournamentFull
+            );
+            tournament
+                .players
+                .try_push(who.clone())
+                .map_err(|_| Error::<T>::TournamentFull)?;
+            let full = tournament.players.len() as u32 == tournament.size;
+            Tournaments::<T>::insert(tournament_id, tournament);
+            Self::deposit_event(Event::Registered { tournament_id, who });
+            if full {
+                Self::start(tournament_id);
+            }
+            Ok(())
+        }
+
+        /// Close registration and seed the bracket. The organizer may start at any time,
+        /// anyone else once registration ended. A tournament with fewer than two players is
+        /// cancelled instead.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1 + 2 * MAX_BRACKET_SIZE as u64))]
+        pub fn start_tournament(
+            origin: OriginFor<T>,
+            tournament_id: TournamentId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let tournament =
+                Tournaments::<T>::get(tournament_id).ok_or(Error::<T>::UnknownTournament)?;
+            ensure!(
+                tournament.status == TournamentStatus::Registration
+                    && (who == tournament.organizer
+                        || frame_system::Pallet::<T>::block_number()
+                            > tournament.registration_ends),
+                Error::<T>::TournamentNotReady
+            );
+            Self::start(tournament_id);
+            Ok(())
+        }
+
+        /// Retry creating the game of a match whose players are both known, after
+        /// `MatchDelayed`. Callable by anyone.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+        pub fn start_match(
+            origin: OriginFor<T>,
+            tournament_id: TournamentId,
+            index: MatchIndex,
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let entry = Matches::<T>::get(tournament_id, index).ok_or(Error::<T>::UnknownMatch)?;
+            let (Slot::Player(a), Slot::Player(b)) = (&entry.slots[0], &entry.slots[1]) else {
+                return Err(Error::<T>::MatchNotReady.into());
+            };
+            ensure!(
+                entry.state == MatchState::Pending,
+                Error::<T>::MatchNotReady
+            );
+            let game_id = T::GameCreator::create_for_tournament(a, b)?;
+            Self::note_match_game(tournament_id, index, entry, game_id);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Seed the registered players into the first round, spreading byes so that every
+        /// first-round match holds at least one player while the roster allows, then create
+        /// the games and advance byes.
+        fn start(tournament_id: TournamentId) {
+            let Some(mut tournament) = Tournaments::<T>::get(tournament_id) else {
+                return;
+            };
+            let players = tournament.players.len() as u32;
+            if players < 2 {
+                tournament.status = TournamentStatus::Cancelled;
+                Tournaments::<T>::insert(tournament_id, tournament);
+                Self::deposit_event(Event::TournamentCancelled { tournament_id });
+                return;
+            }
+            tournament.status = TournamentStatus::Running;
+            let size = tournament.size;
+            let seat = |i: u32| match tournament.players.get(i as usize) {
+                Some(who) => Slot::Player(who.clone()),
+                None => Slot::Bye,
+            };
+            let first_round = size / 2;
+            for index in 0..first_round {
+                Matches::<T>::insert(
+                    tournament_id,
+                    index,
+                    BracketMatch {
+                        round: 0,
+                        slots: [seat(index), seat(first_round + index)],
+                        state: MatchState::Pending,
+                    },
+                );
+            }
+            for round in 1..rounds(size) {
+                for index in round_start(size, round)..round_start(size, round + 1) {
+                    Matches::<T>::insert(
+                        tournament_id,
+                        index,
+                        BracketMatch {
+                            round,
+                            slots: [Slot::Open, Slot::Open],
+                            state: MatchState::Pending,
+                        },
+                    );
+                }
+            }
+            Tournaments::<T>::insert(tournament_id, tournament);
+            Self::deposit_event(Event::TournamentStarted {
+                tournament_id,
+                players,
+            });
+            for index in 0..first_round {
+                Self::play_or_advance(tournament_id, index);
+            }
+        }
+
+        /// Create the game of a match whose slots are both filled, or advance its only
+        /// player when the other side is a bye. Matches still waiting on a slot are left.
+        fn play_or_advance(tournament_id: TournamentId, index: MatchIndex) {
+            let Some(entry) = Matches::<T>::get(tournament_id, index) else {
+                return;
+            };
+            if entry.state != MatchState::Pending {
+                return;
+            }
+            match (&entry.slots[0], &entry.slots[1]) {
+                (Slot::Open, _) | (_, Slot::Open) => {}
+                (Slot::Player(a), Slot::Player(b)) => {
+                    match T::GameCreator::create_for_tournament(a, b) {
+                        Ok(game_id) => Self::note_match_game(tournament_id, index, entry, game_id),
+                        Err(_) => Self::deposit_event(Event::MatchDelayed {
+                            tournament_id,
+                            index,
+                        }),
+                    }
+                }
+                (Slot::Player(who), Slot::Bye) | (Slot::Bye, Slot::Player(who)) => {
+                    let who = who.clone();
+                    Self::decide(tournament_id, index, Some(who))
+                }
+                (Slot::Bye, Slot::Bye) => Self::decide(tournament_id, index, None),
+            }
+        }
+
+        fn note_match_game(
+            tournament_id: TournamentId,
+            index: MatchIndex,
+            mut entry: BracketMatchOf<T>,
+            game_id: GameIdOf<T>,
+        ) {
+            MatchOfGame::<T>::insert(&game_id, (tournament_id, index));
+            entry.state = MatchState::Live(game_id.clone());
+            Matches::<T>::insert(tournament_id, index, entry);
+            Self::deposit_event(Event::MatchStarted {
+                tournament_id,
+                index,
+                game_id,
+            });
+        }
+
+        /// Record the result of a match and move `winner` on, finishing the tournament
+        /// after the final.
+        fn decide(tournament_id: TournamentId, index: MatchIndex, winner: Option<T::AccountId>) {
+            let Some(mut entry) = Matches::<T>::get(tournament_id, index) else {
+                return;
+            };
+            let Some(mut tournament) = Tournaments::<T>::get(tournament_id) else {
+                return;
+            };
+            entry.state = MatchState::Decided(winner.clone());
+            let round = entry.round;
+            Matches::<T>::insert(tournament_id, index, entry);
+            Self::deposit_event(Event::MatchDecided {
+                tournament_id,
+                index,
+                winner: winner.clone(),
+            });
+
+            let Some((next, slot)) = next_match(tournament.size, round, index) else {
+                // Two players always meet by the final, so it never ends on byes.
+                if let Some(winner) = winner {
+                    tournament.status = TournamentStatus::Finished {
+                        winner: winner.clone(),
+                    };
+                    Tournaments::<T>::insert(tournament_id, tournament);
+                    Self::deposit_event(Event::TournamentWinner {
+                        tournament_id,
+                        winner,
+                    });
+                }
+                return;
+            };
+            Matches::<T>::mutate(tournament_id, next, |entry| {
+                if let Some(entry) = entry {
+                    entry.slots[slot] = winner.map_or(Slot::Bye, Slot::Player);
+                }
+            });
+            Self::play_or_advance(tournament_id, next);
+        }
+
+        /// Put a match whose game ended without a winner back to pending and replay it.
+        fn replay(tournament_id: TournamentId, index: MatchIndex) {
+            Matches::<T>::mutate(tournament_id, index, |entry| {
+                if let Some(entry) = entry {
+                    entry.state = MatchState::Pending;
+                }
+            });
+            Self::play_or_advance(tournament_id, index);
+        }
+    }
+
+    impl<T: Config> GameOutcomeObserver<T::AccountId, GameIdOf<T>> for Pallet<T> {
+        /// Advance the winner of a bracket game; a draw is replayed.
+        fn on_game_finished(game_id: &GameIdOf<T>, winner: Option<&T::AccountId>) {
+            let Some((tournament_id, index)) = MatchOfGame::<T>::take(game_id) else {
+                return;
+            };
+            match winner {
+                Some(winner) => Self::decide(tournament_id, index, Some(winner.clone())),
+                None => Self::replay(tournament_id, index),
+            }
+        }
+
+        fn on_game_voided(game_id: &GameIdOf<T>) {
+            if let Some((tournament_id, index)) = MatchOfGame::<T>::take(game_id) {
+                Self::replay(tournament_id, index);
+            }
+        }
+
+        /// `new` takes over `old`'s bracket slot, so a replay is created for them too.
+        fn on_seat_reassigned(game_id: &GameIdOf<T>, old: &T::AccountId, new: &T::AccountId) {
+            let Some((tournament_id, index)) = MatchOfGame::<T>::get(game_id) else {
+                return;
+            };
+            Matches::<T>::mutate(tournament_id, index, |entry| {
+                if let Some(entry) = entry {
+                    for slot in entry.slots.iter_mut() {
+                        if *slot == Slot::Player(old.clone()) {
+                            *slot = Slot::Player(new.clone());
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Every match of a started tournament, in `MatchIndex` order.
+    pub fn bracket(tournament_id: TournamentId) -> Vec<BracketMatchOf<T>> {
+        let Some(tournament) = Tournaments::<T>::get(tournament_id) else {
+            return Vec::new();
+        };
+        (0..tournament.size.saturating_sub(1))
+            .filter_map(|index| Matches::<T>::get(tournament_id, index))
+            .collect()
+    }
+}
@@ -0,0 +1,120 @@
+//! Mock runtime for pallet-eterra-tournaments tests.
+#![cfg(test)]
+
+use crate as pallet_eterra_tournaments;
+use frame_support::{
+    construct_runtime,
+    traits::{ConstU32, ConstU64, Everything},
+};
+use pallet_eterra_simple_matchmaker::{CurrentHandProvider, GameCreator, HandSummary};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{BuildStorage, DispatchError};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeSet;
+
+pub type AccountId = u64;
+
+pub const ORGANIZER: AccountId = 100;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Tournaments: pallet_eterra_tournaments,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = ();
+    type Nonce = u64;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+thread_local! {
+    static NO_HAND: RefCell<BTreeSet<AccountId>> = Default::default();
+    static CREATED_GAMES: RefCell<Vec<(AccountId, AccountId)>> = Default::default();
+    static FAIL_CREATION: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Test-only hands: every account has one unless `remove_hand` was called.
+pub struct MockHandProvider;
+impl CurrentHandProvider<AccountId> for MockHandProvider {
+    fn has_current_hand(who: &AccountId) -> bool {
+        !NO_HAND.with(|s| s.borrow().contains(who))
+    }
+    fn hand_summary(_who: &AccountId) -> Option<HandSummary> {
+        None
+    }
+}
+
+pub fn remove_hand(who: AccountId) {
+    NO_HAND.with(|s| s.borrow_mut().insert(who));
+}
+
+/// Test-only game creator: game ids are the 0-based position in `created_games`.
+pub struct MockGameCreator;
+impl GameCreator<AccountId> for MockGameCreator {
+    type GameId = u32;
+
+    fn create_from_matchmaking(a: &AccountId, b: &AccountId) -> Result<u32, DispatchError> {
+        if FAIL_CREATION.with(|f| f.get()) {
+            return Err(DispatchError::Other("game creation disabled"));
+        }
+        CREATED_GAMES.with(|v| {
+            let mut v = v.borrow_mut();
+            v.push((*a, *b));
+            Ok(v.len() as u32 - 1)
+        })
+    }
+}
+
+pub fn created_games() -> Vec<(AccountId, AccountId)> {
+    CREATED_GAMES.with(|v| v.borrow().clone())
+}
+
+pub fn fail_game_creation(fail: bool) {
+    FAIL_CREATION.with(|f| f.set(fail));
+}
+
+impl pallet_eterra_tournaments::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type GameCreator = MockGameCreator;
+    type HandProvider = MockHandProvider;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,152 @@
+//! Unit tests for pallet-eterra-tournaments.
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::{next_match, rounds, Error, Event, MatchState, Slot, TournamentStatus};
+use frame_support::{assert_noop, assert_ok};
+use pallet_eterra_simple_matchmaker::GameOutcomeObserver;
+
+fn finish(game_id: u32, winner: Option<AccountId>) {
+    <Tournaments as GameOutcomeObserver<AccountId, u32>>::on_game_finished(
+        &game_id,
+        winner.as_ref(),
+    );
+}
+
+#[test]
+fn winners_move_to_the_next_round_until_the_final() {
+    assert_eq!(rounds(8), 3);
+    assert_eq!(next_match(8, 0, 0), Some((4, 0)));
+    assert_eq!(next_match(8, 0, 3), Some((5, 1)));
+    assert_eq!(next_match(8, 1, 5), Some((6, 1)));
+    assert_eq!(next_match(8, 2, 6), None);
+    assert_eq!(next_match(32, 0, 15), Some((23, 1)));
+    assert_eq!(next_match(32, 3, 29), Some((30, 1)));
+}
+
+#[test]
+fn bracket_advances_through_byes_and_replays_draws() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Tournaments::create_tournament(RuntimeOrigin::signed(ORGANIZER), 12, 10),
+            Error::<Test>::InvalidBracketSize
+        );
+        assert_ok!(Tournaments::create_tournament(
+            RuntimeOrigin::signed(ORGANIZER),
+            8,
+            10
+        ));
+        remove_hand(6);
+        assert_noop!(
+            Tournaments::register(RuntimeOrigin::signed(6), 0),
+            Error::<Test>::NoPresetHand
+        );
+        for who in 1..=5 {
+            assert_ok!(Tournaments::register(RuntimeOrigin::signed(who), 0));
+        }
+        assert_noop!(
+            Tournaments::register(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::AlreadyRegistered
+        );
+        assert_noop!(
+            Tournaments::start_tournament(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::TournamentNotReady
+        );
+
+        // Five players in eight seats: 1 meets 5, and 2, 3 and 4 get first-round byes.
+        assert_ok!(Tournaments::start_tournament(
+            RuntimeOrigin::signed(ORGANIZER),
+            0
+        ));
+        assert_eq!(created_games(), vec![(1, 5), (3, 4)]);
+        assert_eq!(
+            Tournaments::bracket_match(0, 4).unwrap().slots,
+            [Slot::Open, Slot::Player(2)]
+        );
+
+        finish(0, Some(5));
+        assert_eq!(created_games().last(), Some(&(5, 2)));
+        // A draw is replayed between the same players.
+        finish(1, None);
+        assert_eq!(created_games().last(), Some(&(3, 4)));
+        assert_eq!(
+            Tournaments::bracket_match(0, 5).unwrap().state,
+            MatchState::Live(3)
+        );
+        finish(3, Some(4));
+        finish(2, Some(2));
+        assert_eq!(created_games().last(), Some(&(2, 4)));
+
+        finish(4, Some(4));
+        System::assert_last_event(
+            Event::TournamentWinner {
+                tournament_id: 0,
+                winner: 4,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Tournaments::tournament(0).unwrap().status,
+            TournamentStatus::Finished { winner: 4 }
+        );
+        assert_eq!(Tournaments::bracket(0).len(), 7);
+    });
+}
+
+#[test]
+fn a_full_bracket_starts_itself_and_retries_delayed_games() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Tournaments::create_tournament(
+            RuntimeOrigin::signed(ORGANIZER),
+            8,
+            10
+        ));
+        fail_game_creation(true);
+        for who in 1..=8 {
+            assert_ok!(Tournaments::register(RuntimeOrigin::signed(who), 0));
+        }
+        assert_eq!(
+            Tournaments::tournament(0).unwrap().status,
+            TournamentStatus::Running
+        );
+        System::assert_has_event(
+            Event::MatchDelayed {
+                tournament_id: 0,
+                index: 3,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Tournaments::start_match(RuntimeOrigin::signed(9), 0, 4),
+            Error::<Test>::MatchNotReady
+        );
+
+        fail_game_creation(false);
+        assert_ok!(Tournaments::start_match(RuntimeOrigin::signed(9), 0, 3));
+        assert_eq!(created_games(), vec![(4, 8)]);
+        assert_noop!(
+            Tournaments::start_match(RuntimeOrigin::signed(9), 0, 3),
+            Error::<Test>::MatchNotReady
+        );
+    });
+}
+
+#[test]
+fn tournaments_without_two_players_are_cancelled() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Tournaments::create_tournament(
+            RuntimeOrigin::signed(ORGANIZER),
+            16,
+            10
+        ));
+        assert_ok!(Tournaments::register(RuntimeOrigin::signed(1), 0));
+        System::set_block_number(11);
+        assert_noop!(
+            Tournaments::register(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::RegistrationClosed
+        );
+        assert_ok!(Tournaments::start_tournament(RuntimeOrigin::signed(2), 0));
+        System::assert_last_event(Event::TournamentCancelled { tournament_id: 0 }.into());
+        assert!(Tournaments::bracket(0).is_empty());
+    });
+}
@@ -0,0 +1,143 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Read APIs of the game pallet. Clients should read through these rather than storage or
+//! the pallet's `#[pallet::getter]` helpers, which follow the storage layout and may change
+//! with it. Signatures here only change behind a bumped `#[api_version]`.
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+pub use pallet_eterra::{
+    AiAction, AiState, GameContext, GameMode, GameView, MoveTiming, OpponentHandSummary,
+    ReplayMove,
+};
+
+/// Chain-wide counters for an ops dashboard, read in a single call.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct SystemSnapshot<Balance> {
+    /// Games still being played, per mode.
+    pub live_games: Vec<(GameMode, u32)>,
+    /// Accounts waiting in the matchmaking queue.
+    pub queue_size: u32,
+    /// Cards minted so far.
+    pub cards_minted: u32,
+    /// Open marketplace listings.
+    pub listings: u32,
+    /// Free balance of the faucet account (0 if none is configured).
+    pub faucet_balance: Balance,
+    /// Daily slots tickets awaiting the next drawing.
+    pub tickets_outstanding: u32,
+    /// PvE games whose AI turn is deferred to a later block.
+    pub pending_ai_turns: u32,
+}
+
+/// A page of a player's game history, see `EterraApi::player_games`.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct PlayerGamesPage<GameId> {
+    pub games: Vec<GameId>,
+    /// Cursor of the next, older page; `None` once the oldest page was returned.
+    pub next_cursor: Option<u32>,
+}
+
+/// Gameplay constants of the running runtime, which differ between dev, test and
+/// production chains.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct GameConstants<Balance> {
+    /// Cards in a hand.
+    pub hand_size: u32,
+    /// Rounds in a game.
+    pub max_rounds: u8,
+    /// Blocks a player has to move before the opponent may force-finish the turn.
+    pub blocks_to_play_limit: u8,
+    /// Width and height of the board.
+    pub board_size: u8,
+    /// Current price of minting a common card.
+    pub mint_fee: Balance,
+    /// Daily slots rolls an account may make per day window.
+    pub max_rolls_per_round: u32,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Game audits, so clients can prove a transcript matches an on-chain result.
+    pub trait EterraApi<GameId, AccountId>
+    where
+        GameId: Codec,
+        AccountId: Codec,
+    {
+        /// Whether replaying `moves` from the game's recorded start reproduces its stored
+        /// board and scores.
+        fn verify_replay(game_id: GameId, moves: Vec<ReplayMove>) -> bool;
+
+        /// The other player's hand as `viewer` may see it, with stats hidden in closed-hand
+        /// games.
+        fn opponent_hand_summary(game_id: GameId, viewer: AccountId)
+            -> Option<OpponentHandSummary>;
+
+        /// One page of `who`'s game history, most recent first, starting at page `cursor`
+        /// (the newest page when `None`). Pass `next_cursor` back to read older games.
+        fn player_games(who: AccountId, cursor: Option<u32>) -> PlayerGamesPage<GameId>;
+    }
+
+    /// Live game state for clients, in shapes that stay put when storage is reorganized.
+    pub trait GameViewApi<GameId, AccountId, BlockNumber>
+    where
+        GameId: Codec,
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// A game, or `None` if it does not exist (never created, voided or pruned).
+        fn game(game_id: GameId) -> Option<GameView<AccountId, BlockNumber>>;
+
+        /// The games `who` is playing, with the context each occupies.
+        fn active_games(who: AccountId) -> Vec<(GameContext, GameId)>;
+
+        /// Card ids of `who`'s current hand, empty if none is set.
+        fn current_hand(who: AccountId) -> Vec<u32>;
+    }
+
+    /// Health counters across the game, matchmaking and economy pallets, so monitoring
+    /// scripts need one call instead of a query per storage item.
+    pub trait DashboardApi<Balance>
+    where
+        Balance: Codec,
+    {
+        fn system_snapshot() -> SystemSnapshot<Balance>;
+    }
+
+    /// Every gameplay-relevant constant in one call, so clients never hard-code them.
+    pub trait GameConstantsApi<Balance>
+    where
+        Balance: Codec,
+    {
+        fn game_constants() -> GameConstants<Balance>;
+    }
+
+    /// Move timing statistics for moderators reviewing ranked accounts for bot assistance.
+    pub trait ModerationApi<GameId, AccountId>
+    where
+        GameId: Codec,
+        AccountId: Codec,
+    {
+        /// Ranked move timing of `who` across all of their games.
+        fn move_timing(who: AccountId) -> MoveTiming;
+
+        /// Ranked move timing of each human player of a game, until its hands are pruned.
+        fn game_move_timings(game_id: GameId) -> Vec<(AccountId, MoveTiming)>;
+    }
+
+    /// Move hints from the Monte-Carlo AI, so UIs can offer one without submitting the AI
+    /// pallet's `suggest_move` extrinsic. `difficulty` is 0..=100; higher values search
+    /// longer and are capped at 100.
+    pub trait AiHintApi<GameId>
+    where
+        GameId: Codec,
+    {
+        /// The move the AI suggests from an arbitrary `state`, `None` if there is none.
+        fn suggest_move(state: AiState, difficulty: u8) -> Option<AiAction>;
+
+        /// The move the AI suggests to whoever is to move in `game_id`, `None` if the game
+        /// is not waiting for a move.
+        fn suggest_for_game(game_id: GameId, difficulty: u8) -> Option<AiAction>;
+    }
+}
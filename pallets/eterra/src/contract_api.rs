@@ -0,0 +1,54 @@
+//! Game operations for smart contracts. A `pallet-contracts` chain extension can route the
+//! function ids below to `GameContractApi`, decoding the SCALE-encoded arguments and encoding
+//! the results, so contract-based tournaments and bots never depend on pallet storage layouts.
+
+use crate::types::board::Board;
+use crate::types::game::GameState;
+use frame_support::pallet_prelude::*;
+use sp_std::vec::Vec;
+
+/// Chain extension function ids, one per `GameContractApi` method. Ids are never reused.
+pub mod func_id {
+    /// `game_state(game_id) -> Option<ContractGameView>`
+    pub const GAME_STATE: u16 = 0x0001;
+    /// `create_pve_game() -> Result<GameId, DispatchError>`, for the calling contract
+    pub const CREATE_PVE_GAME: u16 = 0x0002;
+    /// `card_stats(card_id) -> Option<ContractCardStats>`
+    pub const CARD_STATS: u16 = 0x0003;
+}
+
+/// A game as seen by a contract.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, RuntimeDebug)]
+pub struct ContractGameView<AccountId> {
+    pub state: GameState,
+    pub players: Vec<AccountId>,
+    /// Seat whose turn it is, indexing `players`.
+    pub player_turn: u8,
+    pub round: u8,
+    pub max_rounds: u8,
+    pub board: Board,
+    pub scores: (u8, u8),
+}
+
+/// The playable stats of a card as seen by a contract.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ContractCardStats<AccountId> {
+    pub owner: AccountId,
+    pub north: u8,
+    pub east: u8,
+    pub south: u8,
+    pub west: u8,
+}
+
+/// Reads and writes a contract may perform on the game. Writes act on behalf of `caller`
+/// (the contract account) and run the same checks as the matching extrinsic.
+pub trait GameContractApi<AccountId> {
+    type GameId;
+
+    /// Current state of a game, or `None` if it does not exist.
+    fn game_state(game_id: &Self::GameId) -> Option<ContractGameView<AccountId>>;
+    /// Start a PvE game for `caller` against an AI picked by level, like `create_game`.
+    fn create_pve_game(caller: &AccountId) -> Result<Self::GameId, DispatchError>;
+    /// Stats of a card, or `None` if it does not exist.
+    fn card_stats(card_id: u32) -> Option<ContractCardStats<AccountId>>;
+}
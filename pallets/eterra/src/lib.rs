@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -8,9 +10,13 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod contract_api;
 mod types;
+pub mod webhooks;
 
 pub use crate::types::GameId;
+use frame_support::dispatch::PostDispatchInfo;
+use frame_support::weights::Weight;
 use frame_support::ensure;
 use frame_support::pallet_prelude::ConstU32;
 use frame_support::traits::Get;
@@ -19,6 +25,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::Encode;
 use sp_runtime::traits::Hash;
 use sp_runtime::traits::SaturatedConversion;
+use sp_runtime::traits::Zero;
 use sp_std::vec::Vec;
 pub use types::board::Board;
 pub use types::card::Card;
@@ -26,7 +33,149 @@ pub use types::card::Possession as Player; // PlayerOne / PlayerTwo
 pub use types::game::*;
 
 use eterra_card_ai_adapter::eterra_adapter as ai;
+pub use eterra_card_ai_adapter::eterra_adapter::{Action as AiAction, State as AiState};
+use eterra_core_types::play::{self, PlaceError};
+use eterra_core_types::geometry;
+use eterra_core_types::rating;
+use eterra_core_types::result::{GameResult, SeatResult};
+use eterra_core_types::rules::{
+    energy_cost, final_scores, regenerate_energy, spend_energy, Rules, ENERGY_START,
+};
 use pallet_eterra_monte_carlo_ai as mc_ai; // reserved for future use
+use pallet_eterra_simple_matchmaker::GameOutcomeObserver;
+
+/// Source of a player's progression level, used to auto-select an AI opponent in PvE.
+/// The runtime implements this by delegating to the gamer/profile pallet.
+pub trait PlayerLevelProvider<AccountId> {
+    /// Current level of `who` (0 when unknown).
+    fn level_of(who: &AccountId) -> u8;
+}
+
+impl<AccountId> PlayerLevelProvider<AccountId> for () {
+    fn level_of(_who: &AccountId) -> u8 {
+        0
+    }
+}
+
+/// Receives per-player card usage once a game ends, e.g. to award card XP.
+/// Unused cards are reported separately so they can be excluded from rewards.
+pub trait CardUsageObserver<AccountId> {
+    fn on_cards_resolved(owner: &AccountId, used: &[u32], unused: &[u32], won: bool);
+}
+
+impl<AccountId> CardUsageObserver<AccountId> for () {
+    fn on_cards_resolved(_owner: &AccountId, _used: &[u32], _unused: &[u32], _won: bool) {}
+}
+
+/// Notified once per human player whenever a game they took part in ends, e.g. to pay
+/// activity-based faucet drips or to track onboarding progress.
+pub trait GameActivityObserver<AccountId> {
+    /// `rounds` is the number of rounds the game reached before it ended.
+    fn on_game_completed(who: &AccountId, context: GameContext, rounds: u8);
+}
+
+impl<AccountId> GameActivityObserver<AccountId> for () {
+    fn on_game_completed(_who: &AccountId, _context: GameContext, _rounds: u8) {}
+}
+
+/// Notified when an account's win streak reaches a multiple of `StreakMilestone`, e.g. to
+/// award experience.
+pub trait StreakMilestoneObserver<AccountId> {
+    fn on_streak_milestone(who: &AccountId, streak: u32);
+}
+
+impl<AccountId> StreakMilestoneObserver<AccountId> for () {
+    fn on_streak_milestone(_who: &AccountId, _streak: u32) {}
+}
+
+/// Notified with the seats, scores and winner of every finished game, e.g. to settle
+/// community bounties.
+pub trait GameResultObserver<AccountId> {
+    fn on_game_result(result: &GameResult<AccountId>);
+    /// Upper bound of `on_game_result` for a game played out on the board, charged by every
+    /// move that may complete one.
+    fn max_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId> GameResultObserver<AccountId> for () {
+    fn on_game_result(_result: &GameResult<AccountId>) {}
+}
+
+/// Removes an account's presence from pallets the game pallet does not own, for `deregister`.
+/// The runtime implements this by delegating to the matchmaker and gamer/profile pallets.
+pub trait PresenceCleanup<AccountId> {
+    /// Take `who` out of the matchmaking queue; returns whether they were queued.
+    fn leave_queue(who: &AccountId) -> bool;
+    /// Delete `who`'s public profile (tag/avatar); returns whether anything was removed.
+    fn burn_profile(who: &AccountId) -> bool;
+}
+
+impl<AccountId> PresenceCleanup<AccountId> for () {
+    fn leave_queue(_who: &AccountId) -> bool {
+        false
+    }
+    fn burn_profile(_who: &AccountId) -> bool {
+        false
+    }
+}
+
+/// ~24h with 6s blocks; the window used by `MaxRatedGamesPerDay`.
+const BLOCKS_PER_DAY: u64 = 14_400;
+
+/// Share (percent) of the difficulty-scaled Monte-Carlo budget the AI spends on a move,
+/// indexed by the number of empty board cells. Opening moves are spread across many
+/// near-equivalent options and need less precision; late moves have so few options that the
+/// full budget approaches an exhaustive search. Entries never exceed 100, so the worst-case
+/// cost (and `AiTurnWeight`) is unchanged.
+pub const AI_ITERATION_CURVE: [u8; 17] =
+    [100, 100, 100, 100, 100, 100, 100, 100, 90, 80, 70, 60, 55, 50, 45, 40, 35];
+
+/// Weight of a move or hand submission that does not run the AI search.
+pub const MOVE_BASE_WEIGHT: Weight = Weight::from_parts(10_000, 0);
+
+/// Weight of a placement that flips no cards and does not run the AI search: no neighbour
+/// is rewritten and no score changes.
+pub const SIMPLE_PLACEMENT_WEIGHT: Weight = Weight::from_parts(6_000, 0);
+
+/// Post-dispatch info for calls charged `MOVE_BASE_WEIGHT + AiTurnWeight` (and the result
+/// observers' `max_weight`) up front:
+/// keep the full charge when the AI ran or the game ended, otherwise refund down to the
+/// base weight.
+fn move_post_info(ai_ran: bool) -> PostDispatchInfo {
+    let actual = if ai_ran { None } else { Some(MOVE_BASE_WEIGHT) };
+    PostDispatchInfo {
+        actual_weight: actual,
+        pays_fee: frame_support::dispatch::Pays::Yes,
+    }
+}
+
+/// What a placement did, which decides how much of its up-front weight is refunded.
+#[derive(Clone, Copy, Default)]
+struct MoveOutcome {
+    /// The placed card flipped at least one neighbour.
+    captured: bool,
+    /// The AI answered the move inline.
+    ai_ran: bool,
+    /// The move ended the game, so the result observers ran.
+    finished: bool,
+}
+
+impl MoveOutcome {
+    fn post_info(self) -> PostDispatchInfo {
+        if self.finished {
+            return move_post_info(true);
+        }
+        if self.captured || self.ai_ran {
+            return move_post_info(self.ai_ran);
+        }
+        PostDispatchInfo {
+            actual_weight: Some(SIMPLE_PLACEMENT_WEIGHT),
+            pays_fee: frame_support::dispatch::Pays::Yes,
+        }
+    }
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -51,12 +200,17 @@ pub mod pallet {
     use pallet_eterra_monte_carlo_ai as mc_ai;
     use pallet_eterra_simple_tcg as cards; // reserved for future use
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
     pub trait Config: frame_system::Config + cards::pallet::Config + mc_ai::pallet::Config {
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self>>
+            + TryInto<Event<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         // Exact number of players that can join a single game
         #[pallet::constant]
         type NumPlayers: Get<u32> + Clone + TypeInfo;
@@ -67,10 +221,101 @@ pub mod pallet {
         /// Exactly how many cards a submitted hand must contain
         #[pallet::constant]
         type HandSize: Get<u32>;
-        /// Special account representing the AI opponent in PvE games
-        type AiAccount: Get<Self::AccountId>;
-        /// Default AI difficulty (0..=100)
+        /// Default AI difficulty (0..=100) for AI identities seeded at genesis
         type AiDifficulty: Get<u8>;
+        /// Player level lookup used to auto-select an AI identity for PvE games
+        type PlayerLevel: crate::PlayerLevelProvider<Self::AccountId>;
+        /// Hook notified with used/unused card ids per player when a game ends
+        type CardUsage: crate::CardUsageObserver<Self::AccountId>;
+        /// Hook notified with the winner whenever a game ends (drives matchmaker series)
+        type GameOutcome: pallet_eterra_simple_matchmaker::GameOutcomeObserver<
+            Self::AccountId,
+            GameId<Self>,
+        >;
+        /// Minimum player level gap at which PvP games get a handicap (0 disables handicaps)
+        #[pallet::constant]
+        type HandicapLevelGap: Get<u8>;
+        /// When non-zero, handicapped games cap the stronger player's hand at this total of
+        /// edge ranks instead of giving the weaker player a one point head start
+        #[pallet::constant]
+        type HandicapHandBudget: Get<u16>;
+        /// Extra weight charged up front for calls that may run the AI's Monte-Carlo search
+        /// (PvE moves and hand submission); refunded when the AI does not act
+        #[pallet::constant]
+        type AiTurnWeight: Get<Weight>;
+        /// Queue/profile cleanup performed by `deregister`
+        type PresenceCleanup: crate::PresenceCleanup<Self::AccountId>;
+        /// Origin allowed to manage AI identities (e.g. Root or a council)
+        type GameAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to void corrupted or exploited games (`void_game`)
+        type ModeratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Optional rules (`eterra_core_types::rules::Rules` bitflags) applied to new games
+        #[pallet::constant]
+        type DefaultRules: Get<u8>;
+        /// Non-tournament games an account may complete per day; further games are refused
+        /// until the next day window (0 disables the cap)
+        #[pallet::constant]
+        type MaxRatedGamesPerDay: Get<u32>;
+        /// Hook notified for every human participant when a game ends (drives faucet drips)
+        type GameActivity: crate::GameActivityObserver<Self::AccountId>;
+        /// Maximum spectators counted per live game
+        #[pallet::constant]
+        type MaxSpectators: Get<u32>;
+        /// AI moves played per block. PvE turns of the AI wait in `PendingAiTurns` and are
+        /// played in `on_initialize` of the next blocks, so the human's call never pays for
+        /// the search (0 plays AI turns inline instead, inside the human's call)
+        #[pallet::constant]
+        type MaxAiMovesPerBlock: Get<u32>;
+        /// Capacity of `PendingAiTurns`; once full, AI turns run inline again
+        #[pallet::constant]
+        type MaxPendingAiTurns: Get<u32>;
+        /// Win streak length whose multiples are reported to `StreakRewards` (0 disables)
+        #[pallet::constant]
+        type StreakMilestone: Get<u32>;
+        /// Hook notified when a win streak reaches a milestone (drives streak XP)
+        type StreakRewards: crate::StreakMilestoneObserver<Self::AccountId>;
+        /// Move annotations kept per game, across both players
+        #[pallet::constant]
+        type MaxMoveAnnotations: Get<u32>;
+        /// Hook notified with the full result of every finished game (drives bounties)
+        type GameResults: crate::GameResultObserver<Self::AccountId>;
+        /// Emit `AiSearchStats` with per-action playout statistics for every AI move, for
+        /// offline tuning of heuristics and difficulty curves (development chains only)
+        #[pallet::constant]
+        type DevTelemetry: Get<bool>;
+        /// Blocks a published featured hand stays available to borrow
+        #[pallet::constant]
+        type FeaturedHandDuration: Get<BlockNumberFor<Self>>;
+        /// Blocks after creation both players of a PvP game have to submit their hands
+        /// before the one who did may claim a walkover
+        #[pallet::constant]
+        type HandSubmissionPeriod: Get<BlockNumberFor<Self>>;
+        /// Blocks a finished game's `MoveHistory` is kept before anyone may prune it
+        #[pallet::constant]
+        type MoveHistoryRetention: Get<BlockNumberFor<Self>>;
+        /// Blocks without a move after which a playing game is expired from `on_idle`;
+        /// zero disables expiry
+        #[pallet::constant]
+        type AbandonAfterBlocks: Get<BlockNumberFor<Self>>;
+        /// Games `on_idle` looks at per block when searching for abandoned ones
+        #[pallet::constant]
+        type MaxExpiryScansPerBlock: Get<u32>;
+        /// Named decks each account may keep in `Decks`
+        #[pallet::constant]
+        type MaxDecks: Get<u32>;
+        /// Blocks without a finished ranked game after which a rating loses `RatingDecay`,
+        /// once per such period; zero disables decay
+        #[pallet::constant]
+        type DecayPeriodBlocks: Get<BlockNumberFor<Self>>;
+        /// Rating points lost per idle `DecayPeriodBlocks`
+        #[pallet::constant]
+        type RatingDecay: Get<u32>;
+        /// Rating decay never takes a player below
+        #[pallet::constant]
+        type RatingDecayFloor: Get<u32>;
+        /// Rated accounts `on_idle` looks at per block when decaying idle ratings
+        #[pallet::constant]
+        type MaxDecayScansPerBlock: Get<u32>;
     }
 
     #[pallet::storage]
@@ -82,10 +327,165 @@ pub mod pallet {
         Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>, // Store the complete game struct
     >;
 
+    /// Number of accounts currently watching a live game; removed when the game ends.
+    #[pallet::storage]
+    #[pallet::getter(fn spectator_count)]
+    pub type SpectatorCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, u32, OptionQuery>;
+
+    /// The one game each account is watching, so counts can't be inflated by repeat calls.
+    #[pallet::storage]
+    #[pallet::getter(fn watching)]
+    pub type WatchingOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, GameId<T>, OptionQuery>;
+
+    /// Accounts watching each live game, so their `WatchingOf` entries are cleared with it.
+    #[pallet::storage]
+    pub type WatchersOf<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// FIFO of PvE games whose AI turn waits for `on_initialize`.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_ai_turns)]
+    pub type PendingAiTurns<T: Config> =
+        StorageValue<_, BoundedVec<GameId<T>, T::MaxPendingAiTurns>, ValueQuery>;
+
+    /// AI turns on which the search gave no playable move and the fallback placement was used.
+    #[pallet::storage]
+    #[pallet::getter(fn ai_move_failures)]
+    pub type AiMoveFailures<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Deepest `PendingAiTurns` has been, for monitoring.
+    #[pallet::storage]
+    #[pallet::getter(fn peak_ai_backlog)]
+    pub type PeakAiBacklog<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Consecutive wins of an account outside PvE.
+    #[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct WinStreak {
+        pub current: u32,
+        pub best: u32,
+    }
+
+    /// Current and best win streak per account. Losses and draws reset `current`; PvE games
+    /// are ignored so streaks cannot be farmed against the AI.
+    #[pallet::storage]
+    #[pallet::getter(fn win_streak)]
+    pub type WinStreaks<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, WinStreak, ValueQuery>;
+
+    /// Predefined reactions a player can attach to their own move. There is deliberately no
+    /// free-text variant, so nothing user-written ends up in storage.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum MoveAnnotation {
+        GoodGame,
+        NiceMove,
+        Thinking,
+        Oops,
+        WellPlayed,
+    }
+
+    /// An annotation in a game's move log. `move_no` is the number of cards on the board
+    /// once the annotated move was made (1 for the opening move).
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct AnnotatedMove {
+        pub seat: u8,
+        pub move_no: u8,
+        pub annotation: MoveAnnotation,
+    }
+
+    /// Annotations attached to a game's moves, in the order they were made. Kept after the
+    /// game ends so replays can show them.
+    #[pallet::storage]
+    #[pallet::getter(fn move_annotations)]
+    pub type MoveAnnotations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        BoundedVec<AnnotatedMove, T::MaxMoveAnnotations>,
+        ValueQuery,
+    >;
+
+    /// What a seat did on its turn, as recorded in `MoveHistory`.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum RecordedAction {
+        /// A card with edge ranks (north, east, south, west) was placed at (x, y).
+        /// `hand_index` is `None` for free-form cards played through `play`.
+        Place {
+            hand_index: Option<u8>,
+            card: [u8; 4],
+            x: u8,
+            y: u8,
+        },
+        /// The turn was force-finished without a card being placed.
+        Skip,
+    }
+
+    /// One turn of a game's `MoveHistory`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct RecordedMove<BlockNumber> {
+        pub seat: u8,
+        pub block: BlockNumber,
+        pub action: RecordedAction,
+    }
+
+    /// Turns `MoveHistory` keeps per game: two per round, for the most rounds a game has.
+    pub const MAX_RECORDED_MOVES: u32 = 2 * u8::MAX as u32;
+
+    /// Every turn of a game in the order it was taken, so clients can replay the game
+    /// after it ends. Kept `MoveHistoryRetention` blocks past the end of the game, after
+    /// which anyone may remove it with `prune_move_history`.
+    #[pallet::storage]
+    #[pallet::getter(fn move_history)]
+    pub type MoveHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        BoundedVec<RecordedMove<BlockNumberFor<T>>, ConstU32<MAX_RECORDED_MOVES>>,
+        ValueQuery,
+    >;
+
+    /// Block from which the `MoveHistory` of a finished game may be pruned.
+    #[pallet::storage]
+    pub type MoveHistoryExpiry<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, BlockNumberFor<T>, OptionQuery>;
+
+    /// Last game visited by `migrate_games`; the next batch resumes after it.
+    #[pallet::storage]
+    pub type GameMigrationCursor<T: Config> = StorageValue<_, GameId<T>, OptionQuery>;
+
+    /// Live-game indexes a storage upgrade left for `migrate_games` to rebuild from the games
+    /// stored before it. `LiveGameIds` is always rebuilt.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct PendingReindex {
+        /// `ActiveGameOf` became keyed by (account, context) in storage version 1; the legacy
+        /// markers are dropped and rebuilt.
+        pub markers: bool,
+        /// `LiveGames` was added in storage version 2 and has to be seeded.
+        pub live_counts: bool,
+    }
+
+    /// Index rebuilds still pending for `migrate_games`, set by `on_runtime_upgrade`.
+    #[pallet::storage]
+    pub type GameReindex<T: Config> = StorageValue<_, PendingReindex, OptionQuery>;
+
+    /// Last game looked at by the abandoned-game scan; the next block resumes after it.
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, GameId<T>, OptionQuery>;
+
     #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
     pub enum GameMode {
         PvP,
         PvE,
+        /// Human challenger vs a registered open bot whose moves are answered by its controller.
+        Bot,
     }
 
     #[pallet::storage]
@@ -93,853 +493,3441 @@ pub mod pallet {
     pub type GameModes<T: Config> =
         StorageMap<_, Blake2_128Concat, GameId<T>, GameMode, OptionQuery>;
 
+    /// Games still being played, per mode. Kept for monitoring so live games need not be
+    /// counted by iterating `GameStorage`.
     #[pallet::storage]
-    #[pallet::getter(fn active_game_of)]
-    /// Tracks if an account is currently in an active game. A player may have at most one.
-    pub type ActiveGameOf<T: Config> =
-        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, GameId<T>, OptionQuery>;
+    #[pallet::getter(fn live_games)]
+    pub type LiveGames<T: Config> = StorageMap<_, Twox64Concat, GameMode, u32, ValueQuery>;
+
+    /// Ids of the games still being played, walked by the abandoned-game scan instead of
+    /// the finished games kept in `GameStorage`.
+    #[pallet::storage]
+    pub type LiveGameIds<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, (), OptionQuery>;
+
+    /// Board dimension of new games of each mode, set with `set_board_dim`. Modes without
+    /// an entry play on `BOARD_DIM` x `BOARD_DIM` boards.
+    #[pallet::storage]
+    #[pallet::getter(fn board_dim_of)]
+    pub type ModeBoardDims<T: Config> = StorageMap<_, Twox64Concat, GameMode, u8, OptionQuery>;
+
+    /// Optional rules of new games of each mode, set with `set_mode_rules`. Modes without an
+    /// entry use `DefaultRules`.
+    #[pallet::storage]
+    #[pallet::getter(fn rules_of)]
+    pub type ModeRules<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        GameMode,
+        eterra_core_types::rules::Rules,
+        OptionQuery,
+    >;
+
+    /// Queue/context a game belongs to. The one-active-game rule is enforced per context,
+    /// so e.g. a tournament match does not block casual PvE play.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum GameContext {
+        Ranked,
+        Casual,
+        PvE,
+        Tournament,
+    }
+
+    impl GameMode {
+        /// Context used for games created directly through `create_game`.
+        pub fn default_context(&self) -> GameContext {
+            match self {
+                GameMode::PvP | GameMode::Bot => GameContext::Casual,
+                GameMode::PvE => GameContext::PvE,
+            }
+        }
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn game_context_of)]
+    pub type GameContexts<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, GameContext, OptionQuery>;
 
-    /// Recent games for each player (most-recent first, bounded).
     #[pallet::storage]
-    #[pallet::getter(fn player_games)]
-    pub type PlayerGames<T: Config> = StorageMap<
+    #[pallet::getter(fn active_game_of)]
+    /// Tracks if an account is currently in an active game. A player may have at most one per context.
+    pub type ActiveGameOf<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         AccountIdOf<T>,
-        BoundedVec<GameId<T>, ConstU32<10>>, // keep last 10
-        ValueQuery,
+        Blake2_128Concat,
+        GameContext,
+        GameId<T>,
+        OptionQuery,
     >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        GameCreated {
-            game_id: GameId<T>,
+    /// Why a game ended. Shared with result observers through `GameResult`.
+    pub use eterra_core_types::result::EndReason;
+
+    /// What an account owes, as reported by `Event::ActionRequired`.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum ActionKind {
+        /// It is the account's move.
+        YourTurn,
+        /// The account has not submitted its hand for the game yet.
+        HandNeeded,
+        /// Someone offered the account a wager; it may accept or ignore it.
+        WagerPending,
+    }
+
+    /// Why a moderator voided a game.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum VoidReason {
+        /// The stored game is inconsistent, e.g. after a faulty upgrade.
+        CorruptedState,
+        /// A player exploited a bug to gain an advantage.
+        Exploit,
+        /// The game cannot continue because of a chain or client incident.
+        Incident,
+        /// Nobody played for `AbandonAfterBlocks` and neither player can be declared winner.
+        Abandoned,
+    }
+
+    /// Schema version carried by every `Event::Game`. Bump it whenever a `GameEvent` variant
+    /// changes shape, so indexers can pick the matching decoder per block.
+    pub const GAME_EVENT_VERSION: u8 = 1;
+
+    /// Lifecycle of a game as reported to indexers through `Event::Game`: exactly one entry
+    /// per action. Seats are indices into the game's `players`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+    pub enum GameEvent<AccountId> {
+        /// The game was created; `first` is the seat that moves first.
+        Created {
+            mode: GameMode,
+            context: GameContext,
+            players: Vec<AccountId>,
+            first: u8,
         },
-        MovePlayed {
-            game_id: GameId<T>,
-            player: T::AccountId,
+        /// `seat` placed a card at (x, y). `next` is the seat to move, `None` once the move
+        /// ended the game (a `Finished` entry follows).
+        Moved {
+            seat: u8,
             x: u8,
             y: u8,
+            next: Option<u8>,
         },
-        GameFinished {
-            game_id: GameId<T>,
-            winner: Option<T::AccountId>,
-        },
-        //New Turn
-        NewTurn {
-            game_id: GameId<T>,
-            next_player: AccountIdOf<T>,
-        },
-        TurnForceFinished {
-            game_id: GameId<T>,
-            player: AccountIdOf<T>,
-        },
-        HandSubmitted {
-            game_id: GameId<T>,
-            player: AccountIdOf<T>,
+        /// `seat` ran out of time and its turn was skipped by `force_finish_turn`.
+        TurnSkipped { seat: u8, next: Option<u8> },
+        /// The game ended; `winner` is a seat, `None` for a draw.
+        Finished {
+            winner: Option<u8>,
+            reason: EndReason,
         },
     }
 
-    #[pallet::error]
-    pub enum Error<T> {
-        GameNotFound,
-        InvalidMove,
-        NotYourTurn,
-        CellOccupied,
-        InvalidNumberOfPlayers,
-        InternalError,
-        BlocksToPlayLimitNotPassed,
-        CurrentPlayerCannotForceFinishTurn,
-        PlayerNotInGame,
-        CreatorMustBeInGame,
-        // Hand / deck errors
-        HandSizeInvalid,
-        DuplicateCardInHand,
-        HandAlreadySubmitted,
-        HandNotSubmitted,
-        HandIndexOutOfRange,
-        CardAlreadyUsed,
-        CardDoesNotExist,
-        CardNotOwned,
-        PlayerAlreadyInGame,
-        PresetHandMissing,
+    /// Handicap applied to a PvP game between players of mismatched level. `player` is a seat index.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub enum Handicap {
+        /// The weaker player starts with one extra point.
+        ScoreBonus { player: u8 },
+        /// The stronger player's submitted hand may not exceed `max_total` summed edge ranks.
+        HandBudget { player: u8, max_total: u16 },
     }
 
-    /// Limit of cards per hand (defaults to 5 via Config::HandSize)
-    pub type HandLimit = ConstU32<5>;
+    /// Number of games created so far; mixed into each game's entropy.
+    #[pallet::storage]
+    pub type GameCounter<T: Config> = StorageValue<_, u64, ValueQuery>;
 
-    /// A single entry in a player's submitted hand
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
-    pub struct HandEntry {
-        pub card_id: u32,
-        pub north: u8,
-        pub east: u8,
-        pub south: u8,
-        pub west: u8,
-        pub used: bool,
-    }
+    /// Per-game entropy fixed at creation from the parent block hash and `GameCounter`.
+    /// All in-game randomness derives from it, so it cannot be predicted before the game
+    /// exists but can be replayed for verification afterwards.
+    #[pallet::storage]
+    #[pallet::getter(fn game_entropy)]
+    pub type GameEntropy<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, T::Hash, OptionQuery>;
 
-    /// Stores each player's hand for a given game.
-    /// Keyed by (game_id, account_id) -> bounded vec of exactly HandSize entries.
+    /// Handicap chosen for a game at creation time.
     #[pallet::storage]
-    #[pallet::getter(fn game_hands)]
-    pub type HandsOfGame<T: Config> = StorageDoubleMap<
+    #[pallet::getter(fn game_handicap)]
+    pub type GameHandicaps<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, Handicap, OptionQuery>;
+
+    /// Optional rules fixed for a game at creation time (empty when absent).
+    #[pallet::storage]
+    #[pallet::getter(fn game_rules)]
+    pub type GameRules<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         GameId<T>,
-        Blake2_128Concat,
-        AccountIdOf<T>,
-        BoundedVec<HandEntry, HandLimit>,
-        OptionQuery,
+        eterra_core_types::rules::Rules,
+        ValueQuery,
     >;
 
-    /// The player's current hand configuration (card IDs only). This is editable by the user in the UI.
+    /// Open card wagers: proposer -> opponent -> card the proposer staked. The card stays in
+    /// escrow until the opponent accepts or the proposer cancels.
     #[pallet::storage]
-    #[pallet::getter(fn current_hand_of)]
-    pub type CurrentHandOf<T: Config> = StorageMap<
+    #[pallet::getter(fn wager_offer)]
+    pub type WagerOffers<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         AccountIdOf<T>,
-        BoundedVec<u32, HandLimit>, // exactly HandLimit entries expected by the UI flow
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        u32,
         OptionQuery,
     >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
-        pub fn create_game(
-            origin: OriginFor<T>,
-            mut players: Vec<AccountIdOf<T>>,
-            game_mode: GameMode,
-        ) -> DispatchResult {
-            let who: AccountIdOf<T> = ensure_signed(origin)?;
+    /// Cards staked on a game as (staker, card_id) per seat, settled when it ends.
+    #[pallet::storage]
+    #[pallet::getter(fn game_wager)]
+    pub type GameWagers<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, [(AccountIdOf<T>, u32); 2], OptionQuery>;
 
-            // Require the creator to have a current hand before starting a game
-            ensure!(
-                CurrentHandOf::<T>::contains_key(&who),
-                Error::<T>::PresetHandMissing
-            );
+    /// Energy left to each seat of a `Rules::ENERGY` game; absent for other games.
+    #[pallet::storage]
+    #[pallet::getter(fn game_energy)]
+    pub type GameEnergy<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, [u8; 2], OptionQuery>;
 
-            // Normalize players vector depending on mode
-            match game_mode {
-                GameMode::PvP => {
-                    // For PvP, the caller must have included themselves and exactly one opponent.
-                    ensure!(players.contains(&who), Error::<T>::CreatorMustBeInGame);
-                    ensure!(
-                        players.len()
-                            == <u32 as sp_runtime::traits::SaturatedConversion>::saturated_into::<
-                                usize,
-                            >(T::NumPlayers::get()),
-                        Error::<T>::InvalidNumberOfPlayers
-                    );
-                    // Ensure distinct players; also normalize order to [creator, opponent]
-                    // so downstream logic is predictable.
-                    ensure!(players[0] != players[1], Error::<T>::InvalidMove);
-                    if players[0] != who {
-                        // Put creator in slot 0
-                        if players[1] == who {
-                            players.swap(0, 1);
-                        } else {
-                            // Shouldn’t happen because of the contains() check, but be safe.
-                            return Err(Error::<T>::CreatorMustBeInGame.into());
-                        }
-                    }
-                }
-                GameMode::PvE => {
-                    // For PvE, ignore whatever was passed and force [creator, AI].
-                    let ai_acc = T::AiAccount::get();
-                    // Also guard against creator == AI account (shouldn’t happen for sane config).
-                    ensure!(who != ai_acc, Error::<T>::InvalidMove);
-                    players = sp_std::vec![who.clone(), ai_acc];
-                }
-            }
+    /// Elo rating of every account that finished a ranked game, see `rating_of`.
+    #[pallet::storage]
+    pub type PlayerRating<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, OptionQuery>;
 
-            // From here on, `players` is normalized for both modes.
-            let number_of_players = players.len();
-            ensure!(
-                number_of_players
-                    == <u32 as sp_runtime::traits::SaturatedConversion>::saturated_into::<usize>(
-                        T::NumPlayers::get()
-                    ),
-                Error::<T>::InvalidNumberOfPlayers
-            );
+    /// Block each rated account's idle decay is counted from: their last ranked game, moved
+    /// forward by every period of decay applied since.
+    #[pallet::storage]
+    pub type RatingDecayFrom<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BlockNumberFor<T>, OptionQuery>;
 
-            let creator = players[0].clone();
-            let opponent = players[1].clone();
+    /// Last rated account looked at by the idle decay sweep; the next block resumes after it.
+    #[pallet::storage]
+    pub type DecayCursor<T: Config> = StorageValue<_, AccountIdOf<T>, OptionQuery>;
 
-            // Redundant after normalization, but keep as a safety net.
-            ensure!(creator != opponent, Error::<T>::InvalidMove);
+    /// Rated games completed per account as (day_index, count_in_day).
+    #[pallet::storage]
+    pub type RatedGamesToday<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (u64, u32), ValueQuery>;
+
+    /// Seat to move first and scores (after any handicap) when a game was created.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct GameStart {
+        pub first_player: u8,
+        pub scores: (u8, u8),
+    }
 
-            // Enforce: a wallet may participate in at most one active game, scoped by mode.
-            match game_mode {
-                GameMode::PvP => {
-                    ensure!(
-                        ActiveGameOf::<T>::get(&creator).is_none(),
-                        Error::<T>::PlayerAlreadyInGame
-                    );
-                    ensure!(
-                        ActiveGameOf::<T>::get(&opponent).is_none(),
-                        Error::<T>::PlayerAlreadyInGame
-                    );
-                }
-                GameMode::PvE => {
-                    // Only the human creator is restricted in PvE; the AI may participate in many games.
-                    ensure!(
-                        ActiveGameOf::<T>::get(&creator).is_none(),
-                        Error::<T>::PlayerAlreadyInGame
-                    );
-                }
+    /// Starting state of each game, recorded so transcripts can be replayed against it.
+    #[pallet::storage]
+    #[pallet::getter(fn game_start)]
+    pub type GameStarts<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, GameStart, OptionQuery>;
+
+    /// Block after which a player of a PvP game whose opponent never submitted a hand may
+    /// claim a walkover.
+    #[pallet::storage]
+    #[pallet::getter(fn hand_deadline)]
+    pub type HandDeadlines<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, BlockNumberFor<T>, OptionQuery>;
+
+    /// One turn of a game transcript submitted to `verify_replay`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+    pub enum ReplayMove {
+        /// The player to move placed `hand_index` from their hand at (x, y).
+        Place { hand_index: u8, x: u8, y: u8 },
+        /// The turn was force-finished without a card being placed.
+        Skip,
+    }
+
+    /// Games whose hands were dealt from the players' collections (`create_sealed_game`).
+    #[pallet::storage]
+    #[pallet::getter(fn is_sealed)]
+    pub type SealedGames<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, bool, ValueQuery>;
+
+    /// Aggregate usage of a single card across finished games.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct CardUsageStats {
+        /// Finished games the card was submitted in.
+        pub games: u32,
+        /// Games in which the card was actually placed on the board.
+        pub played: u32,
+        /// Games that ended with the card still in hand.
+        pub unplayed: u32,
+    }
+
+    /// Per-player hand outcome reported in `GameSummary`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+    pub struct HandUsage<AccountId> {
+        pub player: AccountId,
+        pub used: Vec<u32>,
+        pub unused: Vec<u32>,
+    }
+
+    /// Card usage stats keyed by card id (synthetic entries without an id are not tracked).
+    #[pallet::storage]
+    #[pallet::getter(fn card_usage)]
+    pub type CardUsage<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, CardUsageStats, ValueQuery>;
+
+    /// Inclusive upper bounds, in blocks, of the `MoveTiming` latency buckets; slower moves
+    /// land in the last bucket.
+    pub const MOVE_LATENCY_BOUNDS: [u32; 5] = [0, 1, 3, 7, 15];
+
+    /// Coarse latency of ranked moves: blocks between a player's turn starting and their
+    /// move. Counters only, so unnaturally regular timing stands out for moderators without
+    /// recording anything else about the player.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct MoveTiming {
+        pub moves: u32,
+        /// Sum of all latencies, for the average.
+        pub total_blocks: u32,
+        /// Moves per latency bucket, see `MOVE_LATENCY_BOUNDS`.
+        pub buckets: [u32; MOVE_LATENCY_BOUNDS.len() + 1],
+    }
+
+    impl MoveTiming {
+        pub fn record(&mut self, latency: u32) {
+            let bucket = MOVE_LATENCY_BOUNDS
+                .iter()
+                .position(|bound| latency <= *bound)
+                .unwrap_or(MOVE_LATENCY_BOUNDS.len());
+            self.moves = self.moves.saturating_add(1);
+            self.total_blocks = self.total_blocks.saturating_add(latency);
+            self.buckets[bucket] = self.buckets[bucket].saturating_add(1);
+        }
+    }
+
+    /// Ranked move timing of each account across all of its games.
+    #[pallet::storage]
+    #[pallet::getter(fn move_timing)]
+    pub type MoveTimings<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, MoveTiming, ValueQuery>;
+
+    /// Ranked move timing of each player within a game; cleared with the game's hands.
+    #[pallet::storage]
+    pub type GameMoveTimings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        MoveTiming,
+        ValueQuery,
+    >;
+
+    /// Game ids per `PlayerGamePages` page.
+    pub const GAMES_PER_PAGE: u32 = 20;
+
+    /// Full game history of each player, oldest first, in pages of `GAMES_PER_PAGE` ids.
+    /// The `n`-th game ever appended for an account lives in page `n / GAMES_PER_PAGE`;
+    /// voided or reassigned games leave their page short instead of shifting later ones.
+    #[pallet::storage]
+    pub type PlayerGamePages<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Twox64Concat,
+        u32,
+        BoundedVec<GameId<T>, ConstU32<GAMES_PER_PAGE>>,
+        ValueQuery,
+    >;
+
+    /// Games appended to each player's history so far; locates the page to append to.
+    #[pallet::storage]
+    #[pallet::getter(fn player_game_count)]
+    pub type PlayerGameCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+
+    /// History page holding a live game of a player, so the entry can be moved or dropped
+    /// without scanning the history. Cleared when the game ends.
+    #[pallet::storage]
+    pub type LiveGamePage<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        u32,
+        OptionQuery,
+    >;
+
+    /// Pre-v3 history: the last ten games of each player, most recent first.
+    #[frame_support::storage_alias]
+    pub(crate) type PlayerGames<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        BoundedVec<GameId<T>, ConstU32<10>>,
+        ValueQuery,
+    >;
+
+    /// Maximum number of registered AI identities.
+    pub type MaxAiProfiles = ConstU32<16>;
+    /// Maximum number of preset cards in an AI identity's card pool.
+    pub type MaxAiCardPool = ConstU32<16>;
+
+    /// An admin-managed AI identity that can be selected as a PvE opponent.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct AiProfile {
+        /// Monte-Carlo difficulty (0..=100) used when this AI searches for a move.
+        pub difficulty: u8,
+        /// Lowest player level this AI is auto-selected for.
+        pub min_level: u8,
+        /// Display name / persona shown by the UI.
+        pub persona: BoundedVec<u8, ConstU32<32>>,
+        /// Optional fixed card pool (north, east, south, west); empty means generated hands.
+        pub card_pool: BoundedVec<[u8; 4], MaxAiCardPool>,
+    }
+
+    /// Registered AI identities by account.
+    #[pallet::storage]
+    #[pallet::getter(fn ai_profile)]
+    pub type AiProfiles<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, AiProfile, OptionQuery>;
+
+    /// Registration order of AI identities (used for deterministic auto-selection).
+    #[pallet::storage]
+    #[pallet::getter(fn ai_roster)]
+    pub type AiRoster<T: Config> =
+        StorageValue<_, BoundedVec<AccountIdOf<T>, MaxAiProfiles>, ValueQuery>;
+
+    /// AI identities removed with `remove_ai`. Their profile is kept so games already in
+    /// progress keep playing against them, but they are no longer offered for new games.
+    #[pallet::storage]
+    pub type RetiredAis<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (), OptionQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// AI identities available from genesis, using the default `AiDifficulty`.
+        pub ai_accounts: Vec<AccountIdOf<T>>,
+        /// Current hands set at genesis as (account, card ids), each stored as the account's
+        /// active deck 0; the cards are expected to be minted to the account by the cards
+        /// pallet's genesis.
+        pub current_hands: Vec<(AccountIdOf<T>, Vec<u32>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> frame_support::traits::BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for acc in self.ai_accounts.iter() {
+                let profile = AiProfile {
+                    difficulty: T::AiDifficulty::get(),
+                    min_level: 0,
+                    persona: BoundedVec::default(),
+                    card_pool: BoundedVec::default(),
+                };
+                AiProfiles::<T>::insert(acc, profile);
+                AiRoster::<T>::mutate(|roster| {
+                    if !roster.contains(acc) {
+                        roster
+                            .try_push(acc.clone())
+                            .expect("genesis AI accounts must fit MaxAiProfiles");
+                    }
+                });
+            }
+            for (acc, card_ids) in self.current_hands.iter() {
+                let hand: BoundedVec<u32, HandLimit> = card_ids
+                    .clone()
+                    .try_into()
+                    .expect("genesis hands must fit HandLimit");
+                CurrentHandPower::<T>::insert(acc, Pallet::<T>::hand_power(&hand));
+                Decks::<T>::insert(acc, 0, Deck { name: BoundedVec::default(), cards: hand });
+                ActiveDeck::<T>::insert(acc, 0);
+            }
+        }
+    }
+
+    /// Public profile of an account registered as an open bot.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct BotProfile<AccountId> {
+        /// Self-declared strength (0..=100), shown to challengers.
+        pub difficulty: u8,
+        /// Optional account allowed to answer moves for the bot (e.g. an offchain-worker key).
+        pub controller: Option<AccountId>,
+    }
+
+    /// Accounts that anyone can challenge in `GameMode::Bot` games.
+    #[pallet::storage]
+    #[pallet::getter(fn open_bot)]
+    pub type OpenBots<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BotProfile<AccountIdOf<T>>, OptionQuery>;
+
+    /// Every variant pins its index, so builds with and without `legacy-events` or `dev-mode`
+    /// encode the same event alike. New variants take the next free index.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A step of a game's lifecycle in the versioned indexer schema, see `GameEvent`.
+        #[codec(index = 22)]
+        Game {
+            version: u8,
+            game_id: GameId<T>,
+            event: GameEvent<AccountIdOf<T>>,
+        },
+        /// Deprecated: superseded by `GameEvent::Created`.
+        #[cfg(feature = "legacy-events")]
+        #[codec(index = 0)]
+        GameCreated {
+            game_id: GameId<T>,
+        },
+        /// Deprecated: superseded by `GameEvent::Moved`.
+        #[cfg(feature = "legacy-events")]
+        #[codec(index = 1)]
+        MovePlayed {
+            game_id: GameId<T>,
+            player: T::AccountId,
+            x: u8,
+            y: u8,
+        },
+        /// Deprecated: superseded by `GameEvent::Finished`.
+        #[cfg(feature = "legacy-events")]
+        #[codec(index = 2)]
+        GameFinished {
+            game_id: GameId<T>,
+            winner: Option<T::AccountId>,
+        },
+        /// Deprecated: superseded by the `next` seat of `GameEvent::Moved`/`TurnSkipped`.
+        #[cfg(feature = "legacy-events")]
+        #[codec(index = 3)]
+        NewTurn {
+            game_id: GameId<T>,
+            next_player: AccountIdOf<T>,
+        },
+        /// Deprecated: superseded by `GameEvent::TurnSkipped`.
+        #[cfg(feature = "legacy-events")]
+        #[codec(index = 4)]
+        TurnForceFinished {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        #[codec(index = 5)]
+        HandSubmitted {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        #[codec(index = 6)]
+        OpenBotRegistered {
+            bot: AccountIdOf<T>,
+            difficulty: u8,
+            controller: Option<AccountIdOf<T>>,
+        },
+        #[codec(index = 7)]
+        OpenBotUnregistered {
+            bot: AccountIdOf<T>,
+        },
+        #[codec(index = 8)]
+        AiRegistered {
+            ai: AccountIdOf<T>,
+            difficulty: u8,
+            min_level: u8,
+        },
+        #[codec(index = 9)]
+        AiRemoved {
+            ai: AccountIdOf<T>,
+        },
+        /// Final hand usage of a finished game, read before any hand pruning.
+        #[codec(index = 10)]
+        GameSummary {
+            game_id: GameId<T>,
+            reason: EndReason,
+            hands: Vec<HandUsage<AccountIdOf<T>>>,
+        },
+        #[codec(index = 11)]
+        GameForfeited {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        #[codec(index = 12)]
+        GameHandsPruned {
+            game_id: GameId<T>,
+        },
+        #[codec(index = 23)]
+        MoveHistoryPruned {
+            game_id: GameId<T>,
+        },
+        /// New games of `mode` are played on `dim` x `dim` boards.
+        #[codec(index = 24)]
+        BoardDimSet {
+            mode: GameMode,
+            dim: u8,
+        },
+        /// New games of `mode` use `rules`, or `DefaultRules` if `None`.
+        #[codec(index = 25)]
+        ModeRulesSet {
+            mode: GameMode,
+            rules: Option<eterra_core_types::rules::Rules>,
+        },
+        /// Nobody moved in a live game for `AbandonAfterBlocks`. It was won by the player who
+        /// was not stalling, or voided if `winner` is `None`.
+        #[codec(index = 26)]
+        GameExpired {
+            game_id: GameId<T>,
+            winner: Option<AccountIdOf<T>>,
+        },
+        #[codec(index = 27)]
+        DeckCreated { who: AccountIdOf<T>, index: u8 },
+        #[codec(index = 28)]
+        DeckUpdated { who: AccountIdOf<T>, index: u8 },
+        #[codec(index = 29)]
+        DeckDeleted { who: AccountIdOf<T>, index: u8 },
+        /// New games deal `who`'s hand from deck `index`.
+        #[codec(index = 30)]
+        ActiveDeckSet { who: AccountIdOf<T>, index: u8 },
+        #[codec(index = 13)]
+        HandicapApplied {
+            game_id: GameId<T>,
+            handicap: Handicap,
+        },
+        /// A PvE AI hand was generated; each entry is (north, east, south, west).
+        #[codec(index = 14)]
+        AiHandGenerated {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+            entries: Vec<[u8; 4]>,
+        },
+        /// A player's current hand was copied into the game, listing the card ids used.
+        #[codec(index = 15)]
+        HandSnapshotted {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+            card_ids: Vec<u32>,
+        },
+        /// Playout statistics of an AI move's search, one entry per considered action.
+        /// Only emitted when `DevTelemetry` is enabled.
+        #[codec(index = 31)]
+        AiSearchStats {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+            iterations: u32,
+            actions: Vec<mc_ai::ActionStats<ai::Action>>,
+        },
+        /// A sealed game dealt `player` these cards from their collection.
+        #[codec(index = 16)]
+        HandDealt {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+            card_ids: Vec<u32>,
+        },
+        /// Summary of everything `deregister` removed for an account.
+        #[codec(index = 17)]
+        Deregistered {
+            who: AccountIdOf<T>,
+            forfeited_games: u32,
+            unlisted_cards: u32,
+            left_queue: bool,
+            profile_burned: bool,
+        },
+        /// A `migrate_games` batch upgraded `migrated` games to `CURRENT_GAME_VERSION`.
+        #[codec(index = 18)]
+        GamesMigrated { migrated: u32, complete: bool },
+        /// The number of spectators of a live game changed.
+        #[codec(index = 19)]
+        SpectatorsChanged { game_id: GameId<T>, spectators: u32 },
+        /// Support moved `seat` of a live game from `old` to `new` (`reassign_game_seat`).
+        #[codec(index = 32)]
+        GameSeatReassigned {
+            game_id: GameId<T>,
+            seat: u8,
+            old: AccountIdOf<T>,
+            new: AccountIdOf<T>,
+        },
+        /// A live game was voided, by a moderator or by expiry, and everything stored for it
+        /// was cleared. `players` is empty if the stored game could no longer be decoded.
+        #[codec(index = 33)]
+        GameVoided {
+            game_id: GameId<T>,
+            reason: VoidReason,
+            players: Vec<AccountIdOf<T>>,
+            context: Option<GameContext>,
+            round: u8,
+            scores: (u8, u8),
+        },
+        /// A stale `ActiveGameOf` marker was cleared by `repair_active_game`.
+        #[codec(index = 20)]
+        ActiveGameRepaired {
+            who: AccountIdOf<T>,
+            context: GameContext,
+            game_id: GameId<T>,
+        },
+        /// More AI turns are pending than one block can play.
+        #[codec(index = 21)]
+        AiBacklogHigh { depth: u32 },
+        /// `who` won again; `current` is the new streak length.
+        #[codec(index = 34)]
+        StreakExtended {
+            who: AccountIdOf<T>,
+            current: u32,
+            best: u32,
+        },
+        /// A streak of `length` wins ended with a loss or draw.
+        #[codec(index = 35)]
+        StreakBroken { who: AccountIdOf<T>, length: u32 },
+        /// `seat` annotated their move number `move_no`.
+        #[codec(index = 36)]
+        MoveAnnotated {
+            game_id: GameId<T>,
+            seat: u8,
+            move_no: u8,
+            annotation: MoveAnnotation,
+        },
+        /// `proposer` staked `card_id` on a game against `opponent`.
+        #[codec(index = 37)]
+        WagerOffered {
+            proposer: AccountIdOf<T>,
+            opponent: AccountIdOf<T>,
+            card_id: u32,
+        },
+        /// The offer was withdrawn and the card released.
+        #[codec(index = 38)]
+        WagerCancelled {
+            proposer: AccountIdOf<T>,
+            opponent: AccountIdOf<T>,
+            card_id: u32,
+        },
+        /// Both cards are in escrow and the wagered game started.
+        #[codec(index = 39)]
+        WagerAccepted {
+            game_id: GameId<T>,
+            stakes: [(AccountIdOf<T>, u32); 2],
+        },
+        /// The stakes of a finished game went to `winner`, or back to their owners if the
+        /// game was drawn, aborted before round 1 or voided.
+        #[codec(index = 40)]
+        WagerSettled {
+            game_id: GameId<T>,
+            winner: Option<AccountIdOf<T>>,
+        },
+        /// A game admin published a featured hand, borrowable until `expires_at`.
+        #[codec(index = 41)]
+        FeaturedHandPublished {
+            hand_id: u32,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// `who` now plays casual and PvE games with this featured hand (`None`: their own).
+        #[codec(index = 42)]
+        FeaturedHandSelected {
+            who: AccountIdOf<T>,
+            hand_id: Option<u32>,
+        },
+        /// The endpoints finished games are posted to by `webhooks` were replaced.
+        #[codec(index = 43)]
+        ResultWebhooksSet { count: u32 },
+        /// Root overwrote part of a live game through one of the `dev_*` calls.
+        #[cfg(feature = "dev-mode")]
+        #[codec(index = 44)]
+        DevGameStateForced { game_id: GameId<T> },
+        /// The AI search gave no playable move, so the AI played its first legal placement.
+        #[codec(index = 45)]
+        AiMoveFailed {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        /// A ranked game between two humans moved both players' Elo ratings.
+        #[codec(index = 46)]
+        RatingsUpdated {
+            game_id: GameId<T>,
+            ratings: [(AccountIdOf<T>, u32); 2],
+        },
+        /// `account` newly owes an action, the single event notification services need to
+        /// watch. `game_id` is `None` for actions outside a game (wager offers), and
+        /// `deadline_block` is `None` when nothing happens if the action is never taken.
+        /// AI identities are never notified.
+        #[codec(index = 47)]
+        ActionRequired {
+            account: AccountIdOf<T>,
+            game_id: Option<GameId<T>>,
+            kind: ActionKind,
+            deadline_block: Option<BlockNumberFor<T>>,
+        },
+        /// `who` finished no ranked game for `periods` of `DecayPeriodBlocks` and their
+        /// rating fell to `rating`.
+        #[codec(index = 48)]
+        RatingDecayed {
+            who: AccountIdOf<T>,
+            rating: u32,
+            periods: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        GameNotFound,
+        InvalidMove,
+        NotYourTurn,
+        CellOccupied,
+        InvalidNumberOfPlayers,
+        InternalError,
+        BlocksToPlayLimitNotPassed,
+        CurrentPlayerCannotForceFinishTurn,
+        PlayerNotInGame,
+        CreatorMustBeInGame,
+        // Hand / deck errors
+        HandSizeInvalid,
+        DuplicateCardInHand,
+        HandAlreadySubmitted,
+        HandNotSubmitted,
+        HandIndexOutOfRange,
+        CardAlreadyUsed,
+        CardDoesNotExist,
+        CardNotOwned,
+        PlayerAlreadyInGame,
+        PresetHandMissing,
+        // Open bot errors
+        NotAnOpenBot,
+        NotBotController,
+        InvalidDifficulty,
+        // AI registry errors
+        NoAiAvailable,
+        NotAnAi,
+        TooManyAiProfiles,
+        PersonaTooLong,
+        CardPoolTooLarge,
+        GameNotPlaying,
+        GameNotFinished,
+        /// The hand exceeds the budget imposed by this game's handicap.
+        HandOverBudget,
+        /// A sealed game needs every dealt player to own at least `HandSize` cards.
+        NotEnoughCardsToDeal,
+        /// The card is locked on another chain and cannot be played here.
+        CardExported,
+        /// The card is in cold storage and cannot be put in a hand.
+        CardVaulted,
+        /// The account already completed `MaxRatedGamesPerDay` games in this day window.
+        DailyGameLimitReached,
+        /// `migrate_games` needs a batch size of at least one.
+        InvalidBatchSize,
+        /// The game already has `MaxSpectators` spectators.
+        SpectatorLimitReached,
+        /// The caller is not watching this game.
+        NotWatching,
+        /// Every active-game marker of the account points at a live game it plays in.
+        NothingToRepair,
+        /// The latest move of the game was not made by the caller.
+        NoMoveToAnnotate,
+        /// The caller already annotated this move.
+        MoveAlreadyAnnotated,
+        /// The game already holds `MaxMoveAnnotations` annotations.
+        AnnotationLimitReached,
+        /// The card costs more energy than the player has left (`Rules::ENERGY`).
+        NotEnoughEnergy,
+        /// The caller already has an open wager offer to this opponent.
+        WagerAlreadyOffered,
+        /// No wager offer from the proposer to the caller exists.
+        NoWagerOffer,
+        /// A featured hand needs `HandSize` cards with every rank between 1 and 9.
+        InvalidFeaturedHand,
+        /// No featured hand has this id, or it has expired.
+        FeaturedHandUnavailable,
+        /// A webhook endpoint is not an `http(s)://` URL of at most `MAX_WEBHOOK_URL_LEN` bytes.
+        InvalidWebhook,
+        /// More than `MAX_RESULT_WEBHOOKS` endpoints were given.
+        TooManyWebhooks,
+        /// A game needs two different players.
+        PlayersNotDistinct,
+        /// AI identities only fill seats; they cannot create PvE games themselves.
+        CreatorIsAi,
+        /// A game between these players was already created in this block.
+        GameIdCollision,
+        /// The card changed owner within the cards pallet's `TradeCooldown` and cannot be
+        /// put in a ranked hand yet.
+        CardCoolingDown,
+        /// The hand submission deadline of the game has not passed yet.
+        HandDeadlineNotPassed,
+        /// Only a player who submitted a hand may claim a walkover, and only while the
+        /// opponent has not.
+        WalkoverNotAvailable,
+        /// The game is live, or ended less than `MoveHistoryRetention` blocks ago.
+        MoveHistoryRetained,
+        /// Boards are between `MIN_BOARD_DIM` and `MAX_BOARD_DIM` cells wide.
+        InvalidBoardDim,
+        /// The account already has `MaxDecks` decks.
+        TooManyDecks,
+        /// The account has no deck at this index.
+        DeckNotFound,
+        /// Deck names are at most `MAX_DECK_NAME_LEN` bytes.
+        DeckNameTooLong,
+    }
+
+    /// Limit of cards per hand (defaults to 5 via Config::HandSize)
+    pub type HandLimit = ConstU32<5>;
+
+    /// A single entry in a player's submitted hand
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct HandEntry {
+        /// The owned card, or `None` for synthetic cards (AI pools, featured and random hands).
+        pub card_id: Option<u32>,
+        pub north: u8,
+        pub east: u8,
+        pub south: u8,
+        pub west: u8,
+        pub used: bool,
+        /// Element of the card, carried onto the board when it is played.
+        pub element: Option<eterra_core_types::element::Element>,
+    }
+
+    impl HandEntry {
+        /// Energy needed to place this card under `Rules::ENERGY`.
+        pub fn energy_cost(&self) -> u8 {
+            energy_cost(self.north, self.east, self.south, self.west)
+        }
+    }
+
+    /// What `opponent_hand_summary` lets a player see of the other hand in their game.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct OpponentHandSummary {
+        /// Cards in the opponent's hand.
+        pub size: u8,
+        /// Cards the opponent has not played yet.
+        pub remaining: u8,
+        /// Whether each hand index was already played.
+        pub used: BoundedVec<bool, HandLimit>,
+        /// The opponent's card stats, withheld under `Rules::CLOSED_HAND`.
+        pub cards: Option<BoundedVec<HandEntry, HandLimit>>,
+    }
+
+    /// A game as returned by `Pallet::game_view`. Its layout is part of the runtime API and
+    /// only grows by appending fields, unlike `Game`, which follows the storage layout.
+    #[derive(Encode, Decode, Clone, PartialEq, TypeInfo, Debug)]
+    pub struct GameView<AccountId, BlockNumber> {
+        pub players: Vec<AccountId>,
+        pub turn: TurnState,
+        pub round: u8,
+        pub max_rounds: u8,
+        pub board: Board,
+        pub scores: (u8, u8),
+        pub mode: Option<GameMode>,
+        pub context: Option<GameContext>,
+        pub rules: eterra_core_types::rules::Rules,
+        /// Block of the latest move, or of creation before the first one.
+        pub last_played_block: BlockNumber,
+        /// Block after which a missing hand forfeits the game, while one can.
+        pub hand_deadline: Option<BlockNumber>,
+    }
+
+    /// Stores each player's hand for a given game.
+    /// Keyed by (game_id, account_id) -> bounded vec of exactly HandSize entries.
+    /// Clients must read opponents' hands through `opponent_hand_summary`, which honours
+    /// `Rules::CLOSED_HAND`; `used` flags sit next to the stats so a committed-hand scheme
+    /// can later replace the stats without changing how play indexes the hand.
+    #[pallet::storage]
+    #[pallet::getter(fn game_hands)]
+    pub type HandsOfGame<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        BoundedVec<HandEntry, HandLimit>,
+        OptionQuery,
+    >;
+
+    /// Maximum length of a deck name, in bytes.
+    pub const MAX_DECK_NAME_LEN: u32 = 32;
+
+    /// A named hand preset (card IDs only), editable by the user in the UI.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct Deck {
+        pub name: BoundedVec<u8, ConstU32<MAX_DECK_NAME_LEN>>,
+        /// Exactly `HandSize` unique cards owned by the player when last saved.
+        pub cards: BoundedVec<u32, HandLimit>,
+    }
+
+    /// The decks of each player, by index below `MaxDecks`.
+    #[pallet::storage]
+    #[pallet::getter(fn deck_of)]
+    pub type Decks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Twox64Concat,
+        u8,
+        Deck,
+        OptionQuery,
+    >;
+
+    /// The deck new games deal a player's hand from; its cards are the player's current hand.
+    #[pallet::storage]
+    #[pallet::getter(fn active_deck_of)]
+    pub type ActiveDeck<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u8, OptionQuery>;
+
+    /// Pre-v5 single hand preset of each player, now their active deck 0.
+    #[frame_support::storage_alias]
+    pub(crate) type CurrentHandOf<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, BoundedVec<u32, HandLimit>>;
+
+    /// Canonical power score of each player's current hand, computed by `hand_power` whenever
+    /// the active deck is set or edited, or one of its cards is leveled up.
+    #[pallet::storage]
+    #[pallet::getter(fn current_hand_power)]
+    pub type CurrentHandPower<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, OptionQuery>;
+
+    /// Copy of a player's current hand taken when they joined the ranked queue. The game the
+    /// matchmaker creates for them snapshots this hand, so edits made while waiting do not
+    /// change the hand they were paired on.
+    #[pallet::storage]
+    #[pallet::getter(fn queued_hand_of)]
+    pub type QueuedHandOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BoundedVec<u32, HandLimit>, OptionQuery>;
+
+    /// A hand of synthetic cards (north, east, south, west) anyone may borrow for casual and
+    /// PvE games until `expires_at`, without owning the cards.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    pub struct FeaturedHand<BlockNumber> {
+        pub cards: BoundedVec<[u8; 4], HandLimit>,
+        pub expires_at: BlockNumber,
+    }
+
+    #[pallet::storage]
+    pub type NextFeaturedHandId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn featured_hand)]
+    pub type FeaturedHands<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, FeaturedHand<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Featured hand each account chose with `use_featured_hand`. It replaces their current
+    /// hand in casual and PvE games for as long as it is live; ranked play is unaffected.
+    #[pallet::storage]
+    #[pallet::getter(fn featured_hand_of)]
+    pub type FeaturedHandOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, OptionQuery>;
+
+    /// Endpoints the offchain worker posts finished games to, see `webhooks`. Empty (the
+    /// default) turns result webhooks off.
+    #[pallet::storage]
+    #[pallet::getter(fn result_webhooks)]
+    pub type ResultWebhooks<T: Config> = StorageValue<
+        _,
+        BoundedVec<crate::webhooks::WebhookUrl, ConstU32<{ crate::webhooks::MAX_RESULT_WEBHOOKS }>>,
+        ValueQuery,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::drain_pending_ai_turns()
+        }
+
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::expire_abandoned_games(n, remaining_weight);
+            used.saturating_add(Self::decay_idle_ratings(
+                n,
+                remaining_weight.saturating_sub(used),
+            ))
+        }
+
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            crate::webhooks::post_finished_games::<T>(n);
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = StorageVersion::get::<Pallet<T>>();
+            if on_chain >= 7 {
+                return T::DbWeight::get().reads(1);
+            }
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            // v2 -> v3: the ten most recent games become the first history page, oldest
+            // first. Live games among them are not tracked in `LiveGamePage`, so they stay
+            // in the history if voided or reassigned.
+            if on_chain < 3 {
+                let mut players = 0u64;
+                for (who, recent) in PlayerGames::<T>::drain() {
+                    players += 1;
+                    let mut page = recent.into_inner();
+                    page.reverse();
+                    PlayerGameCount::<T>::insert(&who, page.len() as u32);
+                    PlayerGamePages::<T>::insert(&who, 0, BoundedVec::truncate_from(page));
+                }
+                weight = weight
+                    .saturating_add(T::DbWeight::get().reads_writes(players, players * 3));
+            }
+            // v3 -> v4: hand entries gained an `element`; cards already dealt keep none.
+            if on_chain < 4 {
+                #[derive(Decode)]
+                struct V3HandEntry {
+                    card_id: u32,
+                    north: u8,
+                    east: u8,
+                    south: u8,
+                    west: u8,
+                    used: bool,
+                }
+                let mut hands = 0u64;
+                HandsOfGame::<T>::translate::<BoundedVec<V3HandEntry, HandLimit>, _>(|_, who, old| {
+                    hands += 1;
+                    let entries = old.into_iter().map(|e| HandEntry {
+                        card_id: Pallet::<T>::legacy_card_id(e.card_id, &who),
+                        north: e.north,
+                        east: e.east,
+                        south: e.south,
+                        west: e.west,
+                        used: e.used,
+                        element: None,
+                    });
+                    Some(BoundedVec::truncate_from(entries.collect()))
+                });
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(hands, hands));
+            }
+            // v4 -> v5: the single `CurrentHandOf` preset becomes each player's active deck 0.
+            if on_chain < 5 {
+                let mut presets = 0u64;
+                for (who, cards) in CurrentHandOf::<T>::drain() {
+                    presets += 1;
+                    Decks::<T>::insert(&who, 0, Deck { name: BoundedVec::default(), cards });
+                    ActiveDeck::<T>::insert(&who, 0);
+                }
+                weight =
+                    weight.saturating_add(T::DbWeight::get().reads_writes(presets, presets * 3));
+            }
+            // v5 -> v6: synthetic hand entries lost their 0 card id. The v3 step above already
+            // writes the new layout.
+            if (4..6).contains(&on_chain) {
+                #[derive(Decode)]
+                struct V5HandEntry {
+                    card_id: u32,
+                    north: u8,
+                    east: u8,
+                    south: u8,
+                    west: u8,
+                    used: bool,
+                    element: Option<eterra_core_types::element::Element>,
+                }
+                let mut hands = 0u64;
+                HandsOfGame::<T>::translate::<BoundedVec<V5HandEntry, HandLimit>, _>(|_, who, old| {
+                    hands += 1;
+                    let entries = old.into_iter().map(|e| HandEntry {
+                        card_id: Pallet::<T>::legacy_card_id(e.card_id, &who),
+                        north: e.north,
+                        east: e.east,
+                        south: e.south,
+                        west: e.west,
+                        used: e.used,
+                        element: e.element,
+                    });
+                    Some(BoundedVec::truncate_from(entries.collect()))
+                });
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(hands * 2, hands));
+            }
+            // v0 -> v1 (`ActiveGameOf` keyed by (account, context)), v1 -> v2 (`LiveGames`) and
+            // v6 -> v7 (`LiveGameIds`) index the games still being played. That walks every
+            // stored game, so it is left to `migrate_games` batches, see `reindex_game`.
+            let pending = GameReindex::<T>::get();
+            GameReindex::<T>::put(PendingReindex {
+                markers: on_chain < 1 || pending.is_some_and(|p| p.markers),
+                live_counts: on_chain < 2 || pending.is_some_and(|p| p.live_counts),
+            });
+            GameMigrationCursor::<T>::kill();
+            ExpiryCursor::<T>::kill();
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 3));
+            STORAGE_VERSION.put::<Pallet<T>>();
+            weight
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(10_000)]
+        pub fn create_game(
+            origin: OriginFor<T>,
+            players: Vec<AccountIdOf<T>>,
+            game_mode: GameMode,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            Self::do_create_game(who, players, game_mode, false)
+        }
+
+        /// Like `create_game`, but instead of using the active deck every non-AI player is dealt
+        /// `HandSize` random cards from their whole `OwnedCards` collection at game start.
+        #[pallet::call_index(15)]
+        #[pallet::weight(10_000)]
+        pub fn create_sealed_game(
+            origin: OriginFor<T>,
+            players: Vec<AccountIdOf<T>>,
+            game_mode: GameMode,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            Self::do_create_game(who, players, game_mode, true)
+        }
+        #[pallet::call_index(1)]
+        #[pallet::weight(crate::MOVE_BASE_WEIGHT
+            .saturating_add(T::AiTurnWeight::get())
+            .saturating_add(T::GameResults::max_weight()))]
+        pub fn play(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            player_move: Move,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            log::debug!(
+                "Player {:?} is attempting to play on game_id {:?} at {:?}",
+                who,
+                game_id,
+                player_move
+            );
+
+            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+
+            // Validate the current player's turn and move
+            Self::validate_player_turn(&game, &who)?;
+            Self::validate_move(&game, &player_move)?;
+
+            // Determine the current player's index (0 or 1)
+            let player_ix = Self::get_current_player_index(&game, &who);
+            // Free-form cards include 1/1/1/1 ones, so nothing is ever forced through
+            let card = &player_move.place_card;
+            let cost = energy_cost(card.top, card.right, card.bottom, card.left);
+            Self::charge_energy(&game_id, player_ix, cost, 0)?;
+
+            // Place the card and resolve captures
+            let captured = Self::apply_move(&game_id, &mut game, &player_move, player_ix)? > 0;
+
+            let placed = (player_move.place_index_x, player_move.place_index_y);
+            let action = RecordedAction::Place {
+                hand_index: None,
+                card: [card.top, card.right, card.bottom, card.left],
+                x: placed.0,
+                y: placed.1,
+            };
+            Self::record_move(&game_id, player_ix, action);
+            let ai_ran = Self::finalize_move(&game_id, &mut game, player_ix, Some(placed));
+            let finished = Self::is_finished(&game_id);
+            Ok(crate::MoveOutcome { captured, ai_ran, finished }.post_info())
+        }
+
+        /// Submit your current 5-card hand for this game. The submitted hand is always loaded from your current hand configuration.
+        /// The `card_ids` argument is ignored and exists for ABI compatibility only.
+        #[pallet::call_index(2)]
+        #[pallet::weight(crate::MOVE_BASE_WEIGHT
+            .saturating_add(T::AiTurnWeight::get())
+            .saturating_add(T::GameResults::max_weight()))]
+        pub fn submit_hand(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            card_ids: Vec<u32>,
+        ) -> DispatchResultWithPostInfo {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+
+            // Ensure the game exists and the caller is a player in it
+            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+            match game.turn {
+                TurnState::AwaitingHand | TurnState::AwaitingMove(_) => {}
+                TurnState::Finished => return Err(Error::<T>::GameNotPlaying.into()),
+            }
+
+            // Prevent resubmission for this game
+            ensure!(
+                HandsOfGame::<T>::get(&game_id, &who).is_none(),
+                Error::<T>::HandAlreadySubmitted
+            );
+
+            // Load the caller's current hand configuration, or the featured hand they borrowed
+            // for casual and PvE play, and snapshot it into the game
+            let context = GameContexts::<T>::get(&game_id);
+            let borrowed = match context {
+                Some(GameContext::Casual | GameContext::PvE) => Self::featured_hand_for(&who),
+                _ => None,
+            };
+            let hand = match borrowed {
+                Some(hand) => hand,
+                None => Self::build_hand_from_current(&who)?,
+            };
+            let player_ix = Self::get_current_player_index(&game, &who);
+            Self::ensure_within_handicap(&game_id, player_ix, &hand)?;
+            // Cards traded back since the current hand was set are caught here.
+            if context == Some(GameContext::Ranked) {
+                Self::ensure_no_trade_cooldown(&hand)?;
+            }
+
+            Self::store_snapshotted_hand(&game_id, &who, hand);
+            Self::deposit_event(Event::HandSubmitted {
+                game_id,
+                player: who.clone(),
+            });
+
+            // PvE: submitting player is always the human. Generate AI hand right away,
+            // and if it's AI's turn (e.g., AI won first move), let it act immediately.
+            let mut ai_ran = false;
+            if matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvE)) {
+                let ai_acc = match game.players.iter().find(|p| **p != who) {
+                    Some(acc) => acc.clone(),
+                    None => return Ok(crate::move_post_info(false)),
+                };
+                if HandsOfGame::<T>::get(&game_id, &ai_acc).is_none() {
+                    if let Some(ai_hand) = Self::generate_ai_hand_for_game(&game_id, &who) {
+                        Self::store_ai_hand(&game_id, &ai_acc, ai_hand);
+                    }
+                }
+                // The human's hand opens the game; if AI is up next, it moves right away.
+                if !AiProfiles::<T>::contains_key(&who) && game.hand_received() {
+                    GameStorage::<T>::insert(&game_id, game.clone());
+                    Self::notify_turn(&game_id, &game);
+                }
+                ai_ran = Self::take_or_defer_ai_turn(&game_id, &mut game);
+            }
+            Ok(crate::move_post_info(ai_ran))
+        }
+
+        /// Play a card by referencing its index in the submitted hand (0..HandSize-1).
+        #[pallet::call_index(3)]
+        #[pallet::weight(crate::MOVE_BASE_WEIGHT
+            .saturating_add(T::AiTurnWeight::get())
+            .saturating_add(T::GameResults::max_weight()))]
+        pub fn play_from_hand(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            hand_index: u8,
+            x: u8,
+            y: u8,
+        ) -> DispatchResultWithPostInfo {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            Ok(Self::do_play_from_hand(&who, game_id, hand_index, x, y)?.post_info())
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1, 1).ref_time())]
+        pub fn force_finish_turn(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+
+            // Retrieve the game from storage
+            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+
+            // Ensure the caller is a player in the game
+            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+
+            // Only a pending move can time out, and never by the player who owes it
+            let seat = match game.turn {
+                TurnState::AwaitingMove(seat) => seat,
+                TurnState::AwaitingHand | TurnState::Finished => {
+                    return Err(Error::<T>::GameNotPlaying.into())
+                }
+            };
+            ensure!(
+                game.players[seat as usize] != who,
+                Error::<T>::CurrentPlayerCannotForceFinishTurn
+            );
+
+            // Check if the BlocksToPlayLimit has passed (use saturating math and inclusive deadline)
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let limit: BlockNumberFor<T> = T::BlocksToPlayLimit::get().into();
+            let deadline = game.last_played_block.saturating_add(limit);
+            ensure!(
+                current_block >= deadline,
+                Error::<T>::BlocksToPlayLimitNotPassed
+            );
+
+            // Force finish the current turn
+            Self::note_early_abandon(&game_id, &game, &game.players[seat as usize]);
+            Self::record_move(&game_id, seat, RecordedAction::Skip);
+            Self::finalize_move(&game_id, &mut game, seat, None);
+
+            log::debug!(
+                "Force finish turn: game_id {:?}, current round: {}, max rounds: {}",
+                game_id,
+                game.round,
+                game.max_rounds
+            );
+
+            Ok(())
+        }
+
+        /// Replace the cards of your active deck, the hand used for future games. Without an
+        /// active deck, an unnamed one is created at the lowest free index and made active.
+        /// The hand must contain exactly `HandSize` unique cards owned by the caller.
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn set_current_hand(origin: OriginFor<T>, card_ids: Vec<u32>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let hand = Self::validated_hand(&who, card_ids)?;
+            let index = match ActiveDeck::<T>::get(&who) {
+                Some(index) => index,
+                None => {
+                    let index = Self::free_deck_index(&who)?;
+                    ActiveDeck::<T>::insert(&who, index);
+                    index
+                }
+            };
+            let name = Decks::<T>::get(&who, index).map(|d| d.name).unwrap_or_default();
+            CurrentHandPower::<T>::insert(&who, Self::hand_power(&hand));
+            Decks::<T>::insert(&who, index, Deck { name, cards: hand });
+            Ok(())
+        }
+
+        /// Deprecated alias for backwards compatibility. Calls `set_current_hand`.
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)]
+        pub fn set_preset_hand(origin: OriginFor<T>, card_ids: Vec<u32>) -> DispatchResult {
+            Self::set_current_hand(origin, card_ids)
+        }
+
+        /// Register the caller as an open bot that anyone can challenge with `GameMode::Bot`.
+        /// The bot must already have a current hand; `controller` may answer moves on its behalf.
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn register_open_bot(
+            origin: OriginFor<T>,
+            difficulty: u8,
+            controller: Option<AccountIdOf<T>>,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            ensure!(difficulty <= 100, Error::<T>::InvalidDifficulty);
+            ensure!(
+                Self::current_hand_of(&who).is_some(),
+                Error::<T>::PresetHandMissing
+            );
+
+            OpenBots::<T>::insert(
+                &who,
+                BotProfile {
+                    difficulty,
+                    controller: controller.clone(),
+                },
+            );
+            Self::deposit_event(Event::OpenBotRegistered {
+                bot: who,
+                difficulty,
+                controller,
+            });
+            Ok(())
+        }
+
+        /// Remove the caller from the open bot registry. Games already in progress continue.
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)]
+        pub fn unregister_open_bot(origin: OriginFor<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            ensure!(OpenBots::<T>::contains_key(&who), Error::<T>::NotAnOpenBot);
+            OpenBots::<T>::remove(&who);
+            Self::deposit_event(Event::OpenBotUnregistered { bot: who });
+            Ok(())
+        }
+
+        /// Play a card from `bot`'s hand. Callable by the bot itself or its registered controller.
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)]
+        pub fn bot_play_from_hand(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            bot: AccountIdOf<T>,
+            hand_index: u8,
+            x: u8,
+            y: u8,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            Self::ensure_bot_authority(&who, &bot)?;
+            ensure!(
+                matches!(GameModes::<T>::get(&game_id), Some(GameMode::Bot)),
+                Error::<T>::NotAnOpenBot
+            );
+            Self::do_play_from_hand(&bot, game_id, hand_index, x, y).map(|_| ())
+        }
+
+        /// (Game admin) Register or update an AI identity selectable as a PvE opponent.
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn register_ai(
+            origin: OriginFor<T>,
+            ai: AccountIdOf<T>,
+            difficulty: u8,
+            min_level: u8,
+            persona: Vec<u8>,
+            card_pool: Vec<[u8; 4]>,
+        ) -> DispatchResult {
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            ensure!(difficulty <= 100, Error::<T>::InvalidDifficulty);
+            let persona: BoundedVec<u8, ConstU32<32>> =
+                persona.try_into().map_err(|_| Error::<T>::PersonaTooLong)?;
+            let card_pool: BoundedVec<[u8; 4], MaxAiCardPool> =
+                card_pool.try_into().map_err(|_| Error::<T>::CardPoolTooLarge)?;
+
+            AiRoster::<T>::try_mutate(|roster| -> DispatchResult {
+                if !roster.contains(&ai) {
+                    roster
+                        .try_push(ai.clone())
+                        .map_err(|_| Error::<T>::TooManyAiProfiles)?;
+                }
+                Ok(())
+            })?;
+            RetiredAis::<T>::remove(&ai);
+            AiProfiles::<T>::insert(
+                &ai,
+                AiProfile {
+                    difficulty,
+                    min_level,
+                    persona,
+                    card_pool,
+                },
+            );
+            Self::deposit_event(Event::AiRegistered {
+                ai,
+                difficulty,
+                min_level,
+            });
+            Ok(())
+        }
+
+        /// (Game admin) Retire an AI identity so it is no longer offered for new games. Its
+        /// profile is kept, so games already in progress keep playing against it.
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn remove_ai(origin: OriginFor<T>, ai: AccountIdOf<T>) -> DispatchResult {
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            ensure!(Self::is_offered_ai(&ai), Error::<T>::NotAnAi);
+            RetiredAis::<T>::insert(&ai, ());
+            AiRoster::<T>::mutate(|roster| roster.retain(|a| *a != ai));
+            Self::deposit_event(Event::AiRemoved { ai });
+            Ok(())
+        }
+
+        /// Concede a game in progress. The opponent is recorded as the winner.
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)]
+        pub fn forfeit(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+            ensure!(
+                matches!(game.state, GameState::Playing),
+                Error::<T>::GameNotPlaying
+            );
+
+            let winner = game.players.iter().find(|p| **p != who).cloned();
+            Self::note_early_abandon(&game_id, &game, &who);
+            Self::deposit_event(Event::GameForfeited {
+                game_id,
+                player: who,
+            });
+            Self::end_game(&game_id, winner, EndReason::Forfeit);
+            Ok(())
+        }
+
+        /// Remove the per-game hands of a finished game. Callable by anyone; the final usage
+        /// was already reported through `GameSummary` when the game ended.
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)]
+        pub fn prune_game_hands(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(
+                matches!(game.state, GameState::Finished { .. }),
+                Error::<T>::GameNotFinished
+            );
+            let _ = HandsOfGame::<T>::clear_prefix(&game_id, u32::MAX, None);
+            let _ = GameMoveTimings::<T>::clear_prefix(&game_id, u32::MAX, None);
+            Self::deposit_event(Event::GameHandsPruned { game_id });
+            Ok(())
+        }
+
+        /// Remove the caller's presence from the game: clears their decks, open bot entry,
+        /// queued and featured hand selections, leaves the matchmaking queue, stops watching,
+        /// forfeits every unfinished game, unlists their cards from the marketplace, withdraws
+        /// their wager offers and, if `burn_profile` is set, deletes their gamer tag and
+        /// avatar. Cards stay owned by the caller. Emits a single `Deregistered` summary.
+        #[pallet::call_index(14)]
+        #[pallet::weight(10_000)]
+        pub fn deregister(origin: OriginFor<T>, burn_profile: bool) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+
+            // Drop the hand first so a series cannot start its next game for this account
+            let _ = Decks::<T>::clear_prefix(&who, u32::MAX, None);
+            ActiveDeck::<T>::remove(&who);
+            CurrentHandPower::<T>::remove(&who);
+            OpenBots::<T>::remove(&who);
+            FeaturedHandOf::<T>::remove(&who);
+            let left_queue =
+                <T::PresenceCleanup as crate::PresenceCleanup<AccountIdOf<T>>>::leave_queue(&who);
+            QueuedHandOf::<T>::remove(&who);
+            if let Some(watched) = WatchingOf::<T>::take(&who) {
+                WatchersOf::<T>::remove(watched, &who);
+                Self::drop_spectator(&watched);
+            }
+
+            let mut forfeited_games = 0u32;
+            let active: Vec<GameId<T>> = ActiveGameOf::<T>::drain_prefix(&who)
+                .map(|(_, game_id)| game_id)
+                .collect();
+            for game_id in active {
+                let Some(game) = GameStorage::<T>::get(&game_id) else {
+                    continue;
+                };
+                if matches!(game.state, GameState::Finished { .. }) {
+                    continue;
+                }
+                let winner = game.players.iter().find(|p| **p != who).cloned();
+                Self::note_early_abandon(&game_id, &game, &who);
+                Self::end_game(&game_id, winner, EndReason::Forfeit);
+                forfeited_games = forfeited_games.saturating_add(1);
+            }
+
+            let unlisted_cards = cards::Pallet::<T>::unlist_all(&who);
+            for (_, card_id) in WagerOffers::<T>::drain_prefix(&who) {
+                cards::Pallet::<T>::release_escrow(card_id);
+            }
+            let profile_burned = burn_profile
+                && <T::PresenceCleanup as crate::PresenceCleanup<AccountIdOf<T>>>::burn_profile(
+                    &who,
+                );
+
+            Self::deposit_event(Event::Deregistered {
+                who,
+                forfeited_games,
+                unlisted_cards,
+                left_queue,
+                profile_burned,
+            });
+            Ok(())
+        }
+
+        /// Rewrite up to `batch_size` stored games whose `version` is older than
+        /// `CURRENT_GAME_VERSION`, and rebuild the live-game indexes a storage upgrade left
+        /// pending in `GameReindex`. Resumes from where the previous call stopped; emits
+        /// `GamesMigrated` with `complete: true` once the whole map has been walked.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(
+            *batch_size as u64 * 10 + 2,
+            *batch_size as u64 * 7 + 2
+        ))]
+        pub fn migrate_games(origin: OriginFor<T>, batch_size: u32) -> DispatchResult {
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            ensure!(batch_size > 0, Error::<T>::InvalidBatchSize);
+
+            let iter = match GameMigrationCursor::<T>::get() {
+                Some(last) => {
+                    GameStorage::<T>::iter_from(GameStorage::<T>::hashed_key_for(&last))
+                }
+                None => GameStorage::<T>::iter(),
+            };
+            let reindex = GameReindex::<T>::get();
+            let mut visited = 0u32;
+            let mut migrated = 0u32;
+            let mut last = None;
+            for (game_id, mut game) in iter.take(batch_size as usize) {
+                visited = visited.saturating_add(1);
+                let reindexed =
+                    reindex.is_some_and(|pending| Self::reindex_game(&game_id, &game, pending));
+                if game.version < CURRENT_GAME_VERSION {
+                    game.version = CURRENT_GAME_VERSION;
+                    GameStorage::<T>::insert(&game_id, game);
+                    migrated = migrated.saturating_add(1);
+                } else if reindexed {
+                    migrated = migrated.saturating_add(1);
+                }
+                last = Some(game_id);
+            }
+
+            let complete = visited < batch_size;
+            match last {
+                Some(game_id) if !complete => GameMigrationCursor::<T>::put(game_id),
+                _ => GameMigrationCursor::<T>::kill(),
+            }
+            if complete {
+                GameReindex::<T>::kill();
+            }
+            Self::deposit_event(Event::GamesMigrated { migrated, complete });
+            Ok(())
+        }
+
+        /// Start watching a live game, leaving any game the caller was watching before.
+        /// Only a count is kept per game, so lobbies can sort live games by popularity.
+        #[pallet::call_index(17)]
+        #[pallet::weight(10_000)]
+        pub fn watch_game(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(
+                matches!(game.state, GameState::Playing),
+                Error::<T>::GameNotPlaying
+            );
+            let previous = WatchingOf::<T>::get(&who);
+            if previous == Some(game_id) {
+                return Ok(());
+            }
+
+            let spectators = SpectatorCount::<T>::get(&game_id).unwrap_or(0);
+            ensure!(
+                spectators < T::MaxSpectators::get(),
+                Error::<T>::SpectatorLimitReached
+            );
+            if let Some(previous) = previous {
+                WatchersOf::<T>::remove(previous, &who);
+                Self::drop_spectator(&previous);
+            }
+            SpectatorCount::<T>::insert(&game_id, spectators + 1);
+            WatchingOf::<T>::insert(&who, game_id);
+            WatchersOf::<T>::insert(game_id, &who, ());
+            Self::deposit_event(Event::SpectatorsChanged {
+                game_id,
+                spectators: spectators + 1,
+            });
+            Ok(())
+        }
+
+        /// Stop watching `game_id`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(10_000)]
+        pub fn unwatch_game(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            ensure!(
+                WatchingOf::<T>::get(&who) == Some(game_id),
+                Error::<T>::NotWatching
+            );
+            WatchingOf::<T>::remove(&who);
+            WatchersOf::<T>::remove(game_id, &who);
+            Self::drop_spectator(&game_id);
+            Ok(())
+        }
+
+        /// Clear `account`'s `ActiveGameOf` markers that point at a finished or missing game,
+        /// or at a game the account does not play in. Anyone may call this, so accounts
+        /// stranded in `PlayerAlreadyInGame` by a bug or migration can recover without root.
+        #[pallet::call_index(19)]
+        #[pallet::weight(10_000)]
+        pub fn repair_active_game(origin: OriginFor<T>, account: AccountIdOf<T>) -> DispatchResult {
+            ensure_signed(origin)?;
+            let stale: Vec<(GameContext, GameId<T>)> = ActiveGameOf::<T>::iter_prefix(&account)
+                .filter(|(_, game_id)| match GameStorage::<T>::get(game_id) {
+                    Some(game) => {
+                        matches!(game.state, GameState::Finished { .. })
+                            || !game.players.contains(&account)
+                    }
+                    None => true,
+                })
+                .collect();
+            ensure!(!stale.is_empty(), Error::<T>::NothingToRepair);
+
+            for (context, game_id) in stale {
+                ActiveGameOf::<T>::remove(&account, context);
+                Self::deposit_event(Event::ActiveGameRepaired {
+                    who: account.clone(),
+                    context,
+                    game_id,
+                });
+            }
+            Ok(())
+        }
+
+        /// Attach `annotation` to the game's latest placement if the caller made it, as
+        /// recorded in `MoveHistory`; a skipped turn does not pass the move to the other seat.
+        /// Each move takes one annotation and a game holds at most `MaxMoveAnnotations`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(10_000)]
+        pub fn annotate_move(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            annotation: MoveAnnotation,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            let seat = game
+                .players
+                .iter()
+                .position(|p| *p == who)
+                .ok_or(Error::<T>::PlayerNotInGame)? as u8;
+            let (move_no, mover) = MoveHistory::<T>::get(&game_id)
+                .iter()
+                .filter(|m| matches!(m.action, RecordedAction::Place { .. }))
+                .enumerate()
+                .last()
+                .map(|(i, m)| (i as u8 + 1, m.seat))
+                .ok_or(Error::<T>::NoMoveToAnnotate)?;
+            ensure!(mover == seat, Error::<T>::NoMoveToAnnotate);
+
+            MoveAnnotations::<T>::try_mutate(&game_id, |log| -> DispatchResult {
+                ensure!(
+                    !log.iter().any(|a| a.seat == seat && a.move_no == move_no),
+                    Error::<T>::MoveAlreadyAnnotated
+                );
+                log.try_push(AnnotatedMove {
+                    seat,
+                    move_no,
+                    annotation,
+                })
+                .map_err(|_| Error::<T>::AnnotationLimitReached)?;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::MoveAnnotated {
+                game_id,
+                seat,
+                move_no,
+                annotation,
+            });
+            Ok(())
+        }
+
+        /// (Admin) Hand `old`'s seat in a live game to `new`, e.g. after a player recovered
+        /// their account elsewhere. The submitted hand, move timing, `ActiveGameOf` and the
+        /// game's entry in the paged history (`PlayerGamePages`, `PlayerGameCount`) move with
+        /// the seat, and `GameOutcome` moves series, fixtures and brackets; `new` must not
+        /// already play in the game or another game of the same context.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(13, 16))]
+        pub fn reassign_game_seat(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            old: AccountIdOf<T>,
+            new: AccountIdOf<T>,
+        ) -> DispatchResult {
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(
+                !matches!(game.turn, TurnState::Finished),
+                Error::<T>::GameNotPlaying
+            );
+            let seat = game
+                .players
+                .iter()
+                .position(|p| *p == old)
+                .ok_or(Error::<T>::PlayerNotInGame)?;
+            ensure!(!game.players.contains(&new), Error::<T>::PlayerAlreadyInGame);
+            let context = GameContexts::<T>::get(&game_id).unwrap_or(GameContext::Casual);
+            ensure!(
+                ActiveGameOf::<T>::get(&new, context).is_none(),
+                Error::<T>::PlayerAlreadyInGame
+            );
+
+            if let Some(slot) = game.players.get_mut(seat) {
+                *slot = new.clone();
+            }
+            GameStorage::<T>::insert(&game_id, game);
+            if let Some(hand) = HandsOfGame::<T>::take(&game_id, &old) {
+                HandsOfGame::<T>::insert(&game_id, &new, hand);
+            }
+            if GameMoveTimings::<T>::contains_key(&game_id, &old) {
+                let timing = GameMoveTimings::<T>::take(&game_id, &old);
+                GameMoveTimings::<T>::insert(&game_id, &new, timing);
+            }
+            if ActiveGameOf::<T>::get(&old, context) == Some(game_id) {
+                ActiveGameOf::<T>::remove(&old, context);
+            }
+            ActiveGameOf::<T>::insert(&new, context, game_id);
+            Self::remove_player_game(&old, &game_id);
+            Self::append_player_game(&new, game_id);
+            <T::GameOutcome as GameOutcomeObserver<AccountIdOf<T>, GameId<T>>>::on_seat_reassigned(
+                &game_id, &old, &new,
+            );
+
+            Self::deposit_event(Event::GameSeatReassigned {
+                game_id,
+                seat: seat as u8,
+                old,
+                new,
+            });
+            Ok(())
+        }
+
+        /// (Moderator) Cancel a live game without a result and remove everything stored for
+        /// it. Stakes held on the game are refunded through `GameOutcome`. Ratings, streaks,
+        /// daily caps and card usage are only recorded when a game ends, so a voided game
+        /// never affects them. Games that no longer decode can be voided too; stale
+        /// `ActiveGameOf` markers they leave behind are cleared by `repair_active_game`.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(6, 16))]
+        pub fn void_game(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            reason: VoidReason,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+            ensure!(
+                GameStorage::<T>::contains_key(&game_id),
+                Error::<T>::GameNotFound
+            );
+            let game = GameStorage::<T>::get(&game_id);
+            ensure!(
+                !game
+                    .as_ref()
+                    .is_some_and(|g| matches!(g.turn, TurnState::Finished)),
+                Error::<T>::GameNotPlaying
+            );
+
+            Self::do_void_game(&game_id, game, reason);
+            Ok(())
+        }
+
+        /// Stake `card_id` on a casual game against `opponent`. The card is held in escrow
+        /// until `opponent` accepts with a card of their own or the offer is cancelled.
+        #[pallet::call_index(23)]
+        #[pallet::weight(10_000)]
+        pub fn offer_wager(
+            origin: OriginFor<T>,
+            opponent: AccountIdOf<T>,
+            card_id: u32,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            ensure!(who != opponent, Error::<T>::InvalidMove);
+            ensure!(
+                !WagerOffers::<T>::contains_key(&who, &opponent),
+                Error::<T>::WagerAlreadyOffered
+            );
+            cards::Pallet::<T>::escrow_card(&who, card_id)?;
+            WagerOffers::<T>::insert(&who, &opponent, card_id);
+            Self::require_action(&opponent, None, ActionKind::WagerPending, None);
+            Self::deposit_event(Event::WagerOffered {
+                proposer: who,
+                opponent,
+                card_id,
+            });
+            Ok(())
+        }
+
+        /// Withdraw an open wager offer to `opponent` and release the staked card.
+        #[pallet::call_index(24)]
+        #[pallet::weight(10_000)]
+        pub fn cancel_wager(origin: OriginFor<T>, opponent: AccountIdOf<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let card_id =
+                WagerOffers::<T>::take(&who, &opponent).ok_or(Error::<T>::NoWagerOffer)?;
+            cards::Pallet::<T>::release_escrow(card_id);
+            Self::deposit_event(Event::WagerCancelled {
+                proposer: who,
+                opponent,
+                card_id,
+            });
+            Ok(())
+        }
+
+        /// Accept `proposer`'s wager by staking `card_id`, and start the casual game. The
+        /// winner receives both cards; a draw, a game abandoned before round 1 or a voided
+        /// game returns them.
+        #[pallet::call_index(25)]
+        #[pallet::weight(10_000)]
+        pub fn accept_wager(
+            origin: OriginFor<T>,
+            proposer: AccountIdOf<T>,
+            card_id: u32,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let offered =
+                WagerOffers::<T>::take(&proposer, &who).ok_or(Error::<T>::NoWagerOffer)?;
+            cards::Pallet::<T>::escrow_card(&who, card_id)?;
+            let game_id = Self::do_create_pvp_game(&proposer, &who, GameContext::Casual)?;
+            let stakes = [(proposer, offered), (who, card_id)];
+            GameWagers::<T>::insert(game_id, stakes.clone());
+            Self::deposit_event(Event::WagerAccepted { game_id, stakes });
+            Ok(())
+        }
+
+        /// (Game admin) Publish a hand of synthetic cards, given as (north, east, south,
+        /// west) ranks, that any player may borrow for `FeaturedHandDuration` blocks.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+        pub fn publish_featured_hand(origin: OriginFor<T>, cards: Vec<[u8; 4]>) -> DispatchResult {
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                cards.len() as u32 == T::HandSize::get()
+                    && cards.iter().flatten().all(|rank| (1..=9).contains(rank)),
+                Error::<T>::InvalidFeaturedHand
+            );
+            let cards: BoundedVec<_, HandLimit> =
+                cards.try_into().map_err(|_| Error::<T>::InvalidFeaturedHand)?;
+
+            let hand_id = NextFeaturedHandId::<T>::get();
+            NextFeaturedHandId::<T>::put(hand_id.wrapping_add(1));
+            let expires_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::FeaturedHandDuration::get());
+            FeaturedHands::<T>::insert(hand_id, FeaturedHand { cards, expires_at });
+            Self::deposit_event(Event::FeaturedHandPublished {
+                hand_id,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// Play casual and PvE games created from now on with a live featured hand instead
+        /// of your current hand, or go back to your own cards with `None`.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn use_featured_hand(origin: OriginFor<T>, hand_id: Option<u32>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            match hand_id {
+                Some(id) => {
+                    ensure!(
+                        Self::live_featured_hand(id).is_some(),
+                        Error::<T>::FeaturedHandUnavailable
+                    );
+                    FeaturedHandOf::<T>::insert(&who, id);
+                }
+                None => FeaturedHandOf::<T>::remove(&who),
+            }
+            Self::deposit_event(Event::FeaturedHandSelected { who, hand_id });
+            Ok(())
+        }
+
+        /// (Game admin) Replace the HTTP endpoints that nodes running the result-webhook
+        /// offchain worker post finished games to. An empty list turns webhooks off.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_result_webhooks(origin: OriginFor<T>, urls: Vec<Vec<u8>>) -> DispatchResult {
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            let mut endpoints = BoundedVec::<_, _>::new();
+            for url in urls {
+                ensure!(crate::webhooks::is_valid_url(&url), Error::<T>::InvalidWebhook);
+                let url: crate::webhooks::WebhookUrl =
+                    url.try_into().map_err(|_| Error::<T>::InvalidWebhook)?;
+                endpoints.try_push(url).map_err(|_| Error::<T>::TooManyWebhooks)?;
+            }
+            let count = endpoints.len() as u32;
+            ResultWebhooks::<T>::put(endpoints);
+            Self::deposit_event(Event::ResultWebhooksSet { count });
+            Ok(())
+        }
+
+        /// (Root, `dev-mode` builds only) Replace the board and round of a live game, so QA
+        /// can start a scenario mid-game instead of playing up to it. Nothing is validated:
+        /// scores, hands and whose turn it is stay as they were.
+        #[cfg(feature = "dev-mode")]
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn dev_set_board(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            board: Board,
+            round: u8,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::dev_mutate_game(&game_id, |game| {
+                game.board = board;
+                game.round = round;
+            })
+        }
+
+        /// (Root, `dev-mode` builds only) Mark the cards at `hand_indices` of `player`'s hand
+        /// in a live game as played.
+        #[cfg(feature = "dev-mode")]
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+        pub fn dev_mark_hand_used(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+            hand_indices: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let mut hand =
+                HandsOfGame::<T>::get(&game_id, &player).ok_or(Error::<T>::HandNotSubmitted)?;
+            for index in hand_indices {
+                let entry =
+                    hand.get_mut(index as usize).ok_or(Error::<T>::HandIndexOutOfRange)?;
+                entry.used = true;
+            }
+            Self::dev_mutate_game(&game_id, |_| {})?;
+            HandsOfGame::<T>::insert(&game_id, &player, hand);
+            Ok(())
+        }
+
+        /// (Root, `dev-mode` builds only) Overwrite the scores of a live game.
+        #[cfg(feature = "dev-mode")]
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn dev_set_scores(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            scores: (u8, u8),
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::dev_mutate_game(&game_id, |game| game.scores = scores)
+        }
+
+        /// Win a PvP game whose opponent has not submitted a hand by its `HandDeadlines`
+        /// block. The caller must have submitted theirs.
+        #[pallet::call_index(32)]
+        #[pallet::weight(10_000)]
+        pub fn claim_walkover(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+            ensure!(
+                matches!(game.state, GameState::Playing),
+                Error::<T>::GameNotPlaying
+            );
+            let deadline =
+                HandDeadlines::<T>::get(&game_id).ok_or(Error::<T>::WalkoverNotAvailable)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > deadline,
+                Error::<T>::HandDeadlineNotPassed
+            );
+            let opponent = game
+                .players
+                .iter()
+                .find(|p| **p != who)
+                .cloned()
+                .ok_or(Error::<T>::InternalError)?;
+            ensure!(
+                HandsOfGame::<T>::contains_key(&game_id, &who)
+                    && !HandsOfGame::<T>::contains_key(&game_id, &opponent),
+                Error::<T>::WalkoverNotAvailable
+            );
+
+            Self::end_game(&game_id, Some(who), EndReason::Walkover);
+            Ok(())
+        }
+
+        /// Remove the `MoveHistory` of a game that ended at least `MoveHistoryRetention`
+        /// blocks ago. Callable by anyone.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+        pub fn prune_move_history(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let expiry =
+                MoveHistoryExpiry::<T>::get(&game_id).ok_or(Error::<T>::MoveHistoryRetained)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= expiry,
+                Error::<T>::MoveHistoryRetained
+            );
+            MoveHistory::<T>::remove(&game_id);
+            MoveHistoryExpiry::<T>::remove(&game_id);
+            Self::deposit_event(Event::MoveHistoryPruned { game_id });
+            Ok(())
+        }
+
+        /// (Admin) Play new games of `mode` on `dim` x `dim` boards. Games already created
+        /// keep their board.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_board_dim(origin: OriginFor<T>, mode: GameMode, dim: u8) -> DispatchResult {
+            use eterra_core_types::geometry::{is_valid_dim, BOARD_DIM};
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            ensure!(is_valid_dim(dim), Error::<T>::InvalidBoardDim);
+            if dim == BOARD_DIM {
+                ModeBoardDims::<T>::remove(&mode);
+            } else {
+                ModeBoardDims::<T>::insert(&mode, dim);
+            }
+            Self::deposit_event(Event::BoardDimSet { mode, dim });
+            Ok(())
+        }
+
+        /// (Admin) Create new games of `mode` with the optional `rules`, e.g. Same, Plus or
+        /// Combo captures; `None` goes back to `DefaultRules`. Games already created keep
+        /// their rules.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_mode_rules(
+            origin: OriginFor<T>,
+            mode: GameMode,
+            rules: Option<eterra_core_types::rules::Rules>,
+        ) -> DispatchResult {
+            T::GameAdminOrigin::ensure_origin(origin)?;
+            ModeRules::<T>::set(&mode, rules);
+            Self::deposit_event(Event::ModeRulesSet { mode, rules });
+            Ok(())
+        }
+
+        /// Save `card_ids` as a new deck called `name` at the caller's lowest free index. It
+        /// becomes the active deck if the caller has none.
+        #[pallet::call_index(36)]
+        #[pallet::weight(10_000)]
+        pub fn create_deck(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            card_ids: Vec<u32>,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let name = BoundedVec::try_from(name).map_err(|_| Error::<T>::DeckNameTooLong)?;
+            let hand = Self::validated_hand(&who, card_ids)?;
+            let index = Self::free_deck_index(&who)?;
+
+            if !ActiveDeck::<T>::contains_key(&who) {
+                ActiveDeck::<T>::insert(&who, index);
+                CurrentHandPower::<T>::insert(&who, Self::hand_power(&hand));
+            }
+            Decks::<T>::insert(&who, index, Deck { name, cards: hand });
+            Self::deposit_event(Event::DeckCreated { who, index });
+            Ok(())
+        }
+
+        /// Rename deck `index` and/or replace its cards. Changes to the active deck apply to
+        /// games created afterwards.
+        #[pallet::call_index(37)]
+        #[pallet::weight(10_000)]
+        pub fn update_deck(
+            origin: OriginFor<T>,
+            index: u8,
+            name: Option<Vec<u8>>,
+            card_ids: Option<Vec<u32>>,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let mut deck = Decks::<T>::get(&who, index).ok_or(Error::<T>::DeckNotFound)?;
+            if let Some(name) = name {
+                deck.name = BoundedVec::try_from(name).map_err(|_| Error::<T>::DeckNameTooLong)?;
+            }
+            if let Some(card_ids) = card_ids {
+                deck.cards = Self::validated_hand(&who, card_ids)?;
+                if ActiveDeck::<T>::get(&who) == Some(index) {
+                    CurrentHandPower::<T>::insert(&who, Self::hand_power(&deck.cards));
+                }
+            }
+
+            Decks::<T>::insert(&who, index, deck);
+            Self::deposit_event(Event::DeckUpdated { who, index });
+            Ok(())
+        }
+
+        /// Delete deck `index`. Deleting the active deck leaves the caller without a current
+        /// hand until they pick another one with `set_active_deck`.
+        #[pallet::call_index(38)]
+        #[pallet::weight(10_000)]
+        pub fn delete_deck(origin: OriginFor<T>, index: u8) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            ensure!(Decks::<T>::contains_key(&who, index), Error::<T>::DeckNotFound);
+
+            Decks::<T>::remove(&who, index);
+            if ActiveDeck::<T>::get(&who) == Some(index) {
+                ActiveDeck::<T>::remove(&who);
+                CurrentHandPower::<T>::remove(&who);
             }
+            Self::deposit_event(Event::DeckDeleted { who, index });
+            Ok(())
+        }
+
+        /// Deal the caller's hand in future games from deck `index`.
+        #[pallet::call_index(39)]
+        #[pallet::weight(10_000)]
+        pub fn set_active_deck(origin: OriginFor<T>, index: u8) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let deck = Decks::<T>::get(&who, index).ok_or(Error::<T>::DeckNotFound)?;
+
+            ActiveDeck::<T>::insert(&who, index);
+            CurrentHandPower::<T>::insert(&who, Self::hand_power(&deck.cards));
+            Self::deposit_event(Event::ActiveDeckSet { who, index });
+            Ok(())
+        }
+    }
+}
+
+// Helper methods
+impl<T: Config> Pallet<T> {
+    /// Apply `f` to a game that has not finished yet and report it with
+    /// `DevGameStateForced`.
+    #[cfg(feature = "dev-mode")]
+    fn dev_mutate_game(
+        game_id: &GameId<T>,
+        f: impl FnOnce(&mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>),
+    ) -> DispatchResult {
+        GameStorage::<T>::try_mutate(game_id, |game| {
+            let game = game.as_mut().ok_or(Error::<T>::GameNotFound)?;
+            ensure!(
+                !matches!(game.turn, TurnState::Finished),
+                Error::<T>::GameNotPlaying
+            );
+            f(game);
+            Ok::<_, sp_runtime::DispatchError>(())
+        })?;
+        Self::deposit_event(Event::DevGameStateForced { game_id: *game_id });
+        Ok(())
+    }
+
+    /// Create a PvP game between two accounts without a signed origin.
+    /// Intended to be called from the matchmaking pallet via the `GameCreator` trait
+    /// (`GameContext::Ranked`) or from tournament logic (`GameContext::Tournament`).
+    pub fn do_create_pvp_game(
+        a: &AccountIdOf<T>,
+        b: &AccountIdOf<T>,
+        context: GameContext,
+    ) -> Result<GameId<T>, sp_runtime::DispatchError> {
+        use sp_runtime::traits::SaturatedConversion;
+
+        // Sanity checks
+        ensure!(a != b, Error::<T>::PlayersNotDistinct);
+        ensure!(
+            T::NumPlayers::get() == 2,
+            Error::<T>::InvalidNumberOfPlayers
+        );
 
-            let current_block_number = <frame_system::Pallet<T>>::block_number();
-            let game_id =
-                T::Hashing::hash_of(&(creator.clone(), opponent.clone(), current_block_number));
+        // Both players must have a preset/current hand (defense in depth; the matchmaker checks this too)
+        ensure!(
+            Self::current_hand_of(a).is_some() || QueuedHandOf::<T>::contains_key(a),
+            Error::<T>::PresetHandMissing
+        );
+        ensure!(
+            Self::current_hand_of(b).is_some() || QueuedHandOf::<T>::contains_key(b),
+            Error::<T>::PresetHandMissing
+        );
+        // Hands bound at queue time are validated before anything is written.
+        let bound_hands = [Self::bound_hand(a)?, Self::bound_hand(b)?];
 
-            // Ensure the game_id isn’t already in use (collision check)
-            ensure!(
-                !GameStorage::<T>::contains_key(&game_id),
-                Error::<T>::GameNotFound
-            );
+        // Neither is currently in another game of the same context
+        ensure!(
+            ActiveGameOf::<T>::get(a, context).is_none(),
+            Error::<T>::PlayerAlreadyInGame
+        );
+        ensure!(
+            ActiveGameOf::<T>::get(b, context).is_none(),
+            Error::<T>::PlayerAlreadyInGame
+        );
+        Self::ensure_under_daily_cap(a, context)?;
+        Self::ensure_under_daily_cap(b, context)?;
 
-            let initial_board: Board = Default::default();
-            let initial_scores = (5, 5);
+        // Create a deterministic game id from (a,b,block)
+        let current_block_number = <frame_system::Pallet<T>>::block_number();
+        let game_id = T::Hashing::hash_of(&(a.clone(), b.clone(), current_block_number));
 
-            let mut game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers> = Game {
-                state: GameState::Playing,
-                last_played_block: current_block_number,
-                players: players
-                    .clone()
-                    .try_into()
-                    .map_err(|_| Error::<T>::InternalError)?,
-                player_turn: 0,
-                round: 0,
-                max_rounds: T::MaxRounds::get(),
-                board: initial_board.clone(),
-                scores: initial_scores,
-            };
+        // Collision check (extremely unlikely)
+        ensure!(
+            !GameStorage::<T>::contains_key(&game_id),
+            Error::<T>::GameIdCollision
+        );
+        Self::init_game_entropy(&game_id);
+        Self::init_game_rules(&game_id, &GameMode::PvP);
 
-            GameModes::<T>::insert(&game_id, game_mode.clone());
-            // Mark participants as busy with this game
-            match game_mode {
-                GameMode::PvP => {
-                    ActiveGameOf::<T>::insert(&creator, game_id);
-                    ActiveGameOf::<T>::insert(&opponent, game_id);
-                }
-                GameMode::PvE => {
-                    // Only mark the human creator as active; AI is allowed to be in many games simultaneously.
-                    ActiveGameOf::<T>::insert(&creator, game_id);
+        // Build initial game struct
+        let initial_board = Self::new_board(&game_id, &GameMode::PvP);
+        let initial_scores = (5, 5);
+        let players_vec = sp_std::vec![a.clone(), b.clone()];
+
+        let mut game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers> = Game {
+            state: GameState::Playing,
+            last_played_block: current_block_number,
+            players: players_vec
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InternalError)?,
+            player_turn: 0,
+            round: 0,
+            max_rounds: T::MaxRounds::get(),
+            board: initial_board.clone(),
+            scores: initial_scores,
+            version: CURRENT_GAME_VERSION,
+            turn: TurnState::AwaitingMove(0),
+        };
+
+        // Mark this as a PvP game and set active game markers
+        GameModes::<T>::insert(&game_id, GameMode::PvP);
+        GameContexts::<T>::insert(&game_id, context);
+        ActiveGameOf::<T>::insert(a, context, game_id);
+        ActiveGameOf::<T>::insert(b, context, game_id);
+
+        Self::append_player_game(a, game_id);
+        Self::append_player_game(b, game_id);
+
+        // Randomize the starting player from the game's seed, as `create_game` does for PvP
+        game.start(Self::first_seat(&game_id), false);
+
+        Self::apply_handicap(&game_id, &mut game);
+
+        // A bound hand over the handicap budget, or a ranked one holding a card still in its
+        // trade cooldown, is left for the player to resubmit.
+        for (ix, (who, hand)) in [a, b].into_iter().zip(bound_hands).enumerate() {
+            if let Some(hand) = hand {
+                if Self::ensure_within_handicap(&game_id, ix as u8, &hand).is_ok()
+                    && (context != GameContext::Ranked
+                        || Self::ensure_no_trade_cooldown(&hand).is_ok())
+                {
+                    Self::store_snapshotted_hand(&game_id, who, hand);
                 }
             }
+        }
 
-            // Update per-player recent game lists (most-recent first, dedup, prune to 10)
-            let mut push_recent = |acct: &AccountIdOf<T>| {
-                PlayerGames::<T>::mutate(acct, |list| {
-                    if let Some(pos) = list.iter().position(|g| *g == game_id) {
-                        list.remove(pos);
-                    }
-                    // Try to insert at the front; if full, pop last first
-                    if list.len() as u32 >= <ConstU32<10> as sp_runtime::traits::Get<u32>>::get() {
-                        let _ = list.pop();
-                    }
-                    // Insert at front by rebuilding (BoundedVec has no direct insert at 0)
-                    let mut tmp = list.to_vec();
-                    tmp.insert(0, game_id);
-                    *list = BoundedVec::try_from(tmp).expect("<= 10; qed");
-                });
-            };
-            push_recent(&creator);
-            push_recent(&opponent);
+        Self::record_game_start(&game_id, &game);
+        GameStorage::<T>::insert(&game_id, game.clone());
+        Self::emit_created(&game_id, &game, GameMode::PvP, context);
 
-            // If PvE, create AI hand immediately so UI can render it.
-            if matches!(game_mode, GameMode::PvE) {
-                let ai_acc = T::AiAccount::get();
-                if HandsOfGame::<T>::get(&game_id, &ai_acc).is_none() {
-                    if let Some(ai_hand) = Self::generate_ai_hand_default(&game_id) {
-                        HandsOfGame::<T>::insert(&game_id, &ai_acc, ai_hand);
+        Ok(game_id)
+    }
+
+    /// Shared implementation of `create_game` and `create_sealed_game`.
+    fn do_create_game(
+        who: AccountIdOf<T>,
+        mut players: Vec<AccountIdOf<T>>,
+        game_mode: GameMode,
+        sealed: bool,
+    ) -> DispatchResult {
+        // Require the creator to have a current hand (or a borrowed one) before starting a game
+        ensure!(
+            sealed
+                || Self::current_hand_of(&who).is_some()
+                || Self::featured_hand_for(&who).is_some(),
+            Error::<T>::PresetHandMissing
+        );
+
+        // Normalize players vector depending on mode
+        match game_mode {
+            GameMode::PvP => {
+                // For PvP, the caller must have included themselves and exactly one opponent.
+                ensure!(players.contains(&who), Error::<T>::CreatorMustBeInGame);
+                ensure!(
+                    players.len()
+                        == <u32 as sp_runtime::traits::SaturatedConversion>::saturated_into::<
+                            usize,
+                        >(T::NumPlayers::get()),
+                    Error::<T>::InvalidNumberOfPlayers
+                );
+                // Ensure distinct players; also normalize order to [creator, opponent]
+                // so downstream logic is predictable.
+                ensure!(players[0] != players[1], Error::<T>::PlayersNotDistinct);
+                if players[0] != who {
+                    // Put creator in slot 0
+                    if players[1] == who {
+                        players.swap(0, 1);
+                    } else {
+                        // Shouldn’t happen because of the contains() check, but be safe.
+                        return Err(Error::<T>::CreatorMustBeInGame.into());
                     }
                 }
             }
-
-            // Set starting player: PvE -> creator always starts; PvP -> keep randomized start
-            if matches!(game_mode, GameMode::PvE) {
-                // players[0] is guaranteed to be the creator after normalization above
-                game.set_player_turn(0);
-            } else {
-                // PvP: randomize starting player based on creator hash
-                game.set_player_turn(
-                    if sp_io::hashing::blake2_128(&creator.encode())[0] % 2 == 0 {
-                        0
-                    } else {
-                        1
-                    },
-                );
+            GameMode::PvE => {
+                // For PvE, force [creator, AI]. An explicitly listed AI identity is honored;
+                // otherwise one is auto-selected from the registry by the creator's level.
+                ensure!(!AiProfiles::<T>::contains_key(&who), Error::<T>::CreatorIsAi);
+                let ai_acc = match players
+                    .iter()
+                    .find(|p| **p != who && Self::is_offered_ai(p))
+                {
+                    Some(acc) => acc.clone(),
+                    None => Self::select_ai_for(&who).ok_or(Error::<T>::NoAiAvailable)?,
+                };
+                players = sp_std::vec![who.clone(), ai_acc];
+            }
+            GameMode::Bot => {
+                // The single non-creator entry must be a registered open bot.
+                let bot = players
+                    .iter()
+                    .find(|p| **p != who)
+                    .cloned()
+                    .ok_or(Error::<T>::NotAnOpenBot)?;
+                ensure!(OpenBots::<T>::contains_key(&bot), Error::<T>::NotAnOpenBot);
+                players = sp_std::vec![who.clone(), bot];
             }
-
-            GameStorage::<T>::insert(&game_id, game.clone());
-            Self::deposit_event(Event::GameCreated { game_id });
-            Ok(())
         }
-        #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
-        pub fn play(origin: OriginFor<T>, game_id: GameId<T>, player_move: Move) -> DispatchResult {
-            let who = ensure_signed(origin)?;
 
-            log::debug!(
-                "Player {:?} is attempting to play on game_id {:?} at {:?}",
-                who,
-                game_id,
-                player_move
-            );
+        // From here on, `players` is normalized for both modes.
+        let number_of_players = players.len();
+        ensure!(
+            number_of_players
+                == <u32 as sp_runtime::traits::SaturatedConversion>::saturated_into::<usize>(
+                    T::NumPlayers::get()
+                ),
+            Error::<T>::InvalidNumberOfPlayers
+        );
 
-            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+        let creator = players[0].clone();
+        let opponent = players[1].clone();
 
-            // Validate the current player's turn and move
-            Self::validate_player_turn(&game, &who)?;
-            Self::validate_move(&game, &player_move)?;
+        // Redundant after normalization, but keep as a safety net.
+        ensure!(creator != opponent, Error::<T>::PlayersNotDistinct);
 
-            // Determine the current player's index (0 or 1)
-            let player_ix = Self::get_current_player_index(&game, &who);
+        // Enforce: a wallet may participate in at most one active game per context.
+        let context = game_mode.default_context();
+        match game_mode {
+            GameMode::PvP => {
+                ensure!(
+                    ActiveGameOf::<T>::get(&creator, context).is_none(),
+                    Error::<T>::PlayerAlreadyInGame
+                );
+                ensure!(
+                    ActiveGameOf::<T>::get(&opponent, context).is_none(),
+                    Error::<T>::PlayerAlreadyInGame
+                );
+            }
+            GameMode::PvE | GameMode::Bot => {
+                // Only the human creator is restricted in PvE; the AI may participate in many games.
+                ensure!(
+                    ActiveGameOf::<T>::get(&creator, context).is_none(),
+                    Error::<T>::PlayerAlreadyInGame
+                );
+            }
+        }
+        Self::ensure_under_daily_cap(&creator, context)?;
+        if matches!(game_mode, GameMode::PvP) {
+            Self::ensure_under_daily_cap(&opponent, context)?;
+        }
 
-            // Place the card on the board
-            Self::place_card_on_board(&mut game, &player_move, player_ix);
+        let current_block_number = <frame_system::Pallet<T>>::block_number();
+        let game_id =
+            T::Hashing::hash_of(&(creator.clone(), opponent.clone(), current_block_number));
 
-            // Capture logic
-            Self::apply_capture_logic(&mut game, &player_move, player_ix);
+        // Ensure the game_id isn’t already in use (collision check)
+        ensure!(
+            !GameStorage::<T>::contains_key(&game_id),
+            Error::<T>::GameIdCollision
+        );
+        Self::init_game_entropy(&game_id);
+        Self::init_game_rules(&game_id, &game_mode);
 
-            // Update the last_played_block to the current block number
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            game.last_played_block = current_block;
+        // Bot games snapshot the bot's current hand up front so its controller only plays.
+        let bot_hand = match game_mode {
+            GameMode::Bot if !sealed => Some(Self::build_hand_from_current(&opponent)?),
+            _ => None,
+        };
 
-            // Check if the game is won
-            // if let Some(winner) = Self::is_game_won(&game_id, &game) {
-            //     Self::end_game(&game_id, winner);
-            //     return Ok(());
-            // }
+        // Sealed games deal every non-AI player a hand from their whole collection.
+        let mut dealt_hands = Vec::new();
+        if sealed {
+            for p in players.iter() {
+                if matches!(game_mode, GameMode::PvE) && *p == opponent {
+                    continue;
+                }
+                dealt_hands.push((p.clone(), Self::deal_sealed_hand(&game_id, p)?));
+            }
+        }
 
-            // Update to the next turn
-            game.next_turn();
+        let initial_board = Self::new_board(&game_id, &game_mode);
+        let initial_scores = (5, 5);
 
-            log::debug!(
-                "Saving game state after next_turn. Current round: {}, player_turn: {}",
-                game.round,
-                game.player_turn
-            );
+        let mut game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers> = Game {
+            state: GameState::Playing,
+            last_played_block: current_block_number,
+            players: players
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InternalError)?,
+            player_turn: 0,
+            round: 0,
+            max_rounds: T::MaxRounds::get(),
+            board: initial_board.clone(),
+            scores: initial_scores,
+            version: CURRENT_GAME_VERSION,
+            turn: TurnState::AwaitingMove(0),
+        };
 
-            // Emit a NewTurn event for the new current player
-            let next_player = game.players[game.get_player_turn() as usize].clone();
-            Self::deposit_event(Event::NewTurn {
-                game_id,
-                next_player,
-            });
+        GameModes::<T>::insert(&game_id, game_mode.clone());
+        GameContexts::<T>::insert(&game_id, context);
+        // Mark participants as busy with this game
+        match game_mode {
+            GameMode::PvP => {
+                ActiveGameOf::<T>::insert(&creator, context, game_id);
+                ActiveGameOf::<T>::insert(&opponent, context, game_id);
+            }
+            GameMode::PvE | GameMode::Bot => {
+                // Only mark the human creator as active; AI is allowed to be in many games simultaneously.
+                ActiveGameOf::<T>::insert(&creator, context, game_id);
+            }
+        }
 
-            // Save the updated game
-            GameStorage::<T>::insert(&game_id, game.clone());
+        Self::append_player_game(&creator, game_id);
+        Self::append_player_game(&opponent, game_id);
 
-            // Check if the game is won after updating the round
-            if let Some(winner) = Self::is_game_won(&game_id, &game) {
-                Self::end_game(&game_id, winner);
-                return Ok(());
+        // If PvE, create AI hand immediately so UI can render it.
+        if matches!(game_mode, GameMode::PvE) {
+            if HandsOfGame::<T>::get(&game_id, &opponent).is_none() {
+                if let Some(ai_hand) = Self::generate_ai_hand_default(&game_id, &opponent) {
+                    Self::store_ai_hand(&game_id, &opponent, ai_hand);
+                }
             }
+        }
 
-            log::debug!(
-                "Next turn belongs to: {:?}",
-                game.players[game.get_player_turn() as usize]
+        if let Some(hand) = bot_hand {
+            Self::store_snapshotted_hand(&game_id, &opponent, hand);
+        }
+
+        if sealed {
+            SealedGames::<T>::insert(&game_id, true);
+            for (p, hand) in dealt_hands {
+                let card_ids = hand.iter().filter_map(|e| e.card_id).collect();
+                HandsOfGame::<T>::insert(&game_id, &p, hand);
+                Self::deposit_event(Event::HandDealt {
+                    game_id,
+                    player: p,
+                    card_ids,
+                });
+            }
+        }
+
+        // Set starting player: PvE/Bot -> creator always starts; PvP -> keep randomized start
+        if matches!(game_mode, GameMode::PvE | GameMode::Bot) {
+            // players[0] is guaranteed to be the creator after normalization above. PvE
+            // waits for the human's hand, which also sizes the AI's, unless one was dealt.
+            game.start(0, matches!(game_mode, GameMode::PvE) && !sealed);
+        } else {
+            // PvP: randomize the starting player from the game's seed
+            game.start(Self::first_seat(&game_id), false);
+        }
+
+        if matches!(game_mode, GameMode::PvP) {
+            Self::apply_handicap(&game_id, &mut game);
+            HandDeadlines::<T>::insert(
+                &game_id,
+                current_block_number.saturating_add(T::HandSubmissionPeriod::get()),
             );
+        }
 
-            Self::deposit_event(Event::MovePlayed {
-                game_id,
-                player: who,
-                x: player_move.place_index_x,
-                y: player_move.place_index_y,
-            });
+        Self::record_game_start(&game_id, &game);
+        GameStorage::<T>::insert(&game_id, game.clone());
+        Self::emit_created(&game_id, &game, game_mode, context);
+        Ok(())
+    }
 
-            // If this is a PvE game and it's now the AI's turn, let the AI act immediately.
-            if matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvE)) {
-                if let Some(mut g) = GameStorage::<T>::get(&game_id) {
-                    Self::maybe_ai_take_turn(&game_id, &mut g);
-                }
+    /// Check that `card_ids` is a hand `who` may save in a deck: exactly `HandSize` unique,
+    /// existing cards they own, none vaulted or cooling down after a trade.
+    fn validated_hand(
+        who: &AccountIdOf<T>,
+        card_ids: Vec<u32>,
+    ) -> Result<BoundedVec<u32, HandLimit>, sp_runtime::DispatchError> {
+        ensure!(
+            card_ids.len() as u32 == T::HandSize::get(),
+            Error::<T>::HandSizeInvalid
+        );
+        for i in 0..card_ids.len() {
+            for j in (i + 1)..card_ids.len() {
+                ensure!(card_ids[i] != card_ids[j], Error::<T>::DuplicateCardInHand);
             }
+        }
 
-            Ok(())
+        // Validate ownership and that each card exists
+        for &card_id in &card_ids {
+            let info =
+                cards::pallet::Cards::<T>::get(card_id).ok_or(Error::<T>::CardDoesNotExist)?;
+            ensure!(info.owner == *who, Error::<T>::CardNotOwned);
+            ensure!(!cards::Pallet::<T>::is_vaulted(card_id), Error::<T>::CardVaulted);
         }
+        card_ids
+            .try_into()
+            .map_err(|_| Error::<T>::HandSizeInvalid.into())
+    }
 
-        /// Submit your current 5-card hand for this game. The submitted hand is always loaded from your current hand configuration.
-        /// The `card_ids` argument is ignored and exists for ABI compatibility only.
-        #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
-        pub fn submit_hand(
-            origin: OriginFor<T>,
-            game_id: GameId<T>,
-            card_ids: Vec<u32>,
-        ) -> DispatchResult {
-            let who: AccountIdOf<T> = ensure_signed(origin)?;
+    /// Card id of a hand entry stored before synthetic cards had none. Such entries used 0
+    /// both for synthetic cards and for the first minted card, which only its owner can hold.
+    fn legacy_card_id(card_id: u32, who: &AccountIdOf<T>) -> Option<u32> {
+        let owns_first = || cards::pallet::Cards::<T>::get(0).is_some_and(|c| c.owner == *who);
+        (card_id != 0 || owns_first()).then_some(card_id)
+    }
 
-            // Ensure the game exists and the caller is a player in it
-            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
-            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+    /// Lowest deck index below `MaxDecks` at which `who` has no deck.
+    fn free_deck_index(who: &AccountIdOf<T>) -> Result<u8, sp_runtime::DispatchError> {
+        (0..T::MaxDecks::get().min(u8::MAX as u32 + 1))
+            .map(|i| i as u8)
+            .find(|i| !Decks::<T>::contains_key(who, i))
+            .ok_or_else(|| Error::<T>::TooManyDecks.into())
+    }
 
-            // Prevent resubmission for this game
-            ensure!(
-                HandsOfGame::<T>::get(&game_id, &who).is_none(),
-                Error::<T>::HandAlreadySubmitted
-            );
+    /// Cards of `who`'s active deck, the hand their next game is dealt.
+    pub fn current_hand_of(who: &AccountIdOf<T>) -> Option<BoundedVec<u32, HandLimit>> {
+        let index = ActiveDeck::<T>::get(who)?;
+        Decks::<T>::get(who, index).map(|deck| deck.cards)
+    }
 
-            // Load the caller's current hand configuration and snapshot it into the game
-            let current_ids = CurrentHandOf::<T>::get(&who).ok_or(Error::<T>::PresetHandMissing)?;
-            ensure!(
-                current_ids.len() as u32 == T::HandSize::get(),
-                Error::<T>::HandSizeInvalid
-            );
+    /// Pin `who`'s current hand for their ranked queue entry. Called by the runtime's
+    /// matchmaker adapter on join; players without a current hand bind nothing.
+    pub fn bind_queued_hand(who: &AccountIdOf<T>) {
+        match Self::current_hand_of(who) {
+            Some(ids) => QueuedHandOf::<T>::insert(who, ids),
+            None => QueuedHandOf::<T>::remove(who),
+        }
+    }
 
-            // Validate uniqueness (defense in depth)
-            for i in 0..current_ids.len() {
-                for j in (i + 1)..current_ids.len() {
-                    ensure!(
-                        current_ids[i] != current_ids[j],
-                        Error::<T>::DuplicateCardInHand
-                    );
-                }
-            }
+    /// Drop the hand pinned by `bind_queued_hand` once the queue entry is matched or left.
+    pub fn release_queued_hand(who: &AccountIdOf<T>) {
+        QueuedHandOf::<T>::remove(who);
+    }
 
-            // Build hand entries from the cards pallet; validate ownership & existence
-            let mut hand: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
-            for &card_id in current_ids.iter() {
-                let info =
-                    cards::pallet::Cards::<T>::get(card_id).ok_or(Error::<T>::CardDoesNotExist)?;
-                ensure!(info.owner == who, Error::<T>::CardNotOwned);
-                let entry = HandEntry {
-                    card_id,
-                    north: info.north,
-                    east: info.east,
-                    south: info.south,
-                    west: info.west,
-                    used: false,
-                };
-                hand.try_push(entry)
-                    .map_err(|_| Error::<T>::HandSizeInvalid)?;
+    /// Canonical power of a hand: for each card, its edge sum plus its strongest edge (a
+    /// spiky card wins more captures than a flat one with the same total), scaled by rarity.
+    /// Unknown cards count as zero.
+    pub fn hand_power(card_ids: &[u32]) -> u32 {
+        card_ids
+            .iter()
+            .filter_map(|id| cards::pallet::Cards::<T>::get(id))
+            .map(|card| {
+                let edges = [card.north, card.east, card.south, card.west];
+                let sum: u32 = edges.iter().map(|&e| e as u32).sum();
+                let peak = edges.iter().copied().max().unwrap_or(0) as u32;
+                (sum + peak) * Self::rarity_weight(&card.rarity) / 100
+            })
+            .fold(0u32, |acc, p| acc.saturating_add(p))
+    }
+
+    /// Percentage multiplier applied to a card's power by `hand_power`.
+    fn rarity_weight(rarity: &cards::pallet::RarityType) -> u32 {
+        use cards::pallet::RarityType::*;
+        match rarity {
+            Common => 100,
+            Uncommon => 110,
+            Rare => 120,
+            Epic => 135,
+            Legendary => 150,
+        }
+    }
+
+    fn day_index() -> u64 {
+        <frame_system::Pallet<T>>::block_number().saturated_into::<u64>() / BLOCKS_PER_DAY
+    }
+
+    /// Rated games `who` completed in the current day window.
+    pub fn rated_games_today(who: &AccountIdOf<T>) -> u32 {
+        let (day, count) = RatedGamesToday::<T>::get(who);
+        if day == Self::day_index() {
+            count
+        } else {
+            0
+        }
+    }
+
+    /// Reject a new game for `who` once they hit `MaxRatedGamesPerDay`. Tournament games are
+    /// exempt, and a cap of 0 disables the limit.
+    fn ensure_under_daily_cap(who: &AccountIdOf<T>, context: GameContext) -> Result<(), Error<T>> {
+        let cap = T::MaxRatedGamesPerDay::get();
+        if cap == 0 || context == GameContext::Tournament {
+            return Ok(());
+        }
+        ensure!(
+            Self::rated_games_today(who) < cap,
+            Error::<T>::DailyGameLimitReached
+        );
+        Ok(())
+    }
+
+    fn note_rated_game_completed(who: &AccountIdOf<T>) {
+        let today = Self::day_index();
+        RatedGamesToday::<T>::mutate(who, |(day, count)| {
+            if *day != today {
+                *day = today;
+                *count = 0;
             }
+            *count = count.saturating_add(1);
+        });
+    }
 
-            HandsOfGame::<T>::insert(&game_id, &who, hand);
-            Self::deposit_event(Event::HandSubmitted {
-                game_id,
-                player: who.clone(),
-            });
+    fn record_game_start(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) {
+        GameStarts::<T>::insert(
+            game_id,
+            GameStart {
+                first_player: game.player_turn,
+                scores: game.scores,
+            },
+        );
+    }
 
-            // PvE: submitting player is always the human. Generate AI hand right away,
-            // and if it's AI's turn (e.g., AI won first move), let it act immediately.
-            if matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvE)) {
-                let ai_acc = T::AiAccount::get();
-                if HandsOfGame::<T>::get(&game_id, &ai_acc).is_none() {
-                    if let Some(ai_hand) = Self::generate_ai_hand_for_game(&game_id, &who) {
-                        HandsOfGame::<T>::insert(&game_id, &ai_acc, ai_hand);
-                    }
+    /// The opponent's hand as `viewer` may see it: counts and used flags always, card stats
+    /// only when the game does not use `Rules::CLOSED_HAND`. Returns `None` if `viewer` is
+    /// not seated in the game or the opponent has no hand yet.
+    pub fn opponent_hand_summary(
+        game_id: &GameId<T>,
+        viewer: &AccountIdOf<T>,
+    ) -> Option<OpponentHandSummary> {
+        let game = GameStorage::<T>::get(game_id)?;
+        if !game.players.contains(viewer) {
+            return None;
+        }
+        let opponent = game.players.iter().find(|p| *p != viewer)?;
+        let hand = HandsOfGame::<T>::get(game_id, opponent)?;
+
+        let used: BoundedVec<bool, HandLimit> =
+            hand.iter().map(|h| h.used).collect::<Vec<_>>().try_into().ok()?;
+        let closed = GameRules::<T>::get(game_id).contains(Rules::CLOSED_HAND);
+        Some(OpponentHandSummary {
+            size: hand.len() as u8,
+            remaining: used.iter().filter(|u| !**u).count() as u8,
+            used,
+            cards: (!closed).then_some(hand),
+        })
+    }
+
+    /// A game in the stable `GameView` shape, or `None` if it does not exist.
+    pub fn game_view(game_id: &GameId<T>) -> Option<GameView<AccountIdOf<T>, BlockNumberFor<T>>> {
+        let game = GameStorage::<T>::get(game_id)?;
+        Some(GameView {
+            players: game.players.into_inner(),
+            turn: game.turn,
+            round: game.round,
+            max_rounds: game.max_rounds,
+            board: game.board,
+            scores: game.scores,
+            mode: GameModes::<T>::get(game_id),
+            context: GameContexts::<T>::get(game_id),
+            rules: GameRules::<T>::get(game_id),
+            last_played_block: game.last_played_block,
+            hand_deadline: HandDeadlines::<T>::get(game_id),
+        })
+    }
+
+    /// The games `who` is playing, one per context at most.
+    pub fn active_games(who: &AccountIdOf<T>) -> Vec<(GameContext, GameId<T>)> {
+        ActiveGameOf::<T>::iter_prefix(who).collect()
+    }
+
+    /// Re-execute `moves` from the recorded start state and game hands, returning whether
+    /// the resulting board and scores match storage. Returns false for unknown games, games
+    /// whose hands were pruned, and transcripts containing an illegal move. Games played
+    /// through `play` with free-form cards cannot be replayed this way.
+    pub fn verify_replay(game_id: &GameId<T>, moves: &[ReplayMove]) -> bool {
+        let (Some(game), Some(start)) =
+            (GameStorage::<T>::get(game_id), GameStarts::<T>::get(game_id))
+        else {
+            return false;
+        };
+        let (Some(mut hand0), Some(mut hand1)) = (
+            HandsOfGame::<T>::get(game_id, &game.players[0]),
+            HandsOfGame::<T>::get(game_id, &game.players[1]),
+        ) else {
+            return false;
+        };
+        for entry in hand0.iter_mut().chain(hand1.iter_mut()) {
+            entry.used = false;
+        }
+        let mut hands = [hand0, hand1];
+
+        let rules = GameRules::<T>::get(game_id);
+        let mut board = game.board.emptied();
+        let mut scores = start.scores;
+        let mut turn = start.first_player.min(1);
+        for mv in moves {
+            if let ReplayMove::Place { hand_index, x, y } = *mv {
+                let Some(entry) = hands[turn as usize].get_mut(hand_index as usize) else {
+                    return false;
+                };
+                if entry.used {
+                    return false;
                 }
-                // If AI is up next, take its turn now that it has a hand.
-                if let Some(mut game) = GameStorage::<T>::get(&game_id) {
-                    Self::maybe_ai_take_turn(&game_id, &mut game);
+                entry.used = true;
+                let card = Card::new(entry.north, entry.east, entry.south, entry.west)
+                    .with_element(entry.element);
+                let placer = Player::from_index(turn);
+                if play::place_card_with_rules(&mut board, &mut scores, x, y, card, placer, rules)
+                    .is_err()
+                {
+                    return false;
                 }
             }
-            Ok(())
+            turn = 1 - turn;
         }
+        board == game.board && scores == game.scores
+    }
 
-        /// Play a card by referencing its index in the submitted hand (0..HandSize-1).
-        #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
-        pub fn play_from_hand(
-            origin: OriginFor<T>,
-            game_id: GameId<T>,
-            hand_index: u8,
-            x: u8,
-            y: u8,
-        ) -> DispatchResult {
-            let who: AccountIdOf<T> = ensure_signed(origin)?;
+    /// An empty board of the dimension set for new games of `mode`. Under
+    /// `Rules::ELEMENTAL` its cells get elements rolled from the game's seed, so
+    /// `init_game_entropy` and `init_game_rules` must both have run first.
+    fn new_board(game_id: &GameId<T>, mode: &GameMode) -> Board {
+        let dim = ModeBoardDims::<T>::get(mode).unwrap_or(geometry::BOARD_DIM);
+        let board = Board::new(dim).unwrap_or_default();
+        if GameRules::<T>::get(game_id).contains(Rules::ELEMENTAL) {
+            board.with_elements(Self::game_seed(game_id, b"elements").as_ref())
+        } else {
+            board
+        }
+    }
 
-            // Load game
-            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+    /// Snapshot the rules of `mode` (or `DefaultRules`) for a new game, so later changes do
+    /// not affect it.
+    fn init_game_rules(game_id: &GameId<T>, mode: &GameMode) {
+        let rules = ModeRules::<T>::get(mode).unwrap_or(Rules(T::DefaultRules::get()));
+        if rules != Rules::default() {
+            GameRules::<T>::insert(game_id, rules);
+        }
+        if rules.contains(Rules::ENERGY) {
+            GameEnergy::<T>::insert(game_id, [ENERGY_START; 2]);
+        }
+    }
 
-            // Validate it's the caller's turn and the target cell is open
-            Self::validate_player_turn(&game, &who)?;
-            ensure!(x < 4 && y < 4, Error::<T>::InvalidMove);
-            ensure!(
-                game.board[x as usize][y as usize].is_none(),
-                Error::<T>::CellOccupied
-            );
+    /// Charge `seat` of an energy game `cost` for placing a card, given the cheapest card
+    /// it has left. Games without `Rules::ENERGY` are never charged.
+    fn charge_energy(
+        game_id: &GameId<T>,
+        seat: u8,
+        cost: u8,
+        cheapest: u8,
+    ) -> Result<(), Error<T>> {
+        let Some(mut pools) = GameEnergy::<T>::get(game_id) else {
+            return Ok(());
+        };
+        let pool = pools.get_mut(seat as usize).ok_or(Error::<T>::InvalidMove)?;
+        *pool = spend_energy(*pool, cost, cheapest).ok_or(Error::<T>::NotEnoughEnergy)?;
+        GameEnergy::<T>::insert(game_id, pools);
+        Ok(())
+    }
 
-            // Get caller's hand
-            let mut hand =
-                HandsOfGame::<T>::get(&game_id, &who).ok_or(Error::<T>::HandNotSubmitted)?;
-            let idx = hand_index as usize;
-            ensure!(idx < hand.len(), Error::<T>::HandIndexOutOfRange);
-            ensure!(!hand[idx].used, Error::<T>::CardAlreadyUsed);
+    /// Energy cost of the cheapest card not yet played from `hand`.
+    fn cheapest_unused(hand: &[HandEntry]) -> u8 {
+        hand.iter()
+            .filter(|h| !h.used)
+            .map(HandEntry::energy_cost)
+            .min()
+            .unwrap_or(0)
+    }
 
-            // Build the placed card from the saved stats
-            let player_ix = Self::get_current_player_index(&game, &who);
-            let h = hand[idx].clone();
-            let placed = Card {
-                top: h.north,
-                right: h.east,
-                bottom: h.south,
-                left: h.west,
-                possession: None,
-            };
-            let mv = Move {
-                place_card: placed,
-                place_index_x: x,
-                place_index_y: y,
-            };
+    /// Fix the entropy for a freshly created game.
+    fn init_game_entropy(game_id: &GameId<T>) {
+        let counter = GameCounter::<T>::mutate(|c| {
+            let current = *c;
+            *c = c.wrapping_add(1);
+            current
+        });
+        let parent = <frame_system::Pallet<T>>::parent_hash();
+        let entropy = T::Hashing::hash_of(&(parent, counter, game_id));
+        GameEntropy::<T>::insert(game_id, entropy);
+    }
 
-            // Place the card and resolve capture logic (mirrors `play`)
-            Self::place_card_on_board(&mut game, &mv, player_ix);
-            Self::apply_capture_logic(&mut game, &mv, player_ix);
+    /// Seed for one use of randomness within a game, separated by `domain`. Games created
+    /// before entropy was recorded fall back to their id.
+    pub fn game_seed(game_id: &GameId<T>, domain: &[u8]) -> T::Hash {
+        let entropy = GameEntropy::<T>::get(game_id).unwrap_or(*game_id);
+        T::Hashing::hash_of(&(entropy, domain))
+    }
 
-            // Mark card as used and persist the hand
-            hand[idx].used = true;
-            HandsOfGame::<T>::insert(&game_id, &who, hand);
+    /// Seat that opens a PvP game, drawn from its seed; `init_game_entropy` must have run.
+    fn first_seat(game_id: &GameId<T>) -> u8 {
+        let seed = Self::game_seed(game_id, b"first_seat");
+        seed.as_ref().first().map_or(0, |byte| byte % 2)
+    }
 
-            // Update timing and turn
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            game.last_played_block = current_block;
-            game.next_turn();
+    /// Record and apply a handicap when the players' levels differ by at least
+    /// `HandicapLevelGap`. Score bonuses land in `game.scores`, which the AI state mirrors.
+    fn apply_handicap(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) {
+        let gap = T::HandicapLevelGap::get();
+        if gap == 0 || game.players.len() != 2 {
+            return;
+        }
+        let l0 = <T::PlayerLevel as PlayerLevelProvider<AccountIdOf<T>>>::level_of(&game.players[0]);
+        let l1 = <T::PlayerLevel as PlayerLevelProvider<AccountIdOf<T>>>::level_of(&game.players[1]);
+        if l0.abs_diff(l1) < gap {
+            return;
+        }
+        let (weaker, stronger) = if l0 < l1 { (0u8, 1u8) } else { (1u8, 0u8) };
 
-            // Emit events and save game
-            let next_player = game.players[game.get_player_turn() as usize].clone();
-            Self::deposit_event(Event::NewTurn {
-                game_id,
-                next_player,
-            });
-            GameStorage::<T>::insert(&game_id, game.clone());
+        let budget = T::HandicapHandBudget::get();
+        let handicap = if budget == 0 {
+            if weaker == 0 {
+                game.scores.0 = game.scores.0.saturating_add(1);
+            } else {
+                game.scores.1 = game.scores.1.saturating_add(1);
+            }
+            Handicap::ScoreBonus { player: weaker }
+        } else {
+            Handicap::HandBudget {
+                player: stronger,
+                max_total: budget,
+            }
+        };
+        GameHandicaps::<T>::insert(game_id, handicap);
+        Self::deposit_event(Event::HandicapApplied {
+            game_id: *game_id,
+            handicap,
+        });
+    }
 
-            Self::deposit_event(Event::MovePlayed {
-                game_id,
-                player: who,
-                x,
-                y,
-            });
+    /// Reject `hand` if one of its cards changed owner within the cards pallet's
+    /// `TradeCooldown`.
+    fn ensure_no_trade_cooldown(hand: &[HandEntry]) -> Result<(), Error<T>> {
+        ensure!(
+            !hand
+                .iter()
+                .filter_map(|e| e.card_id)
+                .any(pallet_eterra_simple_tcg::Pallet::<T>::is_cooling_down),
+            Error::<T>::CardCoolingDown
+        );
+        Ok(())
+    }
 
-            // Check for win condition after saving
-            if let Some(winner) = Self::is_game_won(&game_id, &game) {
-                Self::end_game(&game_id, winner);
-                return Ok(());
+    /// Reject `hand` if this game's handicap caps the hand budget of the submitting seat.
+    fn ensure_within_handicap(
+        game_id: &GameId<T>,
+        player_ix: u8,
+        hand: &[HandEntry],
+    ) -> Result<(), Error<T>> {
+        if let Some(Handicap::HandBudget { player, max_total }) = GameHandicaps::<T>::get(game_id)
+        {
+            if player == player_ix {
+                let total: u16 = hand
+                    .iter()
+                    .map(|h| h.north as u16 + h.east as u16 + h.south as u16 + h.west as u16)
+                    .sum();
+                ensure!(total <= max_total, Error::<T>::HandOverBudget);
             }
+        }
+        Ok(())
+    }
 
-            // If this is a PvE game and it's now the AI's turn, let the AI act immediately.
-            if matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvE)) {
-                if let Some(mut g) = GameStorage::<T>::get(&game_id) {
-                    Self::maybe_ai_take_turn(&game_id, &mut g);
-                }
-            }
+    /// Deal `HandSize` distinct cards from `who`'s `OwnedCards` (skipping exported ones) for a
+    /// sealed game, using a partial Fisher-Yates shuffle driven by the game's seed.
+    fn deal_sealed_hand(
+        game_id: &GameId<T>,
+        who: &AccountIdOf<T>,
+    ) -> Result<BoundedVec<HandEntry, HandLimit>, sp_runtime::DispatchError> {
+        let mut pool: Vec<u32> = pallet_eterra_simple_tcg::pallet::OwnedCards::<T>::get(who)
+            .into_iter()
+            .filter(|id| {
+                !pallet_eterra_simple_tcg::Pallet::<T>::is_exported(*id)
+                    && !pallet_eterra_simple_tcg::Pallet::<T>::is_vaulted(*id)
+            })
+            .collect();
+        let hand_size = T::HandSize::get() as usize;
+        ensure!(pool.len() >= hand_size, Error::<T>::NotEnoughCardsToDeal);
+
+        let seed = Self::game_seed(game_id, &(b"sealed_deal", who).encode());
+        for i in 0..hand_size {
+            let draw = T::Hashing::hash_of(&(seed, i as u32));
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&draw.as_ref()[..8]);
+            let j = i + (u64::from_le_bytes(word) % (pool.len() - i) as u64) as usize;
+            pool.swap(i, j);
+        }
 
-            Ok(())
+        let mut hand: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
+        for &card_id in pool.iter().take(hand_size) {
+            let info = pallet_eterra_simple_tcg::pallet::Cards::<T>::get(card_id)
+                .ok_or(Error::<T>::CardDoesNotExist)?;
+            let entry = HandEntry {
+                card_id: Some(card_id),
+                north: info.north,
+                east: info.east,
+                south: info.south,
+                west: info.west,
+                used: false,
+                element: info.element,
+            };
+            hand.try_push(entry)
+                .map_err(|_| Error::<T>::HandSizeInvalid)?;
         }
+        Ok(hand)
+    }
 
-        #[pallet::call_index(4)]
-        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1, 1).ref_time())]
-        pub fn force_finish_turn(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
-            let who: AccountIdOf<T> = ensure_signed(origin)?;
+    /// Snapshot `who`'s current hand configuration into in-game hand entries,
+    /// validating size, uniqueness, existence and ownership of every card.
+    fn build_hand_from_current(
+        who: &AccountIdOf<T>,
+    ) -> Result<BoundedVec<HandEntry, HandLimit>, sp_runtime::DispatchError> {
+        let current_ids = Self::current_hand_of(who).ok_or(Error::<T>::PresetHandMissing)?;
+        Self::build_hand_from_ids(who, &current_ids)
+    }
 
-            // Retrieve the game from storage
-            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+    /// A featured hand that was published and has not expired yet.
+    fn live_featured_hand(hand_id: u32) -> Option<FeaturedHand<BlockNumberFor<T>>> {
+        FeaturedHands::<T>::get(hand_id)
+            .filter(|hand| <frame_system::Pallet<T>>::block_number() <= hand.expires_at)
+    }
 
-            // Ensure the caller is a player in the game
-            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+    /// Hand entries of the featured hand `who` selected, while it is live. Synthetic cards
+    /// carry `card_id: None`, like generated AI cards, so no card usage or hand power is
+    /// recorded.
+    fn featured_hand_for(who: &AccountIdOf<T>) -> Option<BoundedVec<HandEntry, HandLimit>> {
+        let hand = Self::live_featured_hand(FeaturedHandOf::<T>::get(who)?)?;
+        let entries = hand
+            .cards
+            .iter()
+            .map(|&[north, east, south, west]| HandEntry {
+                card_id: None,
+                north,
+                east,
+                south,
+                west,
+                used: false,
+                element: None,
+            })
+            .collect::<Vec<_>>();
+        BoundedVec::try_from(entries).ok()
+    }
+
+    /// The hand `who` bound when joining the ranked queue, if any, revalidated for play.
+    fn bound_hand(
+        who: &AccountIdOf<T>,
+    ) -> Result<Option<BoundedVec<HandEntry, HandLimit>>, sp_runtime::DispatchError> {
+        QueuedHandOf::<T>::get(who)
+            .map(|ids| Self::build_hand_from_ids(who, &ids))
+            .transpose()
+    }
+
+    /// Build game hand entries from card ids, checking size, uniqueness, ownership and that
+    /// every card is still playable.
+    fn build_hand_from_ids(
+        who: &AccountIdOf<T>,
+        current_ids: &[u32],
+    ) -> Result<BoundedVec<HandEntry, HandLimit>, sp_runtime::DispatchError> {
+        ensure!(
+            current_ids.len() as u32 == T::HandSize::get(),
+            Error::<T>::HandSizeInvalid
+        );
+
+        // Validate uniqueness (defense in depth)
+        for i in 0..current_ids.len() {
+            for j in (i + 1)..current_ids.len() {
+                ensure!(
+                    current_ids[i] != current_ids[j],
+                    Error::<T>::DuplicateCardInHand
+                );
+            }
+        }
 
-            // Ensure the caller is not the current player
-            let current_player = game.players[game.player_turn as usize].clone();
+        // Build hand entries from the cards pallet; validate ownership & existence
+        let mut hand: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
+        for &card_id in current_ids.iter() {
+            let info = pallet_eterra_simple_tcg::pallet::Cards::<T>::get(card_id)
+                .ok_or(Error::<T>::CardDoesNotExist)?;
+            ensure!(info.owner == *who, Error::<T>::CardNotOwned);
             ensure!(
-                current_player != who,
-                Error::<T>::CurrentPlayerCannotForceFinishTurn
+                !pallet_eterra_simple_tcg::Pallet::<T>::is_exported(card_id),
+                Error::<T>::CardExported
             );
-
-            // Check if the BlocksToPlayLimit has passed (use saturating math and inclusive deadline)
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let limit: BlockNumberFor<T> = T::BlocksToPlayLimit::get().into();
-            let deadline = game.last_played_block.saturating_add(limit);
             ensure!(
-                current_block >= deadline,
-                Error::<T>::BlocksToPlayLimitNotPassed
+                !pallet_eterra_simple_tcg::Pallet::<T>::is_vaulted(card_id),
+                Error::<T>::CardVaulted
             );
+            let entry = HandEntry {
+                card_id: Some(card_id),
+                north: info.north,
+                east: info.east,
+                south: info.south,
+                west: info.west,
+                used: false,
+                element: info.element,
+            };
+            hand.try_push(entry)
+                .map_err(|_| Error::<T>::HandSizeInvalid)?;
+        }
+        Ok(hand)
+    }
 
-            // Force finish the current turn
-            game.next_turn();
-            game.last_played_block = current_block;
+    /// Shared implementation of `play_from_hand`, used both for signed players and for
+    /// controllers answering moves on behalf of an open bot. Reports whether the card
+    /// captured and whether the AI answered the move, which decide the refunded weight.
+    fn do_play_from_hand(
+        who: &AccountIdOf<T>,
+        game_id: GameId<T>,
+        hand_index: u8,
+        x: u8,
+        y: u8,
+    ) -> Result<crate::MoveOutcome, sp_runtime::DispatchError> {
+        // Load game
+        let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+
+        // Validate it's the caller's turn and the target cell is open
+        Self::validate_player_turn(&game, who)?;
+        play::open_cell(&game.board, x, y).map_err(Self::place_error)?;
+
+        // Get caller's hand
+        let mut hand = HandsOfGame::<T>::get(&game_id, who).ok_or(Error::<T>::HandNotSubmitted)?;
+        let idx = hand_index as usize;
+        ensure!(idx < hand.len(), Error::<T>::HandIndexOutOfRange);
+        ensure!(!hand[idx].used, Error::<T>::CardAlreadyUsed);
+
+        // Build the placed card from the saved stats
+        let player_ix = Self::get_current_player_index(&game, who);
+        let h = hand[idx].clone();
+        Self::charge_energy(&game_id, player_ix, h.energy_cost(), Self::cheapest_unused(&hand))?;
+        let placed = Card {
+            top: h.north,
+            right: h.east,
+            bottom: h.south,
+            left: h.west,
+            possession: None,
+            element: h.element,
+        };
+        let mv = Move {
+            place_card: placed,
+            place_index_x: x,
+            place_index_y: y,
+        };
 
-            log::debug!(
-                "Force finish turn: game_id {:?}, current round: {}, max rounds: {}",
-                game_id,
-                game.round,
-                game.max_rounds
-            );
+        // Place the card and resolve capture logic (mirrors `play`)
+        let captured = Self::apply_move(&game_id, &mut game, &mv, player_ix)? > 0;
 
-            // ✅ Check if game is won after forcing turn
-            if let Some(winner) = Self::is_game_won(&game_id, &game) {
-                // End game clears storage and ActiveGameOf markers; early return is fine.
-                Self::end_game(&game_id, winner);
-                return Ok(());
-            }
+        // Mark card as used and persist the hand
+        hand[idx].used = true;
+        HandsOfGame::<T>::insert(&game_id, who, hand);
 
-            // Persist updated game state before emitting events
-            GameStorage::<T>::insert(&game_id, game.clone());
+        let action = RecordedAction::Place {
+            hand_index: Some(hand_index),
+            card: [h.north, h.east, h.south, h.west],
+            x,
+            y,
+        };
+        Self::record_move(&game_id, player_ix, action);
+        let ai_ran = Self::finalize_move(&game_id, &mut game, player_ix, Some((x, y)));
+        let finished = Self::is_finished(&game_id);
+        Ok(crate::MoveOutcome { captured, ai_ran, finished })
+    }
 
-            // Emit events
-            let next_player = game.players[game.player_turn as usize].clone();
-            Self::deposit_event(Event::TurnForceFinished {
-                game_id,
-                player: current_player,
-            });
-            Self::deposit_event(Event::NewTurn {
-                game_id,
-                next_player,
-            });
+    /// Whether the game has ended; `end_game` only writes the final state to storage.
+    fn is_finished(game_id: &GameId<T>) -> bool {
+        GameStorage::<T>::get(game_id).is_some_and(|g| matches!(g.turn, TurnState::Finished))
+    }
 
-            Ok(())
-        }
+    /// Store a hand copied from a player's current hand and report its card ids.
+    fn store_snapshotted_hand(
+        game_id: &GameId<T>,
+        who: &AccountIdOf<T>,
+        hand: BoundedVec<HandEntry, HandLimit>,
+    ) {
+        let card_ids = hand.iter().filter_map(|e| e.card_id).collect();
+        HandsOfGame::<T>::insert(game_id, who, hand);
+        Self::deposit_event(Event::HandSnapshotted {
+            game_id: *game_id,
+            player: who.clone(),
+            card_ids,
+        });
+    }
 
-        /// Save/update your "current hand" (card IDs only) that the UI will use for future games.
-        /// The hand must contain exactly `HandSize` unique cards owned by the caller.
-        #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
-        pub fn set_current_hand(origin: OriginFor<T>, card_ids: Vec<u32>) -> DispatchResult {
-            let who: AccountIdOf<T> = ensure_signed(origin)?;
+    /// Store a generated AI hand and reveal its ranks, since it has no card ids to look up.
+    fn store_ai_hand(
+        game_id: &GameId<T>,
+        ai_acc: &AccountIdOf<T>,
+        hand: BoundedVec<HandEntry, HandLimit>,
+    ) {
+        let entries = hand
+            .iter()
+            .map(|e| [e.north, e.east, e.south, e.west])
+            .collect();
+        HandsOfGame::<T>::insert(game_id, ai_acc, hand);
+        Self::deposit_event(Event::AiHandGenerated {
+            game_id: *game_id,
+            player: ai_acc.clone(),
+            entries,
+        });
+    }
 
-            // Enforce exact hand size and uniqueness
-            ensure!(
-                card_ids.len() as u32 == T::HandSize::get(),
-                Error::<T>::HandSizeInvalid
-            );
-            for i in 0..card_ids.len() {
-                for j in (i + 1)..card_ids.len() {
-                    ensure!(card_ids[i] != card_ids[j], Error::<T>::DuplicateCardInHand);
-                }
-            }
+    /// Ensure `caller` may act for the open bot `bot`: either the bot account itself
+    /// or its registered controller (e.g. an offchain-worker key).
+    fn ensure_bot_authority(
+        caller: &AccountIdOf<T>,
+        bot: &AccountIdOf<T>,
+    ) -> Result<(), Error<T>> {
+        let profile = OpenBots::<T>::get(bot).ok_or(Error::<T>::NotAnOpenBot)?;
+        let allowed = caller == bot || profile.controller.as_ref() == Some(caller);
+        ensure!(allowed, Error::<T>::NotBotController);
+        Ok(())
+    }
 
-            // Validate ownership and that each card exists
-            for &card_id in &card_ids {
-                let info =
-                    cards::pallet::Cards::<T>::get(card_id).ok_or(Error::<T>::CardDoesNotExist)?;
-                ensure!(info.owner == who, Error::<T>::CardNotOwned);
+    /// Whether `who` is a registered AI identity that has not been retired.
+    fn is_offered_ai(who: &AccountIdOf<T>) -> bool {
+        AiProfiles::<T>::contains_key(who) && !RetiredAis::<T>::contains_key(who)
+    }
+
+    /// Pick the AI identity whose `min_level` is the highest one not exceeding `who`'s level.
+    /// Ties resolve to the earliest registered identity.
+    fn select_ai_for(who: &AccountIdOf<T>) -> Option<AccountIdOf<T>> {
+        let level = <T::PlayerLevel as PlayerLevelProvider<AccountIdOf<T>>>::level_of(who);
+        let mut best: Option<(u8, AccountIdOf<T>)> = None;
+        for acc in AiRoster::<T>::get().into_iter() {
+            if acc == *who {
+                continue;
+            }
+            if let Some(p) = AiProfiles::<T>::get(&acc) {
+                let better = best.as_ref().map_or(true, |(lvl, _)| p.min_level > *lvl);
+                if p.min_level <= level && better {
+                    best = Some((p.min_level, acc));
+                }
             }
+        }
+        best.map(|(_, acc)| acc)
+    }
 
-            // Persist as a bounded vec
-            let current: BoundedVec<u32, HandLimit> = card_ids
-                .clone()
-                .try_into()
-                .map_err(|_| Error::<T>::HandSizeInvalid)?;
+    /// Monte-Carlo iterations for an AI move: the difficulty-scaled budget, shaped by
+    /// `AI_ITERATION_CURVE` according to how many cells are still empty.
+    pub fn ai_iteration_budget(difficulty: u8, board: &Board) -> u32 {
+        let empty = board.cells().filter(|cell| cell.is_none()).count();
+        let percent = AI_ITERATION_CURVE[empty.min(AI_ITERATION_CURVE.len() - 1)] as u32;
+        let base = mc_ai::pallet::Pallet::<T>::scaled_iterations::<T>(difficulty);
+        base.saturating_mul(percent) / 100
+    }
 
-            CurrentHandOf::<T>::insert(&who, current);
-            Ok(())
-        }
+    /// The move the Monte-Carlo AI suggests from `state` at `difficulty` (capped at 100), or
+    /// `None` if there is none. Nothing is stored, so this is meant for runtime API calls.
+    pub fn suggest_for_state(state: &AiState, difficulty: u8) -> Option<AiAction> {
+        mc_ai::pallet::Pallet::<T>::suggest::<ai::Adapter>(state, difficulty.min(100))
+    }
 
-        /// Deprecated alias for backwards compatibility. Calls `set_current_hand`.
-        #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
-        pub fn set_preset_hand(origin: OriginFor<T>, card_ids: Vec<u32>) -> DispatchResult {
-            Self::set_current_hand(origin, card_ids)
+    /// A hint for the player to move in `game_id`: the move an AI of `difficulty` (capped at
+    /// 100) would play there, searched with the budget of an on-chain AI move. `None` if the
+    /// game does not exist, is not waiting for a move, or either hand is not known yet.
+    pub fn suggest_for_game(game_id: &GameId<T>, difficulty: u8) -> Option<AiAction> {
+        let game = GameStorage::<T>::get(game_id)?;
+        if !matches!(game.turn, TurnState::AwaitingMove(_)) {
+            return None;
         }
+        let state = Self::build_ai_state(game_id, &game)?;
+        let iterations = Self::ai_iteration_budget(difficulty.min(100), &game.board);
+        mc_ai::pallet::Pallet::<T>::suggest_with_iterations::<ai::Adapter>(&state, iterations)
     }
-}
-
-// Helper methods
-impl<T: Config> Pallet<T> {
-    /// Create a PvP game between two accounts without a signed origin.
-    /// Intended to be called from the matchmaking pallet via the `GameCreator` trait.
-    fn do_create_pvp_game(
-        a: &AccountIdOf<T>,
-        b: &AccountIdOf<T>,
-    ) -> Result<GameId<T>, sp_runtime::DispatchError> {
-        use sp_runtime::traits::SaturatedConversion;
 
-        // Sanity checks
-        ensure!(a != b, Error::<T>::InvalidMove);
-        ensure!(
-            T::NumPlayers::get() == 2,
-            Error::<T>::InvalidNumberOfPlayers
-        );
+    /// Queue the AI's turn for `on_initialize`, or run it now when `MaxAiMovesPerBlock` is 0.
+    /// Returns whether the AI ran.
+    fn take_or_defer_ai_turn(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) -> bool {
+        let turn_acc = &game.players[game.get_player_turn() as usize];
+        if T::MaxAiMovesPerBlock::get() == 0 || !AiProfiles::<T>::contains_key(turn_acc) {
+            return Self::maybe_ai_take_turn(game_id, game);
+        }
 
-        // Both players must have a preset/current hand (defense in depth; the matchmaker checks this too)
-        ensure!(
-            CurrentHandOf::<T>::contains_key(a),
-            Error::<T>::PresetHandMissing
-        );
-        ensure!(
-            CurrentHandOf::<T>::contains_key(b),
-            Error::<T>::PresetHandMissing
-        );
+        let queued = PendingAiTurns::<T>::mutate(|queue| {
+            if queue.contains(game_id) {
+                return true;
+            }
+            let ok = queue.try_push(*game_id).is_ok();
+            PeakAiBacklog::<T>::mutate(|peak| *peak = (*peak).max(queue.len() as u32));
+            ok
+        });
+        if queued {
+            false
+        } else {
+            // Never leave a game without an AI move: with the queue full, play inline.
+            Self::maybe_ai_take_turn(game_id, game)
+        }
+    }
 
-        // Neither is currently in another game
-        ensure!(
-            ActiveGameOf::<T>::get(a).is_none(),
-            Error::<T>::PlayerAlreadyInGame
-        );
-        ensure!(
-            ActiveGameOf::<T>::get(b).is_none(),
-            Error::<T>::PlayerAlreadyInGame
-        );
+    /// Play up to `MaxAiMovesPerBlock` deferred AI turns, oldest first.
+    fn drain_pending_ai_turns() -> Weight {
+        let cap = T::MaxAiMovesPerBlock::get();
+        let mut batch = PendingAiTurns::<T>::get().into_inner();
+        if batch.is_empty() {
+            return T::DbWeight::get().reads(1);
+        }
 
-        // Create a deterministic game id from (a,b,block)
-        let current_block_number = <frame_system::Pallet<T>>::block_number();
-        let game_id = T::Hashing::hash_of(&(a.clone(), b.clone(), current_block_number));
+        let take = if cap == 0 { batch.len() } else { batch.len().min(cap as usize) };
+        let rest = batch.split_off(take);
+        let depth = rest.len() as u32;
+        PendingAiTurns::<T>::put(BoundedVec::truncate_from(rest));
+
+        let mut played = 0u32;
+        for game_id in batch.iter() {
+            if let Some(mut game) = GameStorage::<T>::get(game_id) {
+                // Games that ended while queued are skipped by `maybe_ai_take_turn`
+                if Self::maybe_ai_take_turn(game_id, &mut game) {
+                    played += 1;
+                }
+            }
+        }
 
-        // Collision check (extremely unlikely)
-        ensure!(
-            !GameStorage::<T>::contains_key(&game_id),
-            Error::<T>::GameNotFound
-        );
+        if cap != 0 && depth > cap {
+            Self::deposit_event(Event::AiBacklogHigh { depth });
+        }
 
-        // Build initial game struct
-        let initial_board: Board = Default::default();
-        let initial_scores = (5, 5);
-        let players_vec = sp_std::vec![a.clone(), b.clone()];
+        T::DbWeight::get()
+            .reads_writes(1 + batch.len() as u64, 1)
+            .saturating_add(
+                T::AiTurnWeight::get()
+                    .saturating_add(T::GameResults::max_weight())
+                    .saturating_mul(played as u64),
+            )
+    }
 
-        let mut game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers> = Game {
-            state: GameState::Playing,
-            last_played_block: current_block_number,
-            players: players_vec
-                .clone()
-                .try_into()
-                .map_err(|_| Error::<T>::InternalError)?,
-            player_turn: 0,
-            round: 0,
-            max_rounds: T::MaxRounds::get(),
-            board: initial_board.clone(),
-            scores: initial_scores,
+    /// If the next player is the AI in a PvE game, let the AI take its move immediately.
+    /// Returns whether the AI search ran.
+    fn maybe_ai_take_turn(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) -> bool {
+        // Only PvE
+        if !matches!(GameModes::<T>::get(game_id), Some(GameMode::PvE)) {
+            return false;
+        }
+        // The AI only ever answers a pending move, never a finished or unopened game
+        let seat = match game.turn {
+            TurnState::AwaitingMove(seat) => seat,
+            TurnState::AwaitingHand | TurnState::Finished => return false,
         };
-
-        // Mark this as a PvP game and set active game markers
-        GameModes::<T>::insert(&game_id, GameMode::PvP);
-        ActiveGameOf::<T>::insert(a, game_id);
-        ActiveGameOf::<T>::insert(b, game_id);
-
-        // Push into recent lists for each player (most-recent first, bounded to 10)
-        let mut push_recent = |acct: &AccountIdOf<T>| {
-            PlayerGames::<T>::mutate(acct, |list| {
-                if let Some(pos) = list.iter().position(|g| *g == game_id) {
-                    list.remove(pos);
-                }
-                if list.len() as u32 >= <ConstU32<10> as sp_runtime::traits::Get<u32>>::get() {
-                    let _ = list.pop();
-                }
-                let mut tmp = list.to_vec();
-                tmp.insert(0, game_id);
-                *list = BoundedVec::try_from(tmp).expect("<= 10; qed");
-            });
+        let turn_acc = game.players[seat as usize].clone();
+        let profile = match AiProfiles::<T>::get(&turn_acc) {
+            Some(p) => p,
+            None => return false,
         };
-        push_recent(a);
-        push_recent(b);
+        let ai_acc = turn_acc;
 
-        // Randomize starting player using `a` as seed (keep behavior similar to create_game PvP)
-        game.set_player_turn(if sp_io::hashing::blake2_128(&a.encode())[0] % 2 == 0 {
-            0
+        // Build AI adapter state from on-chain state
+        let state = match Self::build_ai_state(game_id, game) {
+            Some(s) => s,
+            None => return false,
+        };
+        let iterations = Self::ai_iteration_budget(profile.difficulty, &game.board);
+
+        let suggestion = if T::DevTelemetry::get() {
+            mc_ai::pallet::Pallet::<T>::suggest_with_stats::<ai::Adapter>(&state, iterations).map(
+                |(action, actions)| {
+                    Self::deposit_event(Event::AiSearchStats {
+                        game_id: *game_id,
+                        player: ai_acc.clone(),
+                        iterations,
+                        actions,
+                    });
+                    action
+                },
+            )
         } else {
-            1
+            mc_ai::pallet::Pallet::<T>::suggest_with_iterations::<ai::Adapter>(&state, iterations)
+        };
+        let played = suggestion.is_some_and(|action| {
+            let idx = action.hand_index as usize;
+            Self::play_ai_card(game_id, game, &ai_acc, idx, action.x, action.y)
         });
-
-        GameStorage::<T>::insert(&game_id, game.clone());
-        Self::deposit_event(Event::GameCreated { game_id });
-
-        Ok(game_id)
-    }
-    fn map_card_to_ai(c: &Card) -> ai::Card {
-        ai::Card {
-            top: c.top,
-            right: c.right,
-            bottom: c.bottom,
-            left: c.left,
-            possession: c.possession.as_ref().map(|p| match p {
-                Player::PlayerOne => ai::Possession::PlayerOne,
-                Player::PlayerTwo => ai::Possession::PlayerTwo,
-            }),
+        if !played {
+            Self::play_fallback_ai_move(game_id, game, &ai_acc);
         }
+        true
     }
-    /// If the next player is the AI in a PvE game, let the AI take its move immediately.
-    fn maybe_ai_take_turn(
+
+    /// Place the AI's unused card at `idx` on (`x`, `y`), mirroring `play_from_hand`.
+    /// Returns `false`, leaving the game untouched, if the move is not legal.
+    fn play_ai_card(
         game_id: &GameId<T>,
         game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
-    ) {
-        // Only PvE
-        if !matches!(GameModes::<T>::get(game_id), Some(GameMode::PvE)) {
-            return;
+        ai_acc: &AccountIdOf<T>,
+        idx: usize,
+        x: u8,
+        y: u8,
+    ) -> bool {
+        let Some(mut ai_hand) = HandsOfGame::<T>::get(game_id, ai_acc) else {
+            return false;
+        };
+        let cheapest = Self::cheapest_unused(&ai_hand);
+        let Some(slot) = ai_hand.get_mut(idx).filter(|slot| !slot.used) else {
+            return false;
+        };
+        if play::open_cell(&game.board, x, y).is_err() {
+            return false;
         }
-        let ai_acc = T::AiAccount::get();
-        let turn_acc = game.players[game.get_player_turn() as usize].clone();
-        if turn_acc != ai_acc {
-            return;
+        let player_ix = Self::get_current_player_index(game, ai_acc);
+        if Self::charge_energy(game_id, player_ix, slot.energy_cost(), cheapest).is_err() {
+            return false;
         }
+        let mv = Move {
+            place_card: Card {
+                top: slot.north,
+                right: slot.east,
+                bottom: slot.south,
+                left: slot.west,
+                possession: None,
+                element: slot.element,
+            },
+            place_index_x: x,
+            place_index_y: y,
+        };
 
-        // Build AI adapter state from on-chain state
-        let state = match Self::build_ai_state(game_id, game) {
-            Some(s) => s,
-            None => return,
+        // The cell was checked open above
+        let _ = Self::apply_move(game_id, game, &mv, player_ix);
+
+        slot.used = true;
+        let card = [slot.north, slot.east, slot.south, slot.west];
+        HandsOfGame::<T>::insert(game_id, ai_acc, ai_hand);
+
+        let action = RecordedAction::Place {
+            hand_index: Some(idx as u8),
+            card,
+            x,
+            y,
         };
-        let diff = T::AiDifficulty::get();
+        Self::record_move(game_id, player_ix, action);
+        Self::finalize_move(game_id, game, player_ix, Some((x, y)));
+        true
+    }
 
-        if let Some(action) = mc_ai::pallet::Pallet::<T>::suggest::<ai::Adapter>(&state, diff) {
-            let x = action.x;
-            let y = action.y;
-            let idx = action.hand_index as usize;
+    /// Deterministic AI move for when the search suggested nothing playable: the first
+    /// unused card the AI can afford, on the first empty cell in row-major order. Reports
+    /// the failed search so PvE games never wait for the turn timeout.
+    fn play_fallback_ai_move(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        ai_acc: &AccountIdOf<T>,
+    ) -> bool {
+        AiMoveFailures::<T>::mutate(|n| *n = n.saturating_add(1));
+        Self::deposit_event(Event::AiMoveFailed {
+            game_id: *game_id,
+            player: ai_acc.clone(),
+        });
 
-            // Play as AI (mirror play_from_hand)
-            if let Some(mut ai_hand) = HandsOfGame::<T>::get(game_id, &ai_acc) {
-                // Safely access the chosen hand entry without using idx < len comparisons
-                if let Some(slot) = ai_hand.get_mut(idx) {
-                    let xi: usize = x as usize;
-                    let yi: usize = y as usize;
-                    if !slot.used {
-                        if let Some(col) = game.board.get(xi) {
-                            if let Some(cell) = col.get(yi) {
-                                if cell.is_none() {
-                                    let h = slot.clone();
-                                    let placed = Card {
-                                        top: h.north,
-                                        right: h.east,
-                                        bottom: h.south,
-                                        left: h.west,
-                                        possession: None,
-                                    };
-                                    let mv = Move {
-                                        place_card: placed,
-                                        place_index_x: x,
-                                        place_index_y: y,
-                                    };
-
-                                    let player_ix = Self::get_current_player_index(game, &ai_acc);
-                                    Self::place_card_on_board(game, &mv, player_ix);
-                                    Self::apply_capture_logic(game, &mv, player_ix);
-
-                                    slot.used = true;
-                                    HandsOfGame::<T>::insert(game_id, &ai_acc, ai_hand);
-
-                                    let current_block = <frame_system::Pallet<T>>::block_number();
-                                    game.last_played_block = current_block;
-                                    game.next_turn();
-
-                                    let next_player =
-                                        game.players[game.get_player_turn() as usize].clone();
-                                    Self::deposit_event(Event::NewTurn {
-                                        game_id: *game_id,
-                                        next_player,
-                                    });
-                                    GameStorage::<T>::insert(game_id, game.clone());
-
-                                    if let Some(winner) = Self::is_game_won(game_id, game) {
-                                        Self::end_game(game_id, winner);
-                                        return;
-                                    }
-
-                                    Self::deposit_event(Event::MovePlayed {
-                                        game_id: *game_id,
-                                        player: ai_acc,
-                                        x,
-                                        y,
-                                    });
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        let dim = game.board.dim();
+        let Some((x, y)) = (0..dim)
+            .flat_map(|y| (0..dim).map(move |x| (x, y)))
+            .find(|&(x, y)| play::open_cell(&game.board, x, y).is_ok())
+        else {
+            return false;
+        };
+        let slots = HandsOfGame::<T>::get(game_id, ai_acc).map_or(0, |hand| hand.len());
+        (0..slots).any(|idx| Self::play_ai_card(game_id, game, ai_acc, idx, x, y))
     }
 
     fn build_ai_state(
@@ -958,6 +3946,7 @@ impl<T: Config> Pallet<T> {
                 south: 1,
                 west: 1,
                 used: true,
+                element: None,
             });
             for (i, he) in h.iter().enumerate().take(5) {
                 arr[i] = ai::HandEntry {
@@ -966,6 +3955,7 @@ impl<T: Config> Pallet<T> {
                     south: he.south,
                     west: he.west,
                     used: he.used,
+                    element: he.element,
                 };
             }
             ai::Hand { entries: arr }
@@ -973,24 +3963,16 @@ impl<T: Config> Pallet<T> {
 
         let hands = [map_hand(&hand0), map_hand(&hand1)];
 
-        // Map on-chain board (card::Card) to adapter board (ai::Card)
-        let mut board_ai: [[Option<ai::Card>; 4]; 4] =
-            core::array::from_fn(|_| core::array::from_fn(|_| None));
-        for x in 0..4 {
-            for y in 0..4 {
-                if let Some(ref c) = game.board[x][y] {
-                    board_ai[x][y] = Some(Self::map_card_to_ai(c));
-                }
-            }
-        }
-
+        // The adapter shares the on-chain board type, so no mapping is needed
         Some(ai::State {
-            board: board_ai,
+            board: game.board.clone(),
             scores: game.scores,
             player_turn: game.player_turn,
             round: game.round,
             max_rounds: game.max_rounds,
             hands,
+            rules: GameRules::<T>::get(game_id),
+            energy: GameEnergy::<T>::get(game_id).unwrap_or_default(),
         })
     }
 
@@ -1007,8 +3989,11 @@ impl<T: Config> Pallet<T> {
         let avg = (sum as f32) / ((human_hand.len() as f32) * 4.0);
         let target = (avg - 0.5).max(1.0); // slightly easier than human
 
-        // Deterministic pseudo-randomization from (game_id, human)
-        let seed_hash = <T as frame_system::Config>::Hashing::hash_of(&(game_id, human));
+        // Deterministic pseudo-randomization from the game's entropy and the human
+        let seed_hash = <T as frame_system::Config>::Hashing::hash_of(&(
+            Self::game_seed(game_id, b"ai_hand"),
+            human,
+        ));
         let bytes = seed_hash.as_ref();
 
         let mut mk_val = |i: usize| -> u8 {
@@ -1028,285 +4013,995 @@ impl<T: Config> Pallet<T> {
             } else {
                 b_int as u8
             };
-            if clamped < 1 {
-                clamped = 1;
+            if clamped < 1 {
+                clamped = 1;
+            }
+            if clamped > 9 {
+                clamped = 9;
+            }
+            clamped
+        };
+
+        let mut out: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
+        for i in 0..HandLimit::get() {
+            let e = HandEntry {
+                card_id: None,
+                north: mk_val(i as usize),
+                east: mk_val(i as usize + 1),
+                south: mk_val(i as usize + 2),
+                west: mk_val(i as usize + 3),
+                used: false,
+                element: None,
+            };
+            let _ = out.try_push(e);
+        }
+        Some(out)
+    }
+
+    fn is_game_won(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) -> Option<Option<T::AccountId>> {
+        log::debug!(
+            "Checking if game is won. Current round: {}, Max rounds: {}",
+            game.round,
+            game.max_rounds
+        );
+
+        // Ensure game is still in storage before checking win conditions
+        if !GameStorage::<T>::contains_key(game_id) {
+            log::warn!(
+                "Warning: Attempted to check is_game_won() on a removed game: {:?}",
+                game_id
+            );
+            return None;
+        }
+
+        // Decided by the core rules, counting end-of-game bonuses of the optional rules
+        let Some(leader) = play::outcome(
+            &game.board,
+            game.scores,
+            game.round,
+            game.max_rounds,
+            GameRules::<T>::get(game_id),
+        ) else {
+            log::debug!("Game continues. Not at max rounds yet.");
+            return None;
+        };
+        let winner = leader.map(|p| game.players[p.index() as usize].clone()); // None: draw
+
+        log::debug!(
+            "Game ID: {:?}, Scores: {:?}, Winner: {:?}",
+            game_id,
+            game.scores,
+            winner
+        );
+
+        Some(winner)
+    }
+    fn validate_player_turn(
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        who: &AccountIdOf<T>,
+    ) -> Result<(), Error<T>> {
+        match game.turn {
+            TurnState::AwaitingMove(seat) => {
+                ensure!(game.players[seat as usize] == *who, Error::<T>::NotYourTurn);
+                Ok(())
+            }
+            TurnState::AwaitingHand => Err(Error::<T>::HandNotSubmitted),
+            TurnState::Finished => Err(Error::<T>::GameNotPlaying),
+        }
+    }
+
+    /// Append the turn `seat` just took to the game's `MoveHistory`. Must run before
+    /// `finalize_move`, which may end the game.
+    fn record_move(game_id: &GameId<T>, seat: u8, action: RecordedAction) {
+        let block = <frame_system::Pallet<T>>::block_number();
+        // Full only for games of more than `u8::MAX` rounds, which cannot exist.
+        let _ = MoveHistory::<T>::try_append(game_id, RecordedMove { seat, block, action });
+    }
+
+    /// The single post-move pipeline shared by `play`, `play_from_hand`, the AI and
+    /// `force_finish_turn`. After `seat` placed a card at `placed`, or was skipped (`None`),
+    /// it always runs, in this order: save the game, check for a winner against the saved
+    /// state, emit the `GameEvent` (then `Finished` if the game ended), and finally let the
+    /// AI answer in PvE. Returns whether the AI ran; `game` is stale if the game ended.
+    fn finalize_move(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        seat: u8,
+        placed: Option<(u8, u8)>,
+    ) -> bool {
+        if placed.is_some() {
+            Self::note_move_timing(game_id, game, seat);
+        }
+        game.last_played_block = <frame_system::Pallet<T>>::block_number();
+        let opening = game.round == 0;
+        game.next_turn();
+        GameStorage::<T>::insert(game_id, game.clone());
+        GameEnergy::<T>::mutate(game_id, |pools| {
+            if let Some(pool) = pools.as_mut().and_then(|p| p.get_mut(game.player_turn as usize)) {
+                *pool = regenerate_energy(*pool);
+            }
+        });
+        if opening && game.round == 1 {
+            T::GameOutcome::on_first_round_played(game_id);
+        }
+
+        let outcome = Self::is_game_won(game_id, game);
+        let next = outcome.is_none().then(|| game.get_player_turn());
+        let (event, reason) = match placed {
+            Some((x, y)) => (GameEvent::Moved { seat, x, y, next }, EndReason::Completed),
+            None => (GameEvent::TurnSkipped { seat, next }, EndReason::Timeout),
+        };
+        Self::emit_game_event(game_id, &game.players, event);
+        if let Some(winner) = outcome {
+            Self::end_game(game_id, winner, reason);
+            return false;
+        }
+        Self::notify_turn(game_id, game);
+
+        // An AI move hands the turn back to the human, so this never recurses further.
+        if !matches!(GameModes::<T>::get(game_id), Some(GameMode::PvE)) {
+            return false;
+        }
+        Self::take_or_defer_ai_turn(game_id, game)
+    }
+
+    /// Record how long the human in `seat` took to move in a ranked game. Skipped turns are
+    /// not moves and are left out.
+    fn note_move_timing(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        seat: u8,
+    ) {
+        if GameContexts::<T>::get(game_id) != Some(GameContext::Ranked) {
+            return;
+        }
+        let Some(who) = game.players.get(seat as usize) else {
+            return;
+        };
+        if AiProfiles::<T>::contains_key(who) {
+            return;
+        }
+        let now = <frame_system::Pallet<T>>::block_number();
+        let latency: u32 = now.saturating_sub(game.last_played_block).saturated_into();
+        MoveTimings::<T>::mutate(who, |t| t.record(latency));
+        GameMoveTimings::<T>::mutate(game_id, who, |t| t.record(latency));
+    }
+
+    fn validate_move(
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        player_move: &Move,
+    ) -> Result<(), Error<T>> {
+        play::open_cell(&game.board, player_move.place_index_x, player_move.place_index_y)
+            .map(|_| ())
+            .map_err(Self::place_error)
+    }
+
+    fn place_error(e: PlaceError) -> Error<T> {
+        match e {
+            PlaceError::OffBoard => Error::<T>::InvalidMove,
+            PlaceError::Occupied => Error::<T>::CellOccupied,
+        }
+    }
+
+    fn get_current_player_index(
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        who: &AccountIdOf<T>,
+    ) -> u8 {
+        if who == &game.players[0] {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Place `mv` for `player_ix` with the core placement rules and flip the neighbours it
+    /// captures (chaining under `Rules::COMBO`); returns how many flipped.
+    fn apply_move(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        player_move: &Move,
+        player_ix: u8,
+    ) -> Result<u8, Error<T>> {
+        let captured = play::place_card_with_rules(
+            &mut game.board,
+            &mut game.scores,
+            player_move.place_index_x,
+            player_move.place_index_y,
+            player_move.place_card.clone(),
+            Player::from_index(player_ix),
+            GameRules::<T>::get(game_id),
+        )
+        .map_err(Self::place_error)?;
+        log::debug!(
+            "[Captured] {} card(s) around ({},{}) for player {}",
+            captured,
+            player_move.place_index_x,
+            player_move.place_index_y,
+            player_ix
+        );
+        Ok(captured)
+    }
+
+    /// Create a default AI hand at game creation time so UI can display it even before human submits.
+    /// AI identities with a card pool draw from it; otherwise the hand uses deterministic
+    /// pseudo-random stats (1..=9) derived from the game's entropy.
+    fn generate_ai_hand_default(
+        game_id: &GameId<T>,
+        ai_acc: &AccountIdOf<T>,
+    ) -> Option<BoundedVec<HandEntry, HandLimit>> {
+        // Derive bytes from the game's entropy for reproducible pseudo-randomness
+        let h = Self::game_seed(game_id, b"ai_hand_default");
+        let bytes = h.as_ref();
+        if bytes.is_empty() {
+            return None;
+        }
+
+        let pool = AiProfiles::<T>::get(ai_acc)
+            .map(|p| p.card_pool)
+            .unwrap_or_default();
+        if !pool.is_empty() {
+            let mut out: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
+            for i in 0..HandLimit::get() as usize {
+                let pick = (bytes[i % bytes.len()] as usize + i) % pool.len();
+                let [north, east, south, west] = pool[pick];
+                let e = HandEntry {
+                    card_id: None,
+                    north,
+                    east,
+                    south,
+                    west,
+                    used: false,
+                    element: None,
+                };
+                let _ = out.try_push(e);
+            }
+            return Some(out);
+        }
+
+        let mut at = 0usize;
+        let mut next = || -> u8 {
+            let b = bytes[at % bytes.len()];
+            at = at.wrapping_add(1);
+            // Map 0..=255 -> 1..=9
+            (b % 9).saturating_add(1)
+        };
+
+        let mut out: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
+        for _ in 0..HandLimit::get() {
+            let e = HandEntry {
+                card_id: None,
+                north: next(),
+                east: next(),
+                south: next(),
+                west: next(),
+                used: false,
+                element: None,
+            };
+            let _ = out.try_push(e);
+        }
+        Some(out)
+    }
+
+    /// Read both hands of a finishing game, update card usage stats, notify the
+    /// `CardUsage` hook and return the per-player summary. Must run before hands are pruned.
+    fn summarize_hands(
+        game_id: &GameId<T>,
+        players: &[AccountIdOf<T>],
+        winner: Option<&AccountIdOf<T>>,
+    ) -> Vec<HandUsage<AccountIdOf<T>>> {
+        let mut out = Vec::new();
+        for p in players.iter() {
+            let hand = match HandsOfGame::<T>::get(game_id, p) {
+                Some(h) => h,
+                None => continue,
+            };
+            let mut used = Vec::new();
+            let mut unused = Vec::new();
+            for entry in hand.iter() {
+                let Some(card_id) = entry.card_id else { continue };
+                CardUsage::<T>::mutate(card_id, |stats| {
+                    stats.games = stats.games.saturating_add(1);
+                    if entry.used {
+                        stats.played = stats.played.saturating_add(1);
+                    } else {
+                        stats.unplayed = stats.unplayed.saturating_add(1);
+                    }
+                });
+                if entry.used {
+                    used.push(card_id);
+                } else {
+                    unused.push(card_id);
+                }
+            }
+            <T::CardUsage as CardUsageObserver<AccountIdOf<T>>>::on_cards_resolved(
+                p,
+                &used,
+                &unused,
+                winner == Some(p),
+            );
+            out.push(HandUsage {
+                player: p.clone(),
+                used,
+                unused,
+            });
+        }
+        out
+    }
+
+    /// Decrement a live game's spectator count.
+    fn drop_spectator(game_id: &GameId<T>) {
+        let Some(spectators) = SpectatorCount::<T>::get(game_id) else {
+            return;
+        };
+        let spectators = spectators.saturating_sub(1);
+        SpectatorCount::<T>::insert(game_id, spectators);
+        Self::deposit_event(Event::SpectatorsChanged {
+            game_id: *game_id,
+            spectators,
+        });
+    }
+
+    /// Drop the spectator count of a game that ended and stop everyone watching it.
+    fn clear_spectators(game_id: &GameId<T>) {
+        SpectatorCount::<T>::remove(game_id);
+        for (who, ()) in WatchersOf::<T>::drain_prefix(game_id) {
+            if WatchingOf::<T>::get(&who) == Some(*game_id) {
+                WatchingOf::<T>::remove(&who);
+            }
+        }
+    }
+
+    fn end_game(game_id: &GameId<T>, winner: Option<T::AccountId>, reason: EndReason) {
+        // Read and update game in storage to persist final state
+        if let Some(mut g) = GameStorage::<T>::get(game_id) {
+            // Map AccountId winner to player index (0/1) to match GameState::Finished { winner: Option<u8> }
+            let winner_ix: Option<u8> = match winner.as_ref() {
+                Some(acc) if *acc == g.players[0] => Some(0),
+                Some(acc) if *acc == g.players[1] => Some(1),
+                _ => None,
+            };
+
+            // Emit before we change pointers
+            Self::emit_game_event(
+                game_id,
+                &g.players,
+                GameEvent::Finished {
+                    winner: winner_ix,
+                    reason,
+                },
+            );
+
+            let hands = Self::summarize_hands(game_id, &g.players, winner.as_ref());
+            Self::deposit_event(Event::GameSummary {
+                game_id: *game_id,
+                reason,
+                hands,
+            });
+
+            // Clear active-game markers for participants, but only where they point at this
+            // game: AI and bot accounts may be playing several games at once.
+            let context = GameContexts::<T>::get(game_id).unwrap_or(GameContext::Casual);
+            for p in g.players.iter() {
+                if ActiveGameOf::<T>::get(p, context) == Some(*game_id) {
+                    ActiveGameOf::<T>::remove(p, context);
+                }
+                if context != GameContext::Tournament {
+                    Self::note_rated_game_completed(p);
+                }
+                if !AiProfiles::<T>::contains_key(p) {
+                    T::GameActivity::on_game_completed(p, context, g.round);
+                    if context != GameContext::PvE {
+                        Self::note_streak(p, winner.as_ref() == Some(p));
+                    }
+                }
+            }
+
+            if Self::untrack_live_game(game_id) && matches!(g.state, GameState::Playing) {
+                if let Some(mode) = GameModes::<T>::get(game_id) {
+                    LiveGames::<T>::mutate(mode, |n| *n = n.saturating_sub(1));
+                }
+            }
+            if context == GameContext::Ranked {
+                Self::update_ratings(game_id, &g.players, winner_ix);
+            }
+            Self::settle_wager(game_id, g.round, winner.as_ref());
+            HandDeadlines::<T>::remove(game_id);
+            if MoveHistory::<T>::contains_key(game_id) {
+                let now = <frame_system::Pallet<T>>::block_number();
+                let expiry = now.saturating_add(T::MoveHistoryRetention::get());
+                MoveHistoryExpiry::<T>::insert(game_id, expiry);
+            }
+            g.finish(winner_ix);
+            GameStorage::<T>::insert(game_id, g);
+            Self::clear_spectators(game_id);
+            // The history entries are final now.
+            let _ = LiveGamePage::<T>::clear_prefix(game_id, u32::MAX, None);
+
+            if let Some(result) = Self::game_result(game_id, winner_ix, reason) {
+                T::GameResults::on_game_result(&result);
+            }
+
+            // Last, as series may create the next game for the same players right away
+            <T::GameOutcome as GameOutcomeObserver<AccountIdOf<T>, GameId<T>>>::on_game_finished(
+                game_id,
+                winner.as_ref(),
+            );
+        } else {
+            // Should not happen: without the game there are no seats to report.
+            log::warn!("end_game called for a missing game: {:?}", game_id);
+        }
+    }
+
+    /// Clear everything stored for a live game without declaring a winner, returning any
+    /// wager and emitting `GameVoided`.
+    fn do_void_game(
+        game_id: &GameId<T>,
+        game: Option<Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>>,
+        reason: VoidReason,
+    ) {
+        let context = GameContexts::<T>::take(game_id);
+        let mode = GameModes::<T>::take(game_id);
+        let counted = Self::untrack_live_game(game_id);
+        if let (Some(mode), Some(g)) = (mode, game.as_ref()) {
+            if counted && matches!(g.state, GameState::Playing) {
+                LiveGames::<T>::mutate(mode, |n| *n = n.saturating_sub(1));
+            }
+        }
+        let players = game.as_ref().map(|g| g.players.to_vec()).unwrap_or_default();
+        for p in players.iter() {
+            if let Some(context) = context {
+                if ActiveGameOf::<T>::get(p, context) == Some(*game_id) {
+                    ActiveGameOf::<T>::remove(p, context);
+                }
+            }
+            Self::remove_player_game(p, game_id);
+        }
+        GameStorage::<T>::remove(game_id);
+        let _ = HandsOfGame::<T>::clear_prefix(game_id, u32::MAX, None);
+        let _ = GameMoveTimings::<T>::clear_prefix(game_id, u32::MAX, None);
+        Self::clear_spectators(game_id);
+        let _ = LiveGamePage::<T>::clear_prefix(game_id, u32::MAX, None);
+        MoveAnnotations::<T>::remove(game_id);
+        MoveHistory::<T>::remove(game_id);
+        MoveHistoryExpiry::<T>::remove(game_id);
+        GameEntropy::<T>::remove(game_id);
+        GameHandicaps::<T>::remove(game_id);
+        GameRules::<T>::remove(game_id);
+        GameEnergy::<T>::remove(game_id);
+        GameStarts::<T>::remove(game_id);
+        HandDeadlines::<T>::remove(game_id);
+        SealedGames::<T>::remove(game_id);
+        PendingAiTurns::<T>::mutate(|pending| pending.retain(|g| *g != *game_id));
+        Self::settle_wager(game_id, 0, None);
+        T::GameOutcome::on_game_voided(game_id);
+
+        Self::deposit_event(Event::GameVoided {
+            game_id: *game_id,
+            reason,
+            players,
+            context,
+            round: game.as_ref().map_or(0, |g| g.round),
+            scores: game.as_ref().map_or((0, 0), |g| g.scores),
+        });
+    }
+
+    /// Look at up to `MaxExpiryScansPerBlock` live games after `ExpiryCursor` and expire
+    /// every one nobody has moved in for `AbandonAfterBlocks`. Stops early once `limit`
+    /// could not cover another expiry; the cursor is cleared after the last live game.
+    fn expire_abandoned_games(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+        let after = T::AbandonAfterBlocks::get();
+        let max_scans = T::MaxExpiryScansPerBlock::get();
+        let db = T::DbWeight::get();
+        // Reading an index entry and its game, and the worst case of finishing or voiding it.
+        let scan = db.reads(2);
+        let expire = db.reads_writes(16, 24);
+        let step = scan.saturating_add(expire);
+        let mut used = db.reads_writes(1, 1);
+        if after.is_zero() || max_scans == 0 || limit.any_lt(used.saturating_add(step)) {
+            return Weight::zero();
+        }
+
+        let iter = match ExpiryCursor::<T>::get() {
+            Some(last) => {
+                LiveGameIds::<T>::iter_keys_from(LiveGameIds::<T>::hashed_key_for(&last))
+            }
+            None => LiveGameIds::<T>::iter_keys(),
+        };
+        // Collected first: expiring a game removes it from the index being walked.
+        let game_ids: Vec<GameId<T>> = iter.take(max_scans as usize).collect();
+        let mut visited = 0u32;
+        let mut last = None;
+        for game_id in game_ids {
+            visited = visited.saturating_add(1);
+            used = used.saturating_add(scan);
+            last = Some(game_id);
+            let Some(game) = GameStorage::<T>::get(game_id) else {
+                LiveGameIds::<T>::remove(game_id);
+                continue;
+            };
+            let stale = now >= game.last_played_block.saturating_add(after);
+            if stale
+                && matches!(game.state, GameState::Playing)
+                && !matches!(game.turn, TurnState::Finished)
+            {
+                Self::expire_game(&game_id, game);
+                used = used.saturating_add(expire);
             }
-            if clamped > 9 {
-                clamped = 9;
+            if limit.any_lt(used.saturating_add(step)) {
+                break;
             }
-            clamped
-        };
+        }
 
-        let mut out: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
-        for i in 0..HandLimit::get() {
-            let e = HandEntry {
-                card_id: 0,
-                north: mk_val(i as usize),
-                east: mk_val(i as usize + 1),
-                south: mk_val(i as usize + 2),
-                west: mk_val(i as usize + 3),
-                used: false,
-            };
-            let _ = out.try_push(e);
+        let complete = visited < max_scans && !limit.any_lt(used.saturating_add(step));
+        match last {
+            Some(game_id) if !complete => ExpiryCursor::<T>::put(game_id),
+            _ => ExpiryCursor::<T>::kill(),
         }
-        Some(out)
+        used
     }
 
-    fn is_game_won(
+    /// End an abandoned game. The player to move forfeits on time to an opponent who
+    /// submitted a hand or moved; before the first move, a player who submitted a hand wins
+    /// against one who did not. Otherwise the game is voided.
+    fn expire_game(
         game_id: &GameId<T>,
-        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
-    ) -> Option<Option<T::AccountId>> {
-        log::debug!(
-            "Checking if game is won. Current round: {}, Max rounds: {}",
-            game.round,
-            game.max_rounds
-        );
+        game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) {
+        let winner = match game.turn {
+            TurnState::AwaitingMove(seat) => {
+                let stalling = game.players[seat as usize].clone();
+                let history = MoveHistory::<T>::get(game_id);
+                let took_part = |ix: usize, who: &AccountIdOf<T>| {
+                    HandsOfGame::<T>::contains_key(game_id, who)
+                        || history.iter().any(|m| m.seat as usize == ix)
+                };
+                let winner = game
+                    .players
+                    .iter()
+                    .enumerate()
+                    .find(|(ix, p)| **p != stalling && took_part(*ix, p))
+                    .map(|(_, p)| p.clone());
+                if winner.is_some() {
+                    Self::note_early_abandon(game_id, &game, &stalling);
+                }
+                winner
+            }
+            _ => {
+                let submitted: Vec<AccountIdOf<T>> = game
+                    .players
+                    .iter()
+                    .filter(|p| HandsOfGame::<T>::contains_key(game_id, *p))
+                    .cloned()
+                    .collect();
+                match submitted.as_slice() {
+                    [who] => Some(who.clone()),
+                    _ => None,
+                }
+            }
+        };
 
-        // Ensure game is still in storage before checking win conditions
-        if !GameStorage::<T>::contains_key(game_id) {
-            log::warn!(
-                "Warning: Attempted to check is_game_won() on a removed game: {:?}",
-                game_id
-            );
-            return None;
+        match winner.clone() {
+            Some(who) => Self::end_game(game_id, Some(who), EndReason::Timeout),
+            None => Self::do_void_game(game_id, Some(game), VoidReason::Abandoned),
         }
+        Self::deposit_event(Event::GameExpired {
+            game_id: *game_id,
+            winner,
+        });
+    }
 
-        if game.round >= game.max_rounds {
-            log::debug!("Max rounds reached. Determining winner...");
-        } else {
-            log::debug!("Game continues. Not at max rounds yet.");
-            return None;
+    /// Rating of `who`, `rating::INITIAL_RATING` before their first ranked game.
+    pub fn rating_of(who: &AccountIdOf<T>) -> u32 {
+        PlayerRating::<T>::get(who).unwrap_or(rating::INITIAL_RATING)
+    }
+
+    /// Move the Elo ratings of both seats of a finished ranked game, after the idle decay
+    /// either player accrued since their previous one. Games against an AI identity leave
+    /// ratings alone.
+    fn update_ratings(game_id: &GameId<T>, players: &[AccountIdOf<T>], winner: Option<u8>) {
+        let [a, b] = players else {
+            return;
+        };
+        if AiProfiles::<T>::contains_key(a) || AiProfiles::<T>::contains_key(b) {
+            return;
         }
+        let now = <frame_system::Pallet<T>>::block_number();
+        Self::decay_rating(a, now);
+        Self::decay_rating(b, now);
+        let (rating_a, rating_b) =
+            rating::update((Self::rating_of(a), Self::rating_of(b)), winner);
+        PlayerRating::<T>::insert(a, rating_a);
+        PlayerRating::<T>::insert(b, rating_b);
+        RatingDecayFrom::<T>::insert(a, now);
+        RatingDecayFrom::<T>::insert(b, now);
+        Self::deposit_event(Event::RatingsUpdated {
+            game_id: *game_id,
+            ratings: [(a.clone(), rating_a), (b.clone(), rating_b)],
+        });
+    }
 
-        // Determine winner
-        let (score_player_0, score_player_1) = game.scores;
-        let winner = if score_player_0 > score_player_1 {
-            Some(game.players[0].clone())
-        } else if score_player_1 > score_player_0 {
-            Some(game.players[1].clone())
-        } else {
-            None // Draw
+    /// Take `RatingDecay` off `who`'s rating for each whole `DecayPeriodBlocks` since
+    /// `RatingDecayFrom`, down to `RatingDecayFloor`; ratings already below it are kept.
+    /// Accounts rated before decay existed start their first period at `now`.
+    fn decay_rating(who: &AccountIdOf<T>, now: BlockNumberFor<T>) {
+        let period = T::DecayPeriodBlocks::get();
+        let Some(rating) = PlayerRating::<T>::get(who).filter(|_| !period.is_zero()) else {
+            return;
+        };
+        let Some(from) = RatingDecayFrom::<T>::get(who) else {
+            RatingDecayFrom::<T>::insert(who, now);
+            return;
         };
+        let periods = now.saturating_sub(from) / period;
+        if periods.is_zero() {
+            return;
+        }
+        RatingDecayFrom::<T>::insert(who, from.saturating_add(periods.saturating_mul(period)));
+        let periods: u32 = periods.saturated_into();
+        let floor = T::RatingDecayFloor::get().min(rating);
+        let decayed = rating
+            .saturating_sub(T::RatingDecay::get().saturating_mul(periods))
+            .max(floor);
+        if decayed == rating {
+            return;
+        }
+        PlayerRating::<T>::insert(who, decayed);
+        Self::deposit_event(Event::RatingDecayed {
+            who: who.clone(),
+            rating: decayed,
+            periods,
+        });
+    }
 
-        log::debug!(
-            "Game ID: {:?}, Scores: {:?}, Winner: {:?}",
-            game_id,
-            game.scores,
-            winner
-        );
+    /// Look at up to `MaxDecayScansPerBlock` rated accounts after `DecayCursor` and apply the
+    /// idle decay they accrued. Stops early once `limit` could not cover another account;
+    /// the cursor is cleared after the last rated account.
+    fn decay_idle_ratings(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+        let max_scans = T::MaxDecayScansPerBlock::get();
+        let db = T::DbWeight::get();
+        // Reading a rating and its decay start, and writing both back.
+        let step = db.reads_writes(2, 2);
+        let mut used = db.reads_writes(1, 1);
+        if T::DecayPeriodBlocks::get().is_zero()
+            || max_scans == 0
+            || limit.any_lt(used.saturating_add(step))
+        {
+            return Weight::zero();
+        }
 
-        Some(winner)
-    }
-    fn validate_player_turn(
-        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
-        who: &AccountIdOf<T>,
-    ) -> Result<(), Error<T>> {
-        let current_turn_index = game.get_player_turn();
-        let current_turn = game.players[current_turn_index as usize].clone();
-        ensure!(current_turn == *who, Error::<T>::NotYourTurn);
-        Ok(())
+        let iter = match DecayCursor::<T>::get() {
+            Some(last) => {
+                PlayerRating::<T>::iter_keys_from(PlayerRating::<T>::hashed_key_for(&last))
+            }
+            None => PlayerRating::<T>::iter_keys(),
+        };
+        let accounts: Vec<AccountIdOf<T>> = iter.take(max_scans as usize).collect();
+        let mut visited = 0u32;
+        let mut last = None;
+        for who in accounts {
+            visited = visited.saturating_add(1);
+            used = used.saturating_add(step);
+            Self::decay_rating(&who, now);
+            last = Some(who);
+            if limit.any_lt(used.saturating_add(step)) {
+                break;
+            }
+        }
+
+        let complete = visited < max_scans && !limit.any_lt(used.saturating_add(step));
+        match last {
+            Some(who) if !complete => DecayCursor::<T>::put(who),
+            _ => DecayCursor::<T>::kill(),
+        }
+        used
     }
 
-    fn validate_move(
-        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
-        player_move: &Move,
-    ) -> Result<(), Error<T>> {
-        ensure!(
-            player_move.place_index_x < 4 && player_move.place_index_y < 4,
-            Error::<T>::InvalidMove
-        );
-        ensure!(
-            game.board[player_move.place_index_x as usize][player_move.place_index_y as usize]
-                .is_none(),
-            Error::<T>::CellOccupied
-        );
-        Ok(())
+    /// Hand the cards staked on `game_id` to `winner`. Without a winner, or if the game
+    /// ends before round 1 was played, every card goes back to its staker.
+    fn settle_wager(game_id: &GameId<T>, round: u8, winner: Option<&AccountIdOf<T>>) {
+        let Some(stakes) = GameWagers::<T>::take(game_id) else {
+            return;
+        };
+        let winner = winner.filter(|_| round > 0);
+        for (_, card_id) in stakes.iter() {
+            let Some(winner) = winner else {
+                pallet_eterra_simple_tcg::Pallet::<T>::release_escrow(*card_id);
+                continue;
+            };
+            if pallet_eterra_simple_tcg::Pallet::<T>::settle_escrow(*card_id, winner).is_err() {
+                // The card was released to its staker instead.
+                log::warn!("wager card {} could not be handed to the winner", card_id);
+            }
+        }
+        Self::deposit_event(Event::WagerSettled {
+            game_id: *game_id,
+            winner: winner.cloned(),
+        });
     }
 
-    fn get_current_player_index(
+    /// Report `who` leaving `game_id` while its first round is still being played, which
+    /// costs them the queue deposit they were matched with.
+    fn note_early_abandon(
+        game_id: &GameId<T>,
         game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
         who: &AccountIdOf<T>,
-    ) -> u8 {
-        if who == &game.players[0] {
-            0
-        } else {
-            1
+    ) {
+        if game.round == 0 {
+            T::GameOutcome::on_abandoned_before_first_round(game_id, who);
         }
     }
 
-    fn place_card_on_board(
-        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
-        player_move: &Move,
-        player_ix: u8,
-    ) {
-        let placed_card = player_move
-            .place_card
-            .clone()
-            .with_possession(match player_ix {
-                0 => Player::PlayerOne,
-                _ => Player::PlayerTwo,
-            });
-        game.board[player_move.place_index_x as usize][player_move.place_index_y as usize] =
-            Some(placed_card);
+    /// Seats, final scores, hand power and context of a stored two-player game, for
+    /// `GameResults`.
+    fn game_result(
+        game_id: &GameId<T>,
+        winner: Option<u8>,
+        reason: EndReason,
+    ) -> Option<GameResult<AccountIdOf<T>>> {
+        let g = GameStorage::<T>::get(game_id)?;
+        let (s0, s1) = final_scores(&g.board, g.scores, GameRules::<T>::get(game_id));
+        let seat = |ix: usize, score: u8| -> Option<SeatResult<AccountIdOf<T>>> {
+            let account = g.players.get(ix)?.clone();
+            let ids: Vec<u32> = HandsOfGame::<T>::get(game_id, &account)
+                .map(|h| h.iter().filter_map(|e| e.card_id).collect())
+                .unwrap_or_default();
+            Some(SeatResult {
+                hand_power: Self::hand_power(&ids),
+                ai_difficulty: AiProfiles::<T>::get(&account).map(|p| p.difficulty),
+                account,
+                score,
+            })
+        };
+        Some(GameResult {
+            seats: [seat(0, s0)?, seat(1, s1)?],
+            winner,
+            reason,
+            ranked: GameContexts::<T>::get(game_id) == Some(GameContext::Ranked),
+        })
     }
 
-    fn apply_capture_logic(
-        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
-        player_move: &Move,
-        player_ix: u8,
-    ) {
-        // For each of the 4 orthogonal directions, compare the placed card's edge
-        // against the opposite edge of the neighboring card. Capture only if:
-        //  - There is a card
-        //  - It is owned by the opponent
-        //  - Our edge strictly beats their opposing edge (ties do NOT capture)
-        for &(dx, dy, my_rank) in &[
-            (0, -1, player_move.place_card.top), // Top: compare vs neighbor's bottom
-            (1, 0, player_move.place_card.right), // Right: compare vs neighbor's left
-            (0, 1, player_move.place_card.bottom), // Bottom: compare vs neighbor's top
-            (-1, 0, player_move.place_card.left), // Left: compare vs neighbor's right
-        ] {
-            let nx = player_move.place_index_x as isize + dx;
-            let ny = player_move.place_index_y as isize + dy;
-            if nx < 0 || nx >= 4 || ny < 0 || ny >= 4 {
-                continue;
-            }
-
-            let xi = nx as usize;
-            let yi = ny as usize;
-
-            if let Some(mut neighbor) = game.board[xi][yi].clone() {
-                // Only attempt to capture if the neighbor is owned by the opponent
-                let is_opponent_owned = match (neighbor.possession.as_ref(), player_ix) {
-                    (Some(Player::PlayerOne), 1) => true,
-                    (Some(Player::PlayerTwo), 0) => true,
-                    _ => false,
-                };
-                if !is_opponent_owned {
-                    continue;
-                }
+    /// Append `game_id` to `who`'s history: one page write, however long the history is.
+    fn append_player_game(who: &AccountIdOf<T>, game_id: GameId<T>) {
+        let page = PlayerGameCount::<T>::mutate(who, |count| {
+            let page = *count / GAMES_PER_PAGE;
+            *count = count.saturating_add(1);
+            page
+        });
+        // Pages only ever shrink after they were filled, so the last one has room.
+        let _ = PlayerGamePages::<T>::try_mutate(who, page, |ids| ids.try_push(game_id));
+        LiveGamePage::<T>::insert(game_id, who, page);
+    }
 
-                // Determine neighbor's opposing edge rank based on direction
-                let opp_rank = match (dx, dy) {
-                    (0, -1) => neighbor.bottom,
-                    (1, 0) => neighbor.left,
-                    (0, 1) => neighbor.top,
-                    (-1, 0) => neighbor.right,
-                    _ => 0,
-                };
+    /// Drop a live `game_id` from `who`'s history.
+    fn remove_player_game(who: &AccountIdOf<T>, game_id: &GameId<T>) {
+        if let Some(page) = LiveGamePage::<T>::take(game_id, who) {
+            PlayerGamePages::<T>::mutate(who, page, |ids| ids.retain(|g| g != game_id));
+        }
+    }
 
-                log::debug!(
-                    "[CaptureCheck] at ({},{}) vs neighbor ({},{}): my_edge={}, opp_edge={}",
-                    player_move.place_index_x,
-                    player_move.place_index_y,
-                    xi,
-                    yi,
-                    my_rank,
-                    opp_rank
-                );
+    /// One page of `who`'s history, most recent game first, starting at page `cursor` (the
+    /// newest page when `None`). Also returns the cursor of the next, older page, if any.
+    pub fn player_games(
+        who: &AccountIdOf<T>,
+        cursor: Option<u32>,
+    ) -> (Vec<GameId<T>>, Option<u32>) {
+        let count = PlayerGameCount::<T>::get(who);
+        if count == 0 {
+            return (Vec::new(), None);
+        }
+        let page = cursor.unwrap_or((count - 1) / GAMES_PER_PAGE);
+        let mut games = PlayerGamePages::<T>::get(who, page).into_inner();
+        games.reverse();
+        (games, page.checked_sub(1))
+    }
 
-                // Strictly greater captures; ties do not capture
-                if my_rank > opp_rank {
-                    // Adjust scores: remove point from previous owner (opponent), give to current
-                    match neighbor.possession.as_ref() {
-                        Some(Player::PlayerOne) => {
-                            game.scores.0 = game.scores.0.saturating_sub(1);
-                        }
-                        Some(Player::PlayerTwo) => {
-                            game.scores.1 = game.scores.1.saturating_sub(1);
-                        }
-                        _ => {}
-                    }
+    /// Extend or break `who`'s win streak after a game they won or did not win.
+    fn note_streak(who: &AccountIdOf<T>, won: bool) {
+        let mut streak = WinStreaks::<T>::get(who);
+        if !won {
+            if streak.current > 0 {
+                Self::deposit_event(Event::StreakBroken {
+                    who: who.clone(),
+                    length: streak.current,
+                });
+                streak.current = 0;
+                WinStreaks::<T>::insert(who, streak);
+            }
+            return;
+        }
 
-                    match player_ix {
-                        0 => {
-                            game.scores.0 = game.scores.0.saturating_add(1);
-                            neighbor.possession = Some(Player::PlayerOne);
-                        }
-                        _ => {
-                            game.scores.1 = game.scores.1.saturating_add(1);
-                            neighbor.possession = Some(Player::PlayerTwo);
-                        }
-                    }
+        streak.current = streak.current.saturating_add(1);
+        streak.best = streak.best.max(streak.current);
+        WinStreaks::<T>::insert(who, streak);
+        Self::deposit_event(Event::StreakExtended {
+            who: who.clone(),
+            current: streak.current,
+            best: streak.best,
+        });
+        let milestone = T::StreakMilestone::get();
+        if milestone != 0 && streak.current % milestone == 0 {
+            T::StreakRewards::on_streak_milestone(who, streak.current);
+        }
+    }
 
-                    log::debug!(
-                        "[Captured] neighbor ({},{}) now owned by player {}",
-                        xi,
-                        yi,
-                        player_ix
-                    );
+    /// Drop `game_id` from `LiveGameIds`, returning whether it is counted in `LiveGames`. A
+    /// game stored before `LiveGames` existed is only counted once `migrate_games` indexed it.
+    fn untrack_live_game(game_id: &GameId<T>) -> bool {
+        let indexed = LiveGameIds::<T>::take(game_id).is_some();
+        indexed || GameReindex::<T>::get().is_some_and(|pending| !pending.live_counts)
+    }
 
-                    // Persist flipped neighbor back to the board
-                    game.board[xi][yi] = Some(neighbor);
+    /// Rebuild the `pending` indexes for one stored game. Legacy `ActiveGameOf` markers,
+    /// keyed by account alone, are dropped, and a game still being played that `LiveGameIds`
+    /// does not hold yet gets its seats marked, is counted in `LiveGames` and joins
+    /// `LiveGameIds`. AI and bot seats never carry a marker. Returns whether anything was
+    /// written.
+    fn reindex_game(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        pending: PendingReindex,
+    ) -> bool {
+        use frame_support::storage::{unhashed, StoragePrefixedMap};
+        use frame_support::{Blake2_128Concat, StorageHasher};
+        if pending.markers {
+            let prefix = <ActiveGameOf<T> as StoragePrefixedMap<GameId<T>>>::final_prefix();
+            for p in game.players.iter() {
+                let mut legacy = prefix.to_vec();
+                legacy.extend(<Blake2_128Concat as StorageHasher>::hash(&p.encode()));
+                unhashed::kill(&legacy);
+            }
+        }
+        if !matches!(game.state, GameState::Playing) || LiveGameIds::<T>::contains_key(game_id) {
+            return pending.markers;
+        }
+        if pending.markers {
+            let context = GameContexts::<T>::get(game_id).unwrap_or(GameContext::Casual);
+            for p in game.players.iter() {
+                if AiProfiles::<T>::contains_key(p)
+                    || OpenBots::<T>::contains_key(p)
+                    || ActiveGameOf::<T>::contains_key(p, context)
+                {
+                    continue;
                 }
+                ActiveGameOf::<T>::insert(p, context, game_id);
             }
         }
+        if pending.live_counts {
+            if let Some(mode) = GameModes::<T>::get(game_id) {
+                LiveGames::<T>::mutate(mode, |n| *n = n.saturating_add(1));
+            }
+        }
+        LiveGameIds::<T>::insert(game_id, ());
+        true
     }
 
-    /// Create a default AI hand at game creation time so UI can display it even before human submits.
-    /// This hand uses deterministic pseudo-random stats (1..=9) derived from the game_id seed.
-    fn generate_ai_hand_default(game_id: &GameId<T>) -> Option<BoundedVec<HandEntry, HandLimit>> {
-        // Derive bytes from the game_id itself for reproducible pseudo-randomness
-        let h = <T as frame_system::Config>::Hashing::hash_of(game_id);
-        let bytes = h.as_ref();
-        if bytes.is_empty() {
-            return None;
+    /// Report a newly stored game through `GameEvent::Created`.
+    fn emit_created(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        mode: GameMode,
+        context: GameContext,
+    ) {
+        LiveGames::<T>::mutate(&mode, |n| *n = n.saturating_add(1));
+        LiveGameIds::<T>::insert(game_id, ());
+        Self::emit_game_event(
+            game_id,
+            &game.players,
+            GameEvent::Created {
+                mode,
+                context,
+                players: game.players.to_vec(),
+                first: game.player_turn,
+            },
+        );
+
+        // PvP players, and the human of a PvE game still waiting for it, owe a hand unless
+        // one was bound or dealt already.
+        if mode == GameMode::PvP || game.turn == TurnState::AwaitingHand {
+            let deadline = HandDeadlines::<T>::get(game_id);
+            for who in game.players.iter() {
+                if HandsOfGame::<T>::get(game_id, who).is_none() {
+                    Self::require_action(who, Some(*game_id), ActionKind::HandNeeded, deadline);
+                }
+            }
         }
+        Self::notify_turn(game_id, game);
+    }
 
-        let mut at = 0usize;
-        let mut next = || -> u8 {
-            let b = bytes[at % bytes.len()];
-            at = at.wrapping_add(1);
-            // Map 0..=255 -> 1..=9
-            (b % 9).saturating_add(1)
+    /// Tell the player to move that it is their turn, with the block from which the
+    /// opponent may `force_finish_turn`.
+    fn notify_turn(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) {
+        let Some(who) = game.seat_to_move().and_then(|seat| game.players.get(seat as usize))
+        else {
+            return;
         };
+        let limit: BlockNumberFor<T> = T::BlocksToPlayLimit::get().into();
+        let deadline = game.last_played_block.saturating_add(limit);
+        Self::require_action(who, Some(*game_id), ActionKind::YourTurn, Some(deadline));
+    }
 
-        let mut out: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
-        for _ in 0..HandLimit::get() {
-            let e = HandEntry {
-                card_id: 0,
-                north: next(),
-                east: next(),
-                south: next(),
-                west: next(),
-                used: false,
-            };
-            let _ = out.try_push(e);
+    /// Deposit `Event::ActionRequired` unless `who` is an AI identity.
+    fn require_action(
+        who: &AccountIdOf<T>,
+        game_id: Option<GameId<T>>,
+        kind: ActionKind,
+        deadline_block: Option<BlockNumberFor<T>>,
+    ) {
+        if AiProfiles::<T>::contains_key(who) {
+            return;
         }
-        Some(out)
+        Self::deposit_event(Event::ActionRequired {
+            account: who.clone(),
+            game_id,
+            kind,
+            deadline_block,
+        });
     }
 
-    fn end_game(game_id: &GameId<T>, winner: Option<T::AccountId>) {
-        // Read and update game in storage to persist final state
-        if let Some(mut g) = GameStorage::<T>::get(game_id) {
-            // Emit before we change pointers
-            Self::deposit_event(Event::GameFinished {
-                game_id: *game_id,
-                winner: winner.clone(),
-            });
+    /// Deposit `event` as the single `Event::Game` for this action. With the
+    /// `legacy-events` feature the pre-versioning events are emitted alongside it.
+    fn emit_game_event(
+        game_id: &GameId<T>,
+        players: &[AccountIdOf<T>],
+        event: GameEvent<AccountIdOf<T>>,
+    ) {
+        #[cfg(feature = "legacy-events")]
+        Self::emit_legacy_events(game_id, players, &event);
+        #[cfg(not(feature = "legacy-events"))]
+        let _ = players;
+        Self::deposit_event(Event::Game {
+            version: GAME_EVENT_VERSION,
+            game_id: *game_id,
+            event,
+        });
+    }
 
-            // Clear active-game markers for human participants
-            if let Some(a) = g.players.get(0).cloned() {
-                ActiveGameOf::<T>::remove(&a);
+    /// Pre-versioning equivalents of `event`, kept for one release behind `legacy-events`.
+    #[cfg(feature = "legacy-events")]
+    fn emit_legacy_events(
+        game_id: &GameId<T>,
+        players: &[AccountIdOf<T>],
+        event: &GameEvent<AccountIdOf<T>>,
+    ) {
+        let game_id = *game_id;
+        let seat = |ix: u8| players.get(ix as usize).cloned();
+        let next_turn = |next: Option<u8>| {
+            if let Some(next_player) = next.and_then(seat) {
+                Self::deposit_event(Event::NewTurn {
+                    game_id,
+                    next_player,
+                });
+            }
+        };
+        match event {
+            GameEvent::Created { .. } => Self::deposit_event(Event::GameCreated { game_id }),
+            GameEvent::Moved { seat: s, x, y, next } => {
+                next_turn(*next);
+                if let Some(player) = seat(*s) {
+                    Self::deposit_event(Event::MovePlayed {
+                        game_id,
+                        player,
+                        x: *x,
+                        y: *y,
+                    });
+                }
             }
-            if let Some(b) = g.players.get(1).cloned() {
-                ActiveGameOf::<T>::remove(&b);
+            GameEvent::TurnSkipped { seat: s, next } => {
+                if let Some(player) = seat(*s) {
+                    Self::deposit_event(Event::TurnForceFinished { game_id, player });
+                }
+                next_turn(*next);
             }
-
-            // Map AccountId winner to player index (0/1) to match GameState::Finished { winner: Option<u8> }
-            let winner_ix: Option<u8> = match winner.as_ref() {
-                Some(acc) if *acc == g.players[0] => Some(0),
-                Some(acc) if *acc == g.players[1] => Some(1),
-                _ => None,
-            };
-            g.state = GameState::Finished { winner: winner_ix };
-            GameStorage::<T>::insert(game_id, g);
-        } else {
-            // If the game wasn't found (should not happen), still emit the event
-            Self::deposit_event(Event::GameFinished {
-                game_id: *game_id,
-                winner,
-            });
+            GameEvent::Finished { winner, .. } => Self::deposit_event(Event::GameFinished {
+                game_id,
+                winner: winner.and_then(seat),
+            }),
         }
     }
 }
@@ -1319,6 +5014,77 @@ impl<T: Config> pallet_eterra_simple_matchmaker::GameCreator<AccountIdOf<T>> for
         a: &AccountIdOf<T>,
         b: &AccountIdOf<T>,
     ) -> Result<GameId<T>, sp_runtime::DispatchError> {
-        Self::do_create_pvp_game(a, b)
+        Self::do_create_pvp_game(a, b, GameContext::Ranked)
+    }
+
+    fn create_for_league(
+        a: &AccountIdOf<T>,
+        b: &AccountIdOf<T>,
+    ) -> Result<GameId<T>, sp_runtime::DispatchError> {
+        Self::do_create_pvp_game(a, b, GameContext::Tournament)
+    }
+
+    fn can_queue(who: &AccountIdOf<T>) -> bool {
+        ActiveGameOf::<T>::get(who, GameContext::Ranked).is_none()
+            && Self::ensure_under_daily_cap(who, GameContext::Ranked).is_ok()
+    }
+
+    fn creation_weight() -> Weight {
+        T::DbWeight::get().reads_writes(16, 16)
+    }
+}
+
+// Keep the power score of current hands in step with leveled-up cards
+impl<T: Config> pallet_eterra_simple_tcg::CardObserver<AccountIdOf<T>> for Pallet<T> {
+    fn on_card_changed(owner: &AccountIdOf<T>, card_id: u32) {
+        if let Some(hand) = Self::current_hand_of(owner).filter(|hand| hand.contains(&card_id)) {
+            CurrentHandPower::<T>::insert(owner, Self::hand_power(&hand));
+        }
+    }
+}
+
+// Expose Elo ratings for rating-banded matchmaking
+impl<T: Config> pallet_eterra_simple_matchmaker::RatingProvider<AccountIdOf<T>> for Pallet<T> {
+    fn rating(who: &AccountIdOf<T>) -> Option<u32> {
+        Some(Self::rating_of(who))
+    }
+}
+
+// Expose the contract-facing game API for a future chain extension
+impl<T: Config> contract_api::GameContractApi<AccountIdOf<T>> for Pallet<T> {
+    type GameId = GameId<T>;
+
+    fn game_state(
+        game_id: &GameId<T>,
+    ) -> Option<contract_api::ContractGameView<AccountIdOf<T>>> {
+        let game = GameStorage::<T>::get(game_id)?;
+        Some(contract_api::ContractGameView {
+            state: game.state,
+            players: game.players.to_vec(),
+            player_turn: game.player_turn,
+            round: game.round,
+            max_rounds: game.max_rounds,
+            board: game.board,
+            scores: game.scores,
+        })
+    }
+
+    fn create_pve_game(
+        caller: &AccountIdOf<T>,
+    ) -> Result<GameId<T>, sp_runtime::DispatchError> {
+        Self::do_create_game(caller.clone(), sp_std::vec![caller.clone()], GameMode::PvE, false)?;
+        ActiveGameOf::<T>::get(caller, GameContext::PvE)
+            .ok_or_else(|| Error::<T>::GameNotFound.into())
+    }
+
+    fn card_stats(card_id: u32) -> Option<contract_api::ContractCardStats<AccountIdOf<T>>> {
+        let info = pallet_eterra_simple_tcg::pallet::Cards::<T>::get(card_id)?;
+        Some(contract_api::ContractCardStats {
+            owner: info.owner,
+            north: info.north,
+            east: info.east,
+            south: info.south,
+            west: info.west,
+        })
     }
 }
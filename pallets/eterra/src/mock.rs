@@ -2,6 +2,7 @@ use crate as pallet_eterra;
 use frame_support::{
     parameter_types,
     traits::{ConstU16, ConstU32, ConstU64, ConstU8, Currency, Get},
+    PalletId,
 };
 use frame_system as system;
 use pallet_balances;
@@ -38,9 +39,12 @@ parameter_types! {
 }
 
 parameter_types! {
-    pub const FaucetAccountId: u64 = 999; // arbitrary faucet for tests
+    pub const TcgPalletId: PalletId = PalletId(*b"etr/tcg_");
     pub const RandomnessSeedConst: u64 = 42;
     pub const MintFeeConst: u128 = 0; // zero-fee minting in tests to avoid funding hassle
+    pub const MinListingPriceConst: u128 = 1;
+    pub const PriceTickConst: u128 = 1;
+    pub const RenameFeeConst: u128 = 0;
 }
 
 impl system::Config for Test {
@@ -97,7 +101,22 @@ impl pallet_eterra_simple_tcg::Config for Test {
     type RandomnessSeed = RandomnessSeedConst;
     type Currency = Balances;
     type MintFee = MintFeeConst;
-    type FaucetAccount = FaucetAccountId;
+    type PalletId = TcgPalletId;
+    type BridgeOrigin = frame_system::EnsureRoot<u64>;
+    type PriceHistoryLen = ConstU32<8>;
+    type OnMint = ();
+    type OnCardChanged = Eterra;
+    type MaxShowcase = ConstU32<3>;
+    type UnvaultDelay = ConstU64<10>;
+    type MinListingPrice = MinListingPriceConst;
+    type PriceTick = PriceTickConst;
+    type PricingAdminOrigin = frame_system::EnsureRoot<u64>;
+    type RenameFee = RenameFeeConst;
+    type MaxRenameHistory = ConstU32<4>;
+    type NameModeratorOrigin = frame_system::EnsureRoot<u64>;
+    type TradeCooldown = ConstU64<50>;
+    type XpPerLevel = ConstU32<30>;
+    type StarterDeckSize = ConstU32<0>;
 }
 
 #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
@@ -131,6 +150,68 @@ parameter_types! {
     pub const HandSizeConst: u32 = 5;
 }
 
+parameter_types! {
+    pub storage HandicapHandBudget: u16 = 0;
+    pub storage DefaultRules: u8 = 0;
+    pub storage MaxRatedGamesPerDay: u32 = 0;
+    pub const MaxSpectators: u32 = 2;
+    pub storage MaxAiMovesPerBlock: u32 = 0;
+    pub storage DevTelemetry: bool = false;
+    pub const MaxPendingAiTurns: u32 = 4;
+    pub const AiTurnWeightConst: frame_support::weights::Weight =
+        frame_support::weights::Weight::from_parts(1_000_000, 0);
+}
+
+thread_local! {
+    static PLAYER_LEVELS: std::cell::RefCell<std::collections::BTreeMap<u64, u8>> =
+        std::cell::RefCell::new(Default::default());
+}
+
+/// Test-only level provider backed by a thread-local map (unset accounts are level 0).
+pub struct MockPlayerLevel;
+impl pallet_eterra::PlayerLevelProvider<u64> for MockPlayerLevel {
+    fn level_of(who: &u64) -> u8 {
+        PLAYER_LEVELS.with(|m| m.borrow().get(who).copied().unwrap_or(0))
+    }
+}
+
+pub fn set_player_level(who: u64, level: u8) {
+    PLAYER_LEVELS.with(|m| m.borrow_mut().insert(who, level));
+}
+
+thread_local! {
+    static STREAK_MILESTONES: std::cell::RefCell<Vec<(u64, u32)>> = Default::default();
+}
+
+/// Test-only streak hook recording every milestone reached.
+pub struct MockStreakRewards;
+impl pallet_eterra::StreakMilestoneObserver<u64> for MockStreakRewards {
+    fn on_streak_milestone(who: &u64, streak: u32) {
+        STREAK_MILESTONES.with(|m| m.borrow_mut().push((*who, streak)));
+    }
+}
+
+pub fn streak_milestones() -> Vec<(u64, u32)> {
+    STREAK_MILESTONES.with(|m| m.borrow().clone())
+}
+
+thread_local! {
+    static GAME_RESULTS: std::cell::RefCell<Vec<eterra_core_types::result::GameResult<u64>>> =
+        Default::default();
+}
+
+/// Test-only result hook recording every finished game.
+pub struct MockGameResults;
+impl pallet_eterra::GameResultObserver<u64> for MockGameResults {
+    fn on_game_result(result: &eterra_core_types::result::GameResult<u64>) {
+        GAME_RESULTS.with(|r| r.borrow_mut().push(result.clone()));
+    }
+}
+
+pub fn game_results() -> Vec<eterra_core_types::result::GameResult<u64>> {
+    GAME_RESULTS.with(|r| r.borrow().clone())
+}
+
 parameter_types! {
     pub const AiDifficultyConst: u8 = 60;
     pub const AiRandomnessSeedConst: u64 = 12345;
@@ -142,8 +223,37 @@ impl pallet_eterra::Config for Test {
     type MaxRounds = MockMaxRounds;
     type BlocksToPlayLimit = MockBlocksToPlayLimit;
     type HandSize = HandSizeConst;
-    type AiAccount = FaucetAccountId;
     type AiDifficulty = ConstU8<60>;
+    type PlayerLevel = MockPlayerLevel;
+    type CardUsage = ();
+    type GameOutcome = ();
+    type HandicapLevelGap = ConstU8<3>;
+    type HandicapHandBudget = HandicapHandBudget;
+    type AiTurnWeight = AiTurnWeightConst;
+    type PresenceCleanup = ();
+    type GameAdminOrigin = frame_system::EnsureRoot<u64>;
+    type ModeratorOrigin = frame_system::EnsureRoot<u64>;
+    type DefaultRules = DefaultRules;
+    type MaxRatedGamesPerDay = MaxRatedGamesPerDay;
+    type GameActivity = ();
+    type MaxSpectators = MaxSpectators;
+    type MaxAiMovesPerBlock = MaxAiMovesPerBlock;
+    type MaxPendingAiTurns = MaxPendingAiTurns;
+    type StreakMilestone = ConstU32<2>;
+    type StreakRewards = MockStreakRewards;
+    type MaxMoveAnnotations = ConstU32<4>;
+    type GameResults = MockGameResults;
+    type DevTelemetry = DevTelemetry;
+    type FeaturedHandDuration = ConstU64<100>;
+    type HandSubmissionPeriod = ConstU64<20>;
+    type MoveHistoryRetention = ConstU64<50>;
+    type AbandonAfterBlocks = ConstU64<100>;
+    type MaxExpiryScansPerBlock = ConstU32<4>;
+    type MaxDecks = ConstU32<3>;
+    type DecayPeriodBlocks = ConstU64<1_000>;
+    type RatingDecay = ConstU32<10>;
+    type RatingDecayFloor = ConstU32<1_100>;
+    type MaxDecayScansPerBlock = ConstU32<4>;
 }
 
 impl mc_ai::pallet::Config for Test {
@@ -155,13 +265,24 @@ impl mc_ai::pallet::Config for Test {
     type RandomnessSeed = ConstU64<12345>;
 }
 
+/// Account seeded as the default AI identity for PvE games.
+pub const AI_ACCOUNT: u64 = 999;
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Test>::default() // Explicit type annotation
+    let mut t = frame_system::GenesisConfig::<Test>::default() // Explicit type annotation
         .build_storage()
         .unwrap();
+    pallet_eterra::GenesisConfig::<Test> {
+        ai_accounts: vec![AI_ACCOUNT],
+        current_hands: vec![],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
 
     let mut ext = sp_io::TestExternalities::from(t);
     ext.execute_with(|| {
+        PLAYER_LEVELS.with(|m| m.borrow_mut().clear());
+        STREAK_MILESTONES.with(|m| m.borrow_mut().clear());
         System::set_block_number(1); // Reset block number
                                      // fund some accounts
         let _ = <Balances as Currency<u64>>::deposit_creating(&1u64, 1_000_000_000_000);
@@ -19,6 +19,7 @@ use frame_system::RawOrigin;
 use log::{Level, Metadata, Record};
 use sp_core::H256; // Fix: Import H256
 use sp_runtime::traits::{BlakeTwo256, Hash};
+use parity_scale_codec::Encode;
 use std::sync::Once;
 
 use cards::pallet as card_pallet;
@@ -83,9 +84,18 @@ fn setup_new_game() -> (H256, u64, u64) {
         opponent,
         current_block_number,
     );
+    open_with_creator(game_id);
     (game_id, creator, opponent)
 }
 
+/// Give the creator's seat the opening move, whichever seat the game's seed drew, so tests
+/// can script moves in a fixed order.
+fn open_with_creator(game_id: H256) {
+    GameStorage::<Test>::mutate(&game_id, |g| {
+        g.as_mut().expect("game exists").start(0, false)
+    });
+}
+
 // Helper: set a minimal valid "current hand" for `who`.
 fn set_dummy_hand<T: crate::Config>(who: &crate::AccountIdOf<T>) {
     // If your pallet exposes a different alias than `HandLimit`, change it here.
@@ -93,7 +103,8 @@ fn set_dummy_hand<T: crate::Config>(who: &crate::AccountIdOf<T>) {
         .collect::<Vec<_>>()
         .try_into()
         .expect("within hand limit");
-    crate::CurrentHandOf::<T>::insert(who, one);
+    crate::Decks::<T>::insert(who, 0, crate::Deck { name: BoundedVec::default(), cards: one });
+    crate::ActiveDeck::<T>::insert(who, 0);
 }
 
 /// Helper to setup a new game with the given creator and opponent.
@@ -118,6 +129,7 @@ fn setup_new_game_with(creator: u64, opponent: u64) -> (H256, u64, u64) {
         opponent,
         current_block_number,
     );
+    open_with_creator(game_id);
     (game_id, creator, opponent)
 }
 /// Helper: mint exactly HandSize cards for `owner` and set them as the preset hand via pallet call.
@@ -256,7 +268,38 @@ fn create_game_with_same_players_fails() {
             vec![player, player],
             pallet::GameMode::PvP,
         );
-        assert_noop!(result, crate::Error::<Test>::InvalidMove);
+        assert_noop!(result, crate::Error::<Test>::PlayersNotDistinct);
+    });
+}
+
+#[test]
+fn ai_identities_cannot_create_pve_games() {
+    new_test_ext().execute_with(|| {
+        ensure_preset_hand(AI_ACCOUNT);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(AI_ACCOUNT).into(),
+                vec![AI_ACCOUNT],
+                pallet::GameMode::PvE,
+            ),
+            crate::Error::<Test>::CreatorIsAi
+        );
+    });
+}
+
+#[test]
+fn recreating_a_game_in_the_same_block_reports_an_id_collision() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(creator).into(),
+                vec![creator, opponent],
+                pallet::GameMode::PvP,
+            ),
+            crate::Error::<Test>::GameIdCollision
+        );
     });
 }
 
@@ -302,7 +345,7 @@ fn create_game_works() {
         assert_eq!(game.players[0], creator);
         assert_eq!(game.players[1], opponent);
         assert!(current_turn == creator || current_turn == opponent);
-        assert!(game.board.iter().flatten().all(|cell| cell.is_none())); // Verify empty board
+        assert!(game.board.cells().all(|cell| cell.is_none())); // Verify empty board
     });
 }
 #[test]
@@ -689,9 +732,13 @@ fn full_game_simulation() {
         // Ensure GameFinished event is emitted without relying on GameStorage
         let events = frame_system::Pallet::<Test>::events();
         let game_finished_event_found = events.iter().any(|record| match record.event {
-            RuntimeEvent::Eterra(crate::Event::GameFinished {
+            RuntimeEvent::Eterra(crate::Event::Game {
                 game_id: event_game_id,
-                winner: event_winner,
+                event: crate::GameEvent::Finished {
+                    winner: event_winner,
+                    ..
+                },
+                ..
             }) => {
                 log::debug!(
                     "GameFinished event detected: {:?}, Winner: {:?}",
@@ -1039,12 +1086,16 @@ fn game_winner_is_correctly_emitted() {
         // Check if the GameFinished event is emitted
         let events = frame_system::Pallet::<Test>::events();
         let game_finished_event_found = events.iter().any(|record| match &record.event {
-            RuntimeEvent::Eterra(crate::Event::GameFinished {
+            RuntimeEvent::Eterra(crate::Event::Game {
                 game_id: event_game_id,
-                winner: event_winner,
+                event: crate::GameEvent::Finished {
+                    winner: event_winner,
+                    ..
+                },
+                ..
             }) => {
                 assert_eq!(*event_game_id, game_id);
-                assert_eq!(*event_winner, Some(creator)); // Player 0 should win
+                assert_eq!(*event_winner, Some(0)); // Player 0 should win
                 true
             }
             _ => false,
@@ -1149,21 +1200,23 @@ fn exceeding_max_moves_marks_finished_and_keeps_game_in_storage() {
             ),
         ];
 
-        // Play all 10 moves
+        // Play all 10 moves; the last one ends the game and keeps its full weight, which
+        // covers the result observers
+        let mut post = None;
         for (player, mv) in moves.iter() {
-            assert_ok!(Eterra::play(
-                frame_system::RawOrigin::Signed(*player).into(),
-                game_id,
-                mv.clone(),
-            ));
+            let origin = frame_system::RawOrigin::Signed(*player).into();
+            post = Some(Eterra::play(origin, game_id, mv.clone()).expect("move succeeds"));
         }
+        assert_eq!(post.unwrap().actual_weight, None);
 
         // GameFinished must be emitted
         let events = frame_system::Pallet::<Test>::events();
         let finished_emitted = events.iter().any(|record| match &record.event {
-            RuntimeEvent::Eterra(crate::Event::GameFinished { game_id: eid, .. }) => {
-                *eid == game_id
-            }
+            RuntimeEvent::Eterra(crate::Event::Game {
+                game_id: eid,
+                event: crate::GameEvent::Finished { .. },
+                ..
+            }) => *eid == game_id,
             _ => false,
         });
         assert!(
@@ -1265,7 +1318,7 @@ fn force_finish_turn_fails_if_blocks_not_passed() {
 /// 2. Identify the current player
 /// 3. Advance the block number by (BlocksToPlayLimit + 1) to exceed the limit
 /// 4. Call force_finish_turn from the other player
-/// 5. Check that the turn is forced, a TurnForceFinished and NewTurn event are emitted,
+/// 5. Check that the turn is forced, a single TurnSkipped event naming the next seat is emitted,
 ///    and the last_played_block is updated.
 #[test]
 fn force_finish_turn_works_when_limit_passed() {
@@ -1313,34 +1366,27 @@ fn force_finish_turn_works_when_limit_passed() {
 
         // Check that events were emitted
         let events = frame_system::Pallet::<Test>::events();
-        let mut force_finished_found = false;
-        let mut new_turn_found = false;
-
-        for record in events {
-            if let RuntimeEvent::Eterra(crate::Event::TurnForceFinished {
-                game_id: event_game_id,
-                player: event_player,
-            }) = record.event
-            {
-                if event_game_id == game_id && event_player == current_player {
-                    force_finished_found = true;
-                }
-            } else if let RuntimeEvent::Eterra(crate::Event::NewTurn {
-                game_id: event_game_id,
-                next_player: event_next_player,
-            }) = record.event
-            {
-                if event_game_id == game_id && event_next_player == new_current_player {
-                    new_turn_found = true;
-                }
-            }
-        }
+        let seat_of = |acc| game_after.players.iter().position(|p| *p == acc).map(|i| i as u8);
+        let skipped: Vec<(u8, Option<u8>)> = events
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Eterra(crate::Event::Game {
+                    game_id: event_game_id,
+                    event: crate::GameEvent::TurnSkipped { seat, next },
+                    ..
+                }) if event_game_id == game_id => Some((seat, next)),
+                _ => None,
+            })
+            .collect();
 
-        assert!(
-            force_finished_found,
-            "Expected TurnForceFinished event not found"
+        assert_eq!(
+            skipped,
+            vec![(
+                seat_of(current_player).unwrap(),
+                seat_of(new_current_player)
+            )],
+            "Expected a single TurnSkipped event"
         );
-        assert!(new_turn_found, "Expected NewTurn event not found");
     });
 }
 
@@ -1365,7 +1411,7 @@ fn play_emits_new_turn_event() {
             creator_move.clone(),
         ));
 
-        // 3. Check for NewTurn event
+        // 3. Check the Moved event names the next seat
         let events = frame_system::Pallet::<Test>::events();
         let mut new_turn_found = false;
 
@@ -1373,12 +1419,15 @@ fn play_emits_new_turn_event() {
         let expected_next_player = opponent;
 
         for record in &events {
-            if let RuntimeEvent::Eterra(crate::Event::NewTurn {
+            if let RuntimeEvent::Eterra(crate::Event::Game {
                 game_id: event_game_id,
-                next_player,
+                event: crate::GameEvent::Moved { next: Some(next), .. },
+                ..
             }) = &record.event
             {
-                if *event_game_id == game_id && *next_player == expected_next_player {
+                let players = GameStorage::<Test>::get(&game_id).unwrap().players;
+                let next_player = players[*next as usize];
+                if *event_game_id == game_id && next_player == expected_next_player {
                     new_turn_found = true;
                     break;
                 }
@@ -1407,19 +1456,22 @@ fn play_emits_new_turn_event() {
             opponent_move.clone(),
         ));
 
-        // 6. Check for NewTurn event again (now the next turn should belong to the creator)
+        // 6. Check the next seat again (now the next turn should belong to the creator)
         let events = frame_system::Pallet::<Test>::events();
         let mut new_turn_found = false;
 
         let expected_next_player = creator; // After opponent, it goes back to creator
 
         for record in &events {
-            if let RuntimeEvent::Eterra(crate::Event::NewTurn {
+            if let RuntimeEvent::Eterra(crate::Event::Game {
                 game_id: event_game_id,
-                next_player,
+                event: crate::GameEvent::Moved { next: Some(next), .. },
+                ..
             }) = &record.event
             {
-                if *event_game_id == game_id && *next_player == expected_next_player {
+                let players = GameStorage::<Test>::get(&game_id).unwrap().players;
+                let next_player = players[*next as usize];
+                if *event_game_id == game_id && next_player == expected_next_player {
                     new_turn_found = true;
                     break;
                 }
@@ -1433,6 +1485,55 @@ fn play_emits_new_turn_event() {
     });
 }
 
+#[test]
+fn each_move_emits_one_versioned_game_event() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let max_rounds: u8 = <Test as crate::Config>::MaxRounds::get();
+
+        for i in 0..(max_rounds as usize * 2) {
+            let current_player = if i % 2 == 0 { creator } else { opponent };
+            let mv = Move {
+                place_index_x: (i % 4) as u8,
+                place_index_y: ((i / 4) % 4) as u8,
+                place_card: Card::new(5, 3, 2, 4),
+            };
+            assert_ok!(Eterra::play(
+                frame_system::RawOrigin::Signed(current_player).into(),
+                game_id,
+                mv,
+            ));
+        }
+
+        let game_events: Vec<crate::GameEvent<u64>> = frame_system::Pallet::<Test>::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Eterra(crate::Event::Game { version, event, .. }) => {
+                    assert_eq!(version, crate::GAME_EVENT_VERSION);
+                    Some(event)
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Created, one Moved per placement, then Finished
+        assert_eq!(game_events.len(), max_rounds as usize * 2 + 2);
+        assert!(matches!(game_events[0], crate::GameEvent::Created { .. }));
+        let n = game_events.len();
+        assert!(matches!(
+            game_events[n - 2],
+            crate::GameEvent::Moved { next: None, .. }
+        ));
+        assert!(matches!(
+            game_events[n - 1],
+            crate::GameEvent::Finished {
+                reason: crate::EndReason::Completed,
+                ..
+            }
+        ));
+    });
+}
+
 #[test]
 fn debug_game_rounds_and_termination() {
     init_logger();
@@ -1485,9 +1586,10 @@ fn debug_game_rounds_and_termination() {
         // Ensure `GameFinished` event was emitted.
         let events = frame_system::Pallet::<Test>::events();
         let game_finished_event_found = events.iter().any(|record| match record.event {
-            RuntimeEvent::Eterra(crate::Event::GameFinished {
+            RuntimeEvent::Eterra(crate::Event::Game {
                 game_id: event_game_id,
-                winner: _,
+                event: crate::GameEvent::Finished { .. },
+                ..
             }) => {
                 log::info!("🎉 GameFinished Event Found for {:?}", event_game_id);
                 event_game_id == game_id
@@ -1527,6 +1629,80 @@ fn debug_game_rounds_and_termination() {
     });
 }
 
+#[test]
+fn vaulted_cards_cannot_enter_hands() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _) = setup_new_game();
+        let ids = mint_cards_for(creator, 5);
+        assert_ok!(Eterra::set_current_hand(RawOrigin::Signed(creator).into(), ids.clone()));
+        assert_ok!(cards::Pallet::<Test>::vault_card(
+            RawOrigin::Signed(creator).into(),
+            ids[0]
+        ));
+
+        assert_noop!(
+            Eterra::set_current_hand(RawOrigin::Signed(creator).into(), ids.clone()),
+            crate::Error::<Test>::CardVaulted
+        );
+        // A hand saved before vaulting is re-checked when snapshotted into a game.
+        assert_noop!(
+            Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, ids),
+            crate::Error::<Test>::CardVaulted
+        );
+    });
+}
+
+/// Current hand of `who`: four cards of their own and one just bought from account 3.
+fn hand_with_traded_card(who: u64) -> Vec<u32> {
+    let mut ids = mint_cards_for(who, 4);
+    let traded = mint_cards_for(3, 1)[0];
+    assert_ok!(cards::Pallet::<Test>::transfer_card(
+        RawOrigin::Signed(3).into(),
+        traded,
+        who
+    ));
+    ids.push(traded);
+    assert_ok!(Eterra::set_current_hand(RawOrigin::Signed(who).into(), ids.clone()));
+    ids
+}
+
+#[test]
+fn traded_cards_wait_out_the_cooldown_before_entering_ranked_hands() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        let ids = hand_with_traded_card(creator);
+        ensure_preset_hand(opponent);
+        let game_id =
+            <crate::Pallet<Test> as GameCreator<u64>>::create_from_matchmaking(&creator, &opponent)
+                .unwrap();
+
+        assert_noop!(
+            Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, ids.clone()),
+            crate::Error::<Test>::CardCoolingDown
+        );
+        run_to_block(System::block_number() + 50);
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, ids));
+    });
+}
+
+#[test]
+fn casual_games_accept_freshly_traded_cards() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        let ids = hand_with_traded_card(creator);
+        ensure_preset_hand(opponent);
+        let game_id = BlakeTwo256::hash_of(&(creator, opponent, System::block_number()));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(creator).into(),
+            vec![creator, opponent],
+            pallet::GameMode::PvP,
+        ));
+
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, ids));
+        assert!(HandsOfGame::<Test>::get(&game_id, &creator).is_some());
+    });
+}
+
 #[test]
 fn submit_hand_rejects_unowned_card() {
     init_logger();
@@ -1621,6 +1797,68 @@ fn play_from_hand_marks_used_and_prevents_reuse() {
     });
 }
 
+#[test]
+fn play_and_play_from_hand_report_moves_identically() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let creator_cards = mint_cards_for(creator, 5);
+        assert_ok!(Eterra::submit_hand(
+            frame_system::RawOrigin::Signed(creator).into(),
+            game_id,
+            creator_cards,
+        ));
+        ensure_my_turn(game_id, creator, opponent);
+        let seat = GameStorage::<Test>::get(&game_id).unwrap().get_player_turn();
+
+        let last_game_event = || {
+            frame_system::Pallet::<Test>::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::Eterra(crate::Event::Game { event, .. }) => Some(event),
+                    _ => None,
+                })
+                .last()
+        };
+
+        assert_ok!(Eterra::play_from_hand(
+            frame_system::RawOrigin::Signed(creator).into(),
+            game_id,
+            0,
+            3,
+            3,
+        ));
+        let stored = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(
+            last_game_event(),
+            Some(crate::GameEvent::Moved {
+                seat,
+                x: 3,
+                y: 3,
+                next: Some(stored.get_player_turn()),
+            })
+        );
+
+        assert_ok!(Eterra::play(
+            frame_system::RawOrigin::Signed(opponent).into(),
+            game_id,
+            Move {
+                place_index_x: 3,
+                place_index_y: 2,
+                place_card: Card::new(5, 3, 2, 4),
+            },
+        ));
+        assert_eq!(
+            last_game_event(),
+            Some(crate::GameEvent::Moved {
+                seat: 1 - seat,
+                x: 3,
+                y: 2,
+                next: Some(seat),
+            })
+        );
+    });
+}
+
 #[test]
 fn play_from_hand_index_out_of_range_fails() {
     init_logger();
@@ -1861,7 +2099,6 @@ mod ai_integration_tests {
     use super::*;
     use crate::mock::*;
     use crate::types::card::Card;
-    use crate::types::card::Possession as Player;
     use crate::types::game::GameProperties;
     use crate::HandsOfGame;
     use crate::{GameStorage, Move};
@@ -1877,8 +2114,7 @@ mod ai_integration_tests {
     /// Helper to create a new PvE game (human vs AI).
     fn setup_pve_game() -> (H256, u64, <Test as frame_system::Config>::AccountId) {
         let human: u64 = 1;
-        let ai_account: <Test as frame_system::Config>::AccountId =
-            <Test as crate::Config>::AiAccount::get();
+        let ai_account: <Test as frame_system::Config>::AccountId = AI_ACCOUNT;
         // Ensure human has a preset hand before creating PvE game
         ensure_preset_hand(human);
         let current_block_number = <frame_system::Pallet<Test>>::block_number();
@@ -1944,6 +2180,55 @@ mod ai_integration_tests {
         });
     }
 
+    #[test]
+    fn hints_suggest_a_legal_move_without_playing_it() {
+        new_test_ext().execute_with(|| {
+            let (game_id, human, _) = setup_pve_game();
+            // Nothing to suggest while the game waits for the human's hand
+            assert_eq!(Eterra::suggest_for_game(&game_id, 50), None);
+            let ids = mint_cards_for(human, 5);
+            assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), game_id, ids));
+
+            let before = GameStorage::<Test>::get(&game_id).unwrap();
+            assert_eq!(before.players[before.player_turn as usize], human);
+            let hint = Eterra::suggest_for_game(&game_id, 250).expect("the human can move");
+            assert!((hint.hand_index as u32) < <Test as crate::Config>::HandSize::get());
+            assert!(before.board[hint.x as usize][hint.y as usize].is_none());
+            let after = GameStorage::<Test>::get(&game_id).unwrap();
+            assert_eq!((after.board, after.player_turn), (before.board, before.player_turn));
+
+            assert_eq!(Eterra::suggest_for_game(&H256::repeat_byte(9), 50), None);
+        });
+    }
+
+    #[test]
+    fn dev_telemetry_reports_ai_search_stats() {
+        new_test_ext().execute_with(|| {
+            DevTelemetry::set(&true);
+            let (game_id, human, ai_account) = setup_pve_game();
+            let ids = mint_cards_for(human, 5);
+            assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), game_id, ids));
+            assert_ok!(Eterra::play_from_hand(RawOrigin::Signed(human).into(), game_id, 0, 0, 0));
+
+            let stats = frame_system::Pallet::<Test>::events()
+                .into_iter()
+                .find_map(|r| match r.event {
+                    RuntimeEvent::Eterra(crate::Event::AiSearchStats {
+                        game_id: g,
+                        player,
+                        actions,
+                        ..
+                    }) if g == game_id => Some((player, actions)),
+                    _ => None,
+                })
+                .expect("AI move reports its search");
+            assert_eq!(stats.0, ai_account);
+            // 15 open cells x 5 unused cards, each with at least one playout
+            assert_eq!(stats.1.len(), 75);
+            assert!(stats.1.iter().all(|a| a.visits >= 1));
+        });
+    }
+
     #[test]
     fn ai_can_produce_suggestion_from_current_state() {
         new_test_ext().execute_with(|| {
@@ -1957,27 +2242,8 @@ mod ai_integration_tests {
             ));
             // Ensure both hands exist
             let game = GameStorage::<Test>::get(&game_id).unwrap();
-            // Use the AI adapter to map state explicitly (avoid relying on non-existent EterraState::from_game)
-            // Map board: crate Card -> adapter Card
-            let mut board: [[Option<ai::Card>; 4]; 4] =
-                core::array::from_fn(|_| core::array::from_fn(|_| None));
-            for x in 0..4usize {
-                for y in 0..4usize {
-                    if let Some(c) = &game.board[x][y] {
-                        let possession = c.get_possession().cloned().map(|p| match p {
-                            Player::PlayerOne => ai::Possession::PlayerOne,
-                            Player::PlayerTwo => ai::Possession::PlayerTwo,
-                        });
-                        board[x][y] = Some(ai::Card {
-                            top: c.top,
-                            right: c.right,
-                            bottom: c.bottom,
-                            left: c.left,
-                            possession,
-                        });
-                    }
-                }
-            }
+            // The adapter shares the on-chain board type
+            let board: ai::Board = game.board.clone();
 
             // Map hands from on-chain storage into adapter hands
             let human_hand_bv = HandsOfGame::<Test>::get(&game_id, &human)
@@ -2006,6 +2272,7 @@ mod ai_integration_tests {
                             south: he.south,
                             west: he.west,
                             used: he.used,
+                            element: he.element,
                         }
                     });
                     ai::Hand { entries }
@@ -2031,6 +2298,8 @@ mod ai_integration_tests {
                 round: game.round,
                 max_rounds: game.max_rounds,
                 hands,
+                rules: Default::default(),
+                energy: [0, 0],
             };
 
             let diff = <Test as crate::Config>::AiDifficulty::get();
@@ -2055,8 +2324,7 @@ fn multiple_pve_games_have_independent_ai_state() {
         // --- Create two separate PvE games (different humans, same AI account) ---
         let human1: u64 = 1;
         let human2: u64 = 3;
-        let ai_account: <Test as frame_system::Config>::AccountId =
-            <Test as crate::Config>::AiAccount::get();
+        let ai_account: <Test as frame_system::Config>::AccountId = AI_ACCOUNT;
         ensure_preset_hand(human1);
         ensure_preset_hand(human2);
 
@@ -2205,8 +2473,7 @@ fn creator_cannot_start_second_pvp_game_while_active() {
 fn creator_cannot_start_second_pve_game_while_active() {
     new_test_ext().execute_with(|| {
         let human: u64 = 10;
-        let ai_acc: <Test as frame_system::Config>::AccountId =
-            <Test as crate::Config>::AiAccount::get();
+        let ai_acc: <Test as frame_system::Config>::AccountId = AI_ACCOUNT;
         ensure_preset_hand(human);
 
         // First PvE game should succeed. (Players vec must include the human/creator.)
@@ -2254,8 +2521,8 @@ fn create_from_matchmaking_creates_game_and_emits_event() {
         // Storage should contain the game
         assert!(crate::GameStorage::<Test>::contains_key(&game_id));
         // Both players should have this game marked active
-        assert_eq!(crate::ActiveGameOf::<Test>::get(&a), Some(game_id));
-        assert_eq!(crate::ActiveGameOf::<Test>::get(&b), Some(game_id));
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&a, crate::GameContext::Ranked), Some(game_id));
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&b, crate::GameContext::Ranked), Some(game_id));
 
         // Last event should be GameCreated { game_id }
         let ev = frame_system::Pallet::<Test>::events()
@@ -2263,8 +2530,13 @@ fn create_from_matchmaking_creates_game_and_emits_event() {
             .map(|r| r.event.clone())
             .expect("some event expected");
         match ev {
-            RuntimeEvent::Eterra(crate::Event::GameCreated { game_id: gid }) => {
-                assert_eq!(gid, game_id)
+            RuntimeEvent::Eterra(crate::Event::Game {
+                game_id: gid,
+                event: crate::GameEvent::Created { context, .. },
+                ..
+            }) => {
+                assert_eq!(gid, game_id);
+                assert_eq!(context, crate::GameContext::Ranked);
             }
             other => panic!("unexpected event: {:?}", other),
         }
@@ -2291,7 +2563,2461 @@ fn create_from_matchmaking_requires_preset_hands() {
         );
 
         // No game should exist and no ActiveGameOf should be set
-        assert_eq!(crate::ActiveGameOf::<Test>::get(&a), None);
-        assert_eq!(crate::ActiveGameOf::<Test>::get(&b), None);
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&a, crate::GameContext::Ranked), None);
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&b, crate::GameContext::Ranked), None);
+    });
+}
+
+#[test]
+fn matchmaking_snapshots_the_hand_bound_at_queue_time() {
+    new_test_ext().execute_with(|| {
+        type P = crate::Pallet<Test>;
+        let (a, b) = (1u64, 2u64);
+        let bound = ensure_preset_hand(a);
+        ensure_preset_hand(b);
+        P::bind_queued_hand(&a);
+
+        // Editing the current hand while queued does not change the matched hand.
+        let edited = ensure_preset_hand(a);
+        assert_ne!(bound, edited);
+        let game_id = <P as GameCreator<u64>>::create_from_matchmaking(&a, &b).unwrap();
+
+        let snapshot: Vec<u32> = crate::HandsOfGame::<Test>::get(&game_id, &a)
+            .expect("bound hand is snapshotted at creation")
+            .iter()
+            .filter_map(|e| e.card_id)
+            .collect();
+        assert_eq!(snapshot, bound);
+        // Players without a binding still submit their hand themselves.
+        assert!(crate::HandsOfGame::<Test>::get(&game_id, &b).is_none());
+
+        P::release_queued_hand(&a);
+        assert!(crate::QueuedHandOf::<Test>::get(&a).is_none());
+    });
+}
+
+#[test]
+fn only_ranked_results_move_elo_ratings() {
+    new_test_ext().execute_with(|| {
+        type P = crate::Pallet<Test>;
+        let (a, b) = (1u64, 2u64);
+        ensure_preset_hand(a);
+        ensure_preset_hand(b);
+        let game_id = <P as GameCreator<u64>>::create_from_matchmaking(&a, &b).unwrap();
+        assert_eq!(Eterra::rating_of(&a), eterra_core_types::rating::INITIAL_RATING);
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(b).into(), game_id));
+        assert_eq!((Eterra::rating_of(&a), Eterra::rating_of(&b)), (1216, 1184));
+        assert!(System::events().iter().any(|r| r.event
+            == RuntimeEvent::Eterra(crate::Event::RatingsUpdated {
+                game_id,
+                ratings: [(a, 1216), (b, 1184)],
+            })));
+
+        // Casual games leave ratings alone.
+        let (casual, creator, opponent) = setup_new_game_with(3, 4);
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(opponent).into(), casual));
+        assert_eq!(crate::PlayerRating::<Test>::get(creator), None);
+    });
+}
+
+#[test]
+fn idle_ratings_decay_from_on_idle_down_to_the_floor() {
+    new_test_ext().execute_with(|| {
+        type P = crate::Pallet<Test>;
+        let (a, b) = (1u64, 2u64);
+        ensure_preset_hand(a);
+        ensure_preset_hand(b);
+        let game_id = <P as GameCreator<u64>>::create_from_matchmaking(&a, &b).unwrap();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(b).into(), game_id));
+        assert_eq!((Eterra::rating_of(&a), Eterra::rating_of(&b)), (1216, 1184));
+
+        // Not a full period idle yet.
+        run_to_block(System::block_number() + 999);
+        Eterra::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!((Eterra::rating_of(&a), Eterra::rating_of(&b)), (1216, 1184));
+
+        run_to_block(System::block_number() + 1);
+        Eterra::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!((Eterra::rating_of(&a), Eterra::rating_of(&b)), (1206, 1174));
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::RatingDecayed {
+            who: a,
+            rating: 1206,
+            periods: 1,
+        }));
+
+        // Twenty idle periods later both stop at `RatingDecayFloor`.
+        run_to_block(System::block_number() + 20_000);
+        Eterra::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!((Eterra::rating_of(&a), Eterra::rating_of(&b)), (1100, 1100));
+        assert!(crate::DecayCursor::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn open_bot_can_be_challenged_and_controller_answers_moves() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 1;
+        let bot: u64 = 30;
+        let controller: u64 = 31;
+        ensure_preset_hand(human);
+        ensure_preset_hand(bot);
+        assert_ok!(Eterra::register_open_bot(
+            RawOrigin::Signed(bot).into(),
+            40,
+            Some(controller),
+        ));
+        assert_eq!(Eterra::open_bot(bot).map(|p| p.difficulty), Some(40));
+
+        let block = System::block_number();
+        let game_id = BlakeTwo256::hash_of(&(human, bot, block));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![bot],
+            pallet::GameMode::Bot,
+        ));
+        // Bot hand is snapshotted at creation; the bot is not marked busy.
+        assert!(HandsOfGame::<Test>::get(&game_id, &bot).is_some());
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&bot, crate::GameContext::Casual), None);
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&human, crate::GameContext::Casual), Some(game_id));
+
+        // Human starts.
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(human).into(),
+            game_id,
+            vec![]
+        ));
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(human).into(),
+            game_id,
+            0,
+            0,
+            0
+        ));
+
+        // A stranger may not act for the bot; the controller may.
+        assert_noop!(
+            Eterra::bot_play_from_hand(RawOrigin::Signed(2).into(), game_id, bot, 0, 1, 1),
+            crate::Error::<Test>::NotBotController
+        );
+        assert_ok!(Eterra::bot_play_from_hand(
+            RawOrigin::Signed(controller).into(),
+            game_id,
+            bot,
+            0,
+            1,
+            1
+        ));
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert!(game.board[1][1].is_some());
+        assert_eq!(game.players[game.player_turn as usize], human);
+    });
+}
+
+#[test]
+fn bot_game_requires_registered_bot() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 1;
+        ensure_preset_hand(human);
+        ensure_preset_hand(2);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(human).into(),
+                vec![human, 2],
+                pallet::GameMode::Bot,
+            ),
+            crate::Error::<Test>::NotAnOpenBot
+        );
+        assert_noop!(
+            Eterra::register_open_bot(RawOrigin::Signed(2).into(), 101, None),
+            crate::Error::<Test>::InvalidDifficulty
+        );
+        assert_ok!(Eterra::register_open_bot(RawOrigin::Signed(2).into(), 50, None));
+        assert_ok!(Eterra::unregister_open_bot(RawOrigin::Signed(2).into()));
+        assert_eq!(Eterra::open_bot(2), None);
+    });
+}
+
+#[test]
+fn pve_uses_explicitly_selected_ai_identity_and_its_card_pool() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 1;
+        let custom_ai: u64 = 500;
+        ensure_preset_hand(human);
+        assert_noop!(
+            Eterra::register_ai(
+                RawOrigin::Signed(human).into(),
+                custom_ai,
+                80,
+                0,
+                b"Sage".to_vec(),
+                vec![[9, 9, 9, 9]],
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Eterra::register_ai(
+            RawOrigin::Root.into(),
+            custom_ai,
+            80,
+            0,
+            b"Sage".to_vec(),
+            vec![[9, 9, 9, 9]],
+        ));
+
+        let block = System::block_number();
+        let game_id = BlakeTwo256::hash_of(&(human, custom_ai, block));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human, custom_ai],
+            pallet::GameMode::PvE,
+        ));
+        let game = GameStorage::<Test>::get(&game_id).expect("game with selected AI");
+        assert_eq!(game.players[1], custom_ai);
+        let hand = HandsOfGame::<Test>::get(&game_id, &custom_ai).expect("AI hand");
+        assert!(hand.iter().all(|h| h.north == 9 && h.west == 9));
+    });
+}
+
+#[test]
+fn pve_auto_selects_ai_by_level_and_fails_without_registry() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 1;
+        ensure_preset_hand(human);
+        // Level-gated AI is skipped for a level-0 player; the genesis AI is chosen.
+        assert_ok!(Eterra::register_ai(
+            RawOrigin::Root.into(),
+            501,
+            90,
+            10,
+            vec![],
+            vec![],
+        ));
+        let block = System::block_number();
+        let game_id = BlakeTwo256::hash_of(&(human, AI_ACCOUNT, block));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        assert!(GameStorage::<Test>::contains_key(&game_id));
+
+        // With no eligible AI left, PvE creation fails.
+        assert_ok!(Eterra::remove_ai(RawOrigin::Root.into(), AI_ACCOUNT));
+        ensure_preset_hand(3);
+        assert_noop!(
+            Eterra::create_game(RawOrigin::Signed(3).into(), vec![3], pallet::GameMode::PvE),
+            crate::Error::<Test>::NoAiAvailable
+        );
     });
 }
+
+#[test]
+fn removed_ai_keeps_playing_its_games_in_progress() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 1;
+        ensure_preset_hand(human);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        let game_id = Eterra::player_games(&human, None).0[0];
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), game_id, vec![]));
+
+        assert_ok!(Eterra::remove_ai(RawOrigin::Root.into(), AI_ACCOUNT));
+        assert!(Eterra::ai_profile(AI_ACCOUNT).is_some());
+        assert_noop!(
+            Eterra::remove_ai(RawOrigin::Root.into(), AI_ACCOUNT),
+            crate::Error::<Test>::NotAnAi
+        );
+
+        // The retired AI still answers the human's move.
+        assert_ok!(Eterra::play_from_hand(RawOrigin::Signed(human).into(), game_id, 0, 0, 0));
+        let history = crate::MoveHistory::<Test>::get(game_id);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].seat, 1);
+        assert_eq!(
+            GameStorage::<Test>::get(&game_id).unwrap().turn,
+            crate::TurnState::AwaitingMove(0)
+        );
+
+        // It is not offered for new games, even when listed explicitly.
+        ensure_preset_hand(3);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(3).into(),
+                vec![3, AI_ACCOUNT],
+                pallet::GameMode::PvE
+            ),
+            crate::Error::<Test>::NoAiAvailable
+        );
+    });
+}
+
+#[test]
+fn ranked_game_does_not_block_casual_or_pve_games() {
+    new_test_ext().execute_with(|| {
+        type P = crate::Pallet<Test>;
+        let a: u64 = 1;
+        let b: u64 = 2;
+        ensure_preset_hand(a);
+        ensure_preset_hand(b);
+
+        let ranked = <P as GameCreator<u64>>::create_from_matchmaking(&a, &b).expect("ranked");
+        assert_eq!(
+            crate::GameContexts::<Test>::get(&ranked),
+            Some(crate::GameContext::Ranked)
+        );
+        assert!(!<P as GameCreator<u64>>::can_queue(&a));
+
+        // Same players can still start a casual PvP game and a PvE game.
+        System::set_block_number(2);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(a).into(),
+            vec![a, b],
+            pallet::GameMode::PvP,
+        ));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(a).into(),
+            vec![a],
+            pallet::GameMode::PvE,
+        ));
+        // But a second casual game is rejected.
+        System::set_block_number(3);
+        assert_noop!(
+            Eterra::create_game(RawOrigin::Signed(a).into(), vec![a, b], pallet::GameMode::PvP),
+            crate::Error::<Test>::PlayerAlreadyInGame
+        );
+    });
+}
+
+#[test]
+fn walkover_needs_a_missed_hand_deadline() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let deadline = Eterra::hand_deadline(game_id).expect("PvP games get a deadline");
+        assert_eq!(deadline, System::block_number() + 20);
+        assert_eq!(Eterra::game_view(&game_id).unwrap().hand_deadline, Some(deadline));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]));
+
+        assert_noop!(
+            Eterra::claim_walkover(RawOrigin::Signed(creator).into(), game_id),
+            crate::Error::<Test>::HandDeadlineNotPassed
+        );
+        run_to_block(deadline + 1);
+        // The player who missed the deadline cannot claim it.
+        assert_noop!(
+            Eterra::claim_walkover(RawOrigin::Signed(opponent).into(), game_id),
+            crate::Error::<Test>::WalkoverNotAvailable
+        );
+        assert_ok!(Eterra::claim_walkover(RawOrigin::Signed(creator).into(), game_id));
+
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.state, crate::GameState::Finished { winner: Some(0) });
+        assert_eq!(Eterra::hand_deadline(game_id), None);
+        assert!(crate::ActiveGameOf::<Test>::iter_prefix(opponent).next().is_none());
+    });
+}
+
+fn actions_required() -> Vec<(u64, Option<H256>, crate::ActionKind, Option<u64>)> {
+    System::events()
+        .into_iter()
+        .filter_map(|r| match r.event {
+            RuntimeEvent::Eterra(crate::Event::ActionRequired {
+                account,
+                game_id,
+                kind,
+                deadline_block,
+            }) => Some((account, game_id, kind, deadline_block)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn owed_actions_are_reported_through_one_event() {
+    use crate::ActionKind::{HandNeeded, WagerPending, YourTurn};
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let now = System::block_number();
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        let (first, second) = if game.players[game.player_turn as usize] == creator {
+            (creator, opponent)
+        } else {
+            (opponent, creator)
+        };
+        let hand_deadline = Eterra::hand_deadline(game_id);
+        assert_eq!(
+            actions_required(),
+            vec![
+                (creator, Some(game_id), HandNeeded, hand_deadline),
+                (opponent, Some(game_id), HandNeeded, hand_deadline),
+                (first, Some(game_id), YourTurn, Some(now + 5)),
+            ]
+        );
+
+        System::reset_events();
+        run_to_block(now + 2);
+        ensure_my_turn(game_id, second, first);
+        assert_eq!(actions_required(), vec![(second, Some(game_id), YourTurn, Some(now + 7))]);
+
+        System::reset_events();
+        let card = mint_cards_for(creator, 1)[0];
+        assert_ok!(Eterra::offer_wager(RawOrigin::Signed(creator).into(), 9, card));
+        assert_eq!(actions_required(), vec![(9, None, WagerPending, None)]);
+    });
+}
+
+#[test]
+fn forfeit_reports_unused_cards_and_allows_pruning() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let creator_hand = Eterra::current_hand_of(&creator).unwrap();
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(opponent).into(), game_id, vec![]));
+
+        // Hands cannot be pruned while the game is running.
+        assert_noop!(
+            Eterra::prune_game_hands(RawOrigin::Signed(3).into(), game_id),
+            crate::Error::<Test>::GameNotFinished
+        );
+
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        if game.players[game.player_turn as usize] != creator {
+            assert_ok!(Eterra::play_from_hand(
+                RawOrigin::Signed(opponent).into(),
+                game_id,
+                0,
+                3,
+                3
+            ));
+        }
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            0,
+            0,
+            0
+        ));
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        // Creator sits in slot 0, so the opponent (slot 1) wins by forfeit.
+        assert_eq!(game.state, crate::GameState::Finished { winner: Some(1) });
+
+        let summary = System::events()
+            .into_iter()
+            .find_map(|r| match r.event {
+                RuntimeEvent::Eterra(crate::Event::GameSummary { reason, hands, .. }) => {
+                    Some((reason, hands))
+                }
+                _ => None,
+            })
+            .expect("summary emitted");
+        assert_eq!(summary.0, crate::EndReason::Forfeit);
+        let mine = summary.1.iter().find(|h| h.player == creator).unwrap();
+        assert_eq!(mine.used, vec![creator_hand[0]]);
+        assert_eq!(mine.unused.len(), 4);
+        assert_eq!(Eterra::card_usage(creator_hand[0]).played, 1);
+        assert_eq!(Eterra::card_usage(creator_hand[1]).unplayed, 1);
+
+        assert_ok!(Eterra::prune_game_hands(RawOrigin::Signed(3).into(), game_id));
+        assert!(HandsOfGame::<Test>::get(&game_id, &creator).is_none());
+        assert!(HandsOfGame::<Test>::get(&game_id, &opponent).is_none());
+    });
+}
+
+#[test]
+fn move_history_records_every_turn_until_pruned() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(opponent).into(), game_id, vec![]));
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        let first = game.player_turn;
+        let (mover, other) = if first == 0 { (creator, opponent) } else { (opponent, creator) };
+        let h = HandsOfGame::<Test>::get(&game_id, &mover).unwrap()[2].clone();
+
+        assert_ok!(Eterra::play_from_hand(RawOrigin::Signed(mover).into(), game_id, 2, 0, 0));
+        let free_form = Move {
+            place_index_x: 1,
+            place_index_y: 1,
+            place_card: Card::new(1, 2, 3, 4),
+        };
+        assert_ok!(Eterra::play(RawOrigin::Signed(other).into(), game_id, free_form));
+        let now = System::block_number();
+        assert_eq!(
+            Eterra::move_history(game_id).into_inner(),
+            vec![
+                crate::RecordedMove {
+                    seat: first,
+                    block: now,
+                    action: crate::RecordedAction::Place {
+                        hand_index: Some(2),
+                        card: [h.north, h.east, h.south, h.west],
+                        x: 0,
+                        y: 0,
+                    },
+                },
+                crate::RecordedMove {
+                    seat: 1 - first,
+                    block: now,
+                    action: crate::RecordedAction::Place {
+                        hand_index: None,
+                        card: [1, 2, 3, 4],
+                        x: 1,
+                        y: 1,
+                    },
+                },
+            ]
+        );
+
+        // Live games keep their history, and finished ones keep it for the retention window.
+        assert_noop!(
+            Eterra::prune_move_history(RawOrigin::Signed(3).into(), game_id),
+            crate::Error::<Test>::MoveHistoryRetained
+        );
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        run_to_block(now + 49);
+        assert_noop!(
+            Eterra::prune_move_history(RawOrigin::Signed(3).into(), game_id),
+            crate::Error::<Test>::MoveHistoryRetained
+        );
+        run_to_block(now + 50);
+        assert_ok!(Eterra::prune_move_history(RawOrigin::Signed(3).into(), game_id));
+        assert!(Eterra::move_history(game_id).is_empty());
+        assert!(crate::MoveHistoryExpiry::<Test>::get(game_id).is_none());
+    });
+}
+
+#[test]
+fn game_modes_can_be_played_on_smaller_or_larger_boards() {
+    new_test_ext().execute_with(|| {
+        use crate::GameMode;
+        assert_noop!(
+            Eterra::set_board_dim(RawOrigin::Signed(1).into(), GameMode::PvP, 3),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Eterra::set_board_dim(RawOrigin::Root.into(), GameMode::PvP, 6),
+            crate::Error::<Test>::InvalidBoardDim
+        );
+        assert_ok!(Eterra::set_board_dim(RawOrigin::Root.into(), GameMode::PvP, 3));
+        assert_eq!(Eterra::board_dim_of(GameMode::PvP), Some(3));
+        assert_eq!(Eterra::board_dim_of(GameMode::PvE), None);
+
+        let (game_id, ..) = setup_new_game();
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.board.dim(), 3);
+        let card = Card::new(1, 1, 1, 1);
+        let mover = game.players[game.player_turn as usize];
+        let off_board = Move { place_card: card.clone(), place_index_x: 3, place_index_y: 0 };
+        assert_noop!(
+            Eterra::play(RawOrigin::Signed(mover).into(), game_id, off_board),
+            crate::Error::<Test>::InvalidMove
+        );
+
+        // Nine cards fill the board, which ends the game before its last round.
+        for cell in game.board.coords() {
+            let game = GameStorage::<Test>::get(&game_id).unwrap();
+            assert_eq!(game.state, crate::GameState::Playing);
+            let who = game.players[game.player_turn as usize];
+            let m = Move {
+                place_card: card.clone(),
+                place_index_x: cell.x(),
+                place_index_y: cell.y(),
+            };
+            assert_ok!(Eterra::play(RawOrigin::Signed(who).into(), game_id, m));
+        }
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert!(matches!(game.state, crate::GameState::Finished { .. }));
+
+        // Back to the default board.
+        assert_ok!(Eterra::set_board_dim(RawOrigin::Root.into(), GameMode::PvP, 4));
+        assert_eq!(Eterra::board_dim_of(GameMode::PvP), None);
+    });
+}
+
+#[test]
+fn level_gap_gives_weaker_player_a_score_handicap() {
+    new_test_ext().execute_with(|| {
+        // Gap below the threshold: no handicap.
+        set_player_level(1, 2);
+        let (game_id, _, _) = setup_new_game();
+        assert_eq!(Eterra::game_handicap(game_id), None);
+        assert_eq!(GameStorage::<Test>::get(&game_id).unwrap().scores, (5, 5));
+
+        // Player 4 outlevels player 3 by the configured gap: player 3 (seat 0) starts ahead.
+        set_player_level(4, 3);
+        let (game_id, _, _) = setup_new_game_with(3, 4);
+        assert_eq!(
+            Eterra::game_handicap(game_id),
+            Some(crate::Handicap::ScoreBonus { player: 0 })
+        );
+        assert_eq!(GameStorage::<Test>::get(&game_id).unwrap().scores, (6, 5));
+    });
+}
+
+#[test]
+fn hand_budget_handicap_caps_only_the_stronger_player() {
+    new_test_ext().execute_with(|| {
+        HandicapHandBudget::set(&1);
+        set_player_level(1, 10);
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_eq!(
+            Eterra::game_handicap(game_id),
+            Some(crate::Handicap::HandBudget { player: 0, max_total: 1 })
+        );
+        assert_eq!(GameStorage::<Test>::get(&game_id).unwrap().scores, (5, 5));
+
+        assert_noop!(
+            Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]),
+            crate::Error::<Test>::HandOverBudget
+        );
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(opponent).into(), game_id, vec![]));
+    });
+}
+
+#[test]
+fn game_entropy_is_fixed_at_creation_and_unique_per_game() {
+    new_test_ext().execute_with(|| {
+        let (first, _, _) = setup_new_game();
+        let parent = System::parent_hash();
+        assert_eq!(
+            Eterra::game_entropy(first),
+            Some(BlakeTwo256::hash_of(&(parent, 0u64, first)))
+        );
+
+        let (second, _, _) = setup_new_game_with(3, 4);
+        let e1 = Eterra::game_entropy(first).unwrap();
+        let e2 = Eterra::game_entropy(second).unwrap();
+        assert_ne!(e1, e2);
+        assert_eq!(crate::GameCounter::<Test>::get(), 2);
+
+        // Seeds are reproducible from the stored entropy and separated by domain.
+        assert_eq!(
+            Eterra::game_seed(&first, b"ai_hand"),
+            BlakeTwo256::hash_of(&(e1, &b"ai_hand"[..]))
+        );
+        assert_ne!(
+            Eterra::game_seed(&first, b"ai_hand"),
+            Eterra::game_seed(&first, b"draft")
+        );
+    });
+}
+
+#[test]
+fn moves_refund_ai_weight_unless_the_ai_answers() {
+    new_test_ext().execute_with(|| {
+        // PvP: the AI never runs. Nothing to capture on an empty board, so the move is
+        // refunded down to the simple placement weight.
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(opponent).into(), game_id, vec![]));
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        let mover = game.players[game.player_turn as usize];
+        let other = if mover == creator { opponent } else { creator };
+        let post = Eterra::play_from_hand(RawOrigin::Signed(mover).into(), game_id, 0, 0, 0)
+            .expect("move succeeds");
+        assert_eq!(post.actual_weight, Some(crate::SIMPLE_PLACEMENT_WEIGHT));
+
+        // A rank-1 card flips nothing; a rank-9 card next to it captures and keeps the
+        // base weight.
+        let weak = Move {
+            place_index_x: 1,
+            place_index_y: 1,
+            place_card: Card::new(1, 1, 1, 1),
+        };
+        let post = Eterra::play(RawOrigin::Signed(other).into(), game_id, weak)
+            .expect("move succeeds");
+        assert_eq!(post.actual_weight, Some(crate::SIMPLE_PLACEMENT_WEIGHT));
+        let strong = Move {
+            place_index_x: 1,
+            place_index_y: 0,
+            place_card: Card::new(9, 9, 9, 9),
+        };
+        let post = Eterra::play(RawOrigin::Signed(mover).into(), game_id, strong)
+            .expect("move succeeds");
+        assert_eq!(post.actual_weight, Some(crate::MOVE_BASE_WEIGHT));
+
+        // PvE: the AI answers the human's move and the full weight is kept.
+        let human: u64 = 5;
+        ensure_preset_hand(human);
+        let block = System::block_number();
+        let pve_id = BlakeTwo256::hash_of(&(human, AI_ACCOUNT, block));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), pve_id, vec![]));
+        let post = Eterra::play_from_hand(RawOrigin::Signed(human).into(), pve_id, 0, 0, 0)
+            .expect("move succeeds");
+        assert_eq!(post.actual_weight, None);
+        let game = GameStorage::<Test>::get(&pve_id).unwrap();
+        assert_eq!(game.players[game.player_turn as usize], human);
+    });
+}
+
+#[test]
+fn deregister_forfeits_games_unlists_cards_and_clears_hand() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let hand = Eterra::current_hand_of(&creator).unwrap();
+        assert_ok!(cards::Pallet::<Test>::set_price(
+            RawOrigin::Signed(creator).into(),
+            hand[0],
+            100
+        ));
+        assert_ok!(cards::Pallet::<Test>::set_price(
+            RawOrigin::Signed(creator).into(),
+            hand[1],
+            100
+        ));
+
+        assert_ok!(Eterra::deregister(RawOrigin::Signed(creator).into(), true));
+
+        // Creator sits in slot 0, so the opponent wins the abandoned game.
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.state, crate::GameState::Finished { winner: Some(1) });
+        assert!(Eterra::current_hand_of(&creator).is_none());
+        assert_eq!(crate::ActiveGameOf::<Test>::iter_prefix(creator).count(), 0);
+        assert_eq!(crate::ActiveGameOf::<Test>::iter_prefix(opponent).count(), 0);
+        assert!(card_pallet::CardPrices::<Test>::get(hand[0]).is_none());
+        assert!(card_pallet::ListedByOwner::<Test>::get(creator).is_empty());
+        // Cards remain owned by the account.
+        assert_eq!(card_pallet::OwnedCards::<Test>::get(creator).len(), hand.len());
+
+        // No queue/profile hooks in the mock, so those report nothing removed.
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::Deregistered {
+            who: creator,
+            forfeited_games: 1,
+            unlisted_cards: 2,
+            left_queue: false,
+            profile_burned: false,
+        }));
+    });
+}
+
+#[test]
+fn deregister_clears_queued_and_featured_hands_and_stops_watching() {
+    new_test_ext().execute_with(|| {
+        let (game_id, _, _) = setup_new_game();
+        let who: u64 = 5;
+        ensure_preset_hand(who);
+        Eterra::bind_queued_hand(&who);
+        let cards = vec![[9, 1, 1, 1], [1, 9, 1, 1], [1, 1, 9, 1], [1, 1, 1, 9], [5, 5, 5, 5]];
+        assert_ok!(Eterra::publish_featured_hand(RawOrigin::Root.into(), cards));
+        assert_ok!(Eterra::use_featured_hand(RawOrigin::Signed(who).into(), Some(0)));
+        assert_ok!(Eterra::watch_game(RawOrigin::Signed(who).into(), game_id));
+
+        assert_ok!(Eterra::deregister(RawOrigin::Signed(who).into(), false));
+
+        assert!(!crate::QueuedHandOf::<Test>::contains_key(who));
+        assert!(Eterra::featured_hand_of(who).is_none());
+        assert!(Eterra::watching(who).is_none());
+        assert!(!crate::WatchersOf::<Test>::contains_key(game_id, who));
+        assert_eq!(Eterra::spectator_count(game_id).unwrap_or(0), 0);
+    });
+}
+
+#[test]
+fn capture_the_corners_rule_adds_bonus_when_deciding_the_winner() {
+    new_test_ext().execute_with(|| {
+        DefaultRules::set(&eterra_core_types::rules::Rules::CAPTURE_THE_CORNERS);
+        let (game_id, _, opponent) = setup_new_game();
+        assert_eq!(
+            Eterra::game_rules(game_id),
+            eterra_core_types::rules::Rules(eterra_core_types::rules::Rules::CAPTURE_THE_CORNERS)
+        );
+
+        let mut game = GameStorage::<Test>::get(&game_id).unwrap();
+        let corner = Some(Card::new(1, 1, 1, 1).with_possession(Possession::PlayerTwo));
+        for (x, y) in [(0, 0), (3, 0), (0, 3), (3, 3)] {
+            game.board[x][y] = corner.clone();
+        }
+        // Player one leads by one point, but player two holds every corner.
+        game.scores = (6, 5);
+        game.round = game.max_rounds;
+        GameStorage::<Test>::insert(&game_id, game.clone());
+        assert_eq!(Eterra::is_game_won(&game_id, &game), Some(Some(opponent)));
+
+        // Games created without the rule keep the raw scores.
+        crate::GameRules::<Test>::remove(&game_id);
+        assert_eq!(Eterra::is_game_won(&game_id, &game), Some(Some(1)));
+    });
+}
+
+#[test]
+fn combo_rule_chains_captures_through_flipped_cards() {
+    new_test_ext().execute_with(|| {
+        DefaultRules::set(&eterra_core_types::rules::Rules::COMBO);
+        let (game_id, creator, opponent) = setup_new_game();
+        ensure_my_turn(game_id, creator, opponent);
+
+        // Along the bottom row: (1, 3) falls to the new card, (2, 3) to (1, 3), while
+        // (3, 3) holds.
+        let mut game = GameStorage::<Test>::get(&game_id).unwrap();
+        let seat = game.player_turn;
+        let theirs = Possession::from_index(1 - seat);
+        game.board[1][3] = Some(Card::new(1, 5, 1, 1).with_possession(theirs));
+        game.board[2][3] = Some(Card::new(1, 1, 1, 3).with_possession(theirs));
+        game.board[3][3] = Some(Card::new(1, 1, 1, 9).with_possession(theirs));
+        GameStorage::<Test>::insert(&game_id, game.clone());
+
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            Move {
+                place_index_x: 0,
+                place_index_y: 3,
+                place_card: Card::new(1, 9, 1, 1),
+            },
+        ));
+        let after = GameStorage::<Test>::get(&game_id).unwrap();
+        let owner = |x: usize| after.board[x][3].as_ref().unwrap().possession;
+        assert_eq!(owner(2), Some(Possession::from_index(seat)));
+        assert_eq!(owner(3), Some(theirs));
+        let (mine, other) = if seat == 0 { after.scores } else { (after.scores.1, after.scores.0) };
+        let (mine_before, other_before) =
+            if seat == 0 { game.scores } else { (game.scores.1, game.scores.0) };
+        assert_eq!((mine, other), (mine_before + 2, other_before - 2));
+    });
+}
+
+#[test]
+fn mode_rules_pick_same_and_plus_captures_for_new_games() {
+    new_test_ext().execute_with(|| {
+        use crate::GameMode;
+        use eterra_core_types::rules::Rules;
+        let same_plus = Rules(Rules::SAME | Rules::PLUS);
+        assert_noop!(
+            Eterra::set_mode_rules(RawOrigin::Signed(1).into(), GameMode::PvP, Some(same_plus)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Eterra::set_mode_rules(RawOrigin::Root.into(), GameMode::PvP, Some(same_plus)));
+        assert_eq!(Eterra::rules_of(GameMode::PvP), Some(same_plus));
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_eq!(Eterra::game_rules(game_id), same_plus);
+        ensure_my_turn(game_id, creator, opponent);
+
+        // Neither neighbor is beaten, but both edges are equal to the new card's.
+        let mut game = GameStorage::<Test>::get(&game_id).unwrap();
+        let seat = game.player_turn;
+        let theirs = Possession::from_index(1 - seat);
+        game.board[1][0] = Some(Card::new(1, 1, 3, 1).with_possession(theirs));
+        game.board[2][1] = Some(Card::new(1, 1, 1, 4).with_possession(theirs));
+        GameStorage::<Test>::insert(&game_id, game);
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            Move {
+                place_index_x: 1,
+                place_index_y: 1,
+                place_card: Card::new(3, 4, 1, 1),
+            },
+        ));
+        let after = GameStorage::<Test>::get(&game_id).unwrap();
+        let mine = Some(Possession::from_index(seat));
+        assert_eq!(after.board[1][0].as_ref().unwrap().possession, mine);
+        assert_eq!(after.board[2][1].as_ref().unwrap().possession, mine);
+
+        // Back to `DefaultRules` for games created from now on.
+        assert_ok!(Eterra::set_mode_rules(RawOrigin::Root.into(), GameMode::PvP, None));
+        assert_eq!(Eterra::rules_of(GameMode::PvP), None);
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::ModeRulesSet {
+            mode: GameMode::PvP,
+            rules: None,
+        }));
+    });
+}
+
+#[test]
+fn pvp_opening_seat_is_drawn_from_the_game_seed() {
+    new_test_ext().execute_with(|| {
+        ensure_preset_hand(1);
+        ensure_preset_hand(2);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(1).into(),
+            vec![1, 2],
+            pallet::GameMode::PvP,
+        ));
+        let game_id = BlakeTwo256::hash_of(&(1u64, 2u64, System::block_number()));
+        let seat = Eterra::game_seed(&game_id, b"first_seat").as_ref()[0] % 2;
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.player_turn, seat);
+        assert_eq!(game.turn, crate::TurnState::AwaitingMove(seat));
+    });
+}
+
+#[test]
+fn elemental_cells_are_rolled_from_the_game_seed_and_boost_matching_cards() {
+    new_test_ext().execute_with(|| {
+        use eterra_core_types::element::Element;
+        use eterra_core_types::rules::Rules;
+        let elemental = Some(Rules(Rules::ELEMENTAL));
+        assert_ok!(Eterra::set_mode_rules(RawOrigin::Root.into(), crate::GameMode::PvP, elemental));
+        let (game_id, creator, opponent) = setup_new_game();
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        let seed = Eterra::game_seed(&game_id, b"elements");
+        assert_eq!(game.board, crate::Board::default().with_elements(seed.as_ref()));
+
+        // Hands carry the element of each card.
+        let cards = mint_cards_for(creator, 5);
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, cards.clone()));
+        let hand = crate::HandsOfGame::<Test>::get(game_id, creator).unwrap();
+        for (entry, id) in hand.iter().zip(cards) {
+            assert_eq!(entry.element, card_pallet::Cards::<Test>::get(id).unwrap().element);
+        }
+
+        // On a Fire cell, a Fire card's 5 beats the 5 facing it.
+        ensure_my_turn(game_id, creator, opponent);
+        let mut game = GameStorage::<Test>::get(&game_id).unwrap();
+        game.board = crate::Board::default().with_elements(&[1, 1, 1, 1, 1, 0]);
+        let theirs = Possession::from_index(1 - game.player_turn);
+        game.board[1][0] = Some(Card::new(1, 1, 5, 1).with_possession(theirs));
+        GameStorage::<Test>::insert(&game_id, game);
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            Move {
+                place_index_x: 1,
+                place_index_y: 1,
+                place_card: Card::new(5, 1, 1, 1).with_element(Some(Element::Fire)),
+            },
+        ));
+        let after = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_ne!(after.board[1][0].as_ref().unwrap().possession, Some(theirs));
+    });
+}
+
+#[test]
+fn v4_migration_gives_dealt_hand_entries_no_element() {
+    new_test_ext().execute_with(|| {
+        let (game_id, who) = (H256::repeat_byte(7), 3u64);
+        // (card_id, north, east, south, west, used) as stored by v3.
+        let old: Vec<(u32, u8, u8, u8, u8, bool)> = vec![(11, 1, 2, 3, 4, true)];
+        frame_support::storage::unhashed::put(
+            &crate::HandsOfGame::<Test>::hashed_key_for(game_id, who),
+            &old,
+        );
+        frame_support::traits::StorageVersion::new(3).put::<Eterra>();
+
+        Eterra::on_runtime_upgrade();
+
+        assert_eq!(frame_support::traits::StorageVersion::get::<Eterra>(), 7);
+        let hand = crate::HandsOfGame::<Test>::get(game_id, who).expect("decodes after migration");
+        assert_eq!((hand[0].card_id, hand[0].west, hand[0].used), (Some(11), 4, true));
+        assert_eq!(hand[0].element, None);
+    });
+}
+
+#[test]
+fn v5_migration_turns_the_current_hand_into_active_deck_zero() {
+    new_test_ext().execute_with(|| {
+        let who = 3u64;
+        let hand: BoundedVec<u32, crate::HandLimit> = BoundedVec::truncate_from(vec![1, 2, 3]);
+        crate::CurrentHandOf::<Test>::insert(who, hand.clone());
+        frame_support::traits::StorageVersion::new(4).put::<Eterra>();
+
+        Eterra::on_runtime_upgrade();
+
+        assert_eq!(frame_support::traits::StorageVersion::get::<Eterra>(), 7);
+        assert!(crate::CurrentHandOf::<Test>::get(who).is_none());
+        assert_eq!(Eterra::active_deck_of(who), Some(0));
+        assert_eq!(Eterra::current_hand_of(&who), Some(hand));
+    });
+}
+
+#[test]
+fn v6_migration_keeps_the_first_minted_card_apart_from_synthetic_ones() {
+    use eterra_core_types::element::Element;
+    new_test_ext().execute_with(|| {
+        let (game_id, owner, other) = (H256::repeat_byte(9), 1u64, 2u64);
+        assert_eq!(mint_cards_for(owner, 1), vec![0]);
+        // (card_id, north, east, south, west, used, element) as stored by v5.
+        let old: Vec<(u32, u8, u8, u8, u8, bool, Option<Element>)> =
+            vec![(0, 1, 2, 3, 4, true, None), (7, 1, 1, 1, 1, false, None)];
+        for who in [owner, other] {
+            frame_support::storage::unhashed::put(
+                &crate::HandsOfGame::<Test>::hashed_key_for(game_id, who),
+                &old,
+            );
+        }
+        frame_support::traits::StorageVersion::new(5).put::<Eterra>();
+
+        Eterra::on_runtime_upgrade();
+
+        assert_eq!(frame_support::traits::StorageVersion::get::<Eterra>(), 7);
+        let ids = |who| -> Vec<Option<u32>> {
+            let hand = crate::HandsOfGame::<Test>::get(game_id, who).unwrap();
+            hand.iter().map(|e| e.card_id).collect()
+        };
+        assert_eq!(ids(owner), vec![Some(0), Some(7)]);
+        assert_eq!(ids(other), vec![None, Some(7)]);
+    });
+}
+
+#[test]
+fn decks_are_named_presets_and_the_active_one_is_dealt() {
+    new_test_ext().execute_with(|| {
+        let who = 1u64;
+        let first = mint_cards_for(who, 5);
+        let second = mint_cards_for(who, 5);
+        assert_noop!(
+            Eterra::create_deck(RawOrigin::Signed(who).into(), vec![b'x'; 33], first.clone()),
+            crate::Error::<Test>::DeckNameTooLong
+        );
+        assert_ok!(Eterra::create_deck(
+            RawOrigin::Signed(who).into(),
+            b"aggro".to_vec(),
+            first.clone()
+        ));
+        assert_ok!(Eterra::create_deck(
+            RawOrigin::Signed(who).into(),
+            b"control".to_vec(),
+            second.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::DeckCreated {
+            who,
+            index: 1,
+        }));
+        // The first deck became active; the second only waits to be picked.
+        assert_eq!(Eterra::active_deck_of(who), Some(0));
+        assert_eq!(Eterra::current_hand_of(&who).unwrap().to_vec(), first);
+        assert_eq!(Eterra::deck_of(who, 1).unwrap().name.to_vec(), b"control".to_vec());
+
+        assert_ok!(Eterra::set_active_deck(RawOrigin::Signed(who).into(), 1));
+        assert_eq!(Eterra::current_hand_power(who), Some(Eterra::hand_power(&second)));
+        ensure_preset_hand(2);
+        let game_id = BlakeTwo256::hash_of(&(who, 2u64, System::block_number()));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(who).into(),
+            vec![who, 2],
+            pallet::GameMode::PvP,
+        ));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(who).into(), game_id, vec![]));
+        let dealt: Vec<u32> =
+            Eterra::game_hands(game_id, who).unwrap().iter().filter_map(|e| e.card_id).collect();
+        assert_eq!(dealt, second);
+
+        let mut swapped = second.clone();
+        swapped[0] = first[0];
+        assert_ok!(Eterra::update_deck(
+            RawOrigin::Signed(who).into(),
+            1,
+            Some(b"midrange".to_vec()),
+            Some(swapped.clone())
+        ));
+        assert_eq!(Eterra::deck_of(who, 1).unwrap().name.to_vec(), b"midrange".to_vec());
+        assert_eq!(Eterra::current_hand_of(&who).unwrap().to_vec(), swapped);
+
+        assert_ok!(Eterra::delete_deck(RawOrigin::Signed(who).into(), 1));
+        assert_eq!(Eterra::current_hand_of(&who), None);
+        assert_eq!(Eterra::current_hand_power(who), None);
+        assert_noop!(
+            Eterra::set_active_deck(RawOrigin::Signed(who).into(), 1),
+            crate::Error::<Test>::DeckNotFound
+        );
+
+        // Without an active deck, `set_current_hand` saves one at the lowest free index.
+        assert_ok!(Eterra::set_current_hand(RawOrigin::Signed(who).into(), second.clone()));
+        assert_eq!(Eterra::active_deck_of(who), Some(1));
+        assert!(Eterra::deck_of(who, 1).unwrap().name.is_empty());
+        assert_ok!(Eterra::create_deck(
+            RawOrigin::Signed(who).into(),
+            b"spare".to_vec(),
+            second.clone()
+        ));
+        assert_noop!(
+            Eterra::create_deck(RawOrigin::Signed(who).into(), vec![], second),
+            crate::Error::<Test>::TooManyDecks
+        );
+    });
+}
+
+#[test]
+fn closed_hand_rule_hides_opponent_card_stats() {
+    new_test_ext().execute_with(|| {
+        DefaultRules::set(&eterra_core_types::rules::Rules::CLOSED_HAND);
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::submit_hand(
+            frame_system::RawOrigin::Signed(creator).into(),
+            game_id,
+            mint_cards_for(creator, 5),
+        ));
+        ensure_my_turn(game_id, creator, opponent);
+        assert_ok!(Eterra::play_from_hand(
+            frame_system::RawOrigin::Signed(creator).into(),
+            game_id,
+            2,
+            3,
+            3,
+        ));
+
+        let summary = Eterra::opponent_hand_summary(&game_id, &opponent).unwrap();
+        assert_eq!(summary.size, 5);
+        assert_eq!(summary.remaining, 4);
+        assert_eq!(summary.used.to_vec(), vec![false, false, true, false, false]);
+        assert_eq!(summary.cards, None);
+
+        // Outsiders see nothing, and open-hand games reveal the stats.
+        assert_eq!(Eterra::opponent_hand_summary(&game_id, &99), None);
+        crate::GameRules::<Test>::remove(&game_id);
+        let open = Eterra::opponent_hand_summary(&game_id, &opponent).unwrap();
+        assert_eq!(open.cards, Eterra::game_hands(game_id, creator));
+    });
+}
+
+#[test]
+fn hand_events_let_indexers_rebuild_both_hands() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 21;
+        let ids = ensure_preset_hand(human);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        let game_id = Eterra::player_games(&human, None).0[0];
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        let ai_acc = *game.players.iter().find(|p| **p != human).unwrap();
+
+        let revealed = System::events()
+            .into_iter()
+            .find_map(|r| match r.event {
+                RuntimeEvent::Eterra(crate::Event::AiHandGenerated { player, entries, .. })
+                    if player == ai_acc =>
+                {
+                    Some(entries)
+                }
+                _ => None,
+            })
+            .expect("AI hand revealed");
+        let stored = HandsOfGame::<Test>::get(&game_id, &ai_acc).unwrap();
+        let expected: Vec<[u8; 4]> = stored
+            .iter()
+            .map(|e| [e.north, e.east, e.south, e.west])
+            .collect();
+        assert_eq!(revealed, expected);
+
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), game_id, vec![]));
+        assert!(System::events().iter().any(|r| r.event
+            == RuntimeEvent::Eterra(crate::Event::HandSnapshotted {
+                game_id,
+                player: human,
+                card_ids: ids.clone(),
+            })));
+    });
+}
+
+#[test]
+fn sealed_game_deals_distinct_owned_cards_to_both_players() {
+    new_test_ext().execute_with(|| {
+        let (a, b) = (31u64, 32u64);
+        let owned_a = mint_cards_for(a, 8);
+        let owned_b = mint_cards_for(b, 8);
+        // Neither player needs a current hand for sealed play.
+        assert!(Eterra::current_hand_of(&a).is_none());
+
+        assert_ok!(Eterra::create_sealed_game(
+            RawOrigin::Signed(a).into(),
+            vec![a, b],
+            pallet::GameMode::PvP,
+        ));
+        let game_id = Eterra::player_games(&a, None).0[0];
+        assert!(Eterra::is_sealed(game_id));
+
+        for (who, owned) in [(a, &owned_a), (b, &owned_b)] {
+            let hand = HandsOfGame::<Test>::get(&game_id, &who).expect("hand dealt");
+            let ids: Vec<u32> = hand.iter().filter_map(|e| e.card_id).collect();
+            assert_eq!(ids.len(), 5);
+            assert!(ids.iter().all(|id| owned.contains(id)));
+            let mut unique = ids.clone();
+            unique.sort();
+            unique.dedup();
+            assert_eq!(unique.len(), ids.len());
+            assert!(System::events().iter().any(|r| r.event
+                == RuntimeEvent::Eterra(crate::Event::HandDealt {
+                    game_id,
+                    player: who,
+                    card_ids: ids.clone(),
+                })));
+        }
+
+        // Dealt hands cannot be replaced by a submitted one.
+        assert_noop!(
+            Eterra::submit_hand(RawOrigin::Signed(a).into(), game_id, vec![]),
+            crate::Error::<Test>::HandAlreadySubmitted
+        );
+    });
+}
+
+#[test]
+fn sealed_game_requires_enough_owned_cards() {
+    new_test_ext().execute_with(|| {
+        let (a, b) = (33u64, 34u64);
+        mint_cards_for(a, 8);
+        mint_cards_for(b, 4);
+        assert_noop!(
+            Eterra::create_sealed_game(
+                RawOrigin::Signed(a).into(),
+                vec![a, b],
+                pallet::GameMode::PvP,
+            ),
+            crate::Error::<Test>::NotEnoughCardsToDeal
+        );
+    });
+}
+
+#[test]
+fn verify_replay_accepts_only_the_played_transcript() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(opponent).into(), game_id, vec![]));
+
+        let mut moves = Vec::new();
+        for (i, (x, y)) in [(0u8, 0u8), (1, 0), (0, 1), (1, 1)].into_iter().enumerate() {
+            let game = GameStorage::<Test>::get(&game_id).unwrap();
+            let who = game.players[game.player_turn as usize];
+            let hand_index = (i / 2) as u8;
+            assert_ok!(Eterra::play_from_hand(
+                RawOrigin::Signed(who).into(),
+                game_id,
+                hand_index,
+                x,
+                y
+            ));
+            moves.push(crate::ReplayMove::Place { hand_index, x, y });
+        }
+        assert!(Eterra::verify_replay(&game_id, &moves));
+
+        // A truncated transcript leaves the board different from storage.
+        assert!(!Eterra::verify_replay(&game_id, &moves[..3]));
+        // Reusing a card is illegal.
+        let mut reused = moves.clone();
+        reused[2] = crate::ReplayMove::Place { hand_index: 0, x: 0, y: 1 };
+        assert!(!Eterra::verify_replay(&game_id, &reused));
+        // Unknown games never verify.
+        assert!(!Eterra::verify_replay(&H256::repeat_byte(7), &moves));
+    });
+}
+
+#[test]
+fn daily_game_cap_blocks_new_games_until_the_next_day() {
+    new_test_ext().execute_with(|| {
+        MaxRatedGamesPerDay::set(&1);
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        assert_eq!(Eterra::rated_games_today(&creator), 1);
+        assert_eq!(Eterra::rated_games_today(&opponent), 1);
+
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(creator).into(),
+                vec![creator, opponent],
+                pallet::GameMode::PvP,
+            ),
+            crate::Error::<Test>::DailyGameLimitReached
+        );
+        // Tournament games are exempt from the cap.
+        assert_ok!(Eterra::do_create_pvp_game(
+            &opponent,
+            &creator,
+            crate::GameContext::Tournament
+        ));
+
+        // The counter resets once the day window rolls over.
+        System::set_block_number(14_400);
+        assert_eq!(Eterra::rated_games_today(&creator), 0);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(creator).into(),
+            vec![creator, opponent],
+            pallet::GameMode::PvP,
+        ));
+    });
+}
+
+#[test]
+fn legacy_games_decode_and_are_upgraded_by_migrate_games() {
+    new_test_ext().execute_with(|| {
+        let (game_id, _, _) = setup_new_game();
+        let stored = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(stored.version, crate::CURRENT_GAME_VERSION);
+
+        // Rewrite the game in the original layout, which ends right after the scores.
+        let mut legacy = stored.encode();
+        legacy.truncate(legacy.len() - 1 - stored.turn.encode().len());
+        frame_support::storage::unhashed::put_raw(
+            &GameStorage::<Test>::hashed_key_for(&game_id),
+            &legacy,
+        );
+        let decoded = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(decoded.version, 0);
+        assert_eq!(decoded.scores, stored.scores);
+        assert_eq!(decoded.players, stored.players);
+        assert_eq!(decoded.turn, stored.turn);
+
+        assert_noop!(
+            Eterra::migrate_games(RawOrigin::Signed(1).into(), 10),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Eterra::migrate_games(RawOrigin::Root.into(), 10));
+        assert_eq!(
+            GameStorage::<Test>::get(&game_id).unwrap().version,
+            crate::CURRENT_GAME_VERSION
+        );
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::GamesMigrated {
+            migrated: 1,
+            complete: true,
+        }));
+        assert!(crate::GameMigrationCursor::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn pallet_and_ai_adapter_resolve_moves_identically() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]));
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(opponent).into(), game_id, vec![]));
+
+        // Interleave placements so captures happen in several directions.
+        let cells = [(1u8, 1u8), (1, 2), (2, 1), (0, 1), (1, 0), (2, 2), (3, 1), (2, 0)];
+        for (i, (x, y)) in cells.into_iter().enumerate() {
+            let game = GameStorage::<Test>::get(&game_id).unwrap();
+            let state = Eterra::build_ai_state(&game_id, &game).unwrap();
+            let hand_index = (i / 2) as u8;
+            let predicted = ai::Adapter::apply_pure(&state, &ai::Action { hand_index, x, y });
+
+            let who = game.players[game.player_turn as usize];
+            assert_ok!(Eterra::play_from_hand(
+                RawOrigin::Signed(who).into(),
+                game_id,
+                hand_index,
+                x,
+                y
+            ));
+
+            let game = GameStorage::<Test>::get(&game_id).unwrap();
+            assert_eq!(game.board, predicted.board);
+            assert_eq!(game.scores, predicted.scores);
+        }
+    });
+}
+
+#[test]
+fn spectator_counts_are_bounded_and_cleared_at_game_end() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _) = setup_new_game();
+        assert_ok!(Eterra::watch_game(RawOrigin::Signed(10).into(), game_id));
+        // Repeat calls do not inflate the count
+        assert_ok!(Eterra::watch_game(RawOrigin::Signed(10).into(), game_id));
+        assert_ok!(Eterra::watch_game(RawOrigin::Signed(11).into(), game_id));
+        assert_eq!(Eterra::spectator_count(game_id), Some(2));
+
+        // MaxSpectators = 2 in the mock
+        assert_noop!(
+            Eterra::watch_game(RawOrigin::Signed(12).into(), game_id),
+            crate::Error::<Test>::SpectatorLimitReached
+        );
+
+        assert_ok!(Eterra::unwatch_game(RawOrigin::Signed(10).into(), game_id));
+        assert_eq!(Eterra::spectator_count(game_id), Some(1));
+        assert_noop!(
+            Eterra::unwatch_game(RawOrigin::Signed(10).into(), game_id),
+            crate::Error::<Test>::NotWatching
+        );
+
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        assert_eq!(Eterra::spectator_count(game_id), None);
+        assert_eq!(Eterra::watching(11), None);
+        assert_eq!(crate::WatchersOf::<Test>::iter_prefix(game_id).count(), 0);
+        assert_noop!(
+            Eterra::watch_game(RawOrigin::Signed(12).into(), game_id),
+            crate::Error::<Test>::GameNotPlaying
+        );
+    });
+}
+
+#[test]
+fn repair_active_game_clears_only_stale_markers() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _) = setup_new_game();
+        let context = crate::GameContexts::<Test>::get(game_id).unwrap();
+
+        // A live game the account plays in is left alone
+        assert_noop!(
+            Eterra::repair_active_game(RawOrigin::Signed(42).into(), creator),
+            crate::Error::<Test>::NothingToRepair
+        );
+
+        // Strand the creator on a game that no longer exists
+        let missing = H256::repeat_byte(9);
+        crate::ActiveGameOf::<Test>::insert(creator, context, missing);
+        assert_ok!(Eterra::repair_active_game(RawOrigin::Signed(42).into(), creator));
+        assert!(crate::ActiveGameOf::<Test>::get(creator, context).is_none());
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::ActiveGameRepaired {
+            who: creator,
+            context,
+            game_id: missing,
+        }));
+    });
+}
+
+#[test]
+fn reassign_game_seat_moves_hand_and_markers_to_the_new_account() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let context = crate::GameContexts::<Test>::get(game_id).unwrap();
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            mint_cards_for(creator, 5),
+        ));
+        let timing = crate::MoveTiming {
+            moves: 2,
+            total_blocks: 7,
+            ..Default::default()
+        };
+        crate::GameMoveTimings::<Test>::insert(game_id, creator, timing.clone());
+        let recovered: u64 = 77;
+
+        assert_noop!(
+            Eterra::reassign_game_seat(
+                RawOrigin::Signed(creator).into(),
+                game_id,
+                creator,
+                recovered
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Eterra::reassign_game_seat(RawOrigin::Root.into(), game_id, creator, opponent),
+            crate::Error::<Test>::PlayerAlreadyInGame
+        );
+        assert_ok!(Eterra::reassign_game_seat(
+            RawOrigin::Root.into(),
+            game_id,
+            creator,
+            recovered
+        ));
+
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.players.to_vec(), vec![recovered, opponent]);
+        assert!(Eterra::game_hands(game_id, creator).is_none());
+        assert!(Eterra::game_hands(game_id, recovered).is_some());
+        assert!(!crate::GameMoveTimings::<Test>::contains_key(game_id, creator));
+        assert_eq!(crate::GameMoveTimings::<Test>::get(game_id, recovered), timing);
+        assert_eq!(crate::ActiveGameOf::<Test>::get(creator, context), None);
+        assert_eq!(crate::ActiveGameOf::<Test>::get(recovered, context), Some(game_id));
+        assert!(!Eterra::player_games(&creator, None).0.contains(&game_id));
+        assert_eq!(Eterra::player_games(&recovered, None).0.first(), Some(&game_id));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::GameSeatReassigned {
+            game_id,
+            seat: 0,
+            old: creator,
+            new: recovered,
+        }));
+    });
+}
+
+#[test]
+fn void_game_clears_a_live_game_without_a_result() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let context = crate::GameContexts::<Test>::get(game_id).unwrap();
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            mint_cards_for(creator, 5),
+        ));
+        let live = Eterra::live_games(crate::GameMode::PvP);
+        let scores = GameStorage::<Test>::get(&game_id).unwrap().scores;
+
+        assert_noop!(
+            Eterra::void_game(
+                RawOrigin::Signed(creator).into(),
+                game_id,
+                crate::VoidReason::Exploit
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Eterra::void_game(
+            RawOrigin::Root.into(),
+            game_id,
+            crate::VoidReason::Exploit
+        ));
+
+        assert!(GameStorage::<Test>::get(&game_id).is_none());
+        assert!(Eterra::game_hands(game_id, creator).is_none());
+        assert!(crate::GameContexts::<Test>::get(game_id).is_none());
+        assert!(crate::GameStarts::<Test>::get(game_id).is_none());
+        assert_eq!(crate::ActiveGameOf::<Test>::get(creator, context), None);
+        assert_eq!(crate::ActiveGameOf::<Test>::get(opponent, context), None);
+        assert!(!Eterra::player_games(&creator, None).0.contains(&game_id));
+        assert_eq!(Eterra::live_games(crate::GameMode::PvP), live - 1);
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::GameVoided {
+            game_id,
+            reason: crate::VoidReason::Exploit,
+            players: vec![creator, opponent],
+            context: Some(context),
+            round: 0,
+            scores,
+        }));
+
+        assert_noop!(
+            Eterra::void_game(RawOrigin::Root.into(), game_id, crate::VoidReason::Exploit),
+            crate::Error::<Test>::GameNotFound
+        );
+    });
+}
+
+#[test]
+fn abandoned_games_are_expired_from_on_idle() {
+    use frame_support::weights::Weight;
+    new_test_ext().execute_with(|| {
+        let (stalled, creator, opponent) = setup_new_game();
+        let (unstarted, a, b) = setup_new_game_with(3, 4);
+        // Nobody submitted a hand to the second game before it stalled: it cannot have a winner.
+        GameStorage::<Test>::mutate(&unstarted, |g| {
+            g.as_mut().unwrap().turn = crate::TurnState::AwaitingHand;
+        });
+        let _ = HandsOfGame::<Test>::clear_prefix(unstarted, u32::MAX, None);
+        let game = GameStorage::<Test>::get(&stalled).unwrap();
+        let to_move = game.players[game.player_turn as usize];
+        let waiting = if to_move == creator { opponent } else { creator };
+        let waiting_seat = if waiting == game.players[0] { 0 } else { 1 };
+        let start = game.last_played_block;
+        // Only a player who took part can win on time.
+        let cards = mint_cards_for(waiting, 5);
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(waiting).into(), stalled, cards));
+
+        run_to_block(start + 99);
+        Eterra::on_idle(System::block_number(), Weight::MAX);
+        assert!(matches!(
+            GameStorage::<Test>::get(&stalled).unwrap().state,
+            crate::GameState::Playing
+        ));
+        assert!(GameStorage::<Test>::get(&unstarted).is_some());
+
+        run_to_block(start + 100);
+        assert!(crate::LiveGameIds::<Test>::contains_key(stalled));
+        // Without room for a single expiry, nothing is touched.
+        assert_eq!(Eterra::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+        assert!(GameStorage::<Test>::get(&unstarted).is_some());
+
+        Eterra::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(
+            GameStorage::<Test>::get(&stalled).unwrap().state,
+            crate::GameState::Finished { winner: Some(waiting_seat) }
+        );
+        assert!(crate::ActiveGameOf::<Test>::iter_prefix(to_move).next().is_none());
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::GameExpired {
+            game_id: stalled,
+            winner: Some(waiting),
+        }));
+
+        assert!(GameStorage::<Test>::get(&unstarted).is_none());
+        assert!(crate::ActiveGameOf::<Test>::iter_prefix(a).next().is_none());
+        assert!(crate::ActiveGameOf::<Test>::iter_prefix(b).next().is_none());
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::GameExpired {
+            game_id: unstarted,
+            winner: None,
+        }));
+        assert!(crate::ExpiryCursor::<Test>::get().is_none());
+        // Both games left the index the scan walks; the finished one stays in storage.
+        assert!(crate::LiveGameIds::<Test>::iter_keys().next().is_none());
+    });
+}
+
+#[test]
+fn expired_pvp_games_nobody_played_are_voided() {
+    use frame_support::weights::Weight;
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert!(matches!(
+            GameStorage::<Test>::get(&game_id).unwrap().turn,
+            crate::TurnState::AwaitingMove(_)
+        ));
+        let start = GameStorage::<Test>::get(&game_id).unwrap().last_played_block;
+
+        run_to_block(start + 100);
+        Eterra::on_idle(System::block_number(), Weight::MAX);
+
+        // Neither seat submitted a hand or moved: no winner, and ratings stay untouched.
+        assert!(GameStorage::<Test>::get(&game_id).is_none());
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::GameExpired {
+            game_id,
+            winner: None,
+        }));
+        assert_eq!(Eterra::rating_of(&creator), Eterra::rating_of(&opponent));
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::Eterra(crate::Event::GameVoided {
+                reason: crate::VoidReason::Abandoned,
+                ..
+            })
+        )));
+    });
+}
+
+#[test]
+fn player_history_pages_back_from_the_newest_game() {
+    new_test_ext().execute_with(|| {
+        let who: u64 = 5;
+        let ids: Vec<H256> = (0..(crate::GAMES_PER_PAGE + 3) as u8)
+            .map(H256::repeat_byte)
+            .collect();
+        for id in ids.iter() {
+            Eterra::append_player_game(&who, *id);
+        }
+        assert_eq!(Eterra::player_game_count(who), crate::GAMES_PER_PAGE + 3);
+
+        let (newest, cursor) = Eterra::player_games(&who, None);
+        assert_eq!(newest, ids[20..].iter().rev().copied().collect::<Vec<_>>());
+        assert_eq!(cursor, Some(0));
+        let (oldest, cursor) = Eterra::player_games(&who, Some(0));
+        assert_eq!(oldest, ids[..20].iter().rev().copied().collect::<Vec<_>>());
+        assert_eq!(cursor, None);
+
+        // Removing a live game leaves the page short without moving later entries
+        Eterra::remove_player_game(&who, &ids[3]);
+        assert_eq!(Eterra::player_games(&who, Some(0)).0.len(), 19);
+        assert_eq!(Eterra::player_games(&who, None).0.len(), 3);
+    });
+}
+
+#[test]
+fn v1_migration_rebuilds_markers_of_live_games() {
+    use frame_support::storage::{unhashed, StoragePrefixedMap};
+    use frame_support::{Blake2_128Concat, StorageHasher};
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let context = crate::GameContexts::<Test>::get(game_id).unwrap();
+        let _ = crate::ActiveGameOf::<Test>::clear(u32::MAX, None);
+        crate::LiveGameIds::<Test>::remove(game_id);
+        // A v0 marker, keyed by the account alone.
+        let mut legacy =
+            <crate::ActiveGameOf<Test> as StoragePrefixedMap<H256>>::final_prefix().to_vec();
+        legacy.extend(Blake2_128Concat::hash(&creator.encode()));
+        unhashed::put(&legacy, &game_id);
+        frame_support::traits::StorageVersion::new(0).put::<Eterra>();
+
+        Eterra::on_runtime_upgrade();
+        // The rebuild is left to `migrate_games`.
+        assert!(crate::ActiveGameOf::<Test>::get(creator, context).is_none());
+
+        assert_ok!(Eterra::migrate_games(RawOrigin::Root.into(), 10));
+        assert_eq!(crate::ActiveGameOf::<Test>::get(creator, context), Some(game_id));
+        assert_eq!(crate::ActiveGameOf::<Test>::get(opponent, context), Some(game_id));
+        assert!(unhashed::get_raw(&legacy).is_none());
+        assert!(crate::GameReindex::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn v2_migration_counts_games_stored_before_the_upgrade_once() {
+    new_test_ext().execute_with(|| {
+        let (old, _, _) = setup_new_game();
+        let (ended, _, _) = setup_new_game_with(3, 4);
+        crate::LiveGames::<Test>::remove(pallet::GameMode::PvP);
+        crate::LiveGameIds::<Test>::remove(old);
+        crate::LiveGameIds::<Test>::remove(ended);
+        frame_support::traits::StorageVersion::new(1).put::<Eterra>();
+        Eterra::on_runtime_upgrade();
+
+        // Games created or ended before `migrate_games` reaches the old ones are counted
+        // as they happen; an old game ending early is never counted.
+        let (new, _, _) = setup_new_game_with(5, 6);
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(3).into(), ended));
+        assert_eq!(Eterra::live_games(pallet::GameMode::PvP), 1);
+
+        assert_ok!(Eterra::migrate_games(RawOrigin::Root.into(), 10));
+        assert_eq!(Eterra::live_games(pallet::GameMode::PvP), 2);
+        assert!(crate::LiveGameIds::<Test>::contains_key(old));
+        assert!(crate::LiveGameIds::<Test>::contains_key(new));
+        assert!(!crate::LiveGameIds::<Test>::contains_key(ended));
+
+        // A second pass finds nothing left to count.
+        assert_ok!(Eterra::migrate_games(RawOrigin::Root.into(), 10));
+        assert_eq!(Eterra::live_games(pallet::GameMode::PvP), 2);
+    });
+}
+
+#[test]
+fn v7_migration_indexes_live_games() {
+    new_test_ext().execute_with(|| {
+        let (game_id, _, _) = setup_new_game();
+        crate::LiveGameIds::<Test>::remove(game_id);
+        frame_support::traits::StorageVersion::new(6).put::<Eterra>();
+
+        Eterra::on_runtime_upgrade();
+        assert_eq!(frame_support::traits::StorageVersion::get::<Eterra>(), 7);
+        assert!(!crate::LiveGameIds::<Test>::contains_key(game_id));
+
+        // Indexing walks the stored games in `migrate_games` batches.
+        assert_ok!(Eterra::migrate_games(RawOrigin::Root.into(), 1));
+        assert!(crate::LiveGameIds::<Test>::contains_key(game_id));
+        assert!(crate::GameReindex::<Test>::get().is_some());
+        assert_ok!(Eterra::migrate_games(RawOrigin::Root.into(), 1));
+        assert!(crate::GameReindex::<Test>::get().is_none());
+        assert_eq!(Eterra::live_games(pallet::GameMode::PvP), 1);
+    });
+}
+
+#[test]
+fn v3_migration_turns_recent_games_into_the_first_page() {
+    new_test_ext().execute_with(|| {
+        let who: u64 = 5;
+        let recent: Vec<H256> = (1..=3).rev().map(H256::repeat_byte).collect();
+        crate::PlayerGames::<Test>::insert(who, BoundedVec::truncate_from(recent.clone()));
+        frame_support::traits::StorageVersion::new(2).put::<Eterra>();
+
+        Eterra::on_runtime_upgrade();
+
+        assert_eq!(frame_support::traits::StorageVersion::get::<Eterra>(), 7);
+        assert_eq!(Eterra::player_games(&who, None), (recent, None));
+        Eterra::append_player_game(&who, H256::repeat_byte(4));
+        assert_eq!(Eterra::player_games(&who, None).0[0], H256::repeat_byte(4));
+        assert_eq!(Eterra::player_game_count(who), 4);
+    });
+}
+
+#[test]
+fn ai_iteration_budget_grows_as_the_board_fills() {
+    new_test_ext().execute_with(|| {
+        let full_budget =
+            pallet_eterra_monte_carlo_ai::pallet::Pallet::<Test>::scaled_iterations::<Test>(60);
+        let mut board: crate::Board = Default::default();
+
+        let opening = Eterra::ai_iteration_budget(60, &board);
+        assert_eq!(opening, full_budget * crate::AI_ITERATION_CURVE[16] as u32 / 100);
+
+        let mut last = opening;
+        for (i, cell) in board.coords().enumerate().take(15) {
+            let (x, y) = cell.index();
+            board[x][y] = Some(Card::new(1, 1, 1, 1));
+            let budget = Eterra::ai_iteration_budget(60, &board);
+            assert!(budget >= last, "budget shrank after {} placements", i + 1);
+            assert!(budget <= full_budget);
+            last = budget;
+        }
+        // Late moves get the whole difficulty-scaled budget
+        assert_eq!(last, full_budget);
+    });
+}
+
+#[test]
+fn ai_turns_are_played_in_on_initialize_up_to_the_block_cap() {
+    new_test_ext().execute_with(|| {
+        MaxAiMovesPerBlock::set(&1);
+        let block = System::block_number();
+        let mut games = Vec::new();
+        for human in [5u64, 6] {
+            ensure_preset_hand(human);
+            let id = BlakeTwo256::hash_of(&(human, AI_ACCOUNT, block));
+            assert_ok!(Eterra::create_game(
+                RawOrigin::Signed(human).into(),
+                vec![human],
+                pallet::GameMode::PvE,
+            ));
+            assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), id, vec![]));
+            games.push((human, id));
+        }
+
+        System::set_block_number(block + 1);
+        for (human, id) in games.iter() {
+            let game = GameStorage::<Test>::get(id).unwrap();
+            assert_eq!(game.players[game.player_turn as usize], *human);
+            assert_ok!(Eterra::play_from_hand(RawOrigin::Signed(*human).into(), *id, 0, 0, 0));
+        }
+
+        // Neither AI answered inside the human's call; both wait in the queue.
+        let (first, second) = (games[0], games[1]);
+        let to_move = |id: &H256| {
+            let game = GameStorage::<Test>::get(id).unwrap();
+            game.players[game.player_turn as usize]
+        };
+        assert_eq!((to_move(&first.1), to_move(&second.1)), (AI_ACCOUNT, AI_ACCOUNT));
+        assert_eq!(Eterra::pending_ai_turns().into_inner(), vec![first.1, second.1]);
+        assert_eq!(Eterra::peak_ai_backlog(), 2);
+
+        // One AI move per block, oldest first.
+        System::set_block_number(block + 2);
+        Eterra::on_initialize(block + 2);
+        assert_eq!((to_move(&first.1), to_move(&second.1)), (first.0, AI_ACCOUNT));
+        assert_eq!(Eterra::pending_ai_turns().into_inner(), vec![second.1]);
+
+        System::set_block_number(block + 3);
+        Eterra::on_initialize(block + 3);
+        assert_eq!(to_move(&second.1), second.0);
+        assert!(Eterra::pending_ai_turns().is_empty());
+    });
+}
+
+#[test]
+fn finished_games_report_their_result() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(opponent).into(), game_id));
+
+        let results = game_results();
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result.winner().map(|s| s.account), Some(creator));
+        assert_eq!(result.loser().map(|s| s.account), Some(opponent));
+        assert!(result.seats.iter().all(|s| s.ai_difficulty.is_none()));
+    });
+}
+
+#[test]
+fn win_streaks_extend_hit_milestones_and_break() {
+    new_test_ext().execute_with(|| {
+        // Opponent concedes twice: creator reaches the mock milestone of two wins.
+        for _ in 0..2 {
+            let (game_id, _, opponent) = setup_new_game();
+            assert_ok!(Eterra::forfeit(RawOrigin::Signed(opponent).into(), game_id));
+            run_to_block(System::block_number() + 1);
+        }
+        assert_eq!(Eterra::win_streak(1), crate::WinStreak { current: 2, best: 2 });
+        assert_eq!(Eterra::win_streak(2), crate::WinStreak::default());
+        assert_eq!(streak_milestones(), vec![(1, 2)]);
+
+        // A loss resets the current streak but keeps the best one.
+        let (game_id, creator, _) = setup_new_game();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        assert_eq!(Eterra::win_streak(1), crate::WinStreak { current: 0, best: 2 });
+        assert_eq!(Eterra::win_streak(2), crate::WinStreak { current: 1, best: 1 });
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::Eterra(crate::Event::StreakBroken { who: 1, length: 2 })
+        )));
+    });
+}
+
+#[test]
+fn pve_games_do_not_affect_win_streaks() {
+    new_test_ext().execute_with(|| {
+        let human = 5u64;
+        ensure_preset_hand(human);
+        let id = BlakeTwo256::hash_of(&(human, AI_ACCOUNT, System::block_number()));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(human).into(), id));
+        assert_eq!(Eterra::win_streak(AI_ACCOUNT), crate::WinStreak::default());
+        assert!(!System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::Eterra(crate::Event::StreakExtended { .. })
+        )));
+    });
+}
+
+#[test]
+fn set_current_hand_stores_weighted_hand_power() {
+    new_test_ext().execute_with(|| {
+        let owner = 1;
+        let ids = mint_cards_for(owner, 5);
+        for (i, id) in ids.iter().enumerate() {
+            card_pallet::Cards::<Test>::mutate(id, |card| {
+                let card = card.as_mut().expect("minted card exists");
+                card.north = 2;
+                card.east = 3;
+                card.south = 4;
+                card.west = 5;
+                card.rarity = if i == 0 {
+                    card_pallet::RarityType::Legendary
+                } else {
+                    card_pallet::RarityType::Common
+                };
+            });
+        }
+
+        assert_ok!(Eterra::set_current_hand(
+            RawOrigin::Signed(owner).into(),
+            ids.clone()
+        ));
+
+        // Each card: edges 14 + peak 5 = 19; the legendary one is scaled to 28.
+        assert_eq!(Eterra::current_hand_power(owner), Some(4 * 19 + 28));
+        assert_eq!(Eterra::hand_power(&ids), 4 * 19 + 28);
+
+        // Leveling a card of the hand refreshes the stored score: 15 + 5 for that card.
+        card_pallet::CardXp::<Test>::insert(ids[1], 30);
+        assert_ok!(Cards::level_up_card(
+            RawOrigin::Signed(owner).into(),
+            ids[1],
+            cards::Direction::Top
+        ));
+        assert_eq!(Eterra::current_hand_power(owner), Some(3 * 19 + 20 + 28));
+
+        assert_ok!(Eterra::deregister(RawOrigin::Signed(owner).into(), false));
+        assert_eq!(Eterra::current_hand_power(owner), None);
+    });
+}
+
+#[test]
+fn annotate_move_attaches_to_own_latest_move_only() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let annotate = |who: u64, annotation| {
+            Eterra::annotate_move(RawOrigin::Signed(who).into(), game_id, annotation)
+        };
+
+        // Nothing has been played yet
+        assert_noop!(
+            annotate(opponent, crate::MoveAnnotation::Thinking),
+            crate::Error::<Test>::NoMoveToAnnotate
+        );
+
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            Move {
+                place_index_x: 0,
+                place_index_y: 0,
+                place_card: Card::new(5, 3, 2, 4).with_possession(Player::PlayerOne),
+            },
+        ));
+
+        // Only the creator made the latest move
+        assert_noop!(
+            annotate(opponent, crate::MoveAnnotation::NiceMove),
+            crate::Error::<Test>::NoMoveToAnnotate
+        );
+        assert_noop!(
+            annotate(3, crate::MoveAnnotation::NiceMove),
+            crate::Error::<Test>::PlayerNotInGame
+        );
+        assert_ok!(annotate(creator, crate::MoveAnnotation::Thinking));
+        assert_noop!(
+            annotate(creator, crate::MoveAnnotation::Oops),
+            crate::Error::<Test>::MoveAlreadyAnnotated
+        );
+
+        assert_eq!(
+            Eterra::move_annotations(game_id).into_inner(),
+            vec![crate::AnnotatedMove {
+                seat: 0,
+                move_no: 1,
+                annotation: crate::MoveAnnotation::Thinking,
+            }]
+        );
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::MoveAnnotated {
+            game_id,
+            seat: 0,
+            move_no: 1,
+            annotation: crate::MoveAnnotation::Thinking,
+        }));
+    });
+}
+
+#[test]
+fn annotate_move_follows_the_recorded_mover_after_a_skipped_turn() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let annotate = |who: u64| {
+            Eterra::annotate_move(
+                RawOrigin::Signed(who).into(),
+                game_id,
+                crate::MoveAnnotation::WellPlayed,
+            )
+        };
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            Move {
+                place_index_x: 0,
+                place_index_y: 0,
+                place_card: Card::new(5, 3, 2, 4).with_possession(Player::PlayerOne),
+            },
+        ));
+
+        // The opponent's turn is skipped: the creator is to move again, yet the latest
+        // placement is still theirs.
+        let limit = <Test as crate::Config>::BlocksToPlayLimit::get() as u64;
+        run_to_block(System::block_number() + limit + 1);
+        assert_ok!(Eterra::force_finish_turn(RawOrigin::Signed(creator).into(), game_id));
+        assert_eq!(Eterra::game_board(game_id).unwrap().player_turn, 0);
+
+        assert_noop!(annotate(opponent), crate::Error::<Test>::NoMoveToAnnotate);
+        assert_ok!(annotate(creator));
+        assert_eq!(Eterra::move_annotations(game_id)[0].move_no, 1);
+    });
+}
+
+#[test]
+fn annotate_move_is_capped_per_game() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let cap = <Test as crate::Config>::MaxMoveAnnotations::get() as u8;
+
+        for i in 0..=cap {
+            let (who, possession) = if i % 2 == 0 {
+                (creator, Player::PlayerOne)
+            } else {
+                (opponent, Player::PlayerTwo)
+            };
+            assert_ok!(Eterra::play(
+                RawOrigin::Signed(who).into(),
+                game_id,
+                Move {
+                    place_index_x: i / 4,
+                    place_index_y: i % 4,
+                    place_card: Card::new(1, 1, 1, 1).with_possession(possession),
+                },
+            ));
+            let result = Eterra::annotate_move(
+                RawOrigin::Signed(who).into(),
+                game_id,
+                crate::MoveAnnotation::GoodGame,
+            );
+            if i < cap {
+                assert_ok!(result);
+            } else {
+                assert_noop!(result, crate::Error::<Test>::AnnotationLimitReached);
+            }
+        }
+    });
+}
+
+#[test]
+fn live_games_track_games_until_they_end() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _) = setup_new_game();
+        assert_eq!(Eterra::live_games(pallet::GameMode::PvP), 1);
+        assert_eq!(Eterra::live_games(pallet::GameMode::PvE), 0);
+
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        assert_eq!(Eterra::live_games(pallet::GameMode::PvP), 0);
+    });
+}
+
+#[test]
+fn finished_games_accept_no_further_transitions() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.turn, crate::TurnState::Finished);
+
+        let any_move = Move {
+            place_index_x: 0,
+            place_index_y: 0,
+            place_card: Card::new(1, 1, 1, 1),
+        };
+        for who in [creator, opponent] {
+            assert_noop!(
+                Eterra::play(RawOrigin::Signed(who).into(), game_id, any_move.clone()),
+                crate::Error::<Test>::GameNotPlaying
+            );
+            assert_noop!(
+                Eterra::submit_hand(RawOrigin::Signed(who).into(), game_id, vec![]),
+                crate::Error::<Test>::GameNotPlaying
+            );
+        }
+        run_to_block(System::block_number() + 100);
+        assert_noop!(
+            Eterra::force_finish_turn(RawOrigin::Signed(opponent).into(), game_id),
+            crate::Error::<Test>::GameNotPlaying
+        );
+    });
+}
+
+#[test]
+fn pve_games_wait_for_the_human_hand_before_any_move() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 5;
+        ensure_preset_hand(human);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        let game_id = Eterra::player_games(&human, None).0[0];
+        assert_eq!(
+            GameStorage::<Test>::get(&game_id).unwrap().turn,
+            crate::TurnState::AwaitingHand
+        );
+
+        assert_noop!(
+            Eterra::play_from_hand(RawOrigin::Signed(human).into(), game_id, 0, 0, 0),
+            crate::Error::<Test>::HandNotSubmitted
+        );
+        run_to_block(System::block_number() + 100);
+        assert_noop!(
+            Eterra::force_finish_turn(RawOrigin::Signed(AI_ACCOUNT).into(), game_id),
+            crate::Error::<Test>::GameNotPlaying
+        );
+
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), game_id, vec![]));
+        assert_eq!(
+            GameStorage::<Test>::get(&game_id).unwrap().turn,
+            crate::TurnState::AwaitingMove(0)
+        );
+        assert_ok!(Eterra::play_from_hand(RawOrigin::Signed(human).into(), game_id, 0, 0, 0));
+    });
+}
+
+#[test]
+fn energy_rule_charges_placements_and_refills_the_next_player() {
+    new_test_ext().execute_with(|| {
+        DefaultRules::set(&eterra_core_types::rules::Rules::ENERGY);
+        let (game_id, _, _) = setup_new_game();
+        assert_eq!(Eterra::game_energy(game_id), Some([4, 4]));
+
+        let game = Eterra::game_board(game_id).unwrap();
+        let seat = game.player_turn as usize;
+        let mover = game.players[seat];
+        let play = |rank| {
+            Eterra::play(
+                frame_system::RawOrigin::Signed(mover).into(),
+                game_id,
+                Move {
+                    place_index_x: 0,
+                    place_index_y: 0,
+                    place_card: Card::new(rank, rank, rank, rank),
+                },
+            )
+        };
+        assert_noop!(play(9), crate::Error::<Test>::NotEnoughEnergy);
+        assert_ok!(play(5));
+
+        // 5/5/5/5 costs 3; the other seat regenerates 3 for its turn.
+        let mut expected = [7, 7];
+        expected[seat] = 1;
+        assert_eq!(Eterra::game_energy(game_id), Some(expected));
+    });
+}
+
+#[test]
+fn contract_api_creates_and_reads_pve_games() {
+    use crate::contract_api::GameContractApi;
+    new_test_ext().execute_with(|| {
+        let human: u64 = 21;
+        let hand = ensure_preset_hand(human);
+
+        let game_id = <Eterra as GameContractApi<u64>>::create_pve_game(&human).unwrap();
+        let view = <Eterra as GameContractApi<u64>>::game_state(&game_id).unwrap();
+        let game = Eterra::game_board(game_id).unwrap();
+        assert_eq!(view.players, game.players.to_vec());
+        assert_eq!(view.players[0], human);
+        assert_eq!((view.round, view.scores), (game.round, game.scores));
+        assert_eq!(<Eterra as GameContractApi<u64>>::game_state(&H256::zero()), None);
+
+        let card = card_pallet::Cards::<Test>::get(hand[0]).unwrap();
+        let stats = <Eterra as GameContractApi<u64>>::card_stats(hand[0]).unwrap();
+        assert_eq!(stats.owner, human);
+        assert_eq!(
+            [stats.north, stats.east, stats.south, stats.west],
+            [card.north, card.east, card.south, card.west]
+        );
+        assert_eq!(<Eterra as GameContractApi<u64>>::card_stats(u32::MAX), None);
+    });
+}
+
+#[test]
+fn card_wagers_pay_the_winner_and_refund_early_aborts() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (31u64, 32u64);
+        ensure_preset_hand(alice);
+        ensure_preset_hand(bob);
+        let alice_cards = mint_cards_for(alice, 2);
+        let bob_cards = mint_cards_for(bob, 2);
+
+        assert_ok!(Eterra::offer_wager(RawOrigin::Signed(alice).into(), bob, alice_cards[0]));
+        assert!(cards::Pallet::<Test>::is_escrowed(alice_cards[0]));
+        assert_noop!(
+            Eterra::offer_wager(RawOrigin::Signed(alice).into(), bob, alice_cards[1]),
+            crate::Error::<Test>::WagerAlreadyOffered
+        );
+        assert_noop!(
+            Eterra::accept_wager(RawOrigin::Signed(bob).into(), 99, bob_cards[0]),
+            crate::Error::<Test>::NoWagerOffer
+        );
+        assert_ok!(Eterra::accept_wager(RawOrigin::Signed(bob).into(), alice, bob_cards[0]));
+        let game_id = crate::ActiveGameOf::<Test>::get(alice, pallet::GameContext::Casual).unwrap();
+        assert_eq!(
+            Eterra::game_wager(game_id),
+            Some([(alice, alice_cards[0]), (bob, bob_cards[0])])
+        );
+
+        // Play until round 1 is under way, then Alice forfeits and Bob takes both cards.
+        while Eterra::game_board(game_id).unwrap().round == 0 {
+            let game = Eterra::game_board(game_id).unwrap();
+            let (mover, other) = if game.players[game.player_turn as usize] == alice {
+                (alice, bob)
+            } else {
+                (bob, alice)
+            };
+            ensure_my_turn(game_id, other, mover);
+        }
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(alice).into(), game_id));
+        for card_id in [alice_cards[0], bob_cards[0]] {
+            assert_eq!(card_pallet::Cards::<Test>::get(card_id).unwrap().owner, bob);
+            assert!(!cards::Pallet::<Test>::is_escrowed(card_id));
+        }
+
+        // A game abandoned before round 1 returns the stakes.
+        System::set_block_number(2);
+        assert_ok!(Eterra::offer_wager(RawOrigin::Signed(alice).into(), bob, alice_cards[1]));
+        assert_ok!(Eterra::accept_wager(RawOrigin::Signed(bob).into(), alice, bob_cards[1]));
+        let game_id = crate::ActiveGameOf::<Test>::get(alice, pallet::GameContext::Casual).unwrap();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(bob).into(), game_id));
+        assert_eq!(card_pallet::Cards::<Test>::get(alice_cards[1]).unwrap().owner, alice);
+        assert_eq!(card_pallet::Cards::<Test>::get(bob_cards[1]).unwrap().owner, bob);
+        assert!(!cards::Pallet::<Test>::is_escrowed(bob_cards[1]));
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::WagerSettled {
+            game_id,
+            winner: None,
+        }));
+    });
+}
+
+#[test]
+fn ranked_moves_record_latency_buckets_per_game_and_account() {
+    new_test_ext().execute_with(|| {
+        let (a, b) = (1u64, 2u64);
+        set_dummy_hand::<Test>(&a);
+        set_dummy_hand::<Test>(&b);
+        let game_id = <crate::Pallet<Test> as GameCreator<u64>>::create_from_matchmaking(&a, &b)
+            .expect("ranked game created");
+        let game = GameStorage::<Test>::get(game_id).unwrap();
+        let mover = game.players[game.player_turn as usize];
+
+        let now = frame_system::Pallet::<Test>::block_number();
+        frame_system::Pallet::<Test>::set_block_number(now + 3);
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(mover).into(),
+            game_id,
+            Move {
+                place_index_x: 0,
+                place_index_y: 0,
+                place_card: Card::new(1, 1, 1, 1),
+            }
+        ));
+
+        let timing = crate::MoveTimings::<Test>::get(mover);
+        assert_eq!((timing.moves, timing.total_blocks), (1, 3));
+        // 3 blocks falls in the `2..=3` bucket
+        assert_eq!(timing.buckets, [0, 0, 1, 0, 0, 0]);
+        assert_eq!(crate::GameMoveTimings::<Test>::get(game_id, mover), timing);
+        let other = if mover == a { b } else { a };
+        assert_eq!(crate::MoveTimings::<Test>::get(other).moves, 0);
+    });
+}
+
+#[test]
+fn featured_hands_can_be_borrowed_for_pve_until_they_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let human: u64 = 30;
+        let cards = vec![[9, 1, 1, 1], [1, 9, 1, 1], [1, 1, 9, 1], [1, 1, 1, 9], [5, 5, 5, 5]];
+
+        assert_noop!(
+            Eterra::publish_featured_hand(RawOrigin::Signed(human).into(), cards.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Eterra::publish_featured_hand(RawOrigin::Root.into(), cards[..4].to_vec()),
+            crate::Error::<Test>::InvalidFeaturedHand
+        );
+        assert_noop!(
+            Eterra::publish_featured_hand(RawOrigin::Root.into(), vec![[0, 1, 1, 1]; 5]),
+            crate::Error::<Test>::InvalidFeaturedHand
+        );
+        assert_ok!(Eterra::publish_featured_hand(RawOrigin::Root.into(), cards.clone()));
+        assert_noop!(
+            Eterra::use_featured_hand(RawOrigin::Signed(human).into(), Some(1)),
+            crate::Error::<Test>::FeaturedHandUnavailable
+        );
+
+        // The human owns no cards, yet can play PvE with the featured hand.
+        assert_ok!(Eterra::use_featured_hand(RawOrigin::Signed(human).into(), Some(0)));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        let game_id = crate::ActiveGameOf::<Test>::get(human, pallet::GameContext::PvE).unwrap();
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), game_id, vec![]));
+        let hand = crate::HandsOfGame::<Test>::get(game_id, human).unwrap();
+        let ranks: Vec<[u8; 4]> =
+            hand.iter().map(|e| [e.north, e.east, e.south, e.west]).collect();
+        assert_eq!(ranks, cards);
+        assert!(hand.iter().all(|e| e.card_id.is_none()));
+
+        // Once expired, the hand can no longer be selected nor stands in for a preset hand.
+        let expires_at = crate::FeaturedHands::<Test>::get(0).unwrap().expires_at;
+        System::set_block_number(expires_at + 1);
+        assert_noop!(
+            Eterra::use_featured_hand(RawOrigin::Signed(human).into(), Some(0)),
+            crate::Error::<Test>::FeaturedHandUnavailable
+        );
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(human).into(),
+                vec![human],
+                pallet::GameMode::PvE
+            ),
+            crate::Error::<Test>::PresetHandMissing
+        );
+    });
+}
+
+#[test]
+fn result_webhooks_accept_http_endpoints_and_summarize_finished_games() {
+    new_test_ext().execute_with(|| {
+        let url = b"https://bots.example/eterra".to_vec();
+        assert_noop!(
+            Eterra::set_result_webhooks(RawOrigin::Signed(1).into(), vec![url.clone()]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Eterra::set_result_webhooks(RawOrigin::Root.into(), vec![b"ftp://x".to_vec()]),
+            crate::Error::<Test>::InvalidWebhook
+        );
+        assert_noop!(
+            Eterra::set_result_webhooks(RawOrigin::Root.into(), vec![url.clone(); 5]),
+            crate::Error::<Test>::TooManyWebhooks
+        );
+        assert_ok!(Eterra::set_result_webhooks(RawOrigin::Root.into(), vec![url.clone()]));
+        assert_eq!(Eterra::result_webhooks().into_inner(), vec![url]);
+
+        let (game_id, creator, _) = setup_new_game();
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        let json = crate::webhooks::result_json::<Test>(
+            7,
+            &game_id,
+            Some(1),
+            crate::EndReason::Forfeit,
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            format!(
+                "{{\"block\":7,\"game_id\":\"0x{}\",\"reason\":\"forfeit\",\"winner\":1,\
+                 \"seats\":[{{\"account\":\"0x0100000000000000\",\"score\":5}},\
+                 {{\"account\":\"0x0200000000000000\",\"score\":5}}]}}",
+                hex::encode(game_id)
+            )
+        );
+    });
+}
+
+#[test]
+fn ai_without_a_playable_suggestion_falls_back_to_the_first_legal_placement() {
+    new_test_ext().execute_with(|| {
+        let human: u64 = 41;
+        ensure_preset_hand(human);
+        // With a block cap, the AI's turn waits in the queue.
+        MaxAiMovesPerBlock::set(&1);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+        ));
+        let game_id = crate::ActiveGameOf::<Test>::get(human, pallet::GameContext::PvE).unwrap();
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(human).into(), game_id, vec![]));
+        let mut game = GameStorage::<Test>::get(&game_id).unwrap();
+        if game.players[game.player_turn as usize] == human {
+            assert_ok!(Eterra::play_from_hand(RawOrigin::Signed(human).into(), game_id, 0, 2, 2));
+            game = GameStorage::<Test>::get(&game_id).unwrap();
+        }
+        assert_eq!(game.players[game.player_turn as usize], AI_ACCOUNT);
+
+        assert!(crate::Pallet::<Test>::play_fallback_ai_move(&game_id, &mut game, &AI_ACCOUNT));
+        assert_eq!(Eterra::ai_move_failures(), 1);
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::AiMoveFailed {
+            game_id,
+            player: AI_ACCOUNT,
+        }));
+
+        // The first empty cell got the AI's first affordable card and the turn moved on.
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert!(game.board[0][0].is_some());
+        let ai_hand = crate::HandsOfGame::<Test>::get(game_id, AI_ACCOUNT).unwrap();
+        assert_eq!(ai_hand.iter().filter(|entry| entry.used).count(), 1);
+        assert_eq!(game.players[game.player_turn as usize], human);
+    });
+}
+
+#[test]
+fn game_views_mirror_stored_games_and_active_contexts() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let game = GameStorage::<Test>::get(game_id).unwrap();
+
+        let view = Eterra::game_view(&game_id).unwrap();
+        assert_eq!(view.players, vec![creator, opponent]);
+        assert_eq!((view.turn, view.round, view.scores), (game.turn, game.round, game.scores));
+        assert_eq!(view.mode, Some(pallet::GameMode::PvP));
+        assert_eq!(view.context, Some(pallet::GameContext::Casual));
+        assert_eq!(
+            Eterra::active_games(&creator),
+            vec![(pallet::GameContext::Casual, game_id)]
+        );
+        assert_eq!(Eterra::game_view(&H256::zero()), None);
+    });
+}
+
+#[cfg(feature = "dev-mode")]
+#[test]
+fn root_can_fast_forward_live_games_in_dev_mode() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (game_id, creator, _) = setup_new_game();
+        let creator_cards = mint_cards_for(creator, 5);
+        assert_ok!(Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, creator_cards));
+
+        let mut board: crate::Board = Default::default();
+        board[1][1] = Some(Card::new(4, 4, 4, 4).with_possession(Possession::PlayerOne));
+        assert_noop!(
+            Eterra::dev_set_board(RawOrigin::Signed(creator).into(), game_id, board.clone(), 3),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Eterra::dev_set_board(RawOrigin::Root.into(), game_id, board.clone(), 3));
+        assert_ok!(Eterra::dev_set_scores(RawOrigin::Root.into(), game_id, (7, 3)));
+        assert_noop!(
+            Eterra::dev_mark_hand_used(RawOrigin::Root.into(), game_id, creator, vec![0, 9]),
+            crate::Error::<Test>::HandIndexOutOfRange
+        );
+        assert_ok!(Eterra::dev_mark_hand_used(
+            RawOrigin::Root.into(),
+            game_id,
+            creator,
+            vec![0, 2]
+        ));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::DevGameStateForced {
+            game_id,
+        }));
+
+        let game = GameStorage::<Test>::get(game_id).unwrap();
+        assert_eq!((game.board, game.round, game.scores), (board, 3, (7, 3)));
+        let used: Vec<bool> = HandsOfGame::<Test>::get(game_id, creator)
+            .unwrap()
+            .iter()
+            .map(|e| e.used)
+            .collect();
+        assert_eq!(used, vec![true, false, true, false, false]);
+
+        // Finished games are left alone.
+        assert_ok!(Eterra::forfeit(RawOrigin::Signed(creator).into(), game_id));
+        assert_noop!(
+            Eterra::dev_set_scores(RawOrigin::Root.into(), game_id, (0, 0)),
+            crate::Error::<Test>::GameNotPlaying
+        );
+    });
+}
+
+#[test]
+fn event_indices_do_not_depend_on_features() {
+    // `legacy-events` and `dev-mode` only add variants at their own pinned indices.
+    let depth = crate::Event::<Test>::AiBacklogHigh { depth: 7 }.encode();
+    assert_eq!(depth[0], 21);
+    let broken = crate::Event::<Test>::StreakBroken { who: 1, length: 3 }.encode();
+    assert_eq!(broken[0], 35);
+}
@@ -1,3 +1 @@
-use crate::types::card::Card;
-
-pub type Board = [[Option<Card>; 4]; 4];
+pub use eterra_core_types::board::Board;
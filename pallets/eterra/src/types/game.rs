@@ -1,7 +1,8 @@
 use crate::types::board::Board;
-use crate::types::card::Card;
+pub use eterra_core_types::board::Move;
+use frame_support::traits::Get;
 use frame_support::BoundedVec;
-use parity_scale_codec::{Decode, Encode, MaxEncodedLen}; // For Encode, Decode, MaxEncodedLen
+use parity_scale_codec::{Decode, Encode, Input, MaxEncodedLen}; // For Encode, Decode, MaxEncodedLen
 use scale_info::TypeInfo; // For TypeInfo
 
 // Define `Players` type alias
@@ -14,6 +15,23 @@ pub enum GameState {
     Finished { winner: Option<u8> }, // Ready to reward players
 }
 
+/// What a game is waiting for. Every call that advances a game is a transition of this
+/// machine, and each one matches on it exhaustively:
+///
+/// - `AwaitingHand` -> `AwaitingMove(first)` when the human of a PvE game submits a hand,
+/// - `AwaitingMove(seat)` -> `AwaitingMove(other)` when `seat` moves or is skipped,
+/// - `AwaitingHand` | `AwaitingMove(_)` -> `Finished` when the game ends.
+///
+/// `Finished` is terminal: nothing, the AI included, may act on a finished game.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum TurnState {
+    /// A PvE game waiting for the human's hand; nobody may move yet.
+    AwaitingHand,
+    /// The player in this seat is to move.
+    AwaitingMove(u8),
+    Finished,
+}
+
 pub trait GameProperties<Account, NumPlayers> {
     fn get_round(&self) -> u8;
     fn set_round(&mut self, round: u8);
@@ -29,7 +47,13 @@ pub trait GameProperties<Account, NumPlayers> {
     fn next_turn(&mut self);
 }
 
-#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone)]
+/// Layout version written into every new `Game`. Version 0 is the original layout, stored
+/// before the `version` field existed; version 2 appended `turn`.
+pub const CURRENT_GAME_VERSION: u8 = 2;
+
+/// New fields must be appended after `version` and only decoded when `version` says they are
+/// present, so games stored by older runtimes keep decoding after an upgrade.
+#[derive(Encode, TypeInfo, MaxEncodedLen, Clone)]
 pub struct Game<Account, BlockNumber, NumPlayers>
 where
     NumPlayers: Clone, // Add this bound
@@ -42,6 +66,94 @@ where
     pub max_rounds: u8,                        // Maximum number of rounds
     pub board: Board,
     pub scores: (u8, u8), // Scores for each player
+    pub version: u8,      // Layout version, see `CURRENT_GAME_VERSION`
+    /// Turn state machine; `state` and `player_turn` mirror it for older clients.
+    pub turn: TurnState,
+}
+
+impl<Account, BlockNumber, NumPlayers> Decode for Game<Account, BlockNumber, NumPlayers>
+where
+    Account: Decode,
+    BlockNumber: Decode,
+    NumPlayers: Clone + Get<u32>,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+        let state = GameState::decode(input)?;
+        let last_played_block = BlockNumber::decode(input)?;
+        let players = Players::<Account, NumPlayers>::decode(input)?;
+        let player_turn = u8::decode(input)?;
+        let round = u8::decode(input)?;
+        let max_rounds = u8::decode(input)?;
+        let board = Board::decode(input)?;
+        let scores = <(u8, u8)>::decode(input)?;
+        // Version 0 games end right after `scores`.
+        let version = match input.remaining_len()? {
+            Some(0) => 0,
+            _ => u8::decode(input)?,
+        };
+        // Older layouts carry no turn state; it follows from `state` and `player_turn`.
+        let turn = if version >= 2 {
+            TurnState::decode(input)?
+        } else {
+            match state {
+                GameState::Finished { .. } => TurnState::Finished,
+                _ => TurnState::AwaitingMove(player_turn),
+            }
+        };
+        Ok(Self {
+            state,
+            last_played_block,
+            players,
+            player_turn,
+            round,
+            max_rounds,
+            board,
+            scores,
+            version,
+            turn,
+        })
+    }
+}
+
+impl<Account, BlockNumber, NumPlayers> Game<Account, BlockNumber, NumPlayers>
+where
+    NumPlayers: Clone,
+{
+    /// Seat to move, if the game is waiting for a move.
+    pub fn seat_to_move(&self) -> Option<u8> {
+        match self.turn {
+            TurnState::AwaitingMove(seat) => Some(seat),
+            TurnState::AwaitingHand | TurnState::Finished => None,
+        }
+    }
+
+    /// Set the opening seat of a new game, optionally holding the first move until the
+    /// human's hand is in.
+    pub fn start(&mut self, first: u8, awaiting_hand: bool) {
+        self.player_turn = first;
+        self.turn = if awaiting_hand {
+            TurnState::AwaitingHand
+        } else {
+            TurnState::AwaitingMove(first)
+        };
+    }
+
+    /// `AwaitingHand` -> `AwaitingMove`. Returns false in any other state.
+    pub fn hand_received(&mut self) -> bool {
+        match self.turn {
+            TurnState::AwaitingHand => {
+                self.turn = TurnState::AwaitingMove(self.player_turn);
+                true
+            }
+            TurnState::AwaitingMove(_) | TurnState::Finished => false,
+        }
+    }
+
+    /// Enter the terminal state.
+    pub fn finish(&mut self, winner: Option<u8>) {
+        self.state = GameState::Finished { winner };
+        self.turn = TurnState::Finished;
+    }
 }
 
 impl<Account, BlockNumber, NumPlayers> GameProperties<Account, NumPlayers>
@@ -63,6 +175,9 @@ where
 
     fn set_player_turn(&mut self, turn: u8) {
         self.player_turn = turn;
+        if let TurnState::AwaitingMove(_) = self.turn {
+            self.turn = TurnState::AwaitingMove(turn);
+        }
     }
 
     fn get_state(&self) -> GameState {
@@ -71,6 +186,9 @@ where
 
     fn set_state(&mut self, state: GameState) {
         self.state = state;
+        if let GameState::Finished { .. } = state {
+            self.turn = TurnState::Finished;
+        }
     }
 
     fn borrow_players(&self) -> &Players<Account, NumPlayers> {
@@ -78,19 +196,18 @@ where
     }
 
     fn next_turn(&mut self) {
-        // If the game is already finished, don't proceed with next turn
-        if let GameState::Finished { winner: _ } = self.state {
-            log::debug!("Game is finished, skipping next turn.");
-            return;
-        }
-
-        // Switch turn
-        self.player_turn = (self.player_turn + 1) % 2;
-
-        // Increment round only when player_turn wraps back to 0
-        if self.player_turn == 0 {
-            self.round += 1;
-        }
+        // Only a game waiting for a move has a turn to pass on
+        let seat = match self.turn {
+            TurnState::AwaitingMove(seat) => seat,
+            TurnState::AwaitingHand | TurnState::Finished => {
+                log::debug!("Game is not awaiting a move, skipping next turn.");
+                return;
+            }
+        };
+
+        // Switch turn; the round advances when the turn wraps back to seat 0
+        (self.player_turn, self.round) = eterra_core_types::play::next_turn(seat, self.round);
+        self.turn = TurnState::AwaitingMove(self.player_turn);
 
         log::debug!(
             "After next_turn: player_turn = {}, round = {}",
@@ -99,10 +216,3 @@ where
         );
     }
 }
-
-#[derive(Encode, Decode, TypeInfo, PartialEq, Clone, Debug)]
-pub struct Move {
-    pub place_index_x: u8,
-    pub place_index_y: u8,
-    pub place_card: Card,
-}
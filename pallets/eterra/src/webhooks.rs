@@ -0,0 +1,136 @@
+//! Result webhooks: an opt-in offchain worker that posts a JSON summary of every game finished
+//! in a block to the endpoints stored in `ResultWebhooks`, so bots and websites can react to
+//! results without running an indexer. A node only posts once its operator inserted a
+//! `KEY_TYPE` key into the keystore; each body is signed with that key so receivers can check
+//! it came from a node they trust.
+
+use crate::pallet::{Config, EndReason, Event, GameEvent, ResultWebhooks};
+use crate::{GameId, Pallet};
+use alloc::{format, string::String, vec::Vec};
+use frame_support::pallet_prelude::ConstU32;
+use frame_support::BoundedVec;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::Encode;
+use sp_runtime::offchain::{http, Duration};
+use sp_runtime::traits::SaturatedConversion;
+use sp_runtime::{KeyTypeId, RuntimeAppPublic};
+
+/// Keystore key type of the webhook signing key.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"etrw");
+/// Endpoints `ResultWebhooks` may hold.
+pub const MAX_RESULT_WEBHOOKS: u32 = 4;
+/// Longest endpoint URL, in bytes.
+pub const MAX_WEBHOOK_URL_LEN: u32 = 256;
+/// Header carrying the hex-encoded public key that signed the body.
+pub const KEY_HEADER: &str = "X-Eterra-Key";
+/// Header carrying the hex-encoded sr25519 signature of the body.
+pub const SIGNATURE_HEADER: &str = "X-Eterra-Signature";
+/// How long a node waits for each endpoint to answer, in milliseconds.
+const POST_TIMEOUT_MS: u64 = 2_000;
+
+pub type WebhookUrl = BoundedVec<u8, ConstU32<MAX_WEBHOOK_URL_LEN>>;
+
+pub mod crypto {
+    //! The webhook signing key, an sr25519 key of type `KEY_TYPE`.
+    use super::KEY_TYPE;
+    use sp_runtime::app_crypto::{app_crypto, sr25519};
+    app_crypto!(sr25519, KEY_TYPE);
+}
+
+/// Whether `url` is a UTF-8 `http://` or `https://` URL.
+pub fn is_valid_url(url: &[u8]) -> bool {
+    core::str::from_utf8(url)
+        .is_ok_and(|url| url.starts_with("http://") || url.starts_with("https://"))
+}
+
+/// JSON summary of a game that finished in `block`, or `None` if the game is gone. Accounts
+/// are the hex of their SCALE encoding, scores include end-of-game bonuses, and `winner` is
+/// a seat index, `null` for a draw.
+pub fn result_json<T: Config>(
+    block: BlockNumberFor<T>,
+    game_id: &GameId<T>,
+    winner: Option<u8>,
+    reason: EndReason,
+) -> Option<String> {
+    let result = Pallet::<T>::game_result(game_id, winner)?;
+    let reason = match reason {
+        EndReason::Completed => "completed",
+        EndReason::Forfeit => "forfeit",
+        EndReason::Timeout => "timeout",
+        EndReason::Walkover => "walkover",
+    };
+    let winner = winner.map_or_else(|| String::from("null"), |seat| format!("{seat}"));
+    let seats = result
+        .seats
+        .iter()
+        .map(|s| {
+            let account = hex::encode(s.account.encode());
+            format!("{{\"account\":\"0x{}\",\"score\":{}}}", account, s.score)
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    Some(format!(
+        "{{\"block\":{},\"game_id\":\"0x{}\",\"reason\":\"{}\",\"winner\":{},\"seats\":[{}]}}",
+        block.saturated_into::<u64>(),
+        hex::encode(game_id.encode()),
+        reason,
+        winner,
+        seats,
+    ))
+}
+
+/// Post every game finished in block `n` to each endpoint. Does nothing without endpoints
+/// or without a signing key in the node's keystore. Failed posts are logged, not retried.
+pub fn post_finished_games<T: Config>(n: BlockNumberFor<T>) {
+    let endpoints = ResultWebhooks::<T>::get();
+    if endpoints.is_empty() {
+        return;
+    }
+    let Some(key) = crypto::Public::all().into_iter().next() else {
+        return;
+    };
+    let key_hex = format!("0x{}", hex::encode(key.encode()));
+
+    for record in frame_system::Pallet::<T>::read_events_no_consensus() {
+        let event = <T as Config>::RuntimeEvent::from(record.event);
+        let Ok(Event::Game {
+            game_id,
+            event: GameEvent::Finished { winner, reason },
+            ..
+        }) = event.try_into()
+        else {
+            continue;
+        };
+        let Some(body) = result_json::<T>(n, &game_id, winner, reason) else {
+            continue;
+        };
+        let Some(signature) = key.sign(&body) else {
+            log::warn!("result webhooks: signing key unavailable");
+            return;
+        };
+        let signature_hex = format!("0x{}", hex::encode(signature.encode()));
+        for url in endpoints.iter() {
+            if let Err(e) = post(url, &body, &key_hex, &signature_hex) {
+                log::warn!("result webhook post for {:?} failed: {:?}", game_id, e);
+            }
+        }
+    }
+}
+
+/// POST `body` to `url` and wait up to `POST_TIMEOUT_MS` for a 2xx answer.
+fn post(url: &[u8], body: &str, key: &str, signature: &str) -> Result<(), http::Error> {
+    let url = core::str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(POST_TIMEOUT_MS));
+    let pending = http::Request::post(url, sp_std::vec![body.as_bytes()])
+        .add_header("Content-Type", "application/json")
+        .add_header(KEY_HEADER, key)
+        .add_header(SIGNATURE_HEADER, signature)
+        .deadline(deadline)
+        .send()
+        .map_err(|_| http::Error::IoError)?;
+    let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+    if response.code / 100 != 2 {
+        return Err(http::Error::Unknown);
+    }
+    Ok(())
+}
@@ -42,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-    RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, EterraFaucet, Executive, Grandpa,
+    Hash, InherentDataExt, Nonce, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys,
+    System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -170,6 +171,198 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_eterra_daily_slots_runtime_api::DailySlotsApi<Block, AccountId> for Runtime {
+        fn roll_history(
+            who: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<pallet_eterra_daily_slots_runtime_api::RollRecord> {
+            pallet_eterra_daily_slots::Pallet::<Runtime>::roll_history_page(&who, offset, limit)
+        }
+
+        fn window_summary(who: AccountId) -> pallet_eterra_daily_slots_runtime_api::WindowSummary {
+            pallet_eterra_daily_slots::Pallet::<Runtime>::window_summary(&who)
+        }
+    }
+
+    impl pallet_eterra_runtime_api::EterraApi<Block, Hash, AccountId> for Runtime {
+        fn verify_replay(
+            game_id: Hash,
+            moves: Vec<pallet_eterra_runtime_api::ReplayMove>,
+        ) -> bool {
+            pallet_eterra::Pallet::<Runtime>::verify_replay(&game_id, &moves)
+        }
+
+        fn opponent_hand_summary(
+            game_id: Hash,
+            viewer: AccountId,
+        ) -> Option<pallet_eterra_runtime_api::OpponentHandSummary> {
+            pallet_eterra::Pallet::<Runtime>::opponent_hand_summary(&game_id, &viewer)
+        }
+
+        fn player_games(
+            who: AccountId,
+            cursor: Option<u32>,
+        ) -> pallet_eterra_runtime_api::PlayerGamesPage<Hash> {
+            let (games, next_cursor) = pallet_eterra::Pallet::<Runtime>::player_games(&who, cursor);
+            pallet_eterra_runtime_api::PlayerGamesPage { games, next_cursor }
+        }
+    }
+
+    impl pallet_eterra_runtime_api::GameViewApi<Block, Hash, AccountId, BlockNumber> for Runtime {
+        fn game(
+            game_id: Hash,
+        ) -> Option<pallet_eterra_runtime_api::GameView<AccountId, BlockNumber>> {
+            pallet_eterra::Pallet::<Runtime>::game_view(&game_id)
+        }
+
+        fn active_games(who: AccountId) -> Vec<(pallet_eterra_runtime_api::GameContext, Hash)> {
+            pallet_eterra::Pallet::<Runtime>::active_games(&who)
+        }
+
+        fn current_hand(who: AccountId) -> Vec<u32> {
+            pallet_eterra::Pallet::<Runtime>::current_hand_of(&who)
+                .map(|hand| hand.into_inner())
+                .unwrap_or_default()
+        }
+    }
+
+    impl pallet_eterra_runtime_api::ModerationApi<Block, Hash, AccountId> for Runtime {
+        fn move_timing(who: AccountId) -> pallet_eterra_runtime_api::MoveTiming {
+            pallet_eterra::MoveTimings::<Runtime>::get(&who)
+        }
+
+        fn game_move_timings(
+            game_id: Hash,
+        ) -> Vec<(AccountId, pallet_eterra_runtime_api::MoveTiming)> {
+            pallet_eterra::GameMoveTimings::<Runtime>::iter_prefix(&game_id).collect()
+        }
+    }
+
+    impl pallet_eterra_runtime_api::AiHintApi<Block, Hash> for Runtime {
+        fn suggest_move(
+            state: pallet_eterra_runtime_api::AiState,
+            difficulty: u8,
+        ) -> Option<pallet_eterra_runtime_api::AiAction> {
+            pallet_eterra::Pallet::<Runtime>::suggest_for_state(&state, difficulty)
+        }
+
+        fn suggest_for_game(
+            game_id: Hash,
+            difficulty: u8,
+        ) -> Option<pallet_eterra_runtime_api::AiAction> {
+            pallet_eterra::Pallet::<Runtime>::suggest_for_game(&game_id, difficulty)
+        }
+    }
+
+    impl pallet_eterra_runtime_api::DashboardApi<Block, Balance> for Runtime {
+        fn system_snapshot() -> pallet_eterra_runtime_api::SystemSnapshot<Balance> {
+            use pallet_eterra_runtime_api::GameMode;
+            let live_games = [GameMode::PvP, GameMode::PvE, GameMode::Bot]
+                .into_iter()
+                .map(|mode| {
+                    let live = pallet_eterra::LiveGames::<Runtime>::get(&mode);
+                    (mode, live)
+                })
+                .collect();
+            let faucet_balance = Balances::free_balance(&EterraFaucet::account_id());
+            pallet_eterra_runtime_api::SystemSnapshot {
+                live_games,
+                queue_size: pallet_eterra_simple_matchmaker::Pallet::<Runtime>::live_size(),
+                cards_minted: pallet_eterra_simple_tcg::NextCardId::<Runtime>::get(),
+                listings: pallet_eterra_simple_tcg::CardPrices::<Runtime>::count(),
+                faucet_balance,
+                tickets_outstanding: pallet_eterra_daily_slots::TotalTickets::<Runtime>::get(),
+                pending_ai_turns: pallet_eterra::PendingAiTurns::<Runtime>::decode_len()
+                    .unwrap_or(0) as u32,
+            }
+        }
+    }
+
+    impl pallet_eterra_runtime_api::GameConstantsApi<Block, Balance> for Runtime {
+        fn game_constants() -> pallet_eterra_runtime_api::GameConstants<Balance> {
+            use frame_support::traits::Get;
+            pallet_eterra_runtime_api::GameConstants {
+                hand_size: <Runtime as pallet_eterra::Config>::HandSize::get(),
+                max_rounds: <Runtime as pallet_eterra::Config>::MaxRounds::get(),
+                blocks_to_play_limit: <Runtime as pallet_eterra::Config>::BlocksToPlayLimit::get(),
+                board_size: eterra_core_types::geometry::BOARD_DIM,
+                mint_fee: pallet_eterra_simple_tcg::Pallet::<Runtime>::mint_fee(
+                    &pallet_eterra_simple_tcg::RarityType::Common,
+                ),
+                max_rolls_per_round:
+                    <Runtime as pallet_eterra_daily_slots::Config>::MaxRollsPerRound::get(),
+            }
+        }
+    }
+
+    impl pallet_eterra_gamer_runtime_api::ProfileApi<Block, AccountId> for Runtime {
+        fn profile(who: AccountId) -> pallet_eterra_gamer_runtime_api::ProfileSummary {
+            pallet_eterra_gamer::Pallet::<Runtime>::profile_summary(&who)
+        }
+
+        fn showcase(who: AccountId) -> Vec<u32> {
+            pallet_eterra_simple_tcg::Pallet::<Runtime>::showcase(&who).into_inner()
+        }
+    }
+
+    impl pallet_eterra_gamer_runtime_api::CosmeticsApi<Block, AccountId, Hash> for Runtime {
+        fn game_cosmetics(
+            game_id: Hash,
+        ) -> Vec<(AccountId, pallet_eterra_gamer_runtime_api::EquippedCosmetics)> {
+            pallet_eterra::GameStorage::<Runtime>::get(&game_id)
+                .map(|game| game.players.into_inner())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|who| {
+                    let equipped = pallet_eterra_gamer::Pallet::<Runtime>::equipped(&who);
+                    (who, equipped)
+                })
+                .collect()
+        }
+    }
+
+    impl pallet_eterra_simple_tcg_runtime_api::CardsApi<Block, AccountId, Balance> for Runtime {
+        fn card(
+            card_id: u32,
+        ) -> Option<pallet_eterra_simple_tcg_runtime_api::CardView<AccountId, Balance>> {
+            pallet_eterra_simple_tcg::Pallet::<Runtime>::card_view(card_id)
+        }
+
+        fn owned_cards(who: AccountId) -> Vec<u32> {
+            pallet_eterra_simple_tcg::OwnedCards::<Runtime>::get(&who).into_inner()
+        }
+    }
+
+    impl pallet_eterra_simple_tcg_runtime_api::CardMarketApi<Block, Balance> for Runtime {
+        fn floor_price(
+            rarity: pallet_eterra_simple_tcg_runtime_api::RarityType,
+            edition: pallet_eterra_simple_tcg_runtime_api::CardEdition,
+        ) -> Option<Balance> {
+            pallet_eterra_simple_tcg::Pallet::<Runtime>::floor_price(rarity, edition)
+        }
+
+        fn recent_sales(
+            rarity: pallet_eterra_simple_tcg_runtime_api::RarityType,
+            edition: pallet_eterra_simple_tcg_runtime_api::CardEdition,
+        ) -> Vec<Balance> {
+            pallet_eterra_simple_tcg::Pallet::<Runtime>::recent_sales(rarity, edition).into_inner()
+        }
+
+        fn listings_filtered(
+            min_price: Option<Balance>,
+            max_price: Option<Balance>,
+            rarity: Option<pallet_eterra_simple_tcg_runtime_api::RarityType>,
+            edition: Option<pallet_eterra_simple_tcg_runtime_api::CardEdition>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(u32, Balance)> {
+            pallet_eterra_simple_tcg::Pallet::<Runtime>::listings_filtered(
+                min_price, max_price, rarity, edition, offset, limit,
+            )
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
             uxt: <Block as BlockT>::Extrinsic,
@@ -285,11 +478,11 @@ impl_runtime_apis! {
         }
 
         fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<Vec<u8>> {
-            get_preset::<RuntimeGenesisConfig>(id, |_| None)
+            get_preset::<RuntimeGenesisConfig>(id, crate::genesis_config_presets::get_preset)
         }
 
         fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
-            vec![]
+            crate::genesis_config_presets::preset_names()
         }
     }
 }
@@ -0,0 +1,137 @@
+//! Genesis presets served through the `GenesisBuilder` runtime API.
+//!
+//! The "playtest" preset boots a chain where the well-known dev accounts can play right away:
+//! they are funded, own identical balanced starter decks, have a current hand set and have
+//! finished onboarding, the slot reels are weighted and an AI opponent is registered.
+
+use crate::{AccountId, Balance, EterraFaucet, FaucetPayoutAmount, UNIT};
+use alloc::{vec, vec::Vec};
+use frame_support::traits::Get;
+use serde_json::Value;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_genesis_builder::PresetId;
+use sp_keyring::AccountKeyring;
+
+/// Name of the preset with game content bootstrapped.
+pub const PLAYTEST_PRESET: &str = "playtest";
+
+/// Every playtest account receives the same deck; each card's edges sum to 20.
+const STARTER_DECK: [[u8; 4]; 10] = [
+    [5, 5, 5, 5],
+    [7, 3, 6, 4],
+    [3, 7, 4, 6],
+    [6, 4, 3, 7],
+    [4, 6, 7, 3],
+    [8, 2, 5, 5],
+    [2, 8, 5, 5],
+    [5, 5, 8, 2],
+    [5, 5, 2, 8],
+    [6, 6, 4, 4],
+];
+
+/// Cards of the starter deck placed in the current hand (by position in `STARTER_DECK`).
+const STARTER_HAND: [u32; 5] = [0, 1, 2, 3, 4];
+
+/// Same weights on every reel: common low symbols, rare high ones.
+const REEL_WEIGHTS: [(u32, u32); 10] = [
+    (0, 30),
+    (1, 20),
+    (2, 15),
+    (3, 10),
+    (4, 8),
+    (5, 6),
+    (6, 5),
+    (7, 3),
+    (8, 2),
+    (9, 1),
+];
+const REELS: u32 = 3;
+
+fn playtest_genesis() -> Value {
+    let players: Vec<AccountId> = vec![
+        AccountKeyring::Alice.to_account_id(),
+        AccountKeyring::Bob.to_account_id(),
+        AccountKeyring::Charlie.to_account_id(),
+        AccountKeyring::Dave.to_account_id(),
+    ];
+    // Ferdie never plays; it only answers PvE games.
+    let ai_account = AccountKeyring::Ferdie.to_account_id();
+    let root = AccountKeyring::Alice.to_account_id();
+    let authorities: Vec<(AuraId, GrandpaId)> = vec![(
+        AccountKeyring::Alice.public().into(),
+        sp_keyring::Ed25519Keyring::Alice.public().into(),
+    )];
+
+    let endowment: Balance = 1_000_000 * UNIT;
+    let mut endowed = players.clone();
+    endowed.push(AccountKeyring::Eve.to_account_id());
+    endowed.push(ai_account.clone());
+    endowed.push(EterraFaucet::account_id());
+
+    // Starter cards are minted in order, so player `i` owns ids `i * deck ..`.
+    let deck = STARTER_DECK.len() as u32;
+    let starter_cards: Vec<(AccountId, Vec<[u8; 4]>)> = players
+        .iter()
+        .map(|who| (who.clone(), STARTER_DECK.to_vec()))
+        .collect();
+    let current_hands: Vec<(AccountId, Vec<u32>)> = players
+        .iter()
+        .enumerate()
+        .map(|(i, who)| {
+            let first = i as u32 * deck;
+            (who.clone(), STARTER_HAND.iter().map(|c| first + c).collect())
+        })
+        .collect();
+    let reel_weights: Vec<(u32, Vec<(u32, u32)>)> =
+        (0..REELS).map(|reel| (reel, REEL_WEIGHTS.to_vec())).collect();
+
+    serde_json::json!({
+        "balances": {
+            "balances": endowed.iter().cloned().map(|k| (k, endowment)).collect::<Vec<_>>(),
+        },
+        "aura": {
+            "authorities": authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
+        },
+        "grandpa": {
+            "authorities": authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
+        },
+        "sudo": {
+            "key": Some(root),
+        },
+        "eterraFaucet": {
+            "payoutAmount": FaucetPayoutAmount::get(),
+        },
+        "eterraSimpleTcg": {
+            "starterCards": starter_cards,
+        },
+        "eterra": {
+            "aiAccounts": vec![ai_account],
+            "currentHands": current_hands,
+        },
+        "eterraGamer": {
+            "onboarded": players,
+        },
+        "eterraDailySlots": {
+            "reelWeights": reel_weights,
+        },
+    })
+}
+
+/// Patch for the preset `id`, if the runtime provides it.
+pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+    let patch = match id.as_ref() {
+        PLAYTEST_PRESET => playtest_genesis(),
+        _ => return None,
+    };
+    Some(
+        serde_json::to_string(&patch)
+            .expect("serialization to json is expected to work. qed.")
+            .into_bytes(),
+    )
+}
+
+/// Names of the presets served by `get_preset`.
+pub fn preset_names() -> Vec<PresetId> {
+    vec![PresetId::from(PLAYTEST_PRESET)]
+}
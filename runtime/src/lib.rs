@@ -7,6 +7,7 @@ pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 pub mod configs;
+pub mod genesis_config_presets;
 
 extern crate alloc;
 use alloc::vec::Vec;
@@ -20,15 +21,17 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 use codec::{Decode, Encode};
-use frame_support::parameter_types;
+use frame_support::{parameter_types, PalletId};
 // use frame_support::weights::IdentityFee;  // deleted as per instructions
 use frame_support::traits::ConstU32;
 use frame_support::traits::ConstU64;
 use frame_support::traits::ConstU8;
 use frame_support::traits::ConstU128;
 use frame_support::traits::ConstU16;
+use frame_support::traits::ConstBool;
 
 use frame_support::traits::Get;
+use pallet_eterra_simple_matchmaker::GameOutcomeObserver;
 // use frame_support::traits::Contains;  // deleted as per instructions
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -195,7 +198,27 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (SeedOnboardedAccounts,);
+
+/// Gamer pallet v0 -> v1: accounts owning cards before the ranked queue was gated on
+/// onboarding are marked onboarded, so the gate does not lock out existing players.
+pub struct SeedOnboardedAccounts;
+impl frame_support::traits::OnRuntimeUpgrade for SeedOnboardedAccounts {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+        let db = <Runtime as frame_system::Config>::DbWeight::get();
+        if pallet_eterra_gamer::Pallet::<Runtime>::on_chain_storage_version() >= 1 {
+            return db.reads(1);
+        }
+        let mut seeded = 0u64;
+        for owner in pallet_eterra_simple_tcg::OwnedCards::<Runtime>::iter_keys() {
+            pallet_eterra_gamer::Pallet::<Runtime>::mark_onboarded(&owner);
+            seeded += 1;
+        }
+        StorageVersion::new(1).put::<pallet_eterra_gamer::Pallet<Runtime>>();
+        db.reads_writes(1 + 2 * seeded, 1 + seeded)
+    }
+}
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -267,24 +290,72 @@ impl Get<u32> for MaxWeightEntries {
     }
 }
 
-// === Faucet configuration parameters ===
-use sp_runtime::AccountId32;
-
-/// Dev Alice account (//Alice) public key as AccountId32
-const ALICE: AccountId32 = AccountId32::new([
-    0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04, 0xa9, 0x9f, 0xd6,
-    0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d, 0xa2, 0x7d,
-]);
+pub struct MaxReelConfigHistory;
+impl Get<u32> for MaxReelConfigHistory {
+    fn get() -> u32 {
+        20 // past reel configurations kept for auditing
+    }
+}
 
+// === Pallet accounts ===
+// Keyless accounts derived from these ids hold faucet funds and collect fees.
 parameter_types! {
-    pub FaucetAccountParam: AccountId = ALICE.into();
-    pub AiBotAccountParam: AccountId = ALICE.into();
+    pub const FaucetPalletId: PalletId = PalletId(*b"etr/fcet");
+    pub const TcgPalletId: PalletId = PalletId(*b"etr/tcg_");
+    pub const GamerPalletId: PalletId = PalletId(*b"etr/gamr");
+    pub const MatchmakerPalletId: PalletId = PalletId(*b"etr/mtch");
 
     pub const PlayersPerMatchConst: u8 = 2;
     pub const QueueCapacityConst: u32 = 1024;
+    pub const MaxSeriesLengthConst: u8 = 5;
+    /// Five common 2/2/2/2 cards (`pallet_eterra::Pallet::hand_power`); rejects throwaway
+    /// 1/1/1/1 hands from ranked play.
+    pub const MinRankedHandPower: u32 = 50;
+    /// Queued players are paired with hands within about two average cards' worth of power
+    /// of each other.
+    pub const MaxHandPowerGap: u32 = 60;
+    /// Elo points between paired players; a 200 point favourite is expected to score 76%.
+    pub const MaxRatingGap: u32 = 200;
+    /// Queued players compared per pairing; each one costs a hand and a rating read.
+    pub const PairingWindow: u32 = 32;
+    /// Players who chose a region wait this long for a nearby opponent before being
+    /// paired across regions.
+    pub const MaxRegionWaitBlocks: BlockNumber = 5 * MINUTES;
+    /// Small enough not to gate newcomers, large enough to make join/leave churn cost.
+    pub const QueueDeposit: Balance = UNIT;
+    /// Each pairing writes a new game and its events; spread larger bursts over blocks.
+    pub const MaxGamesCreatedPerBlock: u32 = 16;
+    /// Clients heartbeat every few blocks while queued; one silent for this long is gone.
+    pub const QueueHeartbeatBlocks: BlockNumber = 2 * MINUTES;
+    /// A full 16-player league pays out over four blocks.
+    pub const SeasonRewardBatch: u32 = 4;
+    /// Champion, podium and top half of a league; everyone below is unpaid.
+    pub SeasonRewardTiers: Vec<pallet_eterra_simple_matchmaker::league::RewardTier<Balance>> =
+        alloc::vec![
+            pallet_eterra_simple_matchmaker::league::RewardTier {
+                up_to_rank: 1,
+                amount: 500 * UNIT,
+                card: Some([8, 8, 8, 8]),
+            },
+            pallet_eterra_simple_matchmaker::league::RewardTier {
+                up_to_rank: 3,
+                amount: 200 * UNIT,
+                card: None,
+            },
+            pallet_eterra_simple_matchmaker::league::RewardTier {
+                up_to_rank: 8,
+                amount: 50 * UNIT,
+                card: None,
+            },
+        ];
+    /// Rough cost of one Monte-Carlo AI turn at the configured `BaseIterations`.
+    pub const AiTurnWeightParam: frame_support::weights::Weight =
+        frame_support::weights::Weight::from_parts(20_000_000_000, 0);
 
     // Payout is 1000 whole tokens (adjust UNIT to your decimals)
     pub FaucetPayoutAmount: Balance = 1_000 * UNIT;
+    /// Paid for each of a player's first three finished games per day.
+    pub ActivityDripAmount: Balance = 10 * UNIT;
 
 }
 
@@ -293,6 +364,172 @@ impl Get<u8> for AiBotDifficulty {
     fn get() -> u8 { 60 }
 }
 
+/// AI turns `on_initialize` plays per block: as many as fit in a tenth of the block weight
+/// (10 at the current `AiTurnWeightParam`), so the AI backlog cannot crowd out extrinsics.
+pub struct MaxAiMovesPerBlockParam;
+impl Get<u32> for MaxAiMovesPerBlockParam {
+    fn get() -> u32 {
+        let share = configs::RuntimeBlockWeights::get().max_block.ref_time() / 10;
+        (share / AiTurnWeightParam::get().ref_time().max(1)).max(1) as u32
+    }
+}
+
+/// Origin operating game content (AI identities). Swap for a collective or multisig origin
+/// to run the game without the sudo key.
+pub type GameAdminOrigin = frame_system::EnsureRoot<AccountId>;
+/// Origin operating the in-game economy (slot odds, jackpot, XP issuance).
+pub type EconomyAdminOrigin = frame_system::EnsureRoot<AccountId>;
+/// Origin voiding corrupted or exploited games.
+pub type ModeratorOrigin = frame_system::EnsureRoot<AccountId>;
+
+/// Feeds gamer-pallet levels into PvE AI auto-selection.
+pub struct GamerLevelAdapter;
+impl pallet_eterra::PlayerLevelProvider<AccountId> for GamerLevelAdapter {
+    fn level_of(who: &AccountId) -> u8 {
+        pallet_eterra_gamer::Level::<Runtime>::get(who)
+    }
+}
+
+/// Lets `pallet_eterra::deregister` leave the ranked queue and burn the gamer profile.
+pub struct PresenceCleanupAdapter;
+impl pallet_eterra::PresenceCleanup<AccountId> for PresenceCleanupAdapter {
+    fn leave_queue(who: &AccountId) -> bool {
+        pallet_eterra_simple_matchmaker::Pallet::<Runtime>::remove_from_queue(who)
+    }
+    fn burn_profile(who: &AccountId) -> bool {
+        pallet_eterra_gamer::Pallet::<Runtime>::burn_profile(who)
+    }
+}
+
+pub struct GameActivityAdapter;
+impl pallet_eterra::GameActivityObserver<AccountId> for GameActivityAdapter {
+    fn on_game_completed(who: &AccountId, context: pallet_eterra::GameContext, rounds: u8) {
+        let _ = pallet_eterra_faucet::Pallet::<Runtime>::note_game_completed(who, rounds.into());
+        if context == pallet_eterra::GameContext::PvE {
+            pallet_eterra_gamer::Pallet::<Runtime>::note_onboarding_step(
+                who,
+                pallet_eterra_gamer::ONBOARDING_FIRST_GAME,
+            );
+        }
+    }
+}
+
+/// Experience granted per win of a streak milestone (a 5-win milestone pays 5x this).
+pub const STREAK_XP_PER_WIN: u128 = 100;
+
+/// Pays gamer-pallet XP when a win streak reaches a milestone.
+pub struct StreakRewardsAdapter;
+impl pallet_eterra::StreakMilestoneObserver<AccountId> for StreakRewardsAdapter {
+    fn on_streak_milestone(who: &AccountId, streak: u32) {
+        pallet_eterra_gamer::Pallet::<Runtime>::award_experience(
+            who,
+            STREAK_XP_PER_WIN.saturating_mul(streak as u128),
+        );
+    }
+}
+
+/// Experience each card placed by the winner of a game earns.
+pub const CARD_XP_PER_WIN: u32 = 10;
+
+/// Pays card XP to the cards a winner played; unused cards and losing hands earn nothing.
+pub struct CardXpAdapter;
+impl pallet_eterra::CardUsageObserver<AccountId> for CardXpAdapter {
+    fn on_cards_resolved(owner: &AccountId, used: &[u32], _unused: &[u32], won: bool) {
+        if won {
+            pallet_eterra_simple_tcg::Pallet::<Runtime>::award_xp(owner, used, CARD_XP_PER_WIN);
+        }
+    }
+}
+
+/// Settles community bounties against every finished game.
+pub struct BountyAdapter;
+impl pallet_eterra::GameResultObserver<AccountId> for BountyAdapter {
+    fn on_game_result(result: &eterra_core_types::result::GameResult<AccountId>) {
+        pallet_eterra_bounties::Pallet::<Runtime>::settle(result);
+    }
+    fn max_weight() -> frame_support::weights::Weight {
+        pallet_eterra_bounties::Pallet::<Runtime>::settle_weight()
+    }
+}
+
+/// Experience granted for the first solve of each daily puzzle.
+pub const PUZZLE_XP: u128 = 250;
+
+/// Pays puzzle solves out as gamer experience.
+pub struct PuzzleRewardsAdapter;
+impl pallet_eterra_puzzles::PuzzleRewards<AccountId> for PuzzleRewardsAdapter {
+    fn on_puzzle_solved(who: &AccountId, _puzzle_id: u32) {
+        pallet_eterra_gamer::Pallet::<Runtime>::award_experience(who, PUZZLE_XP);
+    }
+}
+
+/// Reports game results to the matchmaker (series, leagues) and to tournament brackets.
+pub struct GameOutcomeAdapter;
+impl GameOutcomeObserver<AccountId, pallet_eterra::GameId<Runtime>> for GameOutcomeAdapter {
+    fn on_game_finished(game_id: &pallet_eterra::GameId<Runtime>, winner: Option<&AccountId>) {
+        EterraSimpleMatchMaker::on_game_finished(game_id, winner);
+        EterraTournaments::on_game_finished(game_id, winner);
+    }
+    fn on_first_round_played(game_id: &pallet_eterra::GameId<Runtime>) {
+        EterraSimpleMatchMaker::on_first_round_played(game_id);
+    }
+    fn on_abandoned_before_first_round(game_id: &pallet_eterra::GameId<Runtime>, who: &AccountId) {
+        EterraSimpleMatchMaker::on_abandoned_before_first_round(game_id, who);
+    }
+    fn on_game_voided(game_id: &pallet_eterra::GameId<Runtime>) {
+        EterraSimpleMatchMaker::on_game_voided(game_id);
+        EterraTournaments::on_game_voided(game_id);
+    }
+    fn on_seat_reassigned(
+        game_id: &pallet_eterra::GameId<Runtime>,
+        old: &AccountId,
+        new: &AccountId,
+    ) {
+        EterraSimpleMatchMaker::on_seat_reassigned(game_id, old, new);
+        EterraTournaments::on_seat_reassigned(game_id, old, new);
+    }
+}
+
+/// Mints season reward cards through the TCG pallet.
+pub struct SeasonCardsAdapter;
+impl pallet_eterra_simple_matchmaker::SeasonCardRewards<AccountId> for SeasonCardsAdapter {
+    fn grant_card(who: &AccountId, edges: [u8; 4]) -> Option<u32> {
+        pallet_eterra_simple_tcg::Pallet::<Runtime>::grant_reward_card(who, edges).ok()
+    }
+}
+
+/// Cards making up a starter deck: one full hand.
+pub const STARTER_DECK_CARDS: u32 = 5;
+
+/// Routes onboarding steps (faucet claim → starter deck → first PvE game) into the gamer
+/// pallet, which gates the ranked queue on their completion.
+pub struct OnboardingAdapter;
+impl pallet_eterra_faucet::ClaimObserver<AccountId> for OnboardingAdapter {
+    fn on_claimed(who: &AccountId) {
+        pallet_eterra_gamer::Pallet::<Runtime>::note_onboarding_step(
+            who,
+            pallet_eterra_gamer::ONBOARDING_FAUCET_CLAIMED,
+        );
+    }
+}
+impl pallet_eterra_simple_tcg::MintObserver<AccountId> for OnboardingAdapter {
+    fn on_card_minted(owner: &AccountId, owned_cards: u32) {
+        if owned_cards >= STARTER_DECK_CARDS {
+            pallet_eterra_gamer::Pallet::<Runtime>::note_onboarding_step(
+                owner,
+                pallet_eterra_gamer::ONBOARDING_STARTER_DECK,
+            );
+        }
+    }
+}
+impl pallet_eterra_simple_matchmaker::OnboardingStatus<AccountId> for OnboardingAdapter {
+    fn is_onboarded(who: &AccountId) -> bool {
+        // Counted from the cards owned now, so bought or gifted cards make up a deck as well.
+        let owned = pallet_eterra_simple_tcg::OwnedCards::<Runtime>::decode_len(who).unwrap_or(0);
+        let has_starter_deck = owned as u32 >= STARTER_DECK_CARDS;
+        pallet_eterra_gamer::Pallet::<Runtime>::is_onboarded(who, has_starter_deck)
+    }
+}
 
 impl pallet_eterra::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
@@ -300,8 +537,37 @@ impl pallet_eterra::Config for Runtime {
     type MaxRounds = EterraMaxRounds;
     type BlocksToPlayLimit = EterraBlocksToPlayLimit;
     type HandSize = ConstU32<5>; // <<—— added
-    type AiAccount = AiBotAccountParam;
     type AiDifficulty = ConstU8<60>;
+    type PlayerLevel = GamerLevelAdapter;
+    type CardUsage = CardXpAdapter;
+    type GameOutcome = GameOutcomeAdapter;
+    type HandicapLevelGap = ConstU8<5>;
+    type HandicapHandBudget = ConstU16<0>;
+    type AiTurnWeight = AiTurnWeightParam;
+    type PresenceCleanup = PresenceCleanupAdapter;
+    type GameAdminOrigin = GameAdminOrigin;
+    type ModeratorOrigin = ModeratorOrigin;
+    type DefaultRules = ConstU8<0>;
+    type MaxRatedGamesPerDay = ConstU32<50>;
+    type GameActivity = GameActivityAdapter;
+    type MaxSpectators = ConstU32<10_000>;
+    type MaxAiMovesPerBlock = MaxAiMovesPerBlockParam;
+    type MaxPendingAiTurns = ConstU32<1_000>;
+    type StreakMilestone = ConstU32<5>;
+    type StreakRewards = StreakRewardsAdapter;
+    type MaxMoveAnnotations = ConstU32<32>;
+    type GameResults = BountyAdapter;
+    type DevTelemetry = ConstBool<false>;
+    type FeaturedHandDuration = ConstU32<DAYS>;
+    type HandSubmissionPeriod = ConstU32<HOURS>;
+    type MoveHistoryRetention = ConstU32<{ 7 * DAYS }>;
+    type AbandonAfterBlocks = ConstU32<{ 2 * DAYS }>;
+    type MaxExpiryScansPerBlock = ConstU32<16>;
+    type MaxDecks = ConstU32<8>;
+    type DecayPeriodBlocks = ConstU32<{ 7 * DAYS }>;
+    type RatingDecay = ConstU32<8>;
+    type RatingDecayFloor = ConstU32<1_000>;
+    type MaxDecayScansPerBlock = ConstU32<16>;
 }
 
 impl pallet_eterra_tcg::Config for Runtime {
@@ -317,7 +583,33 @@ impl pallet_eterra_simple_matchmaker::CurrentHandProvider<AccountId> for HandPro
     fn has_current_hand(who: &AccountId) -> bool {
         // Delegate to your game/cards pallet storage:
         // Adjust the path to your pallet module and types.
-        pallet_eterra::CurrentHandOf::<Runtime>::contains_key(who)
+        pallet_eterra::Pallet::<Runtime>::current_hand_of(who).is_some()
+    }
+
+    fn hand_summary(who: &AccountId) -> Option<pallet_eterra_simple_matchmaker::HandSummary> {
+        // Queued players are paired on the hand they bound when joining.
+        if let Some(ids) = pallet_eterra::QueuedHandOf::<Runtime>::get(who) {
+            return Some(pallet_eterra_simple_matchmaker::HandSummary {
+                cards: ids.len() as u32,
+                total_power: pallet_eterra::Pallet::<Runtime>::hand_power(&ids),
+            });
+        }
+        let ids = pallet_eterra::Pallet::<Runtime>::current_hand_of(who)?;
+        // Hands set before the power score existed fall back to computing it on the fly.
+        let total_power = pallet_eterra::CurrentHandPower::<Runtime>::get(who)
+            .unwrap_or_else(|| pallet_eterra::Pallet::<Runtime>::hand_power(&ids));
+        Some(pallet_eterra_simple_matchmaker::HandSummary {
+            cards: ids.len() as u32,
+            total_power,
+        })
+    }
+
+    fn bind_queued_hand(who: &AccountId) {
+        pallet_eterra::Pallet::<Runtime>::bind_queued_hand(who)
+    }
+
+    fn release_queued_hand(who: &AccountId) {
+        pallet_eterra::Pallet::<Runtime>::release_queued_hand(who)
     }
 }
 
@@ -327,6 +619,23 @@ impl pallet_eterra_simple_matchmaker::Config for Runtime {
     type QueueCapacity = QueueCapacityConst;
     type HandProvider = HandProviderAdapter; // uses the impl above
     type GameCreator  = pallet_eterra::Pallet<Runtime>;
+    type MaxSeriesLength = MaxSeriesLengthConst;
+    type MinRankedHandPower = MinRankedHandPower;
+    type MaxHandPowerGap = MaxHandPowerGap;
+    type Ratings = pallet_eterra::Pallet<Runtime>;
+    type MaxRatingGap = MaxRatingGap;
+    type PairingWindow = PairingWindow;
+    type Onboarding = OnboardingAdapter;
+    type MaxRegionWaitBlocks = MaxRegionWaitBlocks;
+    type Currency = Balances;
+    type QueueDeposit = QueueDeposit;
+    type MaxGamesCreatedPerBlock = MaxGamesCreatedPerBlock;
+    type LeagueAdminOrigin = GameAdminOrigin;
+    type QueueHeartbeatBlocks = QueueHeartbeatBlocks;
+    type PalletId = MatchmakerPalletId;
+    type SeasonRewardTiers = SeasonRewardTiers;
+    type SeasonRewardBatch = SeasonRewardBatch;
+    type SeasonCards = SeasonCardsAdapter;
 }
 
 impl pallet_eterra_simple_tcg::Config for Runtime {
@@ -341,20 +650,43 @@ impl pallet_eterra_simple_tcg::Config for Runtime {
     // NEW: fixed mint fee of 100 whole tokens (uses your UNIT = base units)
     type MintFee = ConstU128<{ 100 * UNIT }>;
 
-    // NEW: the faucet account that should receive the fee (Alice via parameter_types!)
-    type FaucetAccount = FaucetAccountParam;
+    // Mint and rename fees go to the pallet's own account
+    type PalletId = TcgPalletId;
+
+    // Root relays returning cards until an XCM origin for the parachain deployment exists
+    type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
+    type PriceHistoryLen = ConstU32<32>;
+    type OnMint = OnboardingAdapter;
+    type OnCardChanged = pallet_eterra::Pallet<Runtime>;
+    type MaxShowcase = ConstU32<12>;
+    type UnvaultDelay = ConstU32<{ 2 * DAYS }>;
+    // Listings start at 1 UNIT and move in steps of 1/100 UNIT
+    type MinListingPrice = ConstU128<UNIT>;
+    type PriceTick = ConstU128<{ UNIT / 100 }>;
+    // The cards minted toward onboarding stay with the account that minted them
+    type StarterDeckSize = ConstU32<STARTER_DECK_CARDS>;
+    type PricingAdminOrigin = EconomyAdminOrigin;
+    type RenameFee = ConstU128<{ 10 * UNIT }>;
+    type MaxRenameHistory = ConstU32<8>;
+    type NameModeratorOrigin = ModeratorOrigin;
+    type TradeCooldown = ConstU32<{ 3 * DAYS }>;
+    type XpPerLevel = ConstU32<100>;
 }
 
 impl pallet_eterra_daily_slots::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type TimeProvider = pallet_timestamp::Pallet<Runtime>;
+    type EconomyAdminOrigin = EconomyAdminOrigin;
     type MaxSlotLength = MaxSlotLength;
     type MaxOptionsPerSlot = MaxOptionsPerSlot;
     type MaxRollsPerRound = MaxRollsPerRound;
     type MaxRollHistoryLength = MaxRollHistoryLength;
     type MaxWeightEntries = MaxWeightEntries;
+    type MaxReelConfigHistory = MaxReelConfigHistory;
+    type DrawingPeriod = ConstU32<{ 7 * DAYS }>;
     type Currency = Balances;
     type RewardPerWin = RewardPerWinAmount; // defined below
+    type JackpotAccrualPerRoll = JackpotAccrualPerRollAmount;
 }
 
 pub struct RewardPerWinAmount;
@@ -364,9 +696,22 @@ impl frame_support::traits::Get<Balance> for RewardPerWinAmount {
     }
 }
 
+pub struct JackpotAccrualPerRollAmount;
+impl frame_support::traits::Get<Balance> for JackpotAccrualPerRollAmount {
+    fn get() -> Balance {
+        UNIT
+    }
+}
+
 impl pallet_eterra_faucet::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type PalletId = FaucetPalletId;
+    type ActivityDripAmount = ActivityDripAmount;
+    type ActivityDripGames = ConstU32<3>;
+    type MaxActivityDripsPerDay = ConstU32<5_000>;
+    type MinActivityDripRounds = ConstU32<3>;
+    type OnClaim = OnboardingAdapter;
 }
 
 impl pallet_eterra_monte_carlo_ai::Config for Runtime {
@@ -379,6 +724,29 @@ impl pallet_eterra_monte_carlo_ai::Config for Runtime {
     type RandomnessSeed = ConstU64<12345>; // deterministic-ish seed for hashing/entropy
 }
 
+impl pallet_eterra_bounties::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MinReward = ConstU128<{ 10 * UNIT }>;
+    type MaxConditions = ConstU32<4>;
+    type MaxOpenBounties = ConstU32<256>;
+    type MaxOpenBountiesPerPoster = ConstU32<8>;
+}
+
+impl pallet_eterra_puzzles::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PuzzleAdminOrigin = GameAdminOrigin;
+    type PuzzleDuration = ConstU32<DAYS>;
+    type RevealPeriod = ConstU32<DAYS>;
+    type Rewards = PuzzleRewardsAdapter;
+}
+
+impl pallet_eterra_tournaments::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type GameCreator = pallet_eterra::Pallet<Runtime>;
+    type HandProvider = HandProviderAdapter;
+}
+
 parameter_types! {
     pub const GamerTagMaxLen: u32 = 32;
     pub const AvatarCidMaxLen: u32 = 96; // or 128
@@ -388,8 +756,9 @@ parameter_types! {
 impl pallet_eterra_gamer::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
-    type ExpIssuerOrigin = frame_system::EnsureRoot<AccountId>;
-    type FaucetAccount = FaucetAccountParam;
+    type ExpIssuerOrigin = EconomyAdminOrigin;
+    type CosmeticIssuerOrigin = GameAdminOrigin;
+    type PalletId = GamerPalletId;
     type ChangeFee = GamerChangeFee;
     type MaxTagLen = GamerTagMaxLen;
     type MaxAvatarCidLen = AvatarCidMaxLen;
@@ -460,4 +829,13 @@ mod runtime {
 
     #[runtime::pallet_index(15)]
     pub type EterraGamer = pallet_eterra_gamer;
+
+    #[runtime::pallet_index(16)]
+    pub type EterraBounties = pallet_eterra_bounties;
+
+    #[runtime::pallet_index(17)]
+    pub type EterraPuzzles = pallet_eterra_puzzles;
+
+    #[runtime::pallet_index(18)]
+    pub type EterraTournaments = pallet_eterra_tournaments;
 }